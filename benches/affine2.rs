@@ -0,0 +1,45 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::ops::Mul;
+use support::*;
+
+bench_unop!(
+    affine2_inverse,
+    "affine2 inverse",
+    op => inverse,
+    from => random_srt_affine2
+);
+bench_binop!(
+    affine2_mul_affine2,
+    "affine2 mul affine2",
+    op => mul,
+    from => random_srt_affine2
+);
+bench_binop!(
+    affine2_transform_point2,
+    "affine2 transform point2",
+    op => transform_point2,
+    from1 => random_srt_affine2,
+    from2 => random_vec2
+);
+bench_binop!(
+    affine2_transform_vector2,
+    "affine2 transform vector2",
+    op => transform_vector2,
+    from1 => random_srt_affine2,
+    from2 => random_vec2
+);
+
+criterion_group!(
+    benches,
+    affine2_inverse,
+    affine2_mul_affine2,
+    affine2_transform_point2,
+    affine2_transform_vector2,
+);
+
+criterion_main!(benches);
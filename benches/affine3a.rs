@@ -0,0 +1,45 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::ops::Mul;
+use support::*;
+
+bench_unop!(
+    affine3a_inverse,
+    "affine3a inverse",
+    op => inverse,
+    from => random_srt_affine3a
+);
+bench_binop!(
+    affine3a_mul_affine3a,
+    "affine3a mul affine3a",
+    op => mul,
+    from => random_srt_affine3a
+);
+bench_binop!(
+    affine3a_transform_point3,
+    "affine3a transform point3",
+    op => transform_point3,
+    from1 => random_srt_affine3a,
+    from2 => random_vec3
+);
+bench_binop!(
+    affine3a_transform_vector3,
+    "affine3a transform vector3",
+    op => transform_vector3,
+    from1 => random_srt_affine3a,
+    from2 => random_vec3
+);
+
+criterion_group!(
+    benches,
+    affine3a_inverse,
+    affine3a_mul_affine3a,
+    affine3a_transform_point3,
+    affine3a_transform_vector3,
+);
+
+criterion_main!(benches);
@@ -4,7 +4,7 @@ mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::Mat3;
+use glam::{Mat3, Quat};
 use std::ops::Mul;
 use support::*;
 
@@ -24,6 +24,23 @@ bench_unop!(mat3_inverse, "mat3 inverse", op => inverse, from => random_mat3);
 bench_binop!(mat3_mul_mat3, "mat3 mul mat3", op => mul, from => random_mat3);
 bench_from_ypr!(mat3_from_ypr, "mat3 from ypr", ty => Mat3);
 
+#[inline]
+fn random_rotation_mat3(rng: &mut PCG32) -> Mat3 {
+    Mat3::from_quat(random_quat(rng))
+}
+
+#[inline]
+fn mat3_to_quat(m: Mat3) -> Quat {
+    Quat::from_rotation_mat3(&m)
+}
+
+bench_func!(
+    quat_from_mat3,
+    "quat from mat3",
+    op => mat3_to_quat,
+    from => random_rotation_mat3
+);
+
 criterion_group!(
     benches,
     mat3_transpose,
@@ -31,6 +48,7 @@ criterion_group!(
     mat3_inverse,
     mat3_mul_mat3,
     mat3_from_ypr,
+    quat_from_mat3,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,36 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use glam::Mat3A;
+use std::ops::Mul;
+use support::*;
+
+bench_unop!(
+    mat3a_transpose,
+    "mat3a transpose",
+    op => transpose,
+    from => random_mat3a
+);
+bench_unop!(
+    mat3a_determinant,
+    "mat3a determinant",
+    op => determinant,
+    from => random_mat3a
+);
+bench_unop!(mat3a_inverse, "mat3a inverse", op => inverse, from => random_mat3a);
+bench_binop!(mat3a_mul_mat3a, "mat3a mul mat3a", op => mul, from => random_mat3a);
+bench_from_ypr!(mat3a_from_ypr, "mat3a from ypr", ty => Mat3A);
+
+criterion_group!(
+    benches,
+    mat3a_transpose,
+    mat3a_determinant,
+    mat3a_inverse,
+    mat3a_mul_mat3a,
+    mat3a_from_ypr,
+);
+
+criterion_main!(benches);
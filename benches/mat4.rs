@@ -22,6 +22,14 @@ bench_unop!(
 );
 bench_unop!(mat4_inverse, "mat4 inverse", op => inverse, from => random_srt_mat4);
 bench_binop!(mat4_mul_mat4, "mat4 mul mat4", op => mul, from => random_srt_mat4);
+bench_binop!(
+    mat4_solve,
+    "mat4 solve",
+    op => solve,
+    from1 => random_srt_mat4,
+    from2 => random_vec4
+);
+bench_unop!(mat4_qr, "mat4 qr", op => qr, from => random_srt_mat4);
 bench_from_ypr!(mat4_from_ypr, "mat4 from ypr", ty => Mat4);
 
 pub fn mat4_from_srt(c: &mut Criterion) {
@@ -59,6 +67,8 @@ criterion_group!(
     mat4_determinant,
     mat4_inverse,
     mat4_mul_mat4,
+    mat4_solve,
+    mat4_qr,
     mat4_from_ypr,
     mat4_from_srt,
 );
@@ -4,9 +4,9 @@ mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::Quat;
+use glam::{Mat4, Quat};
 use std::ops::Mul;
-use support::{random_f32, random_quat, random_radians};
+use support::{random_f32, random_quat, random_radians, PCG32};
 
 bench_unop!(
     quat_conjugate,
@@ -49,6 +49,23 @@ bench_trinop!(
 
 bench_from_ypr!(quat_from_ypr, "quat from ypr", ty => Quat);
 
+#[inline]
+fn random_rotation_mat4(rng: &mut PCG32) -> Mat4 {
+    Mat4::from_quat(random_quat(rng))
+}
+
+#[inline]
+fn mat4_to_quat(m: Mat4) -> Quat {
+    Quat::from_rotation_mat4(&m)
+}
+
+bench_func!(
+    quat_from_mat4,
+    "quat from mat4",
+    op => mat4_to_quat,
+    from => random_rotation_mat4
+);
+
 criterion_group!(
     benches,
     quat_conjugate,
@@ -56,7 +73,8 @@ criterion_group!(
     quat_lerp,
     quat_slerp,
     quat_mul_quat,
-    quat_from_ypr
+    quat_from_ypr,
+    quat_from_mat4
 );
 
 criterion_main!(benches);
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 use core::f32;
-use glam::f32::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::f32::{
+    Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec3x4, Vec3x8, Vec4,
+};
 
 pub struct PCG32 {
     state: u64,
@@ -50,6 +52,14 @@ pub fn random_vec3a(rng: &mut PCG32) -> Vec3A {
     Vec3A::new(rng.next_f32(), rng.next_f32(), rng.next_f32())
 }
 
+pub fn random_vec3x4(rng: &mut PCG32) -> Vec3x4 {
+    Vec3x4::new(random_vec4(rng), random_vec4(rng), random_vec4(rng))
+}
+
+pub fn random_vec3x8(rng: &mut PCG32) -> Vec3x8 {
+    Vec3x8::from_halves([random_vec3x4(rng), random_vec3x4(rng)])
+}
+
 pub fn random_vec4(rng: &mut PCG32) -> Vec4 {
     Vec4::new(
         rng.next_f32(),
@@ -100,6 +110,10 @@ pub fn random_mat3(rng: &mut PCG32) -> Mat3 {
     Mat3::from_cols(random_vec3(rng), random_vec3(rng), random_vec3(rng))
 }
 
+pub fn random_mat3a(rng: &mut PCG32) -> Mat3A {
+    Mat3A::from_cols(random_vec3a(rng), random_vec3a(rng), random_vec3a(rng))
+}
+
 pub fn random_srt_mat3(rng: &mut PCG32) -> Mat3 {
     Mat3::from_scale_angle_translation(
         random_nonzero_vec2(rng),
@@ -114,3 +128,19 @@ pub fn random_srt_mat4(rng: &mut PCG32) -> Mat4 {
         random_vec3(rng),
     )
 }
+
+pub fn random_srt_affine2(rng: &mut PCG32) -> Affine2 {
+    Affine2::from_scale_angle_translation(
+        random_nonzero_vec2(rng),
+        random_radians(rng),
+        random_vec2(rng),
+    )
+}
+
+pub fn random_srt_affine3a(rng: &mut PCG32) -> Affine3A {
+    Affine3A::from_scale_rotation_translation(
+        random_nonzero_vec3(rng),
+        random_quat(rng),
+        random_vec3(rng),
+    )
+}
@@ -4,9 +4,9 @@ mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::f32::Vec2;
+use glam::f32::{Mat3, Vec2};
 use std::ops::Mul;
-use support::{random_mat2, random_srt_mat3, random_vec2};
+use support::{random_mat2, random_srt_mat3, random_vec2, PCG32};
 
 euler!(vec2_euler, "vec2 euler", ty => Vec2, storage => Vec2, zero => Vec2::zero(), rand => random_vec2);
 
@@ -41,13 +41,71 @@ bench_binop!(
     from2 => random_vec2
 );
 
+// Transforms a slice of points with `transform_points2`, or with `transform_point2` for each
+// element, so the batched version can be compared against the per element one.
+macro_rules! bench_mat3_transform_slice {
+    ($name: ident, $desc: expr, op => $op: expr) => {
+        pub(crate) fn $name(c: &mut Criterion) {
+            const SIZE: usize = 1 << 10;
+            let mut rng = PCG32::default();
+            let mat = random_srt_mat3(&mut rng);
+            let inputs =
+                criterion::black_box((0..SIZE).map(|_| random_vec2(&mut rng)).collect::<Vec<_>>());
+            let mut outputs = inputs.clone();
+            c.bench_function($desc, |b| {
+                b.iter(|| {
+                    outputs.copy_from_slice(&inputs);
+                    $op(&mat, &mut outputs);
+                })
+            });
+            criterion::black_box(outputs);
+        }
+    };
+}
+
+bench_mat3_transform_slice!(
+    mat3_transform_point2_each,
+    "mat3 transform point2 x1024",
+    op => |mat: &Mat3, points: &mut [Vec2]| {
+        for point in points.iter_mut() {
+            *point = mat.transform_point2(*point);
+        }
+    }
+);
+
+bench_mat3_transform_slice!(
+    mat3_transform_points2,
+    "mat3 transform points2 x1024",
+    op => |mat: &Mat3, points: &mut [Vec2]| mat.transform_points2(points)
+);
+
+bench_mat3_transform_slice!(
+    mat3_transform_vector2_each,
+    "mat3 transform vector2 x1024",
+    op => |mat: &Mat3, vectors: &mut [Vec2]| {
+        for vector in vectors.iter_mut() {
+            *vector = mat.transform_vector2(*vector);
+        }
+    }
+);
+
+bench_mat3_transform_slice!(
+    mat3_transform_vectors2,
+    "mat3 transform vectors2 x1024",
+    op => |mat: &Mat3, vectors: &mut [Vec2]| mat.transform_vectors2(vectors)
+);
+
 criterion_group!(
     benches,
     vec2_euler,
     mat2_mul_vec2,
     mat3_transform_point2,
     mat3_transform_vector2,
-    vec2_angle_between
+    vec2_angle_between,
+    mat3_transform_point2_each,
+    mat3_transform_points2,
+    mat3_transform_vector2_each,
+    mat3_transform_vectors2
 );
 
 criterion_main!(benches);
@@ -51,7 +51,7 @@ bench_binop!(
 #[inline]
 fn vec3_to_rgb_op(v: Vec3) -> u32 {
     let (red, green, blue) = (v.min(Vec3::one()).max(Vec3::zero()) * 255.0).into();
-    ((red as u32) << 16 | (green as u32) << 8 | (blue as u32)).into()
+    (red as u32) << 16 | (green as u32) << 8 | (blue as u32)
 }
 
 #[inline]
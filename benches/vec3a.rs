@@ -6,7 +6,7 @@ mod support;
 use criterion::{criterion_group, criterion_main, Criterion};
 use glam::f32::{Vec3, Vec3A};
 use std::ops::Mul;
-use support::{random_mat3, random_quat, random_vec3a};
+use support::{random_mat3, random_mat3a, random_quat, random_vec3a};
 
 bench_binop!(
     quat_mul_vec3a,
@@ -24,10 +24,18 @@ bench_binop!(
     from2 => random_vec3a
 );
 
+bench_binop!(
+    mat3a_mul_vec3a,
+    "mat3a mul vec3a",
+    op => mul,
+    from1 => random_mat3a,
+    from2 => random_vec3a
+);
+
 #[inline]
 fn vec3a_to_rgb_op(v: Vec3A) -> u32 {
     let (red, green, blue) = (v.min(Vec3A::one()).max(Vec3A::zero()) * 255.0).into();
-    ((red as u32) << 16 | (green as u32) << 8 | (blue as u32)).into()
+    (red as u32) << 16 | (green as u32) << 8 | (blue as u32)
 }
 
 #[inline]
@@ -99,6 +107,7 @@ criterion_group!(
     benches,
     quat_mul_vec3a,
     mat3_mul_vec3a,
+    mat3a_mul_vec3a,
     vec3a_angle_between,
     vec3a_euler,
     vec3a_to_rgb,
@@ -0,0 +1,57 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+mod support;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::ops::Mul;
+use support::{random_quat, random_srt_mat4, random_vec3x8};
+
+bench_binop!(
+    quat_mul_vec3x8,
+    "quat mul vec3x8",
+    op => mul,
+    from1 => random_quat,
+    from2 => random_vec3x8
+);
+
+bench_binop!(
+    mat4_transform_point3x8,
+    "mat4 transform point3x8",
+    op => transform_point3x8,
+    from1 => random_srt_mat4,
+    from2 => random_vec3x8
+);
+
+bench_binop!(
+    mat4_transform_vector3x8,
+    "mat4 transform vector3x8",
+    op => transform_vector3x8,
+    from1 => random_srt_mat4,
+    from2 => random_vec3x8
+);
+
+bench_unop!(
+    vec3x8_normalize,
+    "vec3x8 normalize",
+    op => normalize,
+    from => random_vec3x8
+);
+
+bench_binop!(
+    vec3x8_cross,
+    "vec3x8 cross",
+    op => cross,
+    from => random_vec3x8
+);
+
+criterion_group!(
+    benches,
+    quat_mul_vec3x8,
+    mat4_transform_point3x8,
+    mat4_transform_vector3x8,
+    vec3x8_normalize,
+    vec3x8_cross,
+);
+
+criterion_main!(benches);
@@ -15,6 +15,27 @@ bench_binop!(
     from2 => random_vec4
 );
 
-criterion_group!(benches, vec4_mul_mat4,);
+bench_unop!(vec4_sin_cos, "vec4 sin_cos", op => sin_cos, from => random_vec4);
+
+bench_unop!(vec4_exp, "vec4 exp", op => exp, from => random_vec4);
+
+bench_unop!(vec4_ln, "vec4 ln", op => ln, from => random_vec4);
+
+bench_binop!(
+    vec4_atan2,
+    "vec4 atan2",
+    op => atan2,
+    from1 => random_vec4,
+    from2 => random_vec4
+);
+
+criterion_group!(
+    benches,
+    vec4_mul_mat4,
+    vec4_sin_cos,
+    vec4_exp,
+    vec4_ln,
+    vec4_atan2,
+);
 
 criterion_main!(benches);
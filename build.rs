@@ -2,29 +2,96 @@ extern crate version_check as rustc;
 use std::env;
 
 fn main() {
+    // declare the cfgs this script may emit so newer compilers don't warn about them
+    for cfg in &[
+        "vec3a_sse2",
+        "vec3a_sse41",
+        "vec3a_fma",
+        "vec3a_f32",
+        "vec3a_neon",
+        "vec3a_simd128",
+        "vec4_sse2",
+        "vec4_sse41",
+        "vec4_fma",
+        "vec4_f32",
+        "vec4_f32_align16",
+        "vec4_neon",
+        "vec4_simd128",
+        "mat4_avx",
+        "vec3x8_avx",
+    ] {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+    }
+
     if !rustc::is_min_version("1.36.0").unwrap_or(false) {
         panic!("The minimum supported version of Rust for `glam` is 1.36.0");
     }
 
     let force_scalar_math = env::var("CARGO_FEATURE_SCALAR_MATH").is_ok();
 
-    let target_feature_sse2 = match env::var("CARGO_CFG_TARGET_FEATURE") {
-        Ok(cfg) => cfg.split(',').find(|&f| f == "sse2").is_some(),
-        Err(_) => false,
-    };
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let target_feature_sse2 = target_features.split(',').any(|f| f == "sse2");
+    // the optional x86 extensions are only used on top of the SSE2 implementation
+    let target_feature_sse41 = target_features.split(',').any(|f| f == "sse4.1");
+    let target_feature_fma = target_features.split(',').any(|f| f == "fma");
+    let target_feature_avx = target_features.split(',').any(|f| f == "avx");
+
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+
+    // aarch64 NEON intrinsics were stabilised in Rust 1.59
+    let target_feature_neon = target_arch == "aarch64"
+        && target_features.split(',').any(|f| f == "neon")
+        && rustc::is_min_version("1.59.0").unwrap_or(false);
+
+    // wasm32 simd128 intrinsics were stabilised in Rust 1.54
+    let target_feature_simd128 = target_arch == "wasm32"
+        && target_features.split(',').any(|f| f == "simd128")
+        && rustc::is_min_version("1.54.0").unwrap_or(false);
 
     if target_feature_sse2 && !force_scalar_math {
         println!("cargo:rustc-cfg=vec3a_sse2");
+        if target_feature_sse41 {
+            println!("cargo:rustc-cfg=vec3a_sse41");
+        }
+        if target_feature_fma {
+            println!("cargo:rustc-cfg=vec3a_fma");
+        }
     } else {
+        if target_feature_neon && !force_scalar_math {
+            // NEON operates on the 16 byte aligned scalar storage
+            println!("cargo:rustc-cfg=vec3a_neon");
+        }
+        if target_feature_simd128 && !force_scalar_math {
+            // as does simd128
+            println!("cargo:rustc-cfg=vec3a_simd128");
+        }
         println!("cargo:rustc-cfg=vec3a_f32");
     }
 
     if target_feature_sse2 && !force_scalar_math {
         println!("cargo:rustc-cfg=vec4_sse2");
+        if target_feature_sse41 {
+            println!("cargo:rustc-cfg=vec4_sse41");
+        }
+        if target_feature_fma {
+            println!("cargo:rustc-cfg=vec4_fma");
+        }
+        if target_feature_avx {
+            // `Mat4` columns are multiplied in pairs using 256 bit registers
+            println!("cargo:rustc-cfg=mat4_avx");
+            // `Vec3x8` stores each component in a single 256 bit register
+            println!("cargo:rustc-cfg=vec3x8_avx");
+        }
     } else {
         if !force_scalar_math {
             // simd not available but not explicitly disabled so maintain 16 byte alignment
             println!("cargo:rustc-cfg=vec4_f32_align16");
+            if target_feature_neon {
+                println!("cargo:rustc-cfg=vec4_neon");
+            }
+            if target_feature_simd128 {
+                println!("cargo:rustc-cfg=vec4_simd128");
+            }
         }
         println!("cargo:rustc-cfg=vec4_f32");
     }
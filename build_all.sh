@@ -1,5 +1,8 @@
 #!/bin/sh
 
 cargo test --features "bytemuck mint rand serde debug-glam-assert transform-types" && \
+RUSTFLAGS="-C target-feature=+sse4.1,+fma,+avx" cargo test --features "bytemuck mint rand serde debug-glam-assert transform-types" && \
 cargo test --features "scalar-math bytemuck mint rand serde debug-glam-assert transform-types" && \
-cargo test --no-default-features --features "libm scalar-math bytemuck mint rand serde debug-glam-assert transform-types"
+cargo test --no-default-features --features "libm scalar-math bytemuck mint rand serde debug-glam-assert transform-types" && \
+cargo check --tests --target aarch64-unknown-linux-gnu --features "bytemuck mint rand serde debug-glam-assert transform-types" && \
+RUSTFLAGS="-C target-feature=+simd128" cargo check --tests --target wasm32-unknown-unknown --features "bytemuck mint rand serde debug-glam-assert transform-types"
@@ -0,0 +1,69 @@
+/// Euler rotation sequences.
+///
+/// The angles are applied starting from the right, i.e. `EulerRot::YXZ` builds the rotation
+/// `Ry(a) * Rx(b) * Rz(c)`. This corresponds to an intrinsic rotation about the Y axis, then the
+/// rotated X axis and finally the twice rotated Z axis, or equivalently to an extrinsic rotation
+/// about the fixed Z, X and Y axes in that order.
+///
+/// The first six variants are Tait-Bryan angles, where all three axes are distinct. The last six
+/// are proper Euler angles, where the first and last axes are the same.
+///
+/// The default is `EulerRot::YXZ`, which is the yaw, pitch and roll order used by
+/// `Quat::from_rotation_ypr`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    /// Intrinsic three-axis rotation ZYX
+    ZYX,
+    /// Intrinsic three-axis rotation ZXY
+    ZXY,
+    /// Intrinsic three-axis rotation YXZ
+    YXZ,
+    /// Intrinsic three-axis rotation YZX
+    YZX,
+    /// Intrinsic three-axis rotation XYZ
+    XYZ,
+    /// Intrinsic three-axis rotation XZY
+    XZY,
+
+    /// Intrinsic two-axis rotation ZYZ
+    ZYZ,
+    /// Intrinsic two-axis rotation ZXZ
+    ZXZ,
+    /// Intrinsic two-axis rotation YXY
+    YXY,
+    /// Intrinsic two-axis rotation YZY
+    YZY,
+    /// Intrinsic two-axis rotation XYX
+    XYX,
+    /// Intrinsic two-axis rotation XZX
+    XZX,
+}
+
+impl Default for EulerRot {
+    /// Default `YXZ` as yaw (y-axis), pitch (x-axis), roll (z-axis).
+    #[inline]
+    fn default() -> Self {
+        EulerRot::YXZ
+    }
+}
+
+impl EulerRot {
+    /// Returns the indices of the three rotation axes in the order the angles are given.
+    #[inline]
+    pub(crate) fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerRot::ZYX => (2, 1, 0),
+            EulerRot::ZXY => (2, 0, 1),
+            EulerRot::YXZ => (1, 0, 2),
+            EulerRot::YZX => (1, 2, 0),
+            EulerRot::XYZ => (0, 1, 2),
+            EulerRot::XZY => (0, 2, 1),
+            EulerRot::ZYZ => (2, 1, 2),
+            EulerRot::ZXZ => (2, 0, 2),
+            EulerRot::YXY => (1, 0, 1),
+            EulerRot::YZY => (1, 2, 1),
+            EulerRot::XYX => (0, 1, 0),
+            EulerRot::XZX => (0, 2, 0),
+        }
+    }
+}
@@ -0,0 +1,353 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{Mat2, Mat3, Vec2};
+use core::{fmt, ops::Mul};
+
+#[cfg(feature = "std")]
+use std::iter::Product;
+
+const ZERO: Affine2 = Affine2 {
+    matrix2: const_mat2!([0.0; 4]),
+    translation: const_vec2!([0.0; 2]),
+};
+const IDENTITY: Affine2 = Affine2 {
+    matrix2: const_mat2!([1.0, 0.0], [0.0, 1.0]),
+    translation: const_vec2!([0.0; 2]),
+};
+
+/// A 2D affine transform, which can represent translation, rotation, scaling and shear.
+///
+/// The transform is stored as a 2x2 `Mat2` linear part followed by a `Vec2` translation, which
+/// is equivalent to a 3x3 matrix where the last row is implicitly `[0, 0, 1]`.
+///
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Affine2 {
+    pub matrix2: Mat2,
+    pub translation: Vec2,
+}
+
+impl Default for Affine2 {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for Affine2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}]",
+            self.matrix2.x_axis, self.matrix2.y_axis, self.translation
+        )
+    }
+}
+
+impl Affine2 {
+    /// Creates an affine transform with all elements set to `0.0`.
+    ///
+    /// The resulting transform is not invertible.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates an identity affine transform.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates an affine transform from two column vectors of the linear part and a
+    /// `translation`.
+    #[inline]
+    pub fn from_cols(x_axis: Vec2, y_axis: Vec2, translation: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::from_cols(x_axis, y_axis),
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from a `[f32; 6]` storing the two linear part columns
+    /// followed by the translation.
+    #[inline]
+    pub fn from_cols_array(m: &[f32; 6]) -> Self {
+        Self {
+            matrix2: Mat2::from_cols(Vec2::new(m[0], m[1]), Vec2::new(m[2], m[3])),
+            translation: Vec2::new(m[4], m[5]),
+        }
+    }
+
+    /// Creates a `[f32; 6]` storing the two linear part columns followed by the translation.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f32; 6] {
+        let (m00, m01) = self.matrix2.x_axis.into();
+        let (m10, m11) = self.matrix2.y_axis.into();
+        let (m20, m21) = self.translation.into();
+        [m00, m01, m10, m11, m20, m21]
+    }
+
+    /// Creates an affine transform from a `[[f32; 2]; 3]` storing the two linear part columns
+    /// followed by the translation.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f32; 2]; 3]) -> Self {
+        Self {
+            matrix2: Mat2::from_cols(m[0].into(), m[1].into()),
+            translation: m[2].into(),
+        }
+    }
+
+    /// Creates a `[[f32; 2]; 3]` storing the two linear part columns followed by the
+    /// translation.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f32; 2]; 3] {
+        [
+            self.matrix2.x_axis.into(),
+            self.matrix2.y_axis.into(),
+            self.translation.into(),
+        ]
+    }
+
+    /// Creates an affine transform that changes scale.
+    ///
+    /// Note that if any scale is zero the transform will be non-invertible.
+    #[inline]
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::from_scale(scale),
+            translation: Vec2::zero(),
+        }
+    }
+
+    /// Creates an affine transform from the given rotation `angle` (in radians).
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        Self {
+            matrix2: Mat2::from_angle(angle),
+            translation: Vec2::zero(),
+        }
+    }
+
+    /// Creates an affine transformation from the given 2D `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::identity(),
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from a 2x2 matrix (expressing scale, shear and rotation).
+    #[inline]
+    pub fn from_mat2(matrix2: Mat2) -> Self {
+        Self {
+            matrix2,
+            translation: Vec2::zero(),
+        }
+    }
+
+    /// Creates an affine transform from a 2x2 matrix (expressing scale, shear and rotation) and a
+    /// translation vector.
+    ///
+    /// Equivalent to `Affine2::from_translation(translation) * Affine2::from_mat2(mat2)`.
+    #[inline]
+    pub fn from_mat2_translation(matrix2: Mat2, translation: Vec2) -> Self {
+        Self {
+            matrix2,
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from the given 2D `scale`, rotation `angle` (in radians) and
+    /// `translation`.
+    ///
+    /// Equivalent to `Affine2::from_translation(translation) * Affine2::from_angle(angle) *
+    /// Affine2::from_scale(scale)`.
+    #[inline]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::from_scale_angle(scale, angle),
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from the given 2D rotation `angle` (in radians) and
+    /// `translation`.
+    ///
+    /// Equivalent to `Affine2::from_translation(translation) * Affine2::from_angle(angle)`.
+    #[inline]
+    pub fn from_angle_translation(angle: f32, translation: Vec2) -> Self {
+        Self {
+            matrix2: Mat2::from_angle(angle),
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from the first two rows of the given 3x3 matrix.
+    ///
+    /// The given `Mat3` must be an affine transform, i.e. its last row must be `[0, 0, 1]`.
+    #[inline]
+    pub fn from_mat3(m: Mat3) -> Self {
+        Self {
+            matrix2: Mat2::from_cols(m.x_axis.truncate(), m.y_axis.truncate()),
+            translation: m.z_axis.truncate(),
+        }
+    }
+
+    /// Extracts `scale`, `angle` (in radians) and `translation` from `self`.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output will be
+    /// invalid.
+    pub fn to_scale_angle_translation(&self) -> (Vec2, f32, Vec2) {
+        let det = self.matrix2.determinant();
+        glam_assert!(det != 0.0);
+
+        let scale = Vec2::new(
+            self.matrix2.x_axis.length() * det.signum(),
+            self.matrix2.y_axis.length(),
+        );
+        glam_assert!(scale.cmpne(Vec2::zero()).all());
+
+        let x_axis = self.matrix2.x_axis / scale.x;
+        let angle = x_axis.y.atan2(x_axis.x);
+
+        (scale, angle, self.translation)
+    }
+
+    /// Transforms the given 2D point, applying shear, scale, rotation and translation.
+    #[inline]
+    pub fn transform_point2(&self, other: Vec2) -> Vec2 {
+        self.matrix2.mul_vec2(other) + self.translation
+    }
+
+    /// Transforms the given 2D vector, applying shear, scale and rotation (but NOT translation).
+    ///
+    /// To also apply translation, use `transform_point2` instead.
+    #[inline]
+    pub fn transform_vector2(&self, other: Vec2) -> Vec2 {
+        self.matrix2.mul_vec2(other)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.matrix2.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.matrix2.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is less
+    /// than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `Affine2`'s contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.matrix2.abs_diff_eq(other.matrix2, max_abs_diff)
+            && self
+                .translation
+                .abs_diff_eq(other.translation, max_abs_diff)
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// This is cheaper than inverting the equivalent `Mat3` as only the 2x2 linear part needs to
+    /// be inverted.
+    ///
+    /// If the transform is not invertible the returned transform will be invalid.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let matrix2 = self.matrix2.inverse();
+        // transform negative translation by the 2x2 inverse:
+        let translation = -(matrix2.mul_vec2(self.translation));
+        Self {
+            matrix2,
+            translation,
+        }
+    }
+
+    /// Returns the inverse of `self`, or `None` if the transform is not invertible.
+    ///
+    /// See `Mat2::try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        self.matrix2.try_inverse().map(|matrix2| Self {
+            matrix2,
+            translation: -(matrix2.mul_vec2(self.translation)),
+        })
+    }
+
+    /// Returns the inverse of `self`, or the zero transform if the transform is not invertible.
+    ///
+    /// See `Mat2::try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Multiplies two affine transforms.
+    #[inline]
+    pub fn mul_affine2(&self, other: &Self) -> Self {
+        Self {
+            matrix2: self.matrix2.mul_mat2(&other.matrix2),
+            translation: self.transform_point2(other.translation),
+        }
+    }
+}
+
+impl Mul<Affine2> for Affine2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_affine2(&other)
+    }
+}
+
+impl Mul<Mat3> for Affine2 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, other: Mat3) -> Mat3 {
+        Mat3::from(self) * other
+    }
+}
+
+impl Mul<Affine2> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, other: Affine2) -> Mat3 {
+        self * Mat3::from(other)
+    }
+}
+
+impl From<Affine2> for Mat3 {
+    #[inline]
+    fn from(m: Affine2) -> Self {
+        Self::from_cols(
+            m.matrix2.x_axis.extend(0.0),
+            m.matrix2.y_axis.extend(0.0),
+            m.translation.extend(1.0),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for Affine2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
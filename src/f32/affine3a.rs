@@ -0,0 +1,507 @@
+use super::{
+    mat3a::{from_scale_shear_rotation, to_scale_shear_rotation},
+    Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A, Vec3ASwizzles,
+};
+use core::{fmt, ops::Mul};
+
+#[cfg(feature = "std")]
+use std::iter::Product;
+
+const ZERO: Affine3A = Affine3A {
+    matrix3: const_mat3a!([0.0; 9]),
+    translation: const_vec3a!([0.0; 3]),
+};
+const IDENTITY: Affine3A = Affine3A {
+    matrix3: const_mat3a!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]),
+    translation: const_vec3a!([0.0; 3]),
+};
+
+/// A 3D affine transform, which can represent translation, rotation, scaling and shear.
+///
+/// The transform is stored as a 3x3 `Mat3A` linear part followed by a `Vec3A` translation, which
+/// is equivalent to a 4x4 matrix where the last row is implicitly `[0, 0, 0, 1]`.
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Affine3A {
+    pub matrix3: Mat3A,
+    pub translation: Vec3A,
+}
+
+impl Default for Affine3A {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for Affine3A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
+        )
+    }
+}
+
+impl Affine3A {
+    /// Creates an affine transform with all elements set to `0.0`.
+    ///
+    /// The resulting transform is not invertible.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates an identity affine transform.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates an affine transform from three column vectors of the linear part and a
+    /// `translation`.
+    #[inline]
+    pub fn from_cols(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A, translation: Vec3A) -> Self {
+        Self {
+            matrix3: Mat3A::from_cols(x_axis, y_axis, z_axis),
+            translation,
+        }
+    }
+
+    /// Creates an affine transform from a `[f32; 12]` storing the three linear part columns
+    /// followed by the translation.
+    #[inline]
+    pub fn from_cols_array(m: &[f32; 12]) -> Self {
+        Self {
+            matrix3: Mat3A::from_cols(
+                Vec3A::new(m[0], m[1], m[2]),
+                Vec3A::new(m[3], m[4], m[5]),
+                Vec3A::new(m[6], m[7], m[8]),
+            ),
+            translation: Vec3A::new(m[9], m[10], m[11]),
+        }
+    }
+
+    /// Creates a `[f32; 12]` storing the three linear part columns followed by the translation.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f32; 12] {
+        let (m00, m01, m02) = self.matrix3.x_axis.into();
+        let (m10, m11, m12) = self.matrix3.y_axis.into();
+        let (m20, m21, m22) = self.matrix3.z_axis.into();
+        let (m30, m31, m32) = self.translation.into();
+        [m00, m01, m02, m10, m11, m12, m20, m21, m22, m30, m31, m32]
+    }
+
+    /// Creates an affine transform from a `[[f32; 3]; 4]` storing the three linear part columns
+    /// followed by the translation.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f32; 3]; 4]) -> Self {
+        Self {
+            matrix3: Mat3A::from_cols(m[0].into(), m[1].into(), m[2].into()),
+            translation: m[3].into(),
+        }
+    }
+
+    /// Creates a `[[f32; 3]; 4]` storing the three linear part columns followed by the
+    /// translation.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f32; 3]; 4] {
+        [
+            self.matrix3.x_axis.into(),
+            self.matrix3.y_axis.into(),
+            self.matrix3.z_axis.into(),
+            self.translation.into(),
+        ]
+    }
+
+    /// Creates an affine transform that changes scale.
+    ///
+    /// Note that if any scale is zero the transform will be non-invertible.
+    #[inline]
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self {
+            matrix3: Mat3A::from_scale(scale),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform from the given `rotation` quaternion.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self {
+            matrix3: Mat3A::from_quat(rotation),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around a normalized rotation `axis`
+    /// of `angle` (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        Self {
+            matrix3: Mat3A::from_axis_angle(axis, angle),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the x axis of `angle` (in
+    /// radians).
+    #[inline]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        Self {
+            matrix3: Mat3A::from_rotation_x(angle),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the y axis of `angle` (in
+    /// radians).
+    #[inline]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        Self {
+            matrix3: Mat3A::from_rotation_y(angle),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform containing a 3D rotation around the z axis of `angle` (in
+    /// radians).
+    #[inline]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        Self {
+            matrix3: Mat3A::from_rotation_z(angle),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transformation from the given 3D `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            matrix3: Mat3A::identity(),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from a 3x3 matrix (expressing scale, shear and rotation).
+    #[inline]
+    pub fn from_mat3(mat3: Mat3) -> Self {
+        Self {
+            matrix3: mat3.into(),
+            translation: Vec3A::zero(),
+        }
+    }
+
+    /// Creates an affine transform from a 3x3 matrix (expressing scale, shear and rotation) and a
+    /// translation vector.
+    ///
+    /// Equivalent to `Affine3A::from_translation(translation) * Affine3A::from_mat3(mat3)`.
+    #[inline]
+    pub fn from_mat3_translation(mat3: Mat3, translation: Vec3) -> Self {
+        Self {
+            matrix3: mat3.into(),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from the given 3D `scale`, `rotation` and `translation`.
+    ///
+    /// Equivalent to `Affine3A::from_translation(translation) * Affine3A::from_quat(rotation) *
+    /// Affine3A::from_scale(scale)`.
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        let rotation = Mat3A::from_quat(rotation);
+        let scale = Vec3A::from(scale);
+        Self {
+            matrix3: Mat3A::from_cols(
+                rotation.x_axis * scale.xxx(),
+                rotation.y_axis * scale.yyy(),
+                rotation.z_axis * scale.zzz(),
+            ),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from the given 3D `rotation` and `translation`.
+    ///
+    /// Equivalent to `Affine3A::from_translation(translation) * Affine3A::from_quat(rotation)`.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        Self {
+            matrix3: Mat3A::from_quat(rotation),
+            translation: translation.into(),
+        }
+    }
+
+    /// Creates an affine transform from the first three rows of the given 4x4 matrix.
+    ///
+    /// The given `Mat4` must be an affine transform, i.e. contain no perspective transform.
+    #[inline]
+    pub fn from_mat4(m: Mat4) -> Self {
+        Self {
+            matrix3: Mat3A::from_mat4(m),
+            translation: m.w_axis.into(),
+        }
+    }
+
+    /// Extracts `scale`, `rotation` and `translation` from `self`.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output will be
+    /// invalid.
+    pub fn to_scale_rotation_translation(&self) -> (Vec3, Quat, Vec3) {
+        let det = self.matrix3.determinant();
+        glam_assert!(det != 0.0);
+
+        let scale = Vec3A::new(
+            self.matrix3.x_axis.length() * det.signum(),
+            self.matrix3.y_axis.length(),
+            self.matrix3.z_axis.length(),
+        );
+        glam_assert!(scale.cmpne(Vec3A::zero()).all());
+
+        let inv_scale = scale.recip();
+
+        let rotation = Quat::from_rotation_mat3a(&Mat3A::from_cols(
+            self.matrix3.x_axis * inv_scale.xxx(),
+            self.matrix3.y_axis * inv_scale.yyy(),
+            self.matrix3.z_axis * inv_scale.zzz(),
+        ));
+
+        (scale.into(), rotation, self.translation.into())
+    }
+
+    /// Creates an affine transform from the given 3D `scale`, `shear`, `rotation` and
+    /// `translation`.
+    ///
+    /// The transform applies the shear first, then the scale, rotation and translation. The
+    /// shear maps `(x, y, z)` to `(x + shear.x * y + shear.y * z, y + shear.z * z, z)`.
+    #[inline]
+    pub fn from_scale_shear_rotation_translation(
+        scale: Vec3,
+        shear: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+    ) -> Self {
+        Self {
+            matrix3: from_scale_shear_rotation(
+                scale.into(),
+                shear.into(),
+                &Mat3A::from_quat(rotation),
+            ),
+            translation: translation.into(),
+        }
+    }
+
+    /// Extracts `scale`, `shear`, `rotation` and `translation` from `self`, the inverse of
+    /// `Affine3A::from_scale_shear_rotation_translation`.
+    ///
+    /// Unlike `to_scale_rotation_translation` this gives a valid rotation for any
+    /// non-degenerate transform, including ones with shear. `rotation` is never a reflection, if
+    /// `self` has a negative determinant `scale.x` is negative instead, the same convention as
+    /// `Mat3A::polar_decompose`.
+    pub fn to_scale_shear_rotation_translation(&self) -> (Vec3, Vec3, Quat, Vec3) {
+        let (scale, shear, rotation) = to_scale_shear_rotation(&self.matrix3);
+        (
+            scale.into(),
+            shear.into(),
+            Quat::from_rotation_mat3a(&rotation),
+            self.translation.into(),
+        )
+    }
+
+    #[inline]
+    fn look_to_lh(eye: Vec3A, dir: Vec3A, up: Vec3A) -> Self {
+        let f = dir.normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+        let (fx, fy, fz) = f.into();
+        let (sx, sy, sz) = s.into();
+        let (ux, uy, uz) = u.into();
+        Self::from_cols(
+            Vec3A::new(sx, ux, fx),
+            Vec3A::new(sy, uy, fy),
+            Vec3A::new(sz, uz, fz),
+            Vec3A::new(-s.dot(eye), -u.dot(eye), -f.dot(eye)),
+        )
+    }
+
+    /// Creates a left-handed view transform using a camera position, an up direction, and a
+    /// focal point.
+    ///
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+    #[inline]
+    pub fn look_at_lh(eye: Vec3, center: Vec3, up: Vec3) -> Self {
+        let eye = Vec3A::from(eye);
+        let center = Vec3A::from(center);
+        let up = Vec3A::from(up);
+        glam_assert!(up.is_normalized());
+        Self::look_to_lh(eye, center - eye, up)
+    }
+
+    /// Creates a right-handed view transform using a camera position, an up direction, and a
+    /// focal point.
+    ///
+    /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+    #[inline]
+    pub fn look_at_rh(eye: Vec3, center: Vec3, up: Vec3) -> Self {
+        let eye = Vec3A::from(eye);
+        let center = Vec3A::from(center);
+        let up = Vec3A::from(up);
+        glam_assert!(up.is_normalized());
+        Self::look_to_lh(eye, eye - center, up)
+    }
+
+    /// Transforms the given 3D point, applying shear, scale, rotation and translation.
+    #[inline]
+    pub fn transform_point3(&self, other: Vec3) -> Vec3 {
+        Vec3::from(self.transform_point3a(other.into()))
+    }
+
+    /// Transforms the given 3D vector, applying shear, scale and rotation (but NOT translation).
+    ///
+    /// To also apply translation, use `transform_point3` instead.
+    #[inline]
+    pub fn transform_vector3(&self, other: Vec3) -> Vec3 {
+        Vec3::from(self.transform_vector3a(other.into()))
+    }
+
+    /// Transforms the given `Vec3A` as a 3D point, applying shear, scale, rotation and
+    /// translation.
+    #[inline]
+    pub fn transform_point3a(&self, other: Vec3A) -> Vec3A {
+        self.matrix3.mul_vec3a(other) + self.translation
+    }
+
+    /// Transforms the given `Vec3A` as a 3D vector, applying shear, scale and rotation (but NOT
+    /// translation).
+    ///
+    /// To also apply translation, use `transform_point3a` instead.
+    #[inline]
+    pub fn transform_vector3a(&self, other: Vec3A) -> Vec3A {
+        self.matrix3.mul_vec3a(other)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.matrix3.is_finite() && self.translation.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.matrix3.is_nan() || self.translation.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is less
+    /// than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `Affine3A`'s contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.matrix3.abs_diff_eq(other.matrix3, max_abs_diff)
+            && self
+                .translation
+                .abs_diff_eq(other.translation, max_abs_diff)
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// This is cheaper than inverting the equivalent `Mat4` as only the 3x3 linear part needs to
+    /// be inverted.
+    ///
+    /// If the transform is not invertible the returned transform will be invalid.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let matrix3 = self.matrix3.inverse();
+        // transform negative translation by the 3x3 inverse:
+        let translation = -(matrix3.mul_vec3a(self.translation));
+        Self {
+            matrix3,
+            translation,
+        }
+    }
+
+    /// Returns the inverse of `self`, or `None` if the transform is not invertible.
+    ///
+    /// See `Mat3A::try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        self.matrix3.try_inverse().map(|matrix3| Self {
+            matrix3,
+            translation: -(matrix3.mul_vec3a(self.translation)),
+        })
+    }
+
+    /// Returns the inverse of `self`, or the zero transform if the transform is not invertible.
+    ///
+    /// See `Mat3A::try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Multiplies two affine transforms.
+    #[inline]
+    pub fn mul_affine3a(&self, other: &Self) -> Self {
+        Self {
+            matrix3: self.matrix3.mul_mat3a(&other.matrix3),
+            translation: self.transform_point3a(other.translation),
+        }
+    }
+}
+
+impl Mul<Affine3A> for Affine3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_affine3a(&other)
+    }
+}
+
+impl Mul<Mat4> for Affine3A {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, other: Mat4) -> Mat4 {
+        Mat4::from(self) * other
+    }
+}
+
+impl Mul<Affine3A> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, other: Affine3A) -> Mat4 {
+        self * Mat4::from(other)
+    }
+}
+
+impl From<Affine3A> for Mat4 {
+    #[inline]
+    fn from(m: Affine3A) -> Self {
+        Self::from_cols(
+            m.matrix3.x_axis.extend(0.0),
+            m.matrix3.y_axis.extend(0.0),
+            m.matrix3.z_axis.extend(0.0),
+            m.translation.extend(1.0),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for Affine3A {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
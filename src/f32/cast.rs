@@ -1,4 +1,4 @@
-use super::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
@@ -49,6 +49,13 @@ pub union F32x9Cast {
     pub mat3: Mat3,
 }
 
+#[repr(C)]
+pub union F32x12Cast {
+    pub f32x4x3: [[f32; 4]; 3],
+    pub f32x12: [f32; 12],
+    pub mat3a: Mat3A,
+}
+
 #[repr(C)]
 pub union F32x2Cast {
     pub f32x2: [f32; 2],
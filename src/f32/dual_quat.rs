@@ -0,0 +1,365 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+#[cfg(feature = "transform-types")]
+use super::TransformRT;
+use super::{scalar_sin_cos, Mat4, Quat, Vec3, Vec4Swizzles};
+use core::{
+    fmt,
+    ops::{Mul, MulAssign},
+};
+
+#[cfg(feature = "std")]
+use std::iter::Product;
+
+/// A dual quaternion representing a rigid transform, i.e. a rotation followed by a translation.
+///
+/// The rotation is stored in the `real` part and the translation is stored in the `dual` part
+/// as `0.5 * t * real`, where `t` is the translation as a pure quaternion. Unlike blending
+/// matrices, blending dual quaternions preserves rigidity, which avoids the volume loss of
+/// linear blend skinning.
+///
+/// Most methods expect the dual quaternion to be normalized, i.e. the `real` part is normalized
+/// and orthogonal to the `dual` part.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct DualQuat {
+    pub real: Quat,
+    pub dual: Quat,
+}
+
+impl Default for DualQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for DualQuat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.real, self.dual)
+    }
+}
+
+impl DualQuat {
+    /// Creates a dual quaternion with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        let zero = Quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+        Self {
+            real: zero,
+            dual: zero,
+        }
+    }
+
+    /// Creates the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            real: Quat::identity(),
+            dual: Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Creates a dual quaternion from the given `real` and `dual` parts.
+    #[inline]
+    pub fn from_real_dual(real: Quat, dual: Quat) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates a transform from the normalized `rotation` followed by `translation`.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let t = Quat::from(translation.extend(0.0));
+        Self {
+            real: rotation,
+            dual: mul_quat(t, rotation) * 0.5,
+        }
+    }
+
+    /// Creates a transform from the normalized `rotation`.
+    #[inline]
+    pub fn from_rotation(rotation: Quat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            real: rotation,
+            dual: Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Creates a transform from the `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        let t = translation * 0.5;
+        Self {
+            real: Quat::identity(),
+            dual: Quat::from_xyzw(t.x, t.y, t.z, 0.0),
+        }
+    }
+
+    /// Creates a transform from the rotation and translation of `mat`, which must be a rigid
+    /// transform without scale or shear.
+    #[inline]
+    pub fn from_mat4(mat: &Mat4) -> Self {
+        Self::from_rotation_translation(Quat::from_rotation_mat4(mat), mat.w_axis.xyz())
+    }
+
+    /// Returns the rotation and translation of `self`.
+    #[inline]
+    pub fn to_rotation_translation(&self) -> (Quat, Vec3) {
+        (self.real, self.translation())
+    }
+
+    /// Returns the translation of `self`.
+    #[inline]
+    pub fn translation(&self) -> Vec3 {
+        let t = mul_quat(self.dual, self.real.conjugate()) * 2.0;
+        Vec3::new(t.x, t.y, t.z)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.real.is_nan() || self.dual.is_nan()
+    }
+
+    /// Returns whether `self` is normalized, i.e. the `real` part has a length of `1.0` and is
+    /// orthogonal to the `dual` part.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.real.is_normalized() && self.real.dot(self.dual).abs() <= 1e-6
+    }
+
+    /// Returns `self` normalized so that it represents a rigid transform.
+    ///
+    /// For valid results, the `real` part of `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let inv_len = self.real.length_recip();
+        let real = self.real * inv_len;
+        let dual = self.dual * inv_len;
+        // remove the part of the dual that isn't orthogonal to the real part
+        Self {
+            real,
+            dual: dual - real * real.dot(dual),
+        }
+    }
+
+    /// Returns the conjugate of both the `real` and `dual` parts of `self`. For a normalized
+    /// dual quaternion this is also the inverse.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns the inverse of the normalized `self`.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Multiplies two dual quaternions, which applies `other` followed by `self`.
+    #[inline]
+    pub fn mul_dual_quat(&self, other: &Self) -> Self {
+        Self {
+            real: self.real * other.real,
+            dual: mul_quat(self.real, other.dual) + mul_quat(self.dual, other.real),
+        }
+    }
+
+    /// Transforms the given 3D point, applying the rotation and then the translation.
+    #[inline]
+    pub fn transform_point3(&self, other: Vec3) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        self.real * other + self.translation()
+    }
+
+    /// Transforms the given 3D vector, applying the rotation only.
+    #[inline]
+    pub fn transform_vector3(&self, other: Vec3) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        self.real * other
+    }
+
+    /// Returns the normalized `self` raised to the power `n`, which scales both the rotation
+    /// angle and the translation along the screw axis by `n`.
+    pub fn powf(&self, n: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        const EPSILON: f32 = 1.0e-6;
+        let (rx, ry, rz, rw) = self.real.into();
+        let (dx, dy, dz, dw) = self.dual.into();
+        let real_v = Vec3::new(rx, ry, rz);
+        let dual_v = Vec3::new(dx, dy, dz);
+        let sin_half = real_v.length();
+        if sin_half < EPSILON {
+            // a pure translation, scaling the translation is enough once the real part is
+            // flipped to the identity
+            let sign = if rw < 0.0 { -1.0 } else { 1.0 };
+            return Self {
+                real: Quat::identity(),
+                dual: self.dual * (sign * n),
+            };
+        }
+        // Based on "Dual Quaternions for Rigid Transformation Blending" by Ladislav Kavan,
+        // Steven Collins, Carol O'Sullivan and Jiri Zara
+        // the screw axis direction, the pitch along it and its moment
+        let inv_sin_half = sin_half.recip();
+        let half_angle = sin_half.atan2(rw);
+        let direction = real_v * inv_sin_half;
+        let half_pitch = -dw * inv_sin_half;
+        let moment = (dual_v - direction * (half_pitch * rw)) * inv_sin_half;
+
+        let half_angle = half_angle * n;
+        let half_pitch = half_pitch * n;
+        let (sin, cos) = scalar_sin_cos(half_angle);
+        let real = (direction * sin).extend(cos);
+        let dual = (moment * sin + direction * (half_pitch * cos)).extend(-half_pitch * sin);
+        Self {
+            real: real.into(),
+            dual: dual.into(),
+        }
+    }
+
+    /// Performs a screw linear interpolation between `self` and `end` based on the value `s`.
+    ///
+    /// This interpolates the rotation and translation along a single screw motion with constant
+    /// speed, taking the shortest path. When `s` is `0.0`, the result will be equal to `self`.
+    /// When `s` is `1.0`, the result will be equal to `end`.
+    pub fn sclerp(&self, end: &Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        // `q` and `-q` are the same transform, pick the one closest to `self`
+        let end = if self.real.dot(end.real) < 0.0 {
+            Self {
+                real: -end.real,
+                dual: -end.dual,
+            }
+        } else {
+            *end
+        };
+        let diff = self.conjugate().mul_dual_quat(&end);
+        self.mul_dual_quat(&diff.powf(s))
+    }
+
+    /// Computes the weighted blend of the given normalized dual quaternions using dual
+    /// quaternion linear blending (DLB).
+    ///
+    /// This is the blend used by dual quaternion skinning. Each dual quaternion is flipped into
+    /// the hemisphere of the first one before summing, so `q` and `-q` count as the same
+    /// transform. `weights` must have the same length as `dual_quats`. If `dual_quats` is empty
+    /// or the weighted sum is zero the result is the identity.
+    pub fn blend(dual_quats: &[Self], weights: &[f32]) -> Self {
+        glam_assert!(dual_quats.len() == weights.len());
+        let first = match dual_quats.first() {
+            Some(first) => first.real,
+            None => return Self::identity(),
+        };
+        let mut real = Quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+        let mut dual = real;
+        for (dq, &weight) in dual_quats.iter().zip(weights.iter()) {
+            let weight = if dq.real.dot(first) < 0.0 {
+                -weight
+            } else {
+                weight
+            };
+            real = real + dq.real * weight;
+            dual = dual + dq.dual * weight;
+        }
+        if real.length_squared() > 0.0 {
+            Self { real, dual }.normalize()
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DualQuat`'s contain similar elements. It works best
+    /// when comparing with a known value. The `max_abs_diff` that should be used used depends on
+    /// the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.real.abs_diff_eq(other.real, max_abs_diff)
+            && self.dual.abs_diff_eq(other.dual, max_abs_diff)
+    }
+}
+
+/// Multiplies two quaternions which need not be normalized, as the `dual` part of a dual
+/// quaternion generally isn't.
+#[inline]
+fn mul_quat(a: Quat, b: Quat) -> Quat {
+    let (ax, ay, az, aw) = a.into();
+    let (bx, by, bz, bw) = b.into();
+    let (av, bv) = (Vec3::new(ax, ay, az), Vec3::new(bx, by, bz));
+    (bv * aw + av * bw + av.cross(bv))
+        .extend(aw * bw - av.dot(bv))
+        .into()
+}
+
+impl Mul<DualQuat> for DualQuat {
+    type Output = DualQuat;
+    #[inline]
+    fn mul(self, other: DualQuat) -> Self::Output {
+        self.mul_dual_quat(&other)
+    }
+}
+
+impl MulAssign<DualQuat> for DualQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: DualQuat) {
+        *self = self.mul_dual_quat(&other);
+    }
+}
+
+impl From<DualQuat> for Mat4 {
+    #[inline]
+    fn from(dq: DualQuat) -> Mat4 {
+        let (rotation, translation) = dq.to_rotation_translation();
+        Mat4::from_rotation_translation(rotation, translation)
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl From<TransformRT> for DualQuat {
+    #[inline]
+    fn from(rt: TransformRT) -> Self {
+        Self::from_rotation_translation(rt.rotation, rt.translation)
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl From<DualQuat> for TransformRT {
+    #[inline]
+    fn from(dq: DualQuat) -> Self {
+        let (rotation, translation) = dq.to_rotation_translation();
+        TransformRT::from_rotation_translation(rotation, translation)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DualQuat {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::identity(), |a, b| a.mul_dual_quat(b))
+    }
+}
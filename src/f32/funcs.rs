@@ -46,6 +46,71 @@ pub fn scalar_acos(value: f32) -> f32 {
     }
 }
 
+/// Diagonalizes the symmetric `n` x `n` matrix in the top left of `a` using the cyclic Jacobi
+/// method, `n` must be at most 4. Only the upper triangle of `a` is read.
+///
+/// On return the diagonal of `a` holds the unsorted eigenvalues and the columns of the returned
+/// matrix are the matching eigenvectors. Arrays are used instead of `Mat3A` and `Mat4` so the
+/// same code serves both sizes.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn symmetric_eigen_jacobi(a: &mut [[f32; 4]; 4], n: usize) -> [[f32; 4]; 4] {
+    const MAX_SWEEPS: usize = 16;
+    glam_assert!(n <= 4);
+    for row in 1..n {
+        for col in 0..row {
+            a[row][col] = a[col][row];
+        }
+    }
+    let mut v = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    for _ in 0..MAX_SWEEPS {
+        let mut off_diagonal = 0.0;
+        let mut diagonal = 0.0;
+        for p in 0..n {
+            diagonal += a[p][p].abs();
+            for q in (p + 1)..n {
+                off_diagonal += a[p][q].abs();
+            }
+        }
+        if off_diagonal <= core::f32::EPSILON * diagonal {
+            break;
+        }
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // the Jacobi rotation in the p, q plane that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = (t * t + 1.0).sqrt().recip();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = c * akp - s * akq;
+                    a[k][q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                // the eigenvectors are the columns of the accumulated rotations
+                for row in v[..n].iter_mut() {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+    v
+}
+
 #[cfg(vec4_sse2)]
 #[allow(clippy::excessive_precision)]
 pub(crate) mod sse2 {
@@ -76,24 +141,68 @@ pub(crate) mod sse2 {
     _ps_const_ty!(PS_SIGN_MASK, u32x4, 0x8000_0000);
     _ps_const_ty!(PS_NO_FRACTION, f32x4, 8388608.0);
 
-    // _ps_const_ty!(PS_1_0, f32x4, 1.0);
-    // _ps_const_ty!(PS_0_5, f32x4, 0.5);
-
-    // _ps_const_ty!(PI32_1, i32x4, 1);
-    // _ps_const_ty!(PI32_INV_1, i32x4, !1);
-    // _ps_const_ty!(PI32_2, i32x4, 2);
-    // _ps_const_ty!(PI32_4, i32x4, 4);
-
-    // _ps_const_ty!(PS_MINUS_CEPHES_DP1, f32x4, -0.785_156_25);
-    // _ps_const_ty!(PS_MINUS_CEPHES_DP2, f32x4, -2.418_756_5e-4);
-    // _ps_const_ty!(PS_MINUS_CEPHES_DP3, f32x4, -3.774_895e-8);
-    // _ps_const_ty!(PS_SINCOF_P0, f32x4, -1.951_529_6e-4);
-    // _ps_const_ty!(PS_SINCOF_P1, f32x4, 8.332_161e-3);
-    // _ps_const_ty!(PS_SINCOF_P2, f32x4, -1.666_665_5e-1);
-    // _ps_const_ty!(PS_COSCOF_P0, f32x4, 2.443_315_7e-5);
-    // _ps_const_ty!(PS_COSCOF_P1, f32x4, -1.388_731_6E-3);
-    // _ps_const_ty!(PS_COSCOF_P2, f32x4, 4.166_664_6e-2);
-    // _ps_const_ty!(PS_CEPHES_FOPI, f32x4, 1.273_239_5); // 4 / M_PI
+    _ps_const_ty!(PI32_1, i32x4, 1);
+    _ps_const_ty!(PI32_INV_1, i32x4, !1);
+    _ps_const_ty!(PI32_2, i32x4, 2);
+    _ps_const_ty!(PI32_4, i32x4, 4);
+
+    _ps_const_ty!(PS_SINCOF_P0, f32x4, -1.951_529_6e-4);
+    _ps_const_ty!(PS_SINCOF_P1, f32x4, 8.332_161e-3);
+    _ps_const_ty!(PS_SINCOF_P2, f32x4, -1.666_665_5e-1);
+    _ps_const_ty!(PS_COSCOF_P0, f32x4, 2.443_315_7e-5);
+    _ps_const_ty!(PS_COSCOF_P1, f32x4, -1.388_731_6E-3);
+    _ps_const_ty!(PS_COSCOF_P2, f32x4, 4.166_664_6e-2);
+    _ps_const_ty!(PS_CEPHES_FOPI, f32x4, 1.273_239_5); // 4 / M_PI
+    _ps_const_ty!(PS_SIN_COS_MAX, f32x4, 16384.0);
+
+    _ps_const_ty!(PS_HALF, f32x4, 0.5);
+    _ps_const_ty!(PS_QUARTER_PI, f32x4, core::f32::consts::FRAC_PI_4);
+    _ps_const_ty!(PS_INFINITY, f32x4, core::f32::INFINITY);
+    _ps_const_ty!(PS_NEG_INFINITY, f32x4, core::f32::NEG_INFINITY);
+
+    _ps_const_ty!(PS_EXP_HI, f32x4, 88.8);
+    _ps_const_ty!(PS_EXP_LO, f32x4, -104.0);
+    _ps_const_ty!(PS_CEPHES_LOG2EF, f32x4, core::f32::consts::LOG2_E);
+    _ps_const_ty!(PS_CEPHES_EXP_C1, f32x4, 0.693_359_4);
+    _ps_const_ty!(PS_CEPHES_EXP_C2, f32x4, -2.121_944_4e-4);
+    _ps_const_ty!(PS_CEPHES_EXP_P0, f32x4, 1.987_569_1e-4);
+    _ps_const_ty!(PS_CEPHES_EXP_P1, f32x4, 1.398_199_9e-3);
+    _ps_const_ty!(PS_CEPHES_EXP_P2, f32x4, 8.333_452e-3);
+    _ps_const_ty!(PS_CEPHES_EXP_P3, f32x4, 4.166_579_6e-2);
+    _ps_const_ty!(PS_CEPHES_EXP_P4, f32x4, 1.666_666_5e-1);
+    _ps_const_ty!(PS_CEPHES_EXP_P5, f32x4, 5.000_000_1e-1);
+    _ps_const_ty!(PI32_0X7F, i32x4, 0x7f);
+
+    _ps_const_ty!(PS_MIN_NORM_POS, f32x4, core::f32::MIN_POSITIVE);
+    _ps_const_ty!(PS_TWO_POW_23, f32x4, 8388608.0);
+    _ps_const_ty!(PS_23, f32x4, 23.0);
+    _ps_const_ty!(PS_INV_MANT_MASK, u32x4, !0x7f80_0000);
+    _ps_const_ty!(PI32_0X7E, i32x4, 0x7e);
+    _ps_const_ty!(PS_CEPHES_SQRTHF, f32x4, core::f32::consts::FRAC_1_SQRT_2);
+    _ps_const_ty!(PS_CEPHES_LOG_P0, f32x4, 7.037_683_6e-2);
+    _ps_const_ty!(PS_CEPHES_LOG_P1, f32x4, -1.151_461e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P2, f32x4, 1.167_699_9e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P3, f32x4, -1.242_014_1e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P4, f32x4, 1.424_932_3e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P5, f32x4, -1.666_805_8e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P6, f32x4, 2.000_071_5e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P7, f32x4, -2.499_999_4e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_P8, f32x4, 3.333_333_1e-1);
+    _ps_const_ty!(PS_CEPHES_LOG_Q1, f32x4, -2.121_944_4e-4);
+    _ps_const_ty!(PS_CEPHES_LOG_Q2, f32x4, 0.693_359_4);
+    _ps_const_ty!(PS_CEPHES_LOG2EA, f32x4, 0.442_695_04);
+
+    _ps_const_ty!(PS_CEPHES_ASIN_P0, f32x4, 4.216_32e-2);
+    _ps_const_ty!(PS_CEPHES_ASIN_P1, f32x4, 2.418_131_1e-2);
+    _ps_const_ty!(PS_CEPHES_ASIN_P2, f32x4, 4.547_002_6e-2);
+    _ps_const_ty!(PS_CEPHES_ASIN_P3, f32x4, 7.495_300_3e-2);
+    _ps_const_ty!(PS_CEPHES_ASIN_P4, f32x4, 1.666_675_2e-1);
+
+    _ps_const_ty!(PS_CEPHES_TAN_PI_8, f32x4, 0.414_213_57);
+    _ps_const_ty!(PS_CEPHES_ATAN_P0, f32x4, 8.053_744_5e-2);
+    _ps_const_ty!(PS_CEPHES_ATAN_P1, f32x4, -1.387_768_6e-1);
+    _ps_const_ty!(PS_CEPHES_ATAN_P2, f32x4, 1.997_771_1e-1);
+    _ps_const_ty!(PS_CEPHES_ATAN_P3, f32x4, -3.333_295e-1);
 
     _ps_const_ty!(PS_NEGATIVE_ZERO, u32x4, 0x80000000);
     _ps_const_ty!(PS_PI, f32x4, core::f32::consts::PI);
@@ -118,28 +227,28 @@ pub(crate) mod sse2 {
     _ps_const_ty!(PS_TWO_PI, f32x4, core::f32::consts::PI * 2.0);
     _ps_const_ty!(PS_RECIPROCAL_TWO_PI, f32x4, 0.159154943);
 
-    #[cfg(target_feature = "fma")]
+    #[cfg(vec4_fma)]
     macro_rules! m128_mul_add {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_fmadd_ps($a, $b, $c)
         };
     }
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(not(vec4_fma))]
     macro_rules! m128_mul_add {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_add_ps(_mm_mul_ps($a, $b), $c)
         };
     }
 
-    #[cfg(target_feature = "fma")]
+    #[cfg(vec4_fma)]
     macro_rules! m128_neg_mul_sub {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_fnmadd_ps($a, $b, $c)
         };
     }
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(not(vec4_fma))]
     macro_rules! m128_neg_mul_sub {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_sub_ps($c, _mm_mul_ps($a, $b))
@@ -148,6 +257,21 @@ pub(crate) mod sse2 {
 
     #[inline]
     pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
+        #[cfg(vec4_sse41)]
+        {
+            _mm_round_ps(v, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+        }
+
+        #[cfg(not(vec4_sse41))]
+        {
+            m128_round_sse2(v)
+        }
+    }
+
+    /// SSE2 version of `m128_round`, kept for testing the SSE4.1 version against.
+    #[inline]
+    #[cfg_attr(vec4_sse41, allow(dead_code))]
+    pub(crate) unsafe fn m128_round_sse2(v: __m128) -> __m128 {
         // Based on https://github.com/microsoft/DirectXMath `XMVectorRound`
         let sign = _mm_and_ps(v, PS_SIGN_MASK.m128);
         let s_magic = _mm_or_ps(PS_NO_FRACTION.m128, sign);
@@ -162,6 +286,21 @@ pub(crate) mod sse2 {
 
     #[inline]
     pub(crate) unsafe fn m128_floor(v: __m128) -> __m128 {
+        #[cfg(vec4_sse41)]
+        {
+            _mm_round_ps(v, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+        }
+
+        #[cfg(not(vec4_sse41))]
+        {
+            m128_floor_sse2(v)
+        }
+    }
+
+    /// SSE2 version of `m128_floor`, kept for testing the SSE4.1 version against.
+    #[inline]
+    #[cfg_attr(vec4_sse41, allow(dead_code))]
+    pub(crate) unsafe fn m128_floor_sse2(v: __m128) -> __m128 {
         // Based on https://github.com/microsoft/DirectXMath `XMVectorFloor`
         // To handle NAN, INF and numbers greater than 8388608, use masking
         let test = _mm_and_si128(_mm_castps_si128(v), PS_INV_SIGN_MASK.m128i);
@@ -182,6 +321,21 @@ pub(crate) mod sse2 {
 
     #[inline]
     pub(crate) unsafe fn m128_ceil(v: __m128) -> __m128 {
+        #[cfg(vec4_sse41)]
+        {
+            _mm_round_ps(v, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+        }
+
+        #[cfg(not(vec4_sse41))]
+        {
+            m128_ceil_sse2(v)
+        }
+    }
+
+    /// SSE2 version of `m128_ceil`, kept for testing the SSE4.1 version against.
+    #[inline]
+    #[cfg_attr(vec4_sse41, allow(dead_code))]
+    pub(crate) unsafe fn m128_ceil_sse2(v: __m128) -> __m128 {
         // Based on https://github.com/microsoft/DirectXMath `XMVectorCeil`
         // To handle NAN, INF and numbers greater than 8388608, use masking
         let test = _mm_and_si128(_mm_castps_si128(v), PS_INV_SIGN_MASK.m128i);
@@ -258,97 +412,310 @@ pub(crate) mod sse2 {
         result
     }
 
-    // Based on http://gruntthepeon.free.fr/ssemath/sse_mathfun.h
-    // #[cfg(target_feature = "sse2")]
-    // unsafe fn sin_cos_sse2(x: __m128) -> (__m128, __m128) {
-    //     let mut sign_bit_sin = x;
-    //     // take the absolute value
-    //     let mut x = _mm_and_ps(x, PS_INV_SIGN_MASK.m128);
-    //     // extract the sign bit (upper one)
-    //     sign_bit_sin = _mm_and_ps(sign_bit_sin, PS_SIGN_MASK.m128);
-
-    //     // scale by 4/Pi
-    //     let mut y = _mm_mul_ps(x, PS_CEPHES_FOPI.m128);
-
-    //     // store the integer part of y in emm2
-    //     let mut emm2 = _mm_cvttps_epi32(y);
-
-    //     // j=(j+1) & (~1) (see the cephes sources)
-    //     emm2 = _mm_add_epi32(emm2, PI32_1.m128i);
-    //     emm2 = _mm_and_si128(emm2, PI32_INV_1.m128i);
-    //     y = _mm_cvtepi32_ps(emm2);
-
-    //     let mut emm4 = emm2;
-
-    //     /* get the swap sign flag for the sine */
-    //     let mut emm0 = _mm_and_si128(emm2, PI32_4.m128i);
-    //     emm0 = _mm_slli_epi32(emm0, 29);
-    //     let swap_sign_bit_sin = _mm_castsi128_ps(emm0);
-
-    //     /* get the polynom selection mask for the sine*/
-    //     emm2 = _mm_and_si128(emm2, PI32_2.m128i);
-    //     emm2 = _mm_cmpeq_epi32(emm2, _mm_setzero_si128());
-    //     let poly_mask = _mm_castsi128_ps(emm2);
-
-    //     /* The magic pass: "Extended precision modular arithmetic"
-    //     x = ((x - y * DP1) - y * DP2) - y * DP3; */
-    //     let mut xmm1 = PS_MINUS_CEPHES_DP1.m128;
-    //     let mut xmm2 = PS_MINUS_CEPHES_DP2.m128;
-    //     let mut xmm3 = PS_MINUS_CEPHES_DP3.m128;
-    //     xmm1 = _mm_mul_ps(y, xmm1);
-    //     xmm2 = _mm_mul_ps(y, xmm2);
-    //     xmm3 = _mm_mul_ps(y, xmm3);
-    //     x = _mm_add_ps(x, xmm1);
-    //     x = _mm_add_ps(x, xmm2);
-    //     x = _mm_add_ps(x, xmm3);
-
-    //     emm4 = _mm_sub_epi32(emm4, PI32_2.m128i);
-    //     emm4 = _mm_andnot_si128(emm4, PI32_4.m128i);
-    //     emm4 = _mm_slli_epi32(emm4, 29);
-    //     let sign_bit_cos = _mm_castsi128_ps(emm4);
-
-    //     sign_bit_sin = _mm_xor_ps(sign_bit_sin, swap_sign_bit_sin);
-
-    //     // Evaluate the first polynom  (0 <= x <= Pi/4)
-    //     let z = _mm_mul_ps(x, x);
-    //     y = PS_COSCOF_P0.m128;
-
-    //     y = _mm_mul_ps(y, z);
-    //     y = _mm_add_ps(y, PS_COSCOF_P1.m128);
-    //     y = _mm_mul_ps(y, z);
-    //     y = _mm_add_ps(y, PS_COSCOF_P2.m128);
-    //     y = _mm_mul_ps(y, z);
-    //     y = _mm_mul_ps(y, z);
-    //     let tmp = _mm_mul_ps(z, PS_0_5.m128);
-    //     y = _mm_sub_ps(y, tmp);
-    //     y = _mm_add_ps(y, PS_1_0.m128);
-
-    //     // Evaluate the second polynom  (Pi/4 <= x <= 0)
-    //     let mut y2 = PS_SINCOF_P0.m128;
-    //     y2 = _mm_mul_ps(y2, z);
-    //     y2 = _mm_add_ps(y2, PS_SINCOF_P1.m128);
-    //     y2 = _mm_mul_ps(y2, z);
-    //     y2 = _mm_add_ps(y2, PS_SINCOF_P2.m128);
-    //     y2 = _mm_mul_ps(y2, z);
-    //     y2 = _mm_mul_ps(y2, x);
-    //     y2 = _mm_add_ps(y2, x);
-
-    //     // select the correct result from the two polynoms
-    //     xmm3 = poly_mask;
-    //     let ysin2 = _mm_and_ps(xmm3, y2);
-    //     let ysin1 = _mm_andnot_ps(xmm3, y);
-    //     y2 = _mm_sub_ps(y2, ysin2);
-    //     y = _mm_sub_ps(y, ysin1);
-
-    //     xmm1 = _mm_add_ps(ysin1, ysin2);
-    //     xmm2 = _mm_add_ps(y, y2);
-
-    //     // update the sign
-    //     (
-    //         _mm_xor_ps(xmm1, sign_bit_sin),
-    //         _mm_xor_ps(xmm2, sign_bit_cos),
-    //     )
-    // }
+    /// Returns the lanes of `a` where `mask` is set and the lanes of `b` elsewhere.
+    #[inline]
+    unsafe fn m128_select(mask: __m128, a: __m128, b: __m128) -> __m128 {
+        _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+    }
+
+    /// Computes the sine and cosine of each lane of `x`.
+    ///
+    /// The maximum error is 2 ULP. Lanes with a magnitude above `16384` are outside of the range
+    /// the argument reduction is exact for and fall back to the scalar implementation.
+    #[inline]
+    pub(crate) unsafe fn m128_sin_cos(x: __m128) -> (__m128, __m128) {
+        let big = _mm_cmpgt_ps(_mm_andnot_ps(PS_NEGATIVE_ZERO.m128, x), PS_SIN_COS_MAX.m128);
+        if _mm_movemask_ps(big) != 0 {
+            return m128_sin_cos_scalar(x);
+        }
+
+        // Based on http://gruntthepeon.free.fr/ssemath/sse_mathfun.h
+        let sign_bit_sin = _mm_and_ps(x, PS_NEGATIVE_ZERO.m128);
+        // take the absolute value
+        let x = _mm_andnot_ps(PS_NEGATIVE_ZERO.m128, x);
+
+        // scale by 4/Pi and round the octant up to an even number (see the cephes sources)
+        let y = _mm_mul_ps(x, PS_CEPHES_FOPI.m128);
+        let j = _mm_cvttps_epi32(y);
+        let j = _mm_and_si128(_mm_add_epi32(j, PI32_1.m128i), PI32_INV_1.m128i);
+        let y = _mm_cvtepi32_ps(j);
+
+        // get the swap sign flag for the sine
+        let swap_sign_bit_sin =
+            _mm_castsi128_ps(_mm_slli_epi32(_mm_and_si128(j, PI32_4.m128i), 29));
+
+        // get the polynomial selection mask
+        let poly_mask = _mm_castsi128_ps(_mm_cmpeq_epi32(
+            _mm_and_si128(j, PI32_2.m128i),
+            _mm_setzero_si128(),
+        ));
+
+        // x = (x - y * DP1) - y * DP2, evaluated two lanes at a time in double precision. DP1 has
+        // its low 15 bits cleared so y * DP1 is exact and the subtraction does not lose the
+        // significant bits of small results.
+        let dp1 = _mm_set1_pd(0.785_398_163_396_166_6);
+        let dp2 = _mm_set1_pd(1.281_672_075_797_259_5e-12);
+        let reduce = |x: __m128, y: __m128| {
+            let x = _mm_cvtps_pd(x);
+            let y = _mm_cvtps_pd(y);
+            let x = _mm_sub_pd(x, _mm_mul_pd(y, dp1));
+            _mm_cvtpd_ps(_mm_sub_pd(x, _mm_mul_pd(y, dp2)))
+        };
+        let x = _mm_movelh_ps(
+            reduce(x, y),
+            reduce(_mm_movehl_ps(x, x), _mm_movehl_ps(y, y)),
+        );
+
+        let sign_bit_cos = _mm_castsi128_ps(_mm_slli_epi32(
+            _mm_andnot_si128(_mm_sub_epi32(j, PI32_2.m128i), PI32_4.m128i),
+            29,
+        ));
+        let sign_bit_sin = _mm_xor_ps(sign_bit_sin, swap_sign_bit_sin);
+
+        // evaluate the cosine polynomial for 0 <= x <= Pi/4
+        let z = _mm_mul_ps(x, x);
+        let y = m128_mul_add!(PS_COSCOF_P0.m128, z, PS_COSCOF_P1.m128);
+        let y = m128_mul_add!(y, z, PS_COSCOF_P2.m128);
+        let y = _mm_mul_ps(_mm_mul_ps(y, z), z);
+        let y = m128_neg_mul_sub!(z, PS_HALF.m128, y);
+        let y = _mm_add_ps(y, PS_ONE.m128);
+
+        // evaluate the sine polynomial for 0 <= x <= Pi/4
+        let y2 = m128_mul_add!(PS_SINCOF_P0.m128, z, PS_SINCOF_P1.m128);
+        let y2 = m128_mul_add!(y2, z, PS_SINCOF_P2.m128);
+        let y2 = m128_mul_add!(_mm_mul_ps(y2, z), x, x);
+
+        // select the correct result from the two polynomials
+        let sin = m128_select(poly_mask, y2, y);
+        let cos = m128_select(poly_mask, y, y2);
+        (_mm_xor_ps(sin, sign_bit_sin), _mm_xor_ps(cos, sign_bit_cos))
+    }
+
+    #[cold]
+    unsafe fn m128_sin_cos_scalar(x: __m128) -> (__m128, __m128) {
+        let lanes = UnionCast { m128: x }.f32x4;
+        let (sin0, cos0) = super::scalar_sin_cos(lanes[0]);
+        let (sin1, cos1) = super::scalar_sin_cos(lanes[1]);
+        let (sin2, cos2) = super::scalar_sin_cos(lanes[2]);
+        let (sin3, cos3) = super::scalar_sin_cos(lanes[3]);
+        (
+            _mm_set_ps(sin3, sin2, sin1, sin0),
+            _mm_set_ps(cos3, cos2, cos1, cos0),
+        )
+    }
+
+    /// Computes the tangent of each lane of `x`.
+    ///
+    /// The maximum error is 4 ULP.
+    #[inline]
+    pub(crate) unsafe fn m128_tan(x: __m128) -> __m128 {
+        let (sin, cos) = m128_sin_cos(x);
+        _mm_div_ps(sin, cos)
+    }
+
+    /// Computes `asin(|x|)` as `(p, big)` where `asin(|x|)` is `p` if `big` is unset and
+    /// `pi/2 - 2 * p` if it is set. Lanes outside `[-1, 1]` produce `NaN`.
+    #[inline]
+    unsafe fn m128_asin_abs(x: __m128) -> (__m128, __m128) {
+        // Based on the cephes `asinf`
+        let a = _mm_andnot_ps(PS_NEGATIVE_ZERO.m128, x);
+        let big = _mm_cmpgt_ps(a, PS_HALF.m128);
+        // asin(a) = pi/2 - 2 * asin(sqrt((1 - a) / 2)) for a > 0.5
+        let z_big = _mm_mul_ps(PS_HALF.m128, _mm_sub_ps(PS_ONE.m128, a));
+        let z = m128_select(big, z_big, _mm_mul_ps(a, a));
+        let s = m128_select(big, _mm_sqrt_ps(z_big), a);
+
+        let p = m128_mul_add!(PS_CEPHES_ASIN_P0.m128, z, PS_CEPHES_ASIN_P1.m128);
+        let p = m128_mul_add!(p, z, PS_CEPHES_ASIN_P2.m128);
+        let p = m128_mul_add!(p, z, PS_CEPHES_ASIN_P3.m128);
+        let p = m128_mul_add!(p, z, PS_CEPHES_ASIN_P4.m128);
+        let p = m128_mul_add!(_mm_mul_ps(p, z), s, s);
+        (p, big)
+    }
+
+    /// Computes the arcsine of each lane of `x`.
+    ///
+    /// The maximum error is 3 ULP. Lanes outside `[-1, 1]` return `NaN`.
+    #[inline]
+    pub(crate) unsafe fn m128_asin(x: __m128) -> __m128 {
+        let (p, big) = m128_asin_abs(x);
+        let p_big = _mm_sub_ps(PS_HALF_PI.m128, _mm_add_ps(p, p));
+        let result = m128_select(big, p_big, p);
+        _mm_or_ps(result, _mm_and_ps(x, PS_NEGATIVE_ZERO.m128))
+    }
+
+    /// Computes the arccosine of each lane of `x`.
+    ///
+    /// The maximum error is 2 ULP. Lanes outside `[-1, 1]` return `NaN`.
+    #[inline]
+    pub(crate) unsafe fn m128_acos(x: __m128) -> __m128 {
+        let (p, big) = m128_asin_abs(x);
+        let sign = _mm_and_ps(x, PS_NEGATIVE_ZERO.m128);
+        // acos(x) = 2 * asin(sqrt((1 - x) / 2)) for x > 0.5 and pi - that for x < -0.5
+        let p2 = _mm_add_ps(p, p);
+        let big_result = m128_select(
+            _mm_castsi128_ps(_mm_cmpeq_epi32(
+                _mm_castps_si128(sign),
+                _mm_castps_si128(PS_NEGATIVE_ZERO.m128),
+            )),
+            _mm_sub_ps(PS_PI.m128, p2),
+            p2,
+        );
+        // acos(x) = pi/2 - asin(x) otherwise
+        let small_result = _mm_sub_ps(PS_HALF_PI.m128, _mm_or_ps(p, sign));
+        m128_select(big, big_result, small_result)
+    }
+
+    /// Computes the four quadrant arctangent of `y` and `x` in each lane.
+    ///
+    /// The maximum error is 4 ULP. Zeros, infinities and `NaN`s are handled like `f32::atan2`.
+    #[inline]
+    pub(crate) unsafe fn m128_atan2(y: __m128, x: __m128) -> __m128 {
+        // Based on the cephes `atanf` with the argument reduced to `[0, 1]`
+        let ay = _mm_andnot_ps(PS_NEGATIVE_ZERO.m128, y);
+        let ax = _mm_andnot_ps(PS_NEGATIVE_ZERO.m128, x);
+        let swap = _mm_cmpgt_ps(ay, ax);
+        let q = _mm_div_ps(_mm_min_ps(ay, ax), _mm_max_ps(ay, ax));
+        // 0/0 and inf/inf only occur when both inputs have the same magnitude
+        let both_ord = _mm_cmpord_ps(ax, ay);
+        let q_nan = _mm_andnot_ps(_mm_cmpord_ps(q, q), both_ord);
+        let q_fixed = _mm_and_ps(_mm_cmpeq_ps(ax, PS_INFINITY.m128), PS_ONE.m128);
+        let q = m128_select(q_nan, q_fixed, q);
+
+        // atan(q) = pi/4 + atan((q - 1) / (q + 1)) for q > tan(pi/8)
+        let big = _mm_cmpgt_ps(q, PS_CEPHES_TAN_PI_8.m128);
+        let t = m128_select(
+            big,
+            _mm_div_ps(_mm_sub_ps(q, PS_ONE.m128), _mm_add_ps(q, PS_ONE.m128)),
+            q,
+        );
+        let z = _mm_mul_ps(t, t);
+        let p = m128_mul_add!(PS_CEPHES_ATAN_P0.m128, z, PS_CEPHES_ATAN_P1.m128);
+        let p = m128_mul_add!(p, z, PS_CEPHES_ATAN_P2.m128);
+        let p = m128_mul_add!(p, z, PS_CEPHES_ATAN_P3.m128);
+        let p = m128_mul_add!(_mm_mul_ps(p, z), t, t);
+        let a = _mm_add_ps(_mm_and_ps(big, PS_QUARTER_PI.m128), p);
+
+        // undo the argument reduction
+        let a = m128_select(swap, _mm_sub_ps(PS_HALF_PI.m128, a), a);
+        let x_sign = _mm_castsi128_ps(_mm_srai_epi32(_mm_castps_si128(x), 31));
+        let a = m128_select(x_sign, _mm_sub_ps(PS_PI.m128, a), a);
+        // min and max above drop a `NaN` in `y`, an all ones mask is also a `NaN`
+        let a = _mm_or_ps(a, _mm_cmpunord_ps(y, x));
+        _mm_or_ps(a, _mm_and_ps(y, PS_NEGATIVE_ZERO.m128))
+    }
+
+    /// Computes `e^x` for each lane of `x`.
+    ///
+    /// The maximum error is 2 ULP. Results that overflow return infinity and results that
+    /// underflow return zero.
+    #[inline]
+    pub(crate) unsafe fn m128_exp(x: __m128) -> __m128 {
+        // Based on http://gruntthepeon.free.fr/ssemath/sse_mathfun.h
+        // clamp the input so the power of two below can be built from two normal floats, the
+        // operand order keeps `NaN`s
+        let x = _mm_max_ps(PS_EXP_LO.m128, _mm_min_ps(PS_EXP_HI.m128, x));
+
+        // express exp(x) as exp(g + n * ln(2))
+        let n = _mm_cvtps_epi32(_mm_mul_ps(x, PS_CEPHES_LOG2EF.m128));
+        let fx = _mm_cvtepi32_ps(n);
+        let g = m128_neg_mul_sub!(fx, PS_CEPHES_EXP_C1.m128, x);
+        let g = m128_neg_mul_sub!(fx, PS_CEPHES_EXP_C2.m128, g);
+
+        let z = _mm_mul_ps(g, g);
+        let y = m128_mul_add!(PS_CEPHES_EXP_P0.m128, g, PS_CEPHES_EXP_P1.m128);
+        let y = m128_mul_add!(y, g, PS_CEPHES_EXP_P2.m128);
+        let y = m128_mul_add!(y, g, PS_CEPHES_EXP_P3.m128);
+        let y = m128_mul_add!(y, g, PS_CEPHES_EXP_P4.m128);
+        let y = m128_mul_add!(y, g, PS_CEPHES_EXP_P5.m128);
+        let y = m128_mul_add!(y, z, g);
+        let y = _mm_add_ps(y, PS_ONE.m128);
+
+        // scale by 2^n in two steps so that subnormal results are produced correctly
+        let n1 = _mm_srai_epi32(n, 1);
+        let n2 = _mm_sub_epi32(n, n1);
+        let pow2n1 = _mm_castsi128_ps(_mm_slli_epi32(_mm_add_epi32(n1, PI32_0X7F.m128i), 23));
+        let pow2n2 = _mm_castsi128_ps(_mm_slli_epi32(_mm_add_epi32(n2, PI32_0X7F.m128i), 23));
+        _mm_mul_ps(_mm_mul_ps(y, pow2n1), pow2n2)
+    }
+
+    /// Splits each positive lane of `x` into `(m, e)` with `x = (1 + m) * 2^e` and `m` in
+    /// `[sqrt(0.5) - 1, sqrt(2) - 1)`, returning the logarithm polynomial terms `(m, y, e)` where
+    /// `ln(1 + m)` is approximately `m + y`.
+    #[inline]
+    unsafe fn m128_log_reduce(x: __m128) -> (__m128, __m128, __m128) {
+        // Based on http://gruntthepeon.free.fr/ssemath/sse_mathfun.h
+        // move subnormals into the normal range so the exponent can be read from the bits
+        let subnormal = _mm_cmplt_ps(x, PS_MIN_NORM_POS.m128);
+        let x = m128_select(subnormal, _mm_mul_ps(x, PS_TWO_POW_23.m128), x);
+        let e_bias = _mm_and_ps(subnormal, PS_23.m128);
+
+        let e = _mm_sub_epi32(_mm_srli_epi32(_mm_castps_si128(x), 23), PI32_0X7E.m128i);
+        let e = _mm_sub_ps(_mm_cvtepi32_ps(e), e_bias);
+        // keep the mantissa and set the exponent so that m is in [0.5, 1)
+        let m = _mm_or_ps(_mm_and_ps(x, PS_INV_MANT_MASK.m128), PS_HALF.m128);
+
+        // if m < sqrt(0.5) { e -= 1; m = m + m - 1 } else { m = m - 1 }
+        let mask = _mm_cmplt_ps(m, PS_CEPHES_SQRTHF.m128);
+        let e = _mm_sub_ps(e, _mm_and_ps(mask, PS_ONE.m128));
+        let m = _mm_add_ps(_mm_sub_ps(m, PS_ONE.m128), _mm_and_ps(mask, m));
+
+        let z = _mm_mul_ps(m, m);
+        let y = m128_mul_add!(PS_CEPHES_LOG_P0.m128, m, PS_CEPHES_LOG_P1.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P2.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P3.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P4.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P5.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P6.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P7.m128);
+        let y = m128_mul_add!(y, m, PS_CEPHES_LOG_P8.m128);
+        let y = _mm_mul_ps(_mm_mul_ps(y, m), z);
+        let y = m128_neg_mul_sub!(z, PS_HALF.m128, y);
+        (m, y, e)
+    }
+
+    /// Applies the special cases of the logarithm functions to `result`.
+    #[inline]
+    unsafe fn m128_log_special(x: __m128, result: __m128) -> __m128 {
+        let result = m128_select(_mm_cmpeq_ps(x, PS_INFINITY.m128), PS_INFINITY.m128, result);
+        let result = m128_select(
+            _mm_cmpeq_ps(x, _mm_setzero_ps()),
+            PS_NEG_INFINITY.m128,
+            result,
+        );
+        // negative inputs and NaNs return NaN
+        let invalid = _mm_or_ps(_mm_cmplt_ps(x, _mm_setzero_ps()), _mm_cmpunord_ps(x, x));
+        _mm_or_ps(result, invalid)
+    }
+
+    /// Computes the natural logarithm of each lane of `x`.
+    ///
+    /// The maximum error is 1 ULP. Zero returns negative infinity, and negative inputs return
+    /// `NaN`.
+    #[inline]
+    pub(crate) unsafe fn m128_ln(x: __m128) -> __m128 {
+        let (m, y, e) = m128_log_reduce(x);
+        let y = m128_mul_add!(e, PS_CEPHES_LOG_Q1.m128, y);
+        let result = _mm_add_ps(m, y);
+        let result = m128_mul_add!(e, PS_CEPHES_LOG_Q2.m128, result);
+        m128_log_special(x, result)
+    }
+
+    /// Computes the base 2 logarithm of each lane of `x`.
+    ///
+    /// The maximum error is 2 ULP. Zero returns negative infinity, and negative inputs return
+    /// `NaN`.
+    #[inline]
+    pub(crate) unsafe fn m128_log2(x: __m128) -> __m128 {
+        // log2(1 + m) = (m + y) * log2(e), with log2(e) = 1 + LOG2EA to keep precision
+        let (m, y, e) = m128_log_reduce(x);
+        let result = _mm_mul_ps(y, PS_CEPHES_LOG2EA.m128);
+        let result = m128_mul_add!(m, PS_CEPHES_LOG2EA.m128, result);
+        let result = _mm_add_ps(result, y);
+        let result = _mm_add_ps(result, m);
+        let result = _mm_add_ps(result, e);
+        m128_log_special(x, result)
+    }
 }
 
 #[cfg(test)]
@@ -407,13 +774,10 @@ fn test_scalar_acos() {
     const PTVE_ONE: u32 = 0x3f_80_00_00; // 1.0_f32.to_bits();
     const NGVE_ONE: u32 = SIGN | PTVE_ONE;
     const STEP_SIZE: usize = (PTVE_ONE / MAX_TESTS) as usize;
-    for f in (SIGN..=NGVE_ONE)
-        .step_by(STEP_SIZE)
-        .map(|i| f32::from_bits(i))
-    {
+    for f in (SIGN..=NGVE_ONE).step_by(STEP_SIZE).map(f32::from_bits) {
         test_scalar_acos_angle(f);
     }
-    for f in (0..=PTVE_ONE).step_by(STEP_SIZE).map(|i| f32::from_bits(i)) {
+    for f in (0..=PTVE_ONE).step_by(STEP_SIZE).map(f32::from_bits) {
         test_scalar_acos_angle(f);
     }
 
@@ -438,10 +802,10 @@ fn test_scalar_sin_cos() {
     let ptve_pi = core::f32::consts::PI.to_bits();
     let ngve_pi = SIGN | ptve_pi;
     let step_pi = (ptve_pi / MAX_TESTS) as usize;
-    for f in (SIGN..=ngve_pi).step_by(step_pi).map(|i| f32::from_bits(i)) {
+    for f in (SIGN..=ngve_pi).step_by(step_pi).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
-    for f in (0..=ptve_pi).step_by(step_pi).map(|i| f32::from_bits(i)) {
+    for f in (0..=ptve_pi).step_by(step_pi).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
 
@@ -449,13 +813,10 @@ fn test_scalar_sin_cos() {
     let ptve_inf = core::f32::INFINITY.to_bits();
     let ngve_inf = core::f32::NEG_INFINITY.to_bits();
     let step_inf = (ptve_inf / MAX_TESTS) as usize;
-    for f in (SIGN..ngve_inf)
-        .step_by(step_inf)
-        .map(|i| f32::from_bits(i))
-    {
+    for f in (SIGN..ngve_inf).step_by(step_inf).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
-    for f in (0..ptve_inf).step_by(step_inf).map(|i| f32::from_bits(i)) {
+    for f in (0..ptve_inf).step_by(step_inf).map(f32::from_bits) {
         test_scalar_sin_cos_angle(f);
     }
 
@@ -496,6 +857,62 @@ fn test_sse2_m128_sin() {
     }
 }
 
+#[test]
+#[cfg(vec4_sse2)]
+fn test_sse2_m128_round_floor_ceil() {
+    use crate::Vec4;
+
+    // the SSE4.1 versions must match the SSE2 ones, including rounding half-way cases to even
+    let values = [
+        core::f32::NEG_INFINITY,
+        -8388609.0,
+        -8388607.5,
+        -1048576.5,
+        -3.7,
+        -2.5,
+        -2.25,
+        -1.5,
+        -1.3,
+        -0.6,
+        -0.5,
+        -0.0,
+        0.0,
+        0.4,
+        0.5,
+        1.5,
+        1.75,
+        2.5,
+        2.6,
+        3.5,
+        8.2,
+        1048576.5,
+        8388607.5,
+        8388609.0,
+        core::f32::INFINITY,
+    ];
+    for v in values.windows(4) {
+        let v = Vec4::new(v[0], v[1], v[2], v[3]);
+        unsafe {
+            assert_eq!(
+                Vec4(sse2::m128_round_sse2(v.0)),
+                Vec4(sse2::m128_round(v.0))
+            );
+            assert_eq!(
+                Vec4(sse2::m128_floor_sse2(v.0)),
+                Vec4(sse2::m128_floor(v.0))
+            );
+            assert_eq!(Vec4(sse2::m128_ceil_sse2(v.0)), Vec4(sse2::m128_ceil(v.0)));
+        }
+    }
+
+    let nan = Vec4::splat(core::f32::NAN);
+    unsafe {
+        assert!(Vec4(sse2::m128_round(nan.0)).is_nan_mask().all());
+        assert!(Vec4(sse2::m128_floor(nan.0)).is_nan_mask().all());
+        assert!(Vec4(sse2::m128_ceil(nan.0)).is_nan_mask().all());
+    }
+}
+
 // sse2::m128_sin is derived from the XMVectorSin in DirectXMath. It's been
 // observed both here and in the C++ version that the error rate increases
 // as the input angle drifts further from the bounds of PI.
@@ -1,4 +1,4 @@
-use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use crate::{DualQuat, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
 #[cfg(feature = "transform-types")]
 use crate::{TransformRT, TransformSRT};
 use bytemuck::{Pod, Zeroable};
@@ -13,6 +13,9 @@ unsafe impl Zeroable for Mat4 {}
 unsafe impl Pod for Quat {}
 unsafe impl Zeroable for Quat {}
 
+unsafe impl Pod for DualQuat {}
+unsafe impl Zeroable for DualQuat {}
+
 unsafe impl Pod for Vec2 {}
 unsafe impl Zeroable for Vec2 {}
 unsafe impl Pod for Vec3 {}
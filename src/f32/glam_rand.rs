@@ -1,4 +1,4 @@
-use super::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
 use rand::{
     distributions::{Distribution, Standard},
@@ -19,6 +19,13 @@ impl Distribution<Mat3> for Standard {
     }
 }
 
+impl Distribution<Mat3A> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat3A {
+        Mat3A::from_cols_array(&rng.gen())
+    }
+}
+
 impl Distribution<Mat4> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat4 {
@@ -1,4 +1,4 @@
-use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use crate::{Affine2, Affine3A, DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use core::fmt;
 use serde::{
     de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -124,6 +124,30 @@ impl Serialize for Mat3 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Mat3A {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+
+        let mut state = serializer.serialize_tuple_struct("Mat3A", 9)?;
+        state.serialize_field(&m00)?;
+        state.serialize_field(&m01)?;
+        state.serialize_field(&m02)?;
+        state.serialize_field(&m10)?;
+        state.serialize_field(&m11)?;
+        state.serialize_field(&m12)?;
+        state.serialize_field(&m20)?;
+        state.serialize_field(&m21)?;
+        state.serialize_field(&m22)?;
+        state.end()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Mat4 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -138,6 +162,48 @@ impl Serialize for Mat4 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Affine2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple_struct("Affine2", 6)?;
+        for f in self.to_cols_array().iter() {
+            state.serialize_field(f)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Affine3A {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple_struct("Affine3A", 12)?;
+        for f in self.to_cols_array().iter() {
+            state.serialize_field(f)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DualQuat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple_struct("DualQuat", 8)?;
+        for f in self.real.as_ref().iter().chain(self.dual.as_ref().iter()) {
+            state.serialize_field(f)?;
+        }
+        state.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for Vec2 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -341,8 +407,8 @@ impl<'de> Deserialize<'de> for Mat2 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 4] };
-                for i in 0..4 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -376,8 +442,8 @@ impl<'de> Deserialize<'de> for Mat3 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 9] };
-                for i in 0..9 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -392,6 +458,42 @@ impl<'de> Deserialize<'de> for Mat3 {
     }
 }
 
+impl<'de> Deserialize<'de> for Mat3A {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Mat3AVisitor;
+
+        // TODO: Not sure why this line is reported as uncovered
+        impl<'de> Visitor<'de> for Mat3AVisitor {
+            type Value = Mat3A;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Mat3A")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Mat3A, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 9] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let x = Vec3A::new(f[0], f[1], f[2]);
+                let y = Vec3A::new(f[3], f[4], f[5]);
+                let z = Vec3A::new(f[6], f[7], f[8]);
+                Ok(Mat3A::from_cols(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("Mat3A", 9, Mat3AVisitor)
+    }
+}
+
 impl<'de> Deserialize<'de> for Mat4 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -412,8 +514,8 @@ impl<'de> Deserialize<'de> for Mat4 {
                 V: SeqAccess<'de>,
             {
                 let mut f = { [0.0; 16] };
-                for i in 0..16 {
-                    f[i] = seq
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
                         .next_element()?
                         .ok_or_else(|| de::Error::invalid_length(i, &self))?;
                 }
@@ -428,3 +530,102 @@ impl<'de> Deserialize<'de> for Mat4 {
         deserializer.deserialize_tuple_struct("Mat4", 16, Mat4Visitor)
     }
 }
+
+impl<'de> Deserialize<'de> for Affine2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Affine2Visitor;
+
+        impl<'de> Visitor<'de> for Affine2Visitor {
+            type Value = Affine2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Affine2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Affine2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 6] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(Affine2::from_cols_array(&f))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("Affine2", 6, Affine2Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Affine3A {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Affine3AVisitor;
+
+        impl<'de> Visitor<'de> for Affine3AVisitor {
+            type Value = Affine3A;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Affine3A")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Affine3A, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 12] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(Affine3A::from_cols_array(&f))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("Affine3A", 12, Affine3AVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DualQuat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DualQuatVisitor;
+
+        impl<'de> Visitor<'de> for DualQuatVisitor {
+            type Value = DualQuat;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DualQuat")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DualQuat, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 8] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(DualQuat::from_real_dual(
+                    Quat::from_xyzw(f[0], f[1], f[2], f[3]),
+                    Quat::from_xyzw(f[4], f[5], f[6], f[7]),
+                ))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DualQuat", 8, DualQuatVisitor)
+    }
+}
@@ -1,5 +1,12 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
 use super::{scalar_sin_cos, Vec2, Vec4};
 use crate::swizzles::*;
+#[cfg(vec4_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec4_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec4_sse2, target_arch = "x86",))]
 use core::arch::x86::*;
 #[cfg(all(vec4_sse2, target_arch = "x86_64",))]
@@ -173,7 +180,23 @@ impl Mat2 {
             Self(acbd.into())
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let abcd = self.0.into();
+            let ab = vget_low_f32(abcd);
+            let cd = vget_high_f32(abcd);
+            let acbd = vcombine_f32(vzip1_f32(ab, cd), vzip2_f32(ab, cd));
+            Self(acbd.into())
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let abcd = self.0.into();
+            let acbd = i32x4_shuffle::<0, 2, 1, 3>(abcd, abcd);
+            Self(acbd.into())
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             let (m00, m01, m10, m11) = self.0.into();
             Self(Vec4::new(m00, m10, m01, m11))
@@ -192,7 +215,24 @@ impl Mat2 {
             _mm_cvtss_f32(det)
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let abcd = self.0.into();
+            let dcba = vrev64q_f32(vextq_f32(abcd, abcd, 2));
+            let prod = vmulq_f32(abcd, dcba);
+            vgetq_lane_f32(prod, 0) - vgetq_lane_f32(prod, 1)
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let abcd = self.0.into();
+            let dcba = i32x4_shuffle::<3, 2, 1, 0>(abcd, abcd);
+            let prod = f32x4_mul(abcd, dcba);
+            let det = f32x4_sub(prod, i32x4_shuffle::<1, 1, 1, 1>(prod, prod));
+            f32x4_extract_lane::<0>(det)
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             let (a, b, c, d) = self.0.into();
             a * d - b * c
@@ -217,7 +257,33 @@ impl Mat2 {
             Self(_mm_mul_ps(dbca, tmp).into())
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let sign = [1.0, -1.0, -1.0, 1.0];
+            let abcd = self.0.into();
+            let dcba = vrev64q_f32(vextq_f32(abcd, abcd, 2));
+            let prod = vmulq_f32(abcd, dcba);
+            let det = vgetq_lane_f32(prod, 0) - vgetq_lane_f32(prod, 1);
+            glam_assert!(det != 0.0);
+            let tmp = vdivq_f32(vld1q_f32(sign.as_ptr()), vdupq_n_f32(det));
+            let dbca = vcopyq_laneq_f32(vcopyq_laneq_f32(abcd, 0, abcd, 3), 3, abcd, 0);
+            Self(vmulq_f32(dbca, tmp).into())
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            const SIGN: v128 = f32x4(1.0, -1.0, -1.0, 1.0);
+            let abcd = self.0.into();
+            let dcba = i32x4_shuffle::<3, 2, 1, 0>(abcd, abcd);
+            let prod = f32x4_mul(abcd, dcba);
+            let sub = f32x4_sub(prod, i32x4_shuffle::<1, 1, 1, 1>(prod, prod));
+            let det = i32x4_shuffle::<0, 0, 0, 0>(sub, sub);
+            let tmp = f32x4_div(SIGN, det);
+            let dbca = i32x4_shuffle::<3, 1, 2, 0>(abcd, abcd);
+            Self(f32x4_mul(dbca, tmp).into())
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             let (a, b, c, d) = self.0.into();
             let det = a * d - b * c;
@@ -227,14 +293,63 @@ impl Mat2 {
         }
     }
 
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f32::EPSILON` times the product of the column lengths, which is the largest
+    /// determinant possible for columns of those lengths. This detects nearly singular matrices
+    /// regardless of their scale. `None` is also returned if `self` or the computed inverse
+    /// contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let max_det = self.x_axis.length() * self.y_axis.length();
+        if det.abs() > core::f32::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
     /// Transforms a `Vec2`.
     #[inline]
     pub fn mul_vec2(&self, other: Vec2) -> Vec2 {
-        // TODO: SSE2
-        let other = other.xxyy();
-        let tmp = self.0 * other;
-        let (x0, y0, x1, y1) = tmp.into();
-        Vec2::new(x0 + x1, y0 + y1)
+        #[cfg(vec4_neon)]
+        unsafe {
+            let abcd = self.0.into();
+            let xxyy = vcombine_f32(vdup_n_f32(other.x), vdup_n_f32(other.y));
+            let tmp = vmulq_f32(abcd, xxyy);
+            let sum = vadd_f32(vget_low_f32(tmp), vget_high_f32(tmp));
+            Vec2::new(vget_lane_f32(sum, 0), vget_lane_f32(sum, 1))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let abcd = self.0.into();
+            let xxyy = f32x4(other.x, other.x, other.y, other.y);
+            let tmp = f32x4_mul(abcd, xxyy);
+            let sum = f32x4_add(tmp, i32x4_shuffle::<2, 3, 0, 1>(tmp, tmp));
+            Vec2::new(f32x4_extract_lane::<0>(sum), f32x4_extract_lane::<1>(sum))
+        }
+
+        #[cfg(not(any(vec4_neon, vec4_simd128)))]
+        {
+            // TODO: SSE2
+            let other = other.xxyy();
+            let tmp = self.0 * other;
+            let (x0, y0, x1, y1) = tmp.into();
+            Vec2::new(x0 + x1, y0 + y1)
+        }
     }
 
     /// Multiplies two 2x2 matrices.
@@ -267,6 +382,98 @@ impl Mat2 {
         Mat2(self.0 * s)
     }
 
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns `(p, l, u)` such that `p * self` is equal to `l * u`, where `p` is a permutation
+    /// matrix, `l` is lower triangular with ones on its diagonal and `u` is upper triangular.
+    ///
+    /// Returns `None` if `self` is singular, which is detected by a pivot that is not larger than
+    /// `f32::EPSILON` times the largest element of `self`, or if `self` is not finite.
+    pub fn lu(&self) -> Option<(Self, Self, Self)> {
+        let (swap, factor, u) = lu_rows(self)?;
+        let p = if swap {
+            Self::from_cols(Vec2::unit_y(), Vec2::unit_x())
+        } else {
+            Self::identity()
+        };
+        Some((
+            p,
+            Self::from_cols(Vec2::new(1.0, factor), Vec2::unit_y()),
+            Self::from_cols(Vec2::new(u[0].x, 0.0), Vec2::new(u[0].y, u[1].y)),
+        ))
+    }
+
+    /// Solves `self * x = rhs` for `x` using LU decomposition with partial pivoting.
+    ///
+    /// Returns `None` if `self` is singular, see `lu` for how this is detected.
+    pub fn solve(&self, rhs: Vec2) -> Option<Vec2> {
+        let (swap, factor, u) = lu_rows(self)?;
+        let rhs = if swap { rhs.yx() } else { rhs };
+        let y = rhs.y - factor * rhs.x;
+        let x_y = y / u[1].y;
+        let x = Vec2::new((rhs.x - u[0].y * x_y) / u[0].x, x_y);
+        if x.is_finite() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the Cholesky decomposition of the symmetric positive definite `self`.
+    ///
+    /// Returns the lower triangular `l` with a positive diagonal such that `self` is equal to
+    /// `l * l.transpose()`, or `None` if `self` is not positive definite. Only the lower triangle
+    /// of `self` is used.
+    pub fn cholesky(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let (a, b, d) = (self.x_axis.x, self.x_axis.y, self.y_axis.y);
+        let tolerance = core::f32::EPSILON * a.max(d);
+        if a <= tolerance {
+            return None;
+        }
+        let l_xx = a.sqrt();
+        let l_xy = b / l_xx;
+        let d = d - l_xy * l_xy;
+        if d <= tolerance {
+            return None;
+        }
+        Some(Self::from_cols(
+            Vec2::new(l_xx, l_xy),
+            Vec2::new(0.0, d.sqrt()),
+        ))
+    }
+
+    /// Computes the QR decomposition of `self`.
+    ///
+    /// Returns `(q, r)` such that `self` is equal to `q * r`, where `q` is orthogonal and `r` is
+    /// upper triangular with a non-negative diagonal. Note that `q` may contain a reflection.
+    ///
+    /// The decomposition always exists. If `self` is singular an element on the diagonal of `r`
+    /// is zero, or close to zero because of rounding.
+    pub fn qr(&self) -> (Self, Self) {
+        let length = self.x_axis.length();
+        let q_x = if length > 0.0 {
+            self.x_axis / length
+        } else {
+            Vec2::unit_x()
+        };
+        let mut q_y = q_x.perp();
+        let mut r_yy = q_y.dot(self.y_axis);
+        if r_yy < 0.0 {
+            q_y = -q_y;
+            r_yy = -r_yy;
+        }
+        (
+            Self::from_cols(q_x, q_y),
+            Self::from_cols(
+                Vec2::new(length, 0.0),
+                Vec2::new(q_x.dot(self.y_axis), r_yy),
+            ),
+        )
+    }
+
     /// Returns true if the absolute difference of all elements between `self`
     /// and `other` is less than or equal to `max_abs_diff`.
     ///
@@ -280,6 +487,37 @@ impl Mat2 {
     pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
         self.0.abs_diff_eq(other.0, max_abs_diff)
     }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(&self) -> crate::DMat2 {
+        crate::DMat2::from_cols(self.x_axis.as_f64(), self.y_axis.as_f64())
+    }
+}
+
+/// Performs Gaussian elimination with partial pivoting on the rows of `m`.
+///
+/// Returns whether the rows were swapped, the multiple of the first row that was subtracted from
+/// the second and the rows of the upper triangular factor, or `None` if `m` is singular or not
+/// finite.
+fn lu_rows(m: &Mat2) -> Option<(bool, f32, [Vec2; 2])> {
+    if !m.is_finite() {
+        return None;
+    }
+    let row0 = Vec2::new(m.x_axis.x, m.y_axis.x);
+    let row1 = Vec2::new(m.x_axis.y, m.y_axis.y);
+    let tolerance = core::f32::EPSILON * row0.abs().max(row1.abs()).max_element();
+    let swap = row1.x.abs() > row0.x.abs();
+    let (row0, row1) = if swap { (row1, row0) } else { (row0, row1) };
+    if row0.x.abs() <= tolerance {
+        return None;
+    }
+    let factor = row1.x / row0.x;
+    let row1 = Vec2::new(0.0, row1.y - factor * row0.y);
+    if row1.y.abs() <= tolerance {
+        return None;
+    }
+    Some((swap, factor, [row0, row1]))
 }
 
 impl AsRef<[f32; 4]> for Mat2 {
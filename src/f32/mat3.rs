@@ -1,4 +1,13 @@
-use super::{scalar_sin_cos, Quat, Vec2, Vec3, Vec3A, Vec3ASwizzles};
+#[cfg(vec4_sse2)]
+use super::Vec4;
+use super::{scalar_sin_cos, Mat3A, Quat, Vec2, Vec3, Vec3A, Vec3ASwizzles};
+use crate::EulerRot;
+#[cfg(all(vec3a_sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec3a_sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+#[cfg(vec3a_sse2)]
+use core::mem::MaybeUninit;
 use core::{
     fmt,
     ops::{Add, Mul, Sub},
@@ -65,6 +74,40 @@ impl fmt::Display for Mat3 {
     }
 }
 
+#[cfg(vec3a_sse2)]
+impl Mat3 {
+    /// Loads the columns of `self` into `__m128`s. The `w` lanes contain other elements of
+    /// `self` and must be ignored.
+    #[inline]
+    unsafe fn load_cols(&self) -> (__m128, __m128, __m128) {
+        // loaded as two full registers and the last element, so the loads don't overlap
+        let ptr = self as *const Self as *const f32;
+        let xx_xy_xz_yx = _mm_loadu_ps(ptr);
+        let yy_yz_zx_zy = _mm_loadu_ps(ptr.add(4));
+        let zz = _mm_load_ss(ptr.add(8));
+        let yx_yx_yy_yy = _mm_shuffle_ps(xx_xy_xz_yx, yy_yz_zx_zy, 0b00_00_11_11);
+        let x_axis = xx_xy_xz_yx;
+        let y_axis = _mm_shuffle_ps(yx_yx_yy_yy, yy_yz_zx_zy, 0b01_01_10_00);
+        let z_axis = _mm_shuffle_ps(yy_yz_zx_zy, zz, 0b00_00_11_10);
+        (x_axis, y_axis, z_axis)
+    }
+
+    /// Creates a `Mat3` from three columns stored in `__m128`s, ignoring their `w` lanes.
+    #[inline]
+    unsafe fn store_cols(x_axis: __m128, y_axis: __m128, z_axis: __m128) -> Self {
+        // packed into two full registers and the last element, so the stores don't overlap
+        let xz_xz_yx_yx = _mm_shuffle_ps(x_axis, y_axis, 0b00_00_10_10);
+        let xx_xy_xz_yx = _mm_shuffle_ps(x_axis, xz_xz_yx_yx, 0b10_00_01_00);
+        let yy_yz_zx_zy = _mm_shuffle_ps(y_axis, z_axis, 0b01_00_10_01);
+        let mut out = MaybeUninit::<Self>::uninit();
+        let ptr = out.as_mut_ptr() as *mut f32;
+        _mm_storeu_ps(ptr, xx_xy_xz_yx);
+        _mm_storeu_ps(ptr.add(4), yy_yz_zx_zy);
+        _mm_store_ss(ptr.add(8), _mm_movehl_ps(z_axis, z_axis));
+        out.assume_init()
+    }
+}
+
 impl Mat3 {
     /// Creates a 3x3 matrix with all elements set to `0.0`.
     #[inline]
@@ -182,6 +225,14 @@ impl Mat3 {
         Self::from_quat(quat)
     }
 
+    /// Creates a 3x3 rotation matrix from the given Euler rotation sequence and the angles (in
+    /// radians).
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let quat = Quat::from_euler(euler, a, b, c);
+        Self::from_quat(quat)
+    }
+
     /// Creates a 3x3 rotation matrix from `angle` (in radians) around the x axis.
     #[inline]
     pub fn from_rotation_x(angle: f32) -> Self {
@@ -271,24 +322,29 @@ impl Mat3 {
     /// Returns the transpose of `self`.
     #[inline]
     pub fn transpose(&self) -> Self {
-        // #[cfg(vec3a_sse2)]
-        // {
-        //     #[cfg(target_arch = "x86")]
-        //     use core::arch::x86::*;
-        //     #[cfg(target_arch = "x86_64")]
-        //     use core::arch::x86_64::*;
-        //     unsafe {
-        //         let tmp0 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b01_00_01_00);
-        //         let tmp1 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b11_10_11_10);
-
-        //         Self {
-        //             x_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b00_00_10_00).into(),
-        //             y_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b01_01_11_01).into(),
-        //             z_axis: _mm_shuffle_ps(tmp1, self.z_axis.0, 0b10_10_10_00).into(),
-        //         }
-        //     }
-        // }
-        // #[cfg(vec3a_f32)]
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            // the first eight elements are transposed in two registers, the last one stays put
+            let ptr = self as *const Self as *const f32;
+            let m0_m1_m2_m3 = _mm_loadu_ps(ptr);
+            let m4_m5_m6_m7 = _mm_loadu_ps(ptr.add(4));
+            let m6_m6_m1_m1 = _mm_shuffle_ps(m4_m5_m6_m7, m0_m1_m2_m3, 0b01_01_10_10);
+            let m2_m2_m5_m5 = _mm_shuffle_ps(m0_m1_m2_m3, m4_m5_m6_m7, 0b01_01_10_10);
+            let mut out = MaybeUninit::<Self>::uninit();
+            let out_ptr = out.as_mut_ptr() as *mut f32;
+            _mm_storeu_ps(
+                out_ptr,
+                _mm_shuffle_ps(m0_m1_m2_m3, m6_m6_m1_m1, 0b10_00_11_00),
+            );
+            _mm_storeu_ps(
+                out_ptr.add(4),
+                _mm_shuffle_ps(m4_m5_m6_m7, m2_m2_m5_m5, 0b10_00_11_00),
+            );
+            *out_ptr.add(8) = self.z_axis.z;
+            out.assume_init()
+        }
+
+        #[cfg(vec3a_f32)]
         {
             Self {
                 x_axis: Vec3 {
@@ -320,14 +376,52 @@ impl Mat3 {
     ///
     /// If the matrix is not invertible the returned matrix will be invalid.
     pub fn inverse(&self) -> Self {
-        let tmp0 = self.y_axis.cross(self.z_axis);
-        let tmp1 = self.z_axis.cross(self.x_axis);
-        let tmp2 = self.x_axis.cross(self.y_axis);
-        let det = self.z_axis.dot_as_vec3(tmp2);
-        glam_assert!(det.cmpne(Vec3::zero()).all());
-        let inv_det = det.recip();
-        // TODO: Work out if it's possible to get rid of the transpose
-        Mat3::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            let (x_axis, y_axis, z_axis) = self.load_cols();
+            let inverse = Mat3A::from_cols(Vec3A(x_axis), Vec3A(y_axis), Vec3A(z_axis)).inverse();
+            Self::store_cols(inverse.x_axis.0, inverse.y_axis.0, inverse.z_axis.0)
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            let tmp0 = self.y_axis.cross(self.z_axis);
+            let tmp1 = self.z_axis.cross(self.x_axis);
+            let tmp2 = self.x_axis.cross(self.y_axis);
+            let det = self.z_axis.dot_as_vec3(tmp2);
+            glam_assert!(det.cmpne(Vec3::zero()).all());
+            let inv_det = det.recip();
+            // TODO: Work out if it's possible to get rid of the transpose
+            Mat3::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+        }
+    }
+
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f32::EPSILON` times the product of the column lengths, which is the largest
+    /// determinant possible for columns of those lengths. This detects nearly singular matrices
+    /// regardless of their scale. `None` is also returned if `self` or the computed inverse
+    /// contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let max_det = self.x_axis.length() * self.y_axis.length() * self.z_axis.length();
+        if det.abs() > core::f32::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
     }
 
     /// Transforms a `Vec3A`.
@@ -348,10 +442,25 @@ impl Mat3 {
     /// Multiplies two 3x3 matrices.
     #[inline]
     pub fn mul_mat3(&self, other: &Self) -> Self {
-        Self {
-            x_axis: self.mul_vec3(other.x_axis),
-            y_axis: self.mul_vec3(other.y_axis),
-            z_axis: self.mul_vec3(other.z_axis),
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            let (x_axis, y_axis, z_axis) = self.load_cols();
+            let lhs = Mat3A::from_cols(Vec3A(x_axis), Vec3A(y_axis), Vec3A(z_axis));
+            let (x_axis, y_axis, z_axis) = other.load_cols();
+            Self::store_cols(
+                lhs.mul_vec3a(Vec3A(x_axis)).0,
+                lhs.mul_vec3a(Vec3A(y_axis)).0,
+                lhs.mul_vec3a(Vec3A(z_axis)).0,
+            )
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self {
+                x_axis: self.mul_vec3(other.x_axis),
+                y_axis: self.mul_vec3(other.y_axis),
+                z_axis: self.mul_vec3(other.z_axis),
+            }
         }
     }
 
@@ -408,6 +517,147 @@ impl Mat3 {
         res.xy()
     }
 
+    /// Transforms each of the given `Vec2`s as 2D points, in place.
+    ///
+    /// This gives the same results as calling `transform_point2` on each element, but with SIMD
+    /// two points are transformed at once.
+    pub fn transform_points2(&self, points: &mut [Vec2]) {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            // the `x` and `y` elements of each column repeated for two points, and the `z` elements
+            let x_axis = Vec4::new(self.x_axis.x, self.x_axis.y, self.x_axis.x, self.x_axis.y);
+            let y_axis = Vec4::new(self.y_axis.x, self.y_axis.y, self.y_axis.x, self.y_axis.y);
+            let z_axis = Vec4::new(self.z_axis.x, self.z_axis.y, self.z_axis.x, self.z_axis.y);
+            let x_axis_z = Vec4::splat(self.x_axis.z);
+            let y_axis_z = Vec4::splat(self.y_axis.z);
+            let z_axis_z = Vec4::splat(self.z_axis.z);
+            let mut chunks = points.chunks_exact_mut(2);
+            for chunk in &mut chunks {
+                let ptr = chunk.as_mut_ptr() as *mut f32;
+                let x0_y0_x1_y1 = _mm_loadu_ps(ptr);
+                let xx = Vec4(_mm_shuffle_ps(x0_y0_x1_y1, x0_y0_x1_y1, 0b10_10_00_00));
+                let yy = Vec4(_mm_shuffle_ps(x0_y0_x1_y1, x0_y0_x1_y1, 0b11_11_01_01));
+                let mut res = x_axis * xx;
+                res = y_axis.mul_add(yy, res);
+                res = z_axis + res;
+                let mut res_z = x_axis_z * xx;
+                res_z = y_axis_z.mul_add(yy, res_z);
+                res_z = z_axis_z + res_z;
+                _mm_storeu_ps(ptr, (res * res_z.recip()).0);
+            }
+            for point in chunks.into_remainder() {
+                *point = self.transform_point2(*point);
+            }
+        }
+
+        #[cfg(not(vec4_sse2))]
+        {
+            for point in points.iter_mut() {
+                *point = self.transform_point2(*point);
+            }
+        }
+    }
+
+    /// Transforms each of the given `Vec2`s as 2D vectors, in place.
+    ///
+    /// This gives the same results as calling `transform_vector2` on each element, but with SIMD
+    /// two vectors are transformed at once.
+    pub fn transform_vectors2(&self, vectors: &mut [Vec2]) {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            let x_axis = Vec4::new(self.x_axis.x, self.x_axis.y, self.x_axis.x, self.x_axis.y);
+            let y_axis = Vec4::new(self.y_axis.x, self.y_axis.y, self.y_axis.x, self.y_axis.y);
+            let mut chunks = vectors.chunks_exact_mut(2);
+            for chunk in &mut chunks {
+                let ptr = chunk.as_mut_ptr() as *mut f32;
+                let x0_y0_x1_y1 = _mm_loadu_ps(ptr);
+                let xx = Vec4(_mm_shuffle_ps(x0_y0_x1_y1, x0_y0_x1_y1, 0b10_10_00_00));
+                let yy = Vec4(_mm_shuffle_ps(x0_y0_x1_y1, x0_y0_x1_y1, 0b11_11_01_01));
+                let res = y_axis.mul_add(yy, x_axis * xx);
+                _mm_storeu_ps(ptr, res.0);
+            }
+            for vector in chunks.into_remainder() {
+                *vector = self.transform_vector2(*vector);
+            }
+        }
+
+        #[cfg(not(vec4_sse2))]
+        {
+            for vector in vectors.iter_mut() {
+                *vector = self.transform_vector2(*vector);
+            }
+        }
+    }
+
+    /// Computes the eigen decomposition of `self`, which must be symmetric.
+    ///
+    /// Returns the eigenvalues in decreasing order and a matrix whose columns are the matching
+    /// normalized eigenvectors, such that `self` is equal to
+    /// `vectors * Mat3::from_scale(values) * vectors.transpose()`. See `Mat3A::symmetric_eigen`
+    /// for how the signs of the eigenvectors are chosen.
+    #[inline]
+    pub fn symmetric_eigen(&self) -> (Vec3, Self) {
+        let (values, vectors) = Mat3A::from(*self).symmetric_eigen();
+        (values.into(), vectors.into())
+    }
+
+    /// Computes the singular value decomposition of `self`.
+    ///
+    /// Returns `(u, sigma, v)` such that `self` is equal to
+    /// `u * Mat3::from_scale(sigma) * v.transpose()`, where `u` and `v` are rotations. See
+    /// `Mat3A::svd` for how reflections are handled.
+    #[inline]
+    pub fn svd(&self) -> (Self, Vec3, Self) {
+        let (u, sigma, v) = Mat3A::from(*self).svd();
+        (u.into(), sigma.into(), v.into())
+    }
+
+    /// Computes the polar decomposition of `self` into a rotation and a stretch.
+    ///
+    /// Returns `(rotation, stretch)` such that `self` is equal to `rotation * stretch`. A
+    /// reflection gives a negative eigenvalue in `stretch`, see `Mat3A::polar_decompose` for
+    /// details.
+    #[inline]
+    pub fn polar_decompose(&self) -> (Self, Self) {
+        let (rotation, stretch) = Mat3A::from(*self).polar_decompose();
+        (rotation.into(), stretch.into())
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns `(p, l, u)` such that `p * self` is equal to `l * u`. See `Mat3A::lu` for details.
+    #[inline]
+    pub fn lu(&self) -> Option<(Self, Self, Self)> {
+        let (p, l, u) = Mat3A::from(*self).lu()?;
+        Some((p.into(), l.into(), u.into()))
+    }
+
+    /// Solves `self * x = rhs` for `x` using LU decomposition with partial pivoting.
+    ///
+    /// Returns `None` if `self` is singular, see `Mat3A::lu` for how this is detected.
+    #[inline]
+    pub fn solve(&self, rhs: Vec3) -> Option<Vec3> {
+        Mat3A::from(*self).solve(rhs.into()).map(Vec3::from)
+    }
+
+    /// Computes the Cholesky decomposition of the symmetric positive definite `self`.
+    ///
+    /// Returns the lower triangular `l` such that `self` is equal to `l * l.transpose()`, or
+    /// `None` if `self` is not positive definite. Only the lower triangle of `self` is used.
+    #[inline]
+    pub fn cholesky(&self) -> Option<Self> {
+        Mat3A::from(*self).cholesky().map(Self::from)
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns `(q, r)` such that `self` is equal to `q * r`. See `Mat3A::qr` for details.
+    #[inline]
+    pub fn qr(&self) -> (Self, Self) {
+        let (q, r) = Mat3A::from(*self).qr();
+        (q.into(), r.into())
+    }
+
     /// Returns true if the absolute difference of all elements between `self`
     /// and `other` is less than or equal to `max_abs_diff`.
     ///
@@ -423,6 +673,16 @@ impl Mat3 {
             && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
             && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
     }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(&self) -> crate::DMat3 {
+        crate::DMat3::from_cols(
+            self.x_axis.as_f64(),
+            self.y_axis.as_f64(),
+            self.z_axis.as_f64(),
+        )
+    }
 }
 
 impl AsRef<[f32; 9]> for Mat3 {
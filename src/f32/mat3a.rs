@@ -0,0 +1,981 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{
+    scalar_sin_cos, symmetric_eigen_jacobi, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec3ASwizzles,
+    Vec4,
+};
+use crate::EulerRot;
+#[cfg(all(vec3a_sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec3a_sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+use core::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: Mat3A = const_mat3a!([0.0; 9]);
+const IDENTITY: Mat3A = const_mat3a!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+
+/// Creates a `Mat3A` from three column vectors.
+#[inline]
+pub fn mat3a(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Mat3A {
+    Mat3A {
+        x_axis,
+        y_axis,
+        z_axis,
+    }
+}
+
+#[inline]
+fn quat_to_axes(rotation: Quat) -> (Vec3A, Vec3A, Vec3A) {
+    glam_assert!(rotation.is_normalized());
+    let (x, y, z, w) = rotation.into();
+    let x2 = x + x;
+    let y2 = y + y;
+    let z2 = z + z;
+    let xx = x * x2;
+    let xy = x * y2;
+    let xz = x * z2;
+    let yy = y * y2;
+    let yz = y * z2;
+    let zz = z * z2;
+    let wx = w * x2;
+    let wy = w * y2;
+    let wz = w * z2;
+
+    let x_axis = Vec3A::new(1.0 - (yy + zz), xy + wz, xz - wy);
+    let y_axis = Vec3A::new(xy - wz, 1.0 - (xx + zz), yz + wx);
+    let z_axis = Vec3A::new(xz + wy, yz - wx, 1.0 - (xx + yy));
+    (x_axis, y_axis, z_axis)
+}
+
+/// A 3x3 column major matrix with SIMD support.
+///
+/// This type uses `Vec3A` columns and is 16 byte aligned, making it faster than `Mat3` for most
+/// operations at the cost of some wasted space.
+///
+/// It is possible to convert between `Mat3` and `Mat3A` types using `From` trait implementations.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Mat3A {
+    pub x_axis: Vec3A,
+    pub y_axis: Vec3A,
+    pub z_axis: Vec3A,
+}
+
+impl Default for Mat3A {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for Mat3A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
+
+impl Mat3A {
+    /// Creates a 3x3 matrix with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a 3x3 identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 matrix from a `[f32; 9]` stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array(m: &[f32; 9]) -> Self {
+        Self {
+            x_axis: Vec3A::new(m[0], m[1], m[2]),
+            y_axis: Vec3A::new(m[3], m[4], m[5]),
+            z_axis: Vec3A::new(m[6], m[7], m[8]),
+        }
+    }
+
+    /// Creates a `[f32; 9]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f32; 9] {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+        [m00, m01, m02, m10, m11, m12, m20, m21, m22]
+    }
+
+    /// Creates a 3x3 matrix from a `[[f32; 3]; 3]` stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f32; 3]; 3]) -> Self {
+        Self {
+            x_axis: m[0].into(),
+            y_axis: m[1].into(),
+            z_axis: m[2].into(),
+        }
+    }
+
+    /// Creates a `[[f32; 3]; 3]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f32; 3]; 3] {
+        [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+    }
+
+    /// Creates a 3x3 homogeneous transformation matrix from the given `scale`,
+    /// rotation `angle` (in radians) and `translation`.
+    ///
+    /// The resulting matrix can be used to transform 2D points and vectors.
+    #[inline]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        let (sin, cos) = scalar_sin_cos(angle);
+        let (scale_x, scale_y) = scale.into();
+        Self {
+            x_axis: Vec3A::new(cos * scale_x, sin * scale_x, 0.0),
+            y_axis: Vec3A::new(-sin * scale_y, cos * scale_y, 0.0),
+            z_axis: Vec3A::new(translation.x, translation.y, 1.0),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from the given quaternion.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 matrix from the upper left 3x3 part of the given 4x4 matrix, discarding the
+    /// `w` elements and the `w_axis` column.
+    #[inline]
+    pub fn from_mat4(m: Mat4) -> Self {
+        Self {
+            x_axis: m.x_axis.into(),
+            y_axis: m.y_axis.into(),
+            z_axis: m.z_axis.into(),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from a normalized rotation `axis` and
+    /// `angle` (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (sin, cos) = scalar_sin_cos(angle);
+        let (x, y, z) = axis.into();
+        let (xsin, ysin, zsin) = (axis * sin).into();
+        let (x2, y2, z2) = (axis * axis).into();
+        let omc = 1.0 - cos;
+        let xyomc = x * y * omc;
+        let xzomc = x * z * omc;
+        let yzomc = y * z * omc;
+        Self {
+            x_axis: Vec3A::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin),
+            y_axis: Vec3A::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin),
+            z_axis: Vec3A::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from the given Euler angles (in radians).
+    #[inline]
+    pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
+        let quat = Quat::from_rotation_ypr(yaw, pitch, roll);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 3x3 rotation matrix from the given Euler rotation sequence and the angles (in
+    /// radians).
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let quat = Quat::from_euler(euler, a, b, c);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the x axis.
+    #[inline]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: Vec3A::unit_x(),
+            y_axis: Vec3A::new(0.0, cosa, sina),
+            z_axis: Vec3A::new(0.0, -sina, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the y axis.
+    #[inline]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: Vec3A::new(cosa, 0.0, -sina),
+            y_axis: Vec3A::unit_y(),
+            z_axis: Vec3A::new(sina, 0.0, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the z axis.
+    #[inline]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: Vec3A::new(cosa, sina, 0.0),
+            y_axis: Vec3A::new(-sina, cosa, 0.0),
+            z_axis: Vec3A::unit_z(),
+        }
+    }
+
+    /// Creates a 3x3 non-uniform scale matrix.
+    #[inline]
+    pub fn from_scale(scale: Vec3) -> Self {
+        // Do not panic as long as any component is non-zero
+        glam_assert!(scale.cmpne(Vec3::zero()).any());
+        let (x, y, z) = scale.into();
+        Self {
+            x_axis: Vec3A::new(x, 0.0, 0.0),
+            y_axis: Vec3A::new(0.0, y, 0.0),
+            z_axis: Vec3A::new(0.0, 0.0, z),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            let tmp0 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b01_00_01_00);
+            let tmp1 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b11_10_11_10);
+
+            Self {
+                x_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b00_00_10_00).into(),
+                y_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b01_01_11_01).into(),
+                z_axis: _mm_shuffle_ps(tmp1, self.z_axis.0, 0b10_10_10_00).into(),
+            }
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self {
+                x_axis: Vec3A::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+                y_axis: Vec3A::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+                z_axis: Vec3A::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+            }
+        }
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If the matrix is not invertible the returned matrix will be invalid.
+    pub fn inverse(&self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            // Each column is rotated to `yzx` once and shared between the cross products. The
+            // unrotated cross products come out as `zxy`, which the final transpose accounts for.
+            let x_yzx = _mm_shuffle_ps(self.x_axis.0, self.x_axis.0, 0b00_00_10_01);
+            let y_yzx = _mm_shuffle_ps(self.y_axis.0, self.y_axis.0, 0b00_00_10_01);
+            let z_yzx = _mm_shuffle_ps(self.z_axis.0, self.z_axis.0, 0b00_00_10_01);
+            let tmp0_zxy = _mm_sub_ps(
+                _mm_mul_ps(self.y_axis.0, z_yzx),
+                _mm_mul_ps(y_yzx, self.z_axis.0),
+            );
+            let tmp1_zxy = _mm_sub_ps(
+                _mm_mul_ps(self.z_axis.0, x_yzx),
+                _mm_mul_ps(z_yzx, self.x_axis.0),
+            );
+            let tmp2_zxy = _mm_sub_ps(
+                _mm_mul_ps(self.x_axis.0, y_yzx),
+                _mm_mul_ps(x_yzx, self.y_axis.0),
+            );
+
+            // det = dot(z_axis, tmp2), splatted to all lanes
+            let z_zxy = _mm_shuffle_ps(self.z_axis.0, self.z_axis.0, 0b00_01_00_10);
+            let prod = _mm_mul_ps(z_zxy, tmp2_zxy);
+            let det = _mm_add_ps(
+                _mm_add_ps(
+                    _mm_shuffle_ps(prod, prod, 0b00_00_00_00),
+                    _mm_shuffle_ps(prod, prod, 0b01_01_01_01),
+                ),
+                _mm_shuffle_ps(prod, prod, 0b10_10_10_10),
+            );
+            glam_assert!(_mm_cvtss_f32(det) != 0.0);
+            let inv_det = _mm_div_ps(_mm_set1_ps(1.0), det);
+
+            let lo = _mm_unpacklo_ps(tmp0_zxy, tmp1_zxy);
+            let hi = _mm_unpackhi_ps(tmp0_zxy, tmp1_zxy);
+            Self {
+                x_axis: Vec3A(_mm_mul_ps(
+                    _mm_shuffle_ps(lo, tmp2_zxy, 0b01_01_11_10),
+                    inv_det,
+                )),
+                y_axis: Vec3A(_mm_mul_ps(
+                    _mm_shuffle_ps(hi, tmp2_zxy, 0b10_10_01_00),
+                    inv_det,
+                )),
+                z_axis: Vec3A(_mm_mul_ps(
+                    _mm_shuffle_ps(lo, tmp2_zxy, 0b00_00_01_00),
+                    inv_det,
+                )),
+            }
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            let tmp0 = self.y_axis.cross(self.z_axis);
+            let tmp1 = self.z_axis.cross(self.x_axis);
+            let tmp2 = self.x_axis.cross(self.y_axis);
+            let det = self.z_axis.dot_as_vec3(tmp2);
+            glam_assert!(det.cmpne(Vec3A::zero()).all());
+            let inv_det = det.recip();
+            Self::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+        }
+    }
+
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f32::EPSILON` times the product of the column lengths, which is the largest
+    /// determinant possible for columns of those lengths. This detects nearly singular matrices
+    /// regardless of their scale. `None` is also returned if `self` or the computed inverse
+    /// contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let max_det = self.x_axis.length() * self.y_axis.length() * self.z_axis.length();
+        if det.abs() > core::f32::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Transforms a `Vec3A`.
+    #[inline]
+    pub fn mul_vec3a(&self, other: Vec3A) -> Vec3A {
+        let mut res = self.x_axis * other.xxx();
+        res = self.y_axis.mul_add(other.yyy(), res);
+        res = self.z_axis.mul_add(other.zzz(), res);
+        res
+    }
+
+    /// Transforms a `Vec3`.
+    #[inline]
+    pub fn mul_vec3(&self, other: Vec3) -> Vec3 {
+        Vec3::from(self.mul_vec3a(Vec3A::from(other)))
+    }
+
+    /// Multiplies two 3x3 matrices.
+    #[inline]
+    pub fn mul_mat3a(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec3a(other.x_axis),
+            y_axis: self.mul_vec3a(other.y_axis),
+            z_axis: self.mul_vec3a(other.z_axis),
+        }
+    }
+
+    /// Adds two 3x3 matrices.
+    #[inline]
+    pub fn add_mat3a(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis + other.x_axis,
+            y_axis: self.y_axis + other.y_axis,
+            z_axis: self.z_axis + other.z_axis,
+        }
+    }
+
+    /// Subtracts two 3x3 matrices.
+    #[inline]
+    pub fn sub_mat3a(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis - other.x_axis,
+            y_axis: self.y_axis - other.y_axis,
+            z_axis: self.z_axis - other.z_axis,
+        }
+    }
+
+    /// Multiplies a 3x3 matrix by a scalar.
+    #[inline]
+    pub fn mul_scalar(&self, other: f32) -> Self {
+        let s = Vec3A::splat(other);
+        Self {
+            x_axis: self.x_axis * s,
+            y_axis: self.y_axis * s,
+            z_axis: self.z_axis * s,
+        }
+    }
+
+    /// Transforms the given `Vec2` as 2D point.
+    /// This is the equivalent of multiplying the `Vec2` as a `Vec3A` where `z`
+    /// is `1.0`.
+    #[inline]
+    pub fn transform_point2(&self, other: Vec2) -> Vec2 {
+        let mut res = self.x_axis.mul(Vec3A::splat(other.x));
+        res = self.y_axis.mul_add(Vec3A::splat(other.y), res);
+        res = self.z_axis.add(res);
+        res = res.mul(res.zzz().recip());
+        res.xy()
+    }
+
+    /// Transforms the given `Vec2` as 2D vector.
+    /// This is the equivalent of multiplying the `Vec2` as a `Vec3A` where `z`
+    /// is `0.0`.
+    #[inline]
+    pub fn transform_vector2(&self, other: Vec2) -> Vec2 {
+        let mut res = self.x_axis.mul(Vec3A::splat(other.x));
+        res = self.y_axis.mul_add(Vec3A::splat(other.y), res);
+        res.xy()
+    }
+
+    /// Computes the eigen decomposition of `self`, which must be symmetric.
+    ///
+    /// Returns the eigenvalues in decreasing order and a matrix whose columns are the matching
+    /// normalized eigenvectors, such that `self` is equal to
+    /// `vectors * Mat3A::from_scale(values) * vectors.transpose()`.
+    ///
+    /// The sign of an eigenvector is arbitrary, so the first two eigenvectors are chosen to have
+    /// a positive largest component and the third is the cross product of the first two. This
+    /// makes the eigenvector matrix a rotation, which can be converted to a `Quat` using
+    /// `Quat::from_rotation_mat3a`.
+    ///
+    /// Only the upper triangle of `self` is used.
+    pub fn symmetric_eigen(&self) -> (Vec3A, Self) {
+        let (mut values, mut vectors) = symmetric_eigen3(self);
+
+        // sort by decreasing eigenvalue
+        for &(i, j) in [(0, 1), (1, 2), (0, 1)].iter() {
+            if values[i] < values[j] {
+                values.swap(i, j);
+                vectors.swap(i, j);
+            }
+        }
+
+        for vector in vectors.iter_mut().take(2) {
+            let abs = vector.abs();
+            let max = abs.max_element();
+            let largest = if abs.x == max {
+                vector.x
+            } else if abs.y == max {
+                vector.y
+            } else {
+                vector.z
+            };
+            if largest < 0.0 {
+                *vector = -*vector;
+            }
+        }
+        vectors[2] = vectors[0].cross(vectors[1]);
+
+        (
+            Vec3A::new(values[0], values[1], values[2]),
+            Self::from_cols(vectors[0], vectors[1], vectors[2]),
+        )
+    }
+
+    /// Computes the singular value decomposition of `self`.
+    ///
+    /// Returns `(u, sigma, v)` such that `self` is equal to
+    /// `u * Mat3A::from_scale(sigma) * v.transpose()`, where `u` and `v` are rotations and the
+    /// singular values in `sigma` are in order of decreasing magnitude.
+    ///
+    /// Keeping `u` and `v` as rotations means they can be converted to a `Quat`. To make this
+    /// possible the last singular value is negative if `self` has a negative determinant, i.e.
+    /// it contains a reflection. The other singular values are never negative.
+    pub fn svd(&self) -> (Self, Vec3A, Self) {
+        // the right singular vectors are the eigenvectors of `self^T * self`
+        let (_, v) = self.transpose().mul_mat3a(self).symmetric_eigen();
+
+        // the columns of `self * v` are orthogonal with lengths of the singular values, so
+        // orthonormalizing them gives the left singular vectors
+        let b = self.mul_mat3a(&v);
+        let sigma_x = b.x_axis.length();
+        let u_x = if sigma_x > 0.0 {
+            b.x_axis / sigma_x
+        } else {
+            Vec3A::unit_x()
+        };
+        let y_axis = b.y_axis - u_x * u_x.dot(b.y_axis);
+        let sigma_y = y_axis.length();
+        let (u_y, sigma_y, sigma_z) = if sigma_y > core::f32::EPSILON * sigma_x {
+            let u_y = y_axis / sigma_y;
+            // rounding can make the magnitude of the last singular value slightly larger than
+            // the second when they are nearly equal
+            let sigma_z = u_x.cross(u_y).dot(b.z_axis).max(-sigma_y).min(sigma_y);
+            // the last singular value of a singular matrix comes out as rounding noise of
+            // either sign, so flush it to zero to make its sign deterministic
+            let sigma_z = if sigma_z.abs() <= core::f32::EPSILON * sigma_x {
+                0.0
+            } else {
+                sigma_z
+            };
+            (u_y, sigma_y, sigma_z)
+        } else {
+            // rank one or zero, so the remaining singular values are zero and any axis
+            // perpendicular to `u_x` will do
+            let other = if u_x.x.abs() < 0.9 {
+                Vec3A::unit_x()
+            } else {
+                Vec3A::unit_y()
+            };
+            (u_x.cross(other).normalize(), 0.0, 0.0)
+        };
+        let u_z = u_x.cross(u_y);
+
+        (
+            Self::from_cols(u_x, u_y, u_z),
+            Vec3A::new(sigma_x, sigma_y, sigma_z),
+            v,
+        )
+    }
+
+    /// Computes the polar decomposition of `self` into a rotation and a stretch.
+    ///
+    /// Returns `(rotation, stretch)` such that `self` is equal to `rotation * stretch`, where
+    /// `rotation` is the rotation closest to `self` and `stretch` is symmetric. Unlike extracting
+    /// the rotation by normalizing the columns, this gives an orthonormal rotation even when
+    /// `self` contains shear.
+    ///
+    /// `rotation` is never a reflection. If `self` contains a reflection, i.e. has a negative
+    /// determinant, `stretch` has a negative eigenvalue along the axis of least stretch instead
+    /// of being positive semi-definite, so `stretch.determinant()` is negative. This is the same
+    /// convention as `svd` and `Mat4::to_scale_shear_rotation_translation`, which carry a
+    /// reflection as a negative singular value or scale.
+    pub fn polar_decompose(&self) -> (Self, Self) {
+        let (u, sigma, v) = self.svd();
+        let v_t = v.transpose();
+        let v_sigma = Self::from_cols(
+            v.x_axis * sigma.xxx(),
+            v.y_axis * sigma.yyy(),
+            v.z_axis * sigma.zzz(),
+        );
+        (u.mul_mat3a(&v_t), v_sigma.mul_mat3a(&v_t))
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns `(p, l, u)` such that `p * self` is equal to `l * u`, where `p` is a permutation
+    /// matrix, `l` is lower triangular with ones on its diagonal and `u` is upper triangular.
+    ///
+    /// Returns `None` if `self` is singular, which is detected by a pivot that is not larger than
+    /// `f32::EPSILON` times the largest element of `self`, or if `self` is not finite.
+    pub fn lu(&self) -> Option<(Self, Self, Self)> {
+        let (l, u, perm) = lu_rows(self)?;
+        let units = [Vec3A::unit_x(), Vec3A::unit_y(), Vec3A::unit_z()];
+        Some((
+            Self::from_cols(units[perm[0]], units[perm[1]], units[perm[2]]).transpose(),
+            Self::from_cols(l[0], l[1], l[2]).transpose(),
+            Self::from_cols(u[0], u[1], u[2]).transpose(),
+        ))
+    }
+
+    /// Solves `self * x = rhs` for `x` using LU decomposition with partial pivoting.
+    ///
+    /// Returns `None` if `self` is singular, see `lu` for how this is detected.
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, rhs: Vec3A) -> Option<Vec3A> {
+        let (l, u, perm) = lu_rows(self)?;
+        // the elements of `y` and `x` that haven't been solved yet are zero, so they don't
+        // contribute to the dot products
+        let mut y = Vec3A::zero();
+        for i in 0..3 {
+            y[i] = rhs[perm[i]] - l[i].dot(y);
+        }
+        let mut x = Vec3A::zero();
+        for i in (0..3).rev() {
+            x[i] = (y[i] - u[i].dot(x)) / u[i][i];
+        }
+        if x.is_finite() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the Cholesky decomposition of the symmetric positive definite `self`.
+    ///
+    /// Returns the lower triangular `l` with a positive diagonal such that `self` is equal to
+    /// `l * l.transpose()`, or `None` if `self` is not positive definite. Only the lower triangle
+    /// of `self` is used.
+    #[allow(clippy::needless_range_loop)]
+    pub fn cholesky(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let a = [self.x_axis, self.y_axis, self.z_axis];
+        let mut max_diagonal = 0.0_f32;
+        for i in 0..3 {
+            max_diagonal = max_diagonal.max(a[i][i]);
+        }
+        let tolerance = core::f32::EPSILON * max_diagonal;
+        let mut l = [Vec3A::zero(); 3];
+        for j in 0..3 {
+            let mut column = a[j];
+            for k in 0..j {
+                column -= l[k] * l[k][j];
+            }
+            let d = column[j];
+            if d <= tolerance {
+                return None;
+            }
+            // clear the elements above the diagonal, which came from the upper triangle
+            for i in 0..j {
+                column[i] = 0.0;
+            }
+            l[j] = column * d.sqrt().recip();
+        }
+        Some(Self::from_cols(l[0], l[1], l[2]))
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns `(q, r)` such that `self` is equal to `q * r`, where `q` is orthogonal and `r` is
+    /// upper triangular with a non-negative diagonal. Note that `q` may contain a reflection.
+    ///
+    /// The decomposition always exists. If `self` is singular an element on the diagonal of `r`
+    /// is zero, or close to zero because of rounding.
+    #[allow(clippy::needless_range_loop)]
+    pub fn qr(&self) -> (Self, Self) {
+        let mut r = [self.x_axis, self.y_axis, self.z_axis];
+        // the reflections applied to the identity, which gives `q` transposed
+        let mut q_t = [Vec3A::unit_x(), Vec3A::unit_y(), Vec3A::unit_z()];
+        for k in 0..2 {
+            let mut v = r[k];
+            for i in 0..k {
+                v[i] = 0.0;
+            }
+            let length = v.length();
+            if length == 0.0 {
+                continue;
+            }
+            // reflect onto the k'th axis, picking the direction that avoids cancellation
+            let alpha = if v[k] > 0.0 { -length } else { length };
+            v[k] -= alpha;
+            let scale = 2.0 / v.dot(v);
+            for j in k..3 {
+                r[j] -= v * (v.dot(r[j]) * scale);
+            }
+            for j in 0..3 {
+                q_t[j] -= v * (v.dot(q_t[j]) * scale);
+            }
+            for i in k + 1..3 {
+                r[k][i] = 0.0;
+            }
+        }
+
+        let q = Self::from_cols(q_t[0], q_t[1], q_t[2]).transpose();
+        let mut q = [q.x_axis, q.y_axis, q.z_axis];
+        for i in 0..3 {
+            if r[i][i] < 0.0 {
+                q[i] = -q[i];
+                for j in i..3 {
+                    r[j][i] = -r[j][i];
+                }
+            }
+        }
+        (
+            Self::from_cols(q[0], q[1], q[2]),
+            Self::from_cols(r[0], r[1], r[2]),
+        )
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `Mat3A`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(&self) -> crate::DMat3 {
+        crate::DMat3::from_cols(
+            self.x_axis.as_f64(),
+            self.y_axis.as_f64(),
+            self.z_axis.as_f64(),
+        )
+    }
+}
+
+/// Performs Gaussian elimination with partial pivoting on the rows of `m`.
+///
+/// Returns the rows of the unit lower triangular and upper triangular factors and the row
+/// permutation, or `None` if `m` is singular or not finite. Working on the rows of the transpose
+/// keeps the row operations in SIMD registers where available.
+#[allow(clippy::needless_range_loop)]
+fn lu_rows(m: &Mat3A) -> Option<([Vec3A; 3], [Vec3A; 3], [usize; 3])> {
+    if !m.is_finite() {
+        return None;
+    }
+    let rows = m.transpose();
+    let mut u = [rows.x_axis, rows.y_axis, rows.z_axis];
+    let mut l = [Vec3A::zero(); 3];
+    let mut perm = [0, 1, 2];
+    let mut max_abs = 0.0_f32;
+    for row in u.iter() {
+        max_abs = max_abs.max(row.abs().max_element());
+    }
+    let tolerance = core::f32::EPSILON * max_abs;
+    for k in 0..3 {
+        let mut pivot = k;
+        for i in k + 1..3 {
+            if u[i][k].abs() > u[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if u[pivot][k].abs() <= tolerance {
+            return None;
+        }
+        u.swap(k, pivot);
+        l.swap(k, pivot);
+        perm.swap(k, pivot);
+        for i in k + 1..3 {
+            let factor = u[i][k] / u[k][k];
+            u[i] -= u[k] * factor;
+            u[i][k] = 0.0;
+            l[i][k] = factor;
+        }
+        l[k][k] = 1.0;
+    }
+    Some((l, u, perm))
+}
+
+/// Creates the linear part of a transform equal to `rotation * scale * shear`, where `shear` is
+/// the upper unit triangular matrix with the `xy`, `xz` and `yz` factors in `shear`.
+#[inline]
+pub(crate) fn from_scale_shear_rotation(scale: Vec3A, shear: Vec3A, rotation: &Mat3A) -> Mat3A {
+    let x_axis = rotation.x_axis * scale.xxx();
+    let y_axis = rotation.y_axis * scale.yyy();
+    let z_axis = rotation.z_axis * scale.zzz();
+    Mat3A::from_cols(
+        x_axis,
+        x_axis * shear.xxx() + y_axis,
+        x_axis * shear.yyy() + y_axis * shear.zzz() + z_axis,
+    )
+}
+
+/// Splits `m` into `(scale, shear, rotation)`, the inverse of `from_scale_shear_rotation`.
+///
+/// This orthonormalizes the columns of `m` in order. `rotation` is never a reflection, if `m` has
+/// a negative determinant `scale.x` is negative instead. `Mat3A::polar_decompose` follows the
+/// same convention with a negative eigenvalue in its stretch.
+pub(crate) fn to_scale_shear_rotation(m: &Mat3A) -> (Vec3A, Vec3A, Mat3A) {
+    let det = m.determinant();
+    glam_assert!(det != 0.0);
+
+    let scale_x = m.x_axis.length() * det.signum();
+    glam_assert!(scale_x != 0.0);
+    let x_axis = m.x_axis / scale_x;
+
+    let xy = x_axis.dot(m.y_axis);
+    let y_axis = m.y_axis - x_axis * xy;
+    let scale_y = y_axis.length();
+    glam_assert!(scale_y != 0.0);
+    let y_axis = y_axis / scale_y;
+
+    let xz = x_axis.dot(m.z_axis);
+    let yz = y_axis.dot(m.z_axis);
+    let z_axis = m.z_axis - x_axis * xz - y_axis * yz;
+    let scale_z = z_axis.length();
+    glam_assert!(scale_z != 0.0);
+    let z_axis = z_axis / scale_z;
+
+    (
+        Vec3A::new(scale_x, scale_y, scale_z),
+        Vec3A::new(xy / scale_x, xz / scale_x, yz / scale_y),
+        Mat3A::from_cols(x_axis, y_axis, z_axis),
+    )
+}
+
+/// Returns the unsorted eigenvalues and eigenvectors of the symmetric matrix `m`, of which only
+/// the upper triangle is used.
+fn symmetric_eigen3(m: &Mat3A) -> ([f32; 3], [Vec3A; 3]) {
+    let mut a = [
+        [m.x_axis.x, m.y_axis.x, m.z_axis.x, 0.0],
+        [0.0, m.y_axis.y, m.z_axis.y, 0.0],
+        [0.0, 0.0, m.z_axis.z, 0.0],
+        [0.0; 4],
+    ];
+    let v = symmetric_eigen_jacobi(&mut a, 3);
+    (
+        [a[0][0], a[1][1], a[2][2]],
+        [
+            Vec3A::new(v[0][0], v[1][0], v[2][0]),
+            Vec3A::new(v[0][1], v[1][1], v[2][1]),
+            Vec3A::new(v[0][2], v[1][2], v[2][2]),
+        ],
+    )
+}
+
+impl Add<Mat3A> for Mat3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.add_mat3a(&other)
+    }
+}
+
+impl Sub<Mat3A> for Mat3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.sub_mat3a(&other)
+    }
+}
+
+impl Mul<Mat3A> for Mat3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat3a(&other)
+    }
+}
+
+impl Mul<Vec3A> for Mat3A {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, other: Vec3A) -> Vec3A {
+        self.mul_vec3a(other)
+    }
+}
+
+impl Mul<Vec3> for Mat3A {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, other: Vec3) -> Vec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl Mul<Mat3A> for f32 {
+    type Output = Mat3A;
+    #[inline]
+    fn mul(self, other: Mat3A) -> Mat3A {
+        other.mul_scalar(self)
+    }
+}
+
+impl Mul<f32> for Mat3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self.mul_scalar(other)
+    }
+}
+
+impl From<Mat3> for Mat3A {
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self {
+            x_axis: m.x_axis.into(),
+            y_axis: m.y_axis.into(),
+            z_axis: m.z_axis.into(),
+        }
+    }
+}
+
+impl From<Mat3A> for Mat3 {
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self {
+            x_axis: m.x_axis.into(),
+            y_axis: m.y_axis.into(),
+            z_axis: m.z_axis.into(),
+        }
+    }
+}
+
+impl From<Mat3A> for Mat4 {
+    /// Creates a 4x4 matrix from the 3x3 matrix `m`, setting the `w` elements of the first three
+    /// columns to `0.0` and the `w_axis` column to `Vec4::unit_w()`.
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self::from_cols(
+            m.x_axis.extend(0.0),
+            m.y_axis.extend(0.0),
+            m.z_axis.extend(0.0),
+            Vec4::unit_w(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for Mat3A {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for Mat3A {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
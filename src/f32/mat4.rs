@@ -1,7 +1,17 @@
 #[cfg(feature = "num-traits")]
 use num_traits::Float;
 
-use super::{scalar_sin_cos, Mat3, Quat, Vec3, Vec3A, Vec3ASwizzles, Vec4, Vec4Swizzles};
+use super::vec3x8::F32x8;
+use super::{
+    mat3a::{from_scale_shear_rotation, to_scale_shear_rotation},
+    scalar_sin_cos, Mat3, Mat3A, Quat, Vec3, Vec3A, Vec3ASwizzles, Vec3x4, Vec3x8, Vec4,
+    Vec4Swizzles, Vec4x4,
+};
+use crate::EulerRot;
+#[cfg(vec4_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec4_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec4_sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec4_sse2, target_arch = "x86_64"))]
@@ -14,6 +24,19 @@ use core::{
 #[cfg(feature = "std")]
 use std::iter::{Product, Sum};
 
+/// Equivalent of `_mm_shuffle_ps(a, b, _MM_SHUFFLE(w, z, y, x))`: the `x` and `y` lanes of
+/// the result are taken from `a` and the `z` and `w` lanes from `b`.
+#[cfg(vec4_neon)]
+macro_rules! neon_shuffle {
+    ($a:expr, $b:expr, [$x:literal, $y:literal, $z:literal, $w:literal]) => {{
+        let (a, b) = ($a, $b);
+        let r = vdupq_laneq_f32(a, $x);
+        let r = vcopyq_laneq_f32(r, 1, a, $y);
+        let r = vcopyq_laneq_f32(r, 2, b, $z);
+        vcopyq_laneq_f32(r, 3, b, $w)
+    }};
+}
+
 const ZERO: Mat4 = const_mat4!([0.0; 16]);
 const IDENTITY: Mat4 = const_mat4!(
     [1.0, 0.0, 0.0, 0.0],
@@ -209,6 +232,46 @@ impl Mat4 {
         (scale, rotation, translation)
     }
 
+    /// Creates a 4x4 homogeneous transformation matrix from the given `scale`, `shear`,
+    /// `rotation` and `translation`.
+    ///
+    /// The transform applies the shear first, then the scale, rotation and translation. The
+    /// shear maps `(x, y, z)` to `(x + shear.x * y + shear.y * z, y + shear.z * z, z)`.
+    #[inline]
+    pub fn from_scale_shear_rotation_translation(
+        scale: Vec3,
+        shear: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+    ) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let mut m = Self::from(from_scale_shear_rotation(
+            scale.into(),
+            shear.into(),
+            &Mat3A::from_quat(rotation),
+        ));
+        m.w_axis = translation.extend(1.0);
+        m
+    }
+
+    /// Extracts `scale`, `shear`, `rotation` and `translation` from `self`, the inverse of
+    /// `Mat4::from_scale_shear_rotation_translation`.
+    ///
+    /// Unlike `to_scale_rotation_translation` this gives a valid rotation for any
+    /// non-degenerate affine transform, including ones with shear. `rotation` is never a
+    /// reflection, if `self` has a negative determinant `scale.x` is negative instead, the same
+    /// convention as `Mat3A::polar_decompose`. The input matrix is expected to be a 4x4
+    /// homogeneous transformation matrix without a perspective transform.
+    pub fn to_scale_shear_rotation_translation(&self) -> (Vec3, Vec3, Quat, Vec3) {
+        let (scale, shear, rotation) = to_scale_shear_rotation(&Mat3A::from_mat4(*self));
+        (
+            scale.into(),
+            shear.into(),
+            Quat::from_rotation_mat3a(&rotation),
+            self.w_axis.xyz(),
+        )
+    }
+
     /// Creates a 4x4 homogeneous transformation matrix from the given `rotation`.
     #[inline]
     pub fn from_quat(rotation: Quat) -> Self {
@@ -262,6 +325,14 @@ impl Mat4 {
         Self::from_quat(quat)
     }
 
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation from the given Euler
+    /// rotation sequence and the angles (in radians).
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let quat = Quat::from_euler(euler, a, b, c);
+        Self::from_quat(quat)
+    }
+
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around the x axis of `angle` (in radians).
     #[inline]
@@ -603,7 +674,299 @@ impl Mat4 {
                 }
             }
         }
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            // NEON port of the `glm_mat4_inverse` based SSE2 version above
+            let x_axis = self.x_axis.into();
+            let y_axis = self.y_axis.into();
+            let z_axis = self.z_axis.into();
+            let w_axis = self.w_axis.into();
+
+            let fac0 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [3, 3, 3, 3]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [2, 2, 2, 2]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [2, 2, 2, 2]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [3, 3, 3, 3]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let fac1 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [3, 3, 3, 3]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [1, 1, 1, 1]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [1, 1, 1, 1]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [3, 3, 3, 3]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let fac2 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [2, 2, 2, 2]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [1, 1, 1, 1]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [1, 1, 1, 1]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [2, 2, 2, 2]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let fac3 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [3, 3, 3, 3]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [0, 0, 0, 0]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [0, 0, 0, 0]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [3, 3, 3, 3]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let fac4 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [2, 2, 2, 2]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [0, 0, 0, 0]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [0, 0, 0, 0]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [2, 2, 2, 2]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let fac5 = {
+                let swp0a = neon_shuffle!(w_axis, z_axis, [1, 1, 1, 1]);
+                let swp0b = neon_shuffle!(w_axis, z_axis, [0, 0, 0, 0]);
+
+                let swp00 = neon_shuffle!(z_axis, y_axis, [0, 0, 0, 0]);
+                let swp01 = neon_shuffle!(swp0a, swp0a, [0, 0, 0, 2]);
+                let swp02 = neon_shuffle!(swp0b, swp0b, [0, 0, 0, 2]);
+                let swp03 = neon_shuffle!(z_axis, y_axis, [1, 1, 1, 1]);
+
+                let mul00 = vmulq_f32(swp00, swp01);
+                let mul01 = vmulq_f32(swp02, swp03);
+                vsubq_f32(mul00, mul01)
+            };
+            let sign_a = vld1q_f32([-1.0, 1.0, -1.0, 1.0].as_ptr());
+            let sign_b = vld1q_f32([1.0, -1.0, 1.0, -1.0].as_ptr());
+
+            let temp0 = neon_shuffle!(y_axis, x_axis, [0, 0, 0, 0]);
+            let vec0 = neon_shuffle!(temp0, temp0, [0, 2, 2, 2]);
+
+            let temp1 = neon_shuffle!(y_axis, x_axis, [1, 1, 1, 1]);
+            let vec1 = neon_shuffle!(temp1, temp1, [0, 2, 2, 2]);
+
+            let temp2 = neon_shuffle!(y_axis, x_axis, [2, 2, 2, 2]);
+            let vec2 = neon_shuffle!(temp2, temp2, [0, 2, 2, 2]);
+
+            let temp3 = neon_shuffle!(y_axis, x_axis, [3, 3, 3, 3]);
+            let vec3 = neon_shuffle!(temp3, temp3, [0, 2, 2, 2]);
+
+            let mul00 = vmulq_f32(vec1, fac0);
+            let mul01 = vmulq_f32(vec2, fac1);
+            let mul02 = vmulq_f32(vec3, fac2);
+            let sub00 = vsubq_f32(mul00, mul01);
+            let add00 = vaddq_f32(sub00, mul02);
+            let inv0 = vmulq_f32(sign_b, add00);
+
+            let mul03 = vmulq_f32(vec0, fac0);
+            let mul04 = vmulq_f32(vec2, fac3);
+            let mul05 = vmulq_f32(vec3, fac4);
+            let sub01 = vsubq_f32(mul03, mul04);
+            let add01 = vaddq_f32(sub01, mul05);
+            let inv1 = vmulq_f32(sign_a, add01);
+
+            let mul06 = vmulq_f32(vec0, fac1);
+            let mul07 = vmulq_f32(vec1, fac3);
+            let mul08 = vmulq_f32(vec3, fac5);
+            let sub02 = vsubq_f32(mul06, mul07);
+            let add02 = vaddq_f32(sub02, mul08);
+            let inv2 = vmulq_f32(sign_b, add02);
+
+            let mul09 = vmulq_f32(vec0, fac2);
+            let mul10 = vmulq_f32(vec1, fac4);
+            let mul11 = vmulq_f32(vec2, fac5);
+            let sub03 = vsubq_f32(mul09, mul10);
+            let add03 = vaddq_f32(sub03, mul11);
+            let inv3 = vmulq_f32(sign_a, add03);
+
+            let row0 = neon_shuffle!(inv0, inv1, [0, 0, 0, 0]);
+            let row1 = neon_shuffle!(inv2, inv3, [0, 0, 0, 0]);
+            let row2 = neon_shuffle!(row0, row1, [0, 2, 0, 2]);
+
+            let dot0 = self.x_axis.dot(row2.into());
+            glam_assert!(dot0 != 0.0);
+
+            let rcp0 = vdupq_n_f32(1.0 / dot0);
+
+            Self {
+                x_axis: vmulq_f32(inv0, rcp0).into(),
+                y_axis: vmulq_f32(inv1, rcp0).into(),
+                z_axis: vmulq_f32(inv2, rcp0).into(),
+                w_axis: vmulq_f32(inv3, rcp0).into(),
+            }
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            // simd128 port of the `glm_mat4_inverse` based SSE2 version above
+            let x_axis = self.x_axis.into();
+            let y_axis = self.y_axis.into();
+            let z_axis = self.z_axis.into();
+            let w_axis = self.w_axis.into();
+
+            let fac0 = {
+                let swp0a = i32x4_shuffle::<3, 3, 7, 7>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<2, 2, 6, 6>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<2, 2, 6, 6>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<3, 3, 7, 7>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let fac1 = {
+                let swp0a = i32x4_shuffle::<3, 3, 7, 7>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<1, 1, 5, 5>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<1, 1, 5, 5>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<3, 3, 7, 7>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let fac2 = {
+                let swp0a = i32x4_shuffle::<2, 2, 6, 6>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<1, 1, 5, 5>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<1, 1, 5, 5>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<2, 2, 6, 6>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let fac3 = {
+                let swp0a = i32x4_shuffle::<3, 3, 7, 7>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<0, 0, 4, 4>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<0, 0, 4, 4>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<3, 3, 7, 7>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let fac4 = {
+                let swp0a = i32x4_shuffle::<2, 2, 6, 6>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<0, 0, 4, 4>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<0, 0, 4, 4>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<2, 2, 6, 6>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let fac5 = {
+                let swp0a = i32x4_shuffle::<1, 1, 5, 5>(w_axis, z_axis);
+                let swp0b = i32x4_shuffle::<0, 0, 4, 4>(w_axis, z_axis);
+
+                let swp00 = i32x4_shuffle::<0, 0, 4, 4>(z_axis, y_axis);
+                let swp01 = i32x4_shuffle::<0, 0, 0, 2>(swp0a, swp0a);
+                let swp02 = i32x4_shuffle::<0, 0, 0, 2>(swp0b, swp0b);
+                let swp03 = i32x4_shuffle::<1, 1, 5, 5>(z_axis, y_axis);
+
+                let mul00 = f32x4_mul(swp00, swp01);
+                let mul01 = f32x4_mul(swp02, swp03);
+                f32x4_sub(mul00, mul01)
+            };
+            let sign_a = f32x4(-1.0, 1.0, -1.0, 1.0);
+            let sign_b = f32x4(1.0, -1.0, 1.0, -1.0);
+
+            let temp0 = i32x4_shuffle::<0, 0, 4, 4>(y_axis, x_axis);
+            let vec0 = i32x4_shuffle::<0, 2, 2, 2>(temp0, temp0);
+
+            let temp1 = i32x4_shuffle::<1, 1, 5, 5>(y_axis, x_axis);
+            let vec1 = i32x4_shuffle::<0, 2, 2, 2>(temp1, temp1);
+
+            let temp2 = i32x4_shuffle::<2, 2, 6, 6>(y_axis, x_axis);
+            let vec2 = i32x4_shuffle::<0, 2, 2, 2>(temp2, temp2);
+
+            let temp3 = i32x4_shuffle::<3, 3, 7, 7>(y_axis, x_axis);
+            let vec3 = i32x4_shuffle::<0, 2, 2, 2>(temp3, temp3);
+
+            let mul00 = f32x4_mul(vec1, fac0);
+            let mul01 = f32x4_mul(vec2, fac1);
+            let mul02 = f32x4_mul(vec3, fac2);
+            let sub00 = f32x4_sub(mul00, mul01);
+            let add00 = f32x4_add(sub00, mul02);
+            let inv0 = f32x4_mul(sign_b, add00);
+
+            let mul03 = f32x4_mul(vec0, fac0);
+            let mul04 = f32x4_mul(vec2, fac3);
+            let mul05 = f32x4_mul(vec3, fac4);
+            let sub01 = f32x4_sub(mul03, mul04);
+            let add01 = f32x4_add(sub01, mul05);
+            let inv1 = f32x4_mul(sign_a, add01);
+
+            let mul06 = f32x4_mul(vec0, fac1);
+            let mul07 = f32x4_mul(vec1, fac3);
+            let mul08 = f32x4_mul(vec3, fac5);
+            let sub02 = f32x4_sub(mul06, mul07);
+            let add02 = f32x4_add(sub02, mul08);
+            let inv2 = f32x4_mul(sign_b, add02);
+
+            let mul09 = f32x4_mul(vec0, fac2);
+            let mul10 = f32x4_mul(vec1, fac4);
+            let mul11 = f32x4_mul(vec2, fac5);
+            let sub03 = f32x4_sub(mul09, mul10);
+            let add03 = f32x4_add(sub03, mul11);
+            let inv3 = f32x4_mul(sign_a, add03);
+
+            let row0 = i32x4_shuffle::<0, 0, 4, 4>(inv0, inv1);
+            let row1 = i32x4_shuffle::<0, 0, 4, 4>(inv2, inv3);
+            let row2 = i32x4_shuffle::<0, 2, 4, 6>(row0, row1);
+
+            let dot0 = self.x_axis.dot(row2.into());
+            glam_assert!(dot0 != 0.0);
+
+            let rcp0 = f32x4_splat(1.0 / dot0);
+
+            Self {
+                x_axis: f32x4_mul(inv0, rcp0).into(),
+                y_axis: f32x4_mul(inv1, rcp0).into(),
+                z_axis: f32x4_mul(inv2, rcp0).into(),
+                w_axis: f32x4_mul(inv3, rcp0).into(),
+            }
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             let (m00, m01, m02, m03) = self.x_axis.into();
             let (m10, m11, m12, m13) = self.y_axis.into();
@@ -678,6 +1041,39 @@ impl Mat4 {
         }
     }
 
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f32::EPSILON` times the product of the column lengths of its upper 3x3 part
+    /// and the length of its bottom row. For an affine matrix this is the largest determinant
+    /// possible for a linear part with those column lengths. This detects nearly singular matrices
+    /// regardless of their scale, and the translation doesn't affect the tolerance. `None` is also
+    /// returned if `self` or the computed inverse contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let bottom_row = Vec4::new(self.x_axis.w, self.y_axis.w, self.z_axis.w, self.w_axis.w);
+        let max_det = self.x_axis.truncate().length()
+            * self.y_axis.truncate().length()
+            * self.z_axis.truncate().length()
+            * bottom_row.length();
+        if det.abs() > core::f32::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
     /// Creates a left-handed view matrix using a camera position, an up direction, and a camera
     /// direction.
     #[inline]
@@ -911,16 +1307,111 @@ impl Mat4 {
     /// Transforms a 4D vector.
     #[inline]
     pub fn mul_vec4(&self, other: Vec4) -> Vec4 {
-        let mut res = self.x_axis * other.xxxx();
-        res = self.y_axis.mul_add(other.yyyy(), res);
-        res = self.z_axis.mul_add(other.zzzz(), res);
-        res = self.w_axis.mul_add(other.wwww(), res);
-        res
+        #[cfg(vec4_neon)]
+        unsafe {
+            let v = other.into();
+            let res = vmulq_laneq_f32(self.x_axis.into(), v, 0);
+            let res = vmlaq_laneq_f32(res, self.y_axis.into(), v, 1);
+            let res = vmlaq_laneq_f32(res, self.z_axis.into(), v, 2);
+            Vec4::from(vmlaq_laneq_f32(res, self.w_axis.into(), v, 3))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let v = other.into();
+            let res = f32x4_mul(self.x_axis.into(), i32x4_shuffle::<0, 0, 0, 0>(v, v));
+            let res = f32x4_add(
+                res,
+                f32x4_mul(self.y_axis.into(), i32x4_shuffle::<1, 1, 1, 1>(v, v)),
+            );
+            let res = f32x4_add(
+                res,
+                f32x4_mul(self.z_axis.into(), i32x4_shuffle::<2, 2, 2, 2>(v, v)),
+            );
+            Vec4::from(f32x4_add(
+                res,
+                f32x4_mul(self.w_axis.into(), i32x4_shuffle::<3, 3, 3, 3>(v, v)),
+            ))
+        }
+
+        #[cfg(not(any(vec4_neon, vec4_simd128)))]
+        {
+            let mut res = self.x_axis * other.xxxx();
+            res = self.y_axis.mul_add(other.yyyy(), res);
+            res = self.z_axis.mul_add(other.zzzz(), res);
+            res = self.w_axis.mul_add(other.wwww(), res);
+            res
+        }
     }
 
     /// Multiplies two 4x4 matrices.
     #[inline]
     pub fn mul_mat4(&self, other: &Self) -> Self {
+        #[cfg(mat4_avx)]
+        unsafe {
+            #[cfg(vec4_fma)]
+            macro_rules! m256_mul_add {
+                ($a:expr, $b:expr, $c:expr) => {
+                    _mm256_fmadd_ps($a, $b, $c)
+                };
+            }
+
+            #[cfg(not(vec4_fma))]
+            macro_rules! m256_mul_add {
+                ($a:expr, $b:expr, $c:expr) => {
+                    _mm256_add_ps(_mm256_mul_ps($a, $b), $c)
+                };
+            }
+
+            // each column of `self` is duplicated into both halves so two columns of `other` can
+            // be transformed at once
+            let x_axis = _mm256_broadcast_ps(&self.x_axis.0);
+            let y_axis = _mm256_broadcast_ps(&self.y_axis.0);
+            let z_axis = _mm256_broadcast_ps(&self.z_axis.0);
+            let w_axis = _mm256_broadcast_ps(&self.w_axis.0);
+            // summed as two independent pairs to shorten the dependency chain
+            let mul_cols = |cols: __m256| {
+                let xy = m256_mul_add!(
+                    y_axis,
+                    _mm256_shuffle_ps(cols, cols, 0b01_01_01_01),
+                    _mm256_mul_ps(x_axis, _mm256_shuffle_ps(cols, cols, 0b00_00_00_00))
+                );
+                let zw = m256_mul_add!(
+                    w_axis,
+                    _mm256_shuffle_ps(cols, cols, 0b11_11_11_11),
+                    _mm256_mul_ps(z_axis, _mm256_shuffle_ps(cols, cols, 0b10_10_10_10))
+                );
+                _mm256_add_ps(xy, zw)
+            };
+            let xy = mul_cols(_mm256_insertf128_ps(
+                _mm256_castps128_ps256(other.x_axis.0),
+                other.y_axis.0,
+                1,
+            ));
+            let zw = mul_cols(_mm256_insertf128_ps(
+                _mm256_castps128_ps256(other.z_axis.0),
+                other.w_axis.0,
+                1,
+            ));
+            Self {
+                x_axis: Vec4(_mm256_castps256_ps128(xy)),
+                y_axis: Vec4(_mm256_extractf128_ps(xy, 1)),
+                z_axis: Vec4(_mm256_castps256_ps128(zw)),
+                w_axis: Vec4(_mm256_extractf128_ps(zw, 1)),
+            }
+        }
+
+        #[cfg(not(mat4_avx))]
+        {
+            self.mul_mat4_by_cols(other)
+        }
+    }
+
+    /// Multiplies two 4x4 matrices one column at a time. This is used when AVX isn't available
+    /// and is kept for testing the AVX version against.
+    #[inline]
+    #[cfg_attr(mat4_avx, allow(dead_code))]
+    pub(crate) fn mul_mat4_by_cols(&self, other: &Self) -> Self {
         Self {
             x_axis: self.mul_vec4(other.x_axis),
             y_axis: self.mul_vec4(other.y_axis),
@@ -1003,6 +1494,206 @@ impl Mat4 {
         Vec3A::from(res)
     }
 
+    /// Transforms the four vectors of the given `Vec4x4`.
+    #[inline]
+    pub fn mul_vec4x4(&self, other: Vec4x4) -> Vec4x4 {
+        Vec4x4::splat(self.x_axis) * other.x
+            + Vec4x4::splat(self.y_axis) * other.y
+            + Vec4x4::splat(self.z_axis) * other.z
+            + Vec4x4::splat(self.w_axis) * other.w
+    }
+
+    /// Transforms the four vectors of the given `Vec3x4` as 3D points.
+    ///
+    /// This is the equivalent of calling `transform_point3` on each vector.
+    #[inline]
+    pub fn transform_point3x4(&self, other: Vec3x4) -> Vec3x4 {
+        let res = Vec4x4::splat(self.x_axis) * other.x
+            + Vec4x4::splat(self.y_axis) * other.y
+            + Vec4x4::splat(self.z_axis) * other.z
+            + Vec4x4::splat(self.w_axis);
+        res.truncate() * res.w.recip()
+    }
+
+    /// Transforms the four vectors of the given `Vec3x4` as 3D vectors.
+    ///
+    /// This is the equivalent of calling `transform_vector3` on each vector.
+    #[inline]
+    pub fn transform_vector3x4(&self, other: Vec3x4) -> Vec3x4 {
+        Vec4x4::splat(self.x_axis).truncate() * other.x
+            + Vec4x4::splat(self.y_axis).truncate() * other.y
+            + Vec4x4::splat(self.z_axis).truncate() * other.z
+    }
+
+    /// Transforms the eight vectors of the given `Vec3x8` as 3D points.
+    ///
+    /// This is the equivalent of calling `transform_point3` on each vector.
+    #[inline]
+    pub fn transform_point3x8(&self, other: Vec3x8) -> Vec3x8 {
+        let res = Vec3x8::splat(self.x_axis.truncate()).mul_lanes(other.x)
+            + Vec3x8::splat(self.y_axis.truncate()).mul_lanes(other.y)
+            + Vec3x8::splat(self.z_axis.truncate()).mul_lanes(other.z)
+            + Vec3x8::splat(self.w_axis.truncate());
+        let w = F32x8::splat(self.x_axis.w) * other.x
+            + F32x8::splat(self.y_axis.w) * other.y
+            + F32x8::splat(self.z_axis.w) * other.z
+            + F32x8::splat(self.w_axis.w);
+        res.mul_lanes(w.recip())
+    }
+
+    /// Transforms the eight vectors of the given `Vec3x8` as 3D vectors.
+    ///
+    /// This is the equivalent of calling `transform_vector3` on each vector.
+    #[inline]
+    pub fn transform_vector3x8(&self, other: Vec3x8) -> Vec3x8 {
+        Vec3x8::splat(self.x_axis.truncate()).mul_lanes(other.x)
+            + Vec3x8::splat(self.y_axis.truncate()).mul_lanes(other.y)
+            + Vec3x8::splat(self.z_axis.truncate()).mul_lanes(other.z)
+    }
+
+    /// Computes the LU decomposition of `self` with partial pivoting.
+    ///
+    /// Returns `(p, l, u)` such that `p * self` is equal to `l * u`, where `p` is a permutation
+    /// matrix, `l` is lower triangular with ones on its diagonal and `u` is upper triangular.
+    ///
+    /// Returns `None` if `self` is singular, which is detected by a pivot that is not larger than
+    /// `f32::EPSILON` times the largest element of `self`, or if `self` is not finite.
+    pub fn lu(&self) -> Option<(Self, Self, Self)> {
+        let (l, u, perm) = lu_rows(self)?;
+        let units = [
+            Vec4::unit_x(),
+            Vec4::unit_y(),
+            Vec4::unit_z(),
+            Vec4::unit_w(),
+        ];
+        Some((
+            Self::from_cols(
+                units[perm[0]],
+                units[perm[1]],
+                units[perm[2]],
+                units[perm[3]],
+            )
+            .transpose(),
+            Self::from_cols(l[0], l[1], l[2], l[3]).transpose(),
+            Self::from_cols(u[0], u[1], u[2], u[3]).transpose(),
+        ))
+    }
+
+    /// Solves `self * x = rhs` for `x` using LU decomposition with partial pivoting.
+    ///
+    /// Returns `None` if `self` is singular, see `lu` for how this is detected.
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, rhs: Vec4) -> Option<Vec4> {
+        let (l, u, perm) = lu_rows(self)?;
+        // the elements of `y` and `x` that haven't been solved yet are zero, so they don't
+        // contribute to the dot products
+        let mut y = Vec4::zero();
+        for i in 0..4 {
+            y[i] = rhs[perm[i]] - l[i].dot(y);
+        }
+        let mut x = Vec4::zero();
+        for i in (0..4).rev() {
+            x[i] = (y[i] - u[i].dot(x)) / u[i][i];
+        }
+        if x.is_finite() {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the Cholesky decomposition of the symmetric positive definite `self`.
+    ///
+    /// Returns the lower triangular `l` with a positive diagonal such that `self` is equal to
+    /// `l * l.transpose()`, or `None` if `self` is not positive definite. Only the lower triangle
+    /// of `self` is used.
+    #[allow(clippy::needless_range_loop)]
+    pub fn cholesky(&self) -> Option<Self> {
+        if !self.is_finite() {
+            return None;
+        }
+        let a = [self.x_axis, self.y_axis, self.z_axis, self.w_axis];
+        let mut max_diagonal = 0.0_f32;
+        for i in 0..4 {
+            max_diagonal = max_diagonal.max(a[i][i]);
+        }
+        let tolerance = core::f32::EPSILON * max_diagonal;
+        let mut l = [Vec4::zero(); 4];
+        for j in 0..4 {
+            let mut column = a[j];
+            for k in 0..j {
+                column -= l[k] * l[k][j];
+            }
+            let d = column[j];
+            if d <= tolerance {
+                return None;
+            }
+            // clear the elements above the diagonal, which came from the upper triangle
+            for i in 0..j {
+                column[i] = 0.0;
+            }
+            l[j] = column * d.sqrt().recip();
+        }
+        Some(Self::from_cols(l[0], l[1], l[2], l[3]))
+    }
+
+    /// Computes the QR decomposition of `self` using Householder reflections.
+    ///
+    /// Returns `(q, r)` such that `self` is equal to `q * r`, where `q` is orthogonal and `r` is
+    /// upper triangular with a non-negative diagonal. Note that `q` may contain a reflection.
+    ///
+    /// The decomposition always exists. If `self` is singular an element on the diagonal of `r`
+    /// is zero, or close to zero because of rounding.
+    #[allow(clippy::needless_range_loop)]
+    pub fn qr(&self) -> (Self, Self) {
+        let mut r = [self.x_axis, self.y_axis, self.z_axis, self.w_axis];
+        // the reflections applied to the identity, which gives `q` transposed
+        let mut q_t = [
+            Vec4::unit_x(),
+            Vec4::unit_y(),
+            Vec4::unit_z(),
+            Vec4::unit_w(),
+        ];
+        for k in 0..3 {
+            let mut v = r[k];
+            for i in 0..k {
+                v[i] = 0.0;
+            }
+            let length = v.length();
+            if length == 0.0 {
+                continue;
+            }
+            // reflect onto the k'th axis, picking the direction that avoids cancellation
+            let alpha = if v[k] > 0.0 { -length } else { length };
+            v[k] -= alpha;
+            let scale = 2.0 / v.dot(v);
+            for j in k..4 {
+                r[j] -= v * (v.dot(r[j]) * scale);
+            }
+            for j in 0..4 {
+                q_t[j] -= v * (v.dot(q_t[j]) * scale);
+            }
+            for i in k + 1..4 {
+                r[k][i] = 0.0;
+            }
+        }
+
+        let q = Self::from_cols(q_t[0], q_t[1], q_t[2], q_t[3]).transpose();
+        let mut q = [q.x_axis, q.y_axis, q.z_axis, q.w_axis];
+        for i in 0..4 {
+            if r[i][i] < 0.0 {
+                q[i] = -q[i];
+                for j in i..4 {
+                    r[j][i] = -r[j][i];
+                }
+            }
+        }
+        (
+            Self::from_cols(q[0], q[1], q[2], q[3]),
+            Self::from_cols(r[0], r[1], r[2], r[3]),
+        )
+    }
+
     /// Returns true if the absolute difference of all elements between `self` and `other` is less
     /// than or equal to `max_abs_diff`.
     ///
@@ -1019,6 +1710,60 @@ impl Mat4 {
             && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
             && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
     }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(&self) -> crate::DMat4 {
+        crate::DMat4::from_cols(
+            self.x_axis.as_f64(),
+            self.y_axis.as_f64(),
+            self.z_axis.as_f64(),
+            self.w_axis.as_f64(),
+        )
+    }
+}
+
+/// Performs Gaussian elimination with partial pivoting on the rows of `m`.
+///
+/// Returns the rows of the unit lower triangular and upper triangular factors and the row
+/// permutation, or `None` if `m` is singular or not finite. Working on the rows of the transpose
+/// keeps the row operations in SIMD registers where available.
+#[allow(clippy::needless_range_loop)]
+fn lu_rows(m: &Mat4) -> Option<([Vec4; 4], [Vec4; 4], [usize; 4])> {
+    if !m.is_finite() {
+        return None;
+    }
+    let rows = m.transpose();
+    let mut u = [rows.x_axis, rows.y_axis, rows.z_axis, rows.w_axis];
+    let mut l = [Vec4::zero(); 4];
+    let mut perm = [0, 1, 2, 3];
+    let mut max_abs = 0.0_f32;
+    for row in u.iter() {
+        max_abs = max_abs.max(row.abs().max_element());
+    }
+    let tolerance = core::f32::EPSILON * max_abs;
+    for k in 0..4 {
+        let mut pivot = k;
+        for i in k + 1..4 {
+            if u[i][k].abs() > u[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if u[pivot][k].abs() <= tolerance {
+            return None;
+        }
+        u.swap(k, pivot);
+        l.swap(k, pivot);
+        perm.swap(k, pivot);
+        for i in k + 1..4 {
+            let factor = u[i][k] / u[k][k];
+            u[i] -= u[k] * factor;
+            u[i][k] = 0.0;
+            l[i][k] = factor;
+        }
+        l[k][k] = 1.0;
+    }
+    Some((l, u, perm))
 }
 
 impl AsRef<[f32; 16]> for Mat4 {
@@ -1067,6 +1812,14 @@ impl Mul<Vec4> for Mat4 {
     }
 }
 
+impl Mul<Vec4x4> for Mat4 {
+    type Output = Vec4x4;
+    #[inline]
+    fn mul(self, other: Vec4x4) -> Vec4x4 {
+        self.mul_vec4x4(other)
+    }
+}
+
 impl Mul<Mat4> for f32 {
     type Output = Mat4;
     #[inline]
@@ -1102,3 +1855,35 @@ impl<'a> Product<&'a Self> for Mat4 {
         iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
     }
 }
+
+#[test]
+fn test_mat4_private() {
+    // `mul_mat4` uses 256 bit registers when AVX is enabled, which may round differently
+    let q = Quat::from_axis_angle(Vec3::new(0.3, -1.2, 2.1).normalize(), 1.7);
+    let mats = [
+        Mat4::from_scale_rotation_translation(
+            Vec3::new(1.5, -0.75, 2.25),
+            q,
+            Vec3::new(-3.7, 12.9, 0.31),
+        ),
+        Mat4::perspective_rh(1.17, 1.37, 0.13, 517.0),
+        Mat4::look_at_rh(
+            Vec3::new(1.3, -7.1, 2.9),
+            Vec3::new(-0.4, 0.2, 0.7),
+            Vec3::unit_y(),
+        ),
+        Mat4::from_rotation_ypr(0.7, -2.3, 1.1),
+    ];
+    for a in mats.iter() {
+        for b in mats.iter() {
+            let expected = a.mul_mat4_by_cols(b);
+            let max_abs_diff = expected
+                .to_cols_array()
+                .iter()
+                .fold(1.0, |m, v| v.abs().max(m))
+                * 1.0e-6;
+            assert!(expected.abs_diff_eq(a.mul_mat4(b), max_abs_diff));
+            assert!(expected.abs_diff_eq(*a * *b, max_abs_diff));
+        }
+    }
+}
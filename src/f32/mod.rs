@@ -1,7 +1,11 @@
+mod affine2;
+mod affine3a;
 mod cast;
+mod dual_quat;
 mod funcs;
 mod mat2;
 mod mat3;
+mod mat3a;
 mod mat4;
 mod quat;
 #[cfg(feature = "transform-types")]
@@ -15,14 +19,21 @@ mod vec3_swizzle;
 mod vec3a;
 mod vec3a_mask;
 mod vec3a_swizzle;
+mod vec3x4;
+mod vec3x8;
 mod vec4;
 mod vec4_mask;
 mod vec4_swizzle;
+mod vec4x4;
 
-pub use cast::{F32x16Cast, F32x2Cast, F32x3Cast, F32x4Cast, F32x9Cast};
-pub(crate) use funcs::{scalar_acos, scalar_sin_cos};
+pub use affine2::*;
+pub use affine3a::*;
+pub use cast::{F32x12Cast, F32x16Cast, F32x2Cast, F32x3Cast, F32x4Cast, F32x9Cast};
+pub use dual_quat::*;
+pub(crate) use funcs::{scalar_acos, scalar_sin_cos, symmetric_eigen_jacobi};
 pub use mat2::*;
 pub use mat3::*;
+pub use mat3a::*;
 pub use mat4::*;
 pub use quat::*;
 #[cfg(feature = "transform-types")]
@@ -36,9 +47,12 @@ pub use vec3_swizzle::*;
 pub use vec3a::*;
 pub use vec3a_mask::*;
 pub use vec3a_swizzle::*;
+pub use vec3x4::*;
+pub use vec3x8::Vec3x8;
 pub use vec4::*;
 pub use vec4_mask::*;
 pub use vec4_swizzle::*;
+pub use vec4x4::*;
 
 #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(C)]
@@ -66,20 +80,9 @@ pub struct XYAxes {
 
 #[cfg(feature = "bytemuck")]
 mod glam_bytemuck;
-#[cfg(feature = "bytemuck")]
-pub use glam_bytemuck::*;
-
 #[cfg(feature = "mint")]
 mod glam_mint;
-#[cfg(feature = "mint")]
-pub use glam_mint::*;
-
 #[cfg(feature = "rand")]
 mod glam_rand;
-#[cfg(feature = "rand")]
-pub use glam_rand::*;
-
 #[cfg(feature = "serde")]
 mod glam_serde;
-#[cfg(feature = "serde")]
-pub use glam_serde::*;
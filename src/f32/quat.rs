@@ -1,7 +1,18 @@
 #[cfg(feature = "num-traits")]
 use num_traits::Float;
 
-use super::{scalar_acos, scalar_sin_cos, Mat3, Mat4, Vec3, Vec3A, Vec4, Vec4Swizzles};
+use super::vec3x8::F32x8;
+#[cfg(vec4_sse2)]
+use super::Vec4Swizzles;
+use super::{
+    scalar_acos, scalar_sin_cos, symmetric_eigen_jacobi, Mat3, Mat3A, Mat4, Vec2, Vec3, Vec3A,
+    Vec3x4, Vec3x8, Vec4, Vec4x4,
+};
+use crate::EulerRot;
+#[cfg(vec4_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec4_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec4_sse2, target_arch = "x86",))]
 use core::arch::x86::*;
 #[cfg(all(vec4_sse2, target_arch = "x86_64",))]
@@ -126,6 +137,8 @@ impl Quat {
     #[inline]
     /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
     /// in radians.
+    ///
+    /// This is equivalent to `from_euler(EulerRot::YXZ, yaw, pitch, roll)`.
     pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
         // Self::from_rotation_y(yaw) * Self::from_rotation_x(pitch) * Self::from_rotation_z(roll)
         let (y0, w0) = scalar_sin_cos(yaw * 0.5);
@@ -145,10 +158,132 @@ impl Quat {
         Self(Vec4::new(x4, y4, z4, w4))
     }
 
+    /// Creates a quaternion from the given Euler rotation sequence and the angles (in radians).
+    ///
+    /// The angles `a`, `b` and `c` are applied about the first, second and third axis of `euler`
+    /// respectively, e.g. `EulerRot::XYZ` gives `Rx(a) * Ry(b) * Rz(c)`.
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let (i, j, k) = euler.axes();
+        Self::from_rotation_axis_index(i, a)
+            * Self::from_rotation_axis_index(j, b)
+            * Self::from_rotation_axis_index(k, c)
+    }
+
     #[inline]
-    fn from_rotation_axes(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+    fn from_rotation_axis_index(axis: usize, angle: f32) -> Self {
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        let mut v = [0.0, 0.0, 0.0, c];
+        v[axis] = s;
+        Self(Vec4::from(v))
+    }
+
+    #[inline]
+    fn from_rotation_axes(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
         // Based on https://github.com/microsoft/DirectXMath `XMQuaternionRotationMatrix`
-        // TODO: sse2 version
+        #[cfg(vec4_sse2)]
+        unsafe {
+            const MPMP: __m128 = const_m128!([-1.0, 1.0, -1.0, 1.0]);
+            const MMPP: __m128 = const_m128!([-1.0, -1.0, 1.0, 1.0]);
+            const ONE: __m128 = const_m128!([1.0; 4]);
+            const HALF: __m128 = const_m128!([0.5; 4]);
+            let r0: __m128 = x_axis.into();
+            let r1: __m128 = y_axis.into();
+            let r2: __m128 = z_axis.into();
+            let zero = _mm_setzero_ps();
+            let select = |mask, if_true, if_false| {
+                _mm_or_ps(_mm_and_ps(mask, if_true), _mm_andnot_ps(mask, if_false))
+            };
+
+            let r00 = _mm_shuffle_ps(r0, r0, 0b00_00_00_00);
+            let r11 = _mm_shuffle_ps(r1, r1, 0b01_01_01_01);
+            let r22 = _mm_shuffle_ps(r2, r2, 0b10_10_10_10);
+
+            // x^2 >= y^2 equivalent to r11 - r00 <= 0
+            let x2gey2 = _mm_cmple_ps(_mm_sub_ps(r11, r00), zero);
+            // z^2 >= w^2 equivalent to r11 + r00 <= 0
+            let z2gew2 = _mm_cmple_ps(_mm_add_ps(r11, r00), zero);
+            // x^2 + y^2 >= z^2 + w^2 equivalent to r22 <= 0
+            let x2py2gez2pw2 = _mm_cmple_ps(r22, zero);
+
+            // (4*x^2, 4*y^2, 4*z^2, 4*w^2), summed in the same order as the scalar version
+            let omm22_omm22_opm22_opm22 = _mm_add_ps(ONE, _mm_mul_ps(MMPP, r22));
+            let dif10_dif10_sum10_sum10 = _mm_add_ps(r11, _mm_mul_ps(MMPP, r00));
+            let x2y2z2w2 = _mm_add_ps(
+                omm22_omm22_opm22_opm22,
+                _mm_mul_ps(MPMP, dif10_dif10_sum10_sum10),
+            );
+
+            // (r01, r02, r12, r11)
+            let t0 = _mm_shuffle_ps(r0, r1, 0b01_10_10_01);
+            // (r10, r10, r20, r21)
+            let t1 = _mm_shuffle_ps(r1, r2, 0b01_00_00_00);
+            // (r10, r20, r21, r10)
+            let t1 = _mm_shuffle_ps(t1, t1, 0b01_11_10_00);
+            // (4*x*y, 4*x*z, 4*y*z, unused)
+            let xyxzyz = _mm_add_ps(t0, t1);
+
+            // (r21, r20, r10, r10)
+            let t0 = _mm_shuffle_ps(r2, r1, 0b00_00_00_01);
+            // (r12, r12, r02, r01)
+            let t1 = _mm_shuffle_ps(r1, r0, 0b01_10_10_10);
+            // (r12, r02, r01, r12)
+            let t1 = _mm_shuffle_ps(t1, t1, 0b01_11_10_00);
+            // (4*x*w, 4*y*w, 4*z*w, unused)
+            let xwywzw = _mm_mul_ps(MPMP, _mm_sub_ps(t0, t1));
+
+            // (4*x^2, 4*y^2, 4*x*y, unused)
+            let t0 = _mm_shuffle_ps(x2y2z2w2, xyxzyz, 0b00_00_01_00);
+            // (4*z^2, 4*w^2, 4*z*w, unused)
+            let t1 = _mm_shuffle_ps(x2y2z2w2, xwywzw, 0b00_10_11_10);
+            // (4*x*z, 4*y*z, 4*x*w, 4*y*w)
+            let t2 = _mm_shuffle_ps(xyxzyz, xwywzw, 0b01_00_10_01);
+
+            // (4*x*x, 4*x*y, 4*x*z, 4*x*w)
+            let tensor0 = _mm_shuffle_ps(t0, t2, 0b10_00_10_00);
+            // (4*y*x, 4*y*y, 4*y*z, 4*y*w)
+            let tensor1 = _mm_shuffle_ps(t0, t2, 0b11_01_01_10);
+            // (4*z*x, 4*z*y, 4*z*z, 4*z*w)
+            let tensor2 = _mm_shuffle_ps(t2, t1, 0b10_00_01_00);
+            // (4*w*x, 4*w*y, 4*w*z, 4*w*w)
+            let tensor3 = _mm_shuffle_ps(t2, t1, 0b01_10_11_10);
+
+            // select the row of the tensor product matrix that has the largest magnitude
+            let row = select(
+                x2py2gez2pw2,
+                select(x2gey2, tensor0, tensor1),
+                select(z2gew2, tensor2, tensor3),
+            );
+
+            // the row is a multiple of the quaternion by four times its largest element, which
+            // is the square root of the element on the diagonal
+            let inv = _mm_div_ps(HALF, _mm_sqrt_ps(x2y2z2w2));
+            let inv = select(
+                x2py2gez2pw2,
+                select(
+                    x2gey2,
+                    _mm_shuffle_ps(inv, inv, 0b00_00_00_00),
+                    _mm_shuffle_ps(inv, inv, 0b01_01_01_01),
+                ),
+                select(
+                    z2gew2,
+                    _mm_shuffle_ps(inv, inv, 0b10_10_10_10),
+                    _mm_shuffle_ps(inv, inv, 0b11_11_11_11),
+                ),
+            );
+            Self(Vec4(_mm_mul_ps(row, inv)))
+        }
+
+        #[cfg(not(vec4_sse2))]
+        {
+            Self::from_rotation_axes_scalar(x_axis, y_axis, z_axis)
+        }
+    }
+
+    /// Scalar version of `from_rotation_axes`, kept for testing the SSE2 version against.
+    #[inline]
+    #[cfg_attr(vec4_sse2, allow(dead_code))]
+    fn from_rotation_axes_scalar(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
         let (m00, m01, m02) = x_axis.into();
         let (m10, m11, m12) = y_axis.into();
         let (m20, m21, m22) = z_axis.into();
@@ -208,13 +343,199 @@ impl Quat {
     /// Creates a quaternion from a 3x3 rotation matrix.
     #[inline]
     pub fn from_rotation_mat3(mat: &Mat3) -> Self {
+        Self::from_rotation_axes(mat.x_axis.into(), mat.y_axis.into(), mat.z_axis.into())
+    }
+
+    /// Creates a quaternion from a 3x3 SIMD aligned rotation matrix.
+    #[inline]
+    pub fn from_rotation_mat3a(mat: &Mat3A) -> Self {
         Self::from_rotation_axes(mat.x_axis, mat.y_axis, mat.z_axis)
     }
 
     /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
     #[inline]
     pub fn from_rotation_mat4(mat: &Mat4) -> Self {
-        Self::from_rotation_axes(mat.x_axis.xyz(), mat.y_axis.xyz(), mat.z_axis.xyz())
+        Self::from_rotation_axes(mat.x_axis.into(), mat.y_axis.into(), mat.z_axis.into())
+    }
+
+    /// Returns the shortest rotation that transforms the normalized vector `from` onto the
+    /// normalized vector `to`.
+    ///
+    /// If `from` and `to` are antiparallel the result is a half turn about an arbitrary axis
+    /// perpendicular to `from`.
+    pub fn from_rotation_arc(from: Vec3, to: Vec3) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        const ONE_MINUS_EPSILON: f32 = 1.0 - 2.0 * core::f32::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPSILON {
+            // parallel
+            Self::identity()
+        } else if dot < -ONE_MINUS_EPSILON {
+            // antiparallel, so any axis perpendicular to `from` gives a shortest rotation
+            let other = if from.x.abs() < 0.9 {
+                Vec3::unit_x()
+            } else {
+                Vec3::unit_y()
+            };
+            Self::from_axis_angle(from.cross(other).normalize(), core::f32::consts::PI)
+        } else {
+            // the half way quaternion between the identity and the rotation by twice the angle
+            let c = from.cross(to);
+            Self::from_xyzw(c.x, c.y, c.z, 1.0 + dot).normalize()
+        }
+    }
+
+    /// Returns the shortest rotation that transforms the normalized vector `from` onto either
+    /// the normalized vector `to` or `-to`, whichever is closer.
+    ///
+    /// The rotation is in the plane of `from` and `to` and is never more than a quarter turn, so
+    /// if `from` and `to` are parallel or antiparallel the result is the identity. This is useful
+    /// when the sign of a direction doesn't matter, such as when aligning with a line or an axis.
+    #[inline]
+    pub fn from_rotation_arc_colinear(from: Vec3, to: Vec3) -> Self {
+        if from.dot(to) < 0.0 {
+            Self::from_rotation_arc(from, -to)
+        } else {
+            Self::from_rotation_arc(from, to)
+        }
+    }
+
+    /// Returns the rotation about the z axis that transforms the normalized 2D vector `from` onto
+    /// the normalized 2D vector `to`.
+    ///
+    /// If `from` and `to` are antiparallel the result is a half turn about the z axis.
+    pub fn from_rotation_arc_2d(from: Vec2, to: Vec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        const ONE_MINUS_EPSILON: f32 = 1.0 - 2.0 * core::f32::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPSILON {
+            Self::identity()
+        } else if dot < -ONE_MINUS_EPSILON {
+            Self::from_rotation_z(core::f32::consts::PI)
+        } else {
+            Self::from_xyzw(0.0, 0.0, from.perp_dot(to), 1.0 + dot).normalize()
+        }
+    }
+
+    /// Returns the rotation that transforms the positive z axis onto `forward` and the positive
+    /// y axis onto the plane of `forward` and `up`.
+    ///
+    /// This is the orientation of a left-handed camera, i.e. the inverse of the rotation of
+    /// `Mat4::look_at_lh(eye, eye + forward, up)`. Neither vector needs to be normalized. If
+    /// `forward` is zero the result is the identity, and if `up` is zero or parallel to `forward`
+    /// the result is the shortest rotation of the positive z axis onto `forward`.
+    pub fn look_rotation(forward: Vec3, up: Vec3) -> Self {
+        const EPSILON_SQUARED: f32 = 1.0e-12;
+        let forward_length_sq = forward.length_squared();
+        if forward_length_sq < EPSILON_SQUARED {
+            return Self::identity();
+        }
+        let forward = forward / forward_length_sq.sqrt();
+        let side = up.cross(forward);
+        let side_length_sq = side.length_squared();
+        if side_length_sq <= EPSILON_SQUARED * up.length_squared() {
+            return Self::from_rotation_arc(Vec3::unit_z(), forward);
+        }
+        let side = side / side_length_sq.sqrt();
+        Self::from_rotation_axes(side.into(), forward.cross(side).into(), forward.into())
+    }
+
+    /// Returns the rotation that transforms the negative z axis onto `forward` and the positive
+    /// y axis onto the plane of `forward` and `up`.
+    ///
+    /// This is the orientation of a right-handed camera, i.e. the inverse of the rotation of
+    /// `Mat4::look_at_rh(eye, eye + forward, up)`. Degenerate inputs are handled the same way as
+    /// `Quat::look_rotation`.
+    #[inline]
+    pub fn look_rotation_rh(forward: Vec3, up: Vec3) -> Self {
+        Self::look_rotation(-forward, up)
+    }
+
+    /// Decomposes `self` into a swing and a twist about the normalized `axis`, such that
+    /// `self == swing * twist`.
+    ///
+    /// The twist is the rotation of `self` about `axis` and the swing is the remaining rotation
+    /// about an axis perpendicular to `axis`. The twist is returned with a non-negative `w`, so
+    /// its angle is in the range `[-PI, PI]`. If `self` is a half turn about an axis perpendicular
+    /// to `axis` the twist is undefined and the identity is returned.
+    pub fn swing_twist(self, axis: Vec3) -> (Self, Self) {
+        glam_assert!(self.is_normalized());
+        glam_assert!(axis.is_normalized());
+        const EPSILON_SQUARED: f32 = 1.0e-12;
+        let (x, y, z, w) = self.0.into();
+        let projected = axis * Vec3::new(x, y, z).dot(axis);
+        let twist = Self::from_xyzw(projected.x, projected.y, projected.z, w);
+        let length_sq = twist.length_squared();
+        if length_sq < EPSILON_SQUARED {
+            return (self, Self::identity());
+        }
+        let twist = twist * (length_sq.sqrt().recip() * if w < 0.0 { -1.0 } else { 1.0 });
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Limits the twist of `self` about the normalized `axis` to the range `[min_angle,
+    /// max_angle]` (in radians), leaving the swing unchanged.
+    ///
+    /// The angles must be in the range `[-PI, PI]`. See `Quat::swing_twist` for the definition of
+    /// the twist.
+    pub fn clamp_twist(self, axis: Vec3, min_angle: f32, max_angle: f32) -> Self {
+        glam_assert!(min_angle <= max_angle);
+        let (swing, twist) = self.swing_twist(axis);
+        let (x, y, z, w) = twist.0.into();
+        let angle = Vec3::new(x, y, z).dot(axis).atan2(w) * 2.0;
+        if angle < min_angle {
+            swing * Self::from_axis_angle(axis, min_angle)
+        } else if angle > max_angle {
+            swing * Self::from_axis_angle(axis, max_angle)
+        } else {
+            self
+        }
+    }
+
+    /// Limits the swing of `self` away from the normalized `twist_axis` to an elliptical cone,
+    /// leaving the twist unchanged.
+    ///
+    /// The cone allows a swing of up to `max_angle_1` (in radians) about the normalized
+    /// `swing_axis`, which must be perpendicular to `twist_axis`, and up to `max_angle_2` about
+    /// `twist_axis.cross(swing_axis)`. A swing outside of the cone is scaled back towards the
+    /// identity until it is on the boundary. See `Quat::swing_twist` for the definition of the
+    /// swing.
+    pub fn clamp_swing(
+        self,
+        twist_axis: Vec3,
+        swing_axis: Vec3,
+        max_angle_1: f32,
+        max_angle_2: f32,
+    ) -> Self {
+        glam_assert!(swing_axis.is_normalized());
+        glam_assert!(twist_axis.dot(swing_axis).abs() < 1.0e-4);
+        glam_assert!(max_angle_1 >= 0.0 && max_angle_2 >= 0.0);
+        let (swing, twist) = self.swing_twist(twist_axis);
+        // take the shortest path so the swing angle is at most PI
+        let swing = if swing.w < 0.0 { -swing } else { swing };
+        let scaled_axis = swing.to_scaled_axis();
+        let other_axis = twist_axis.cross(swing_axis);
+        let angle_1 = scaled_axis.dot(swing_axis);
+        let angle_2 = scaled_axis.dot(other_axis);
+        // the squared distance of the swing from the identity relative to the cone boundary,
+        // where a zero angle is always within the limit even if the limit is zero
+        let ratio = |angle: f32, max_angle: f32| {
+            if angle == 0.0 {
+                0.0
+            } else {
+                let r = angle / max_angle;
+                r * r
+            }
+        };
+        let distance_sq = ratio(angle_1, max_angle_1) + ratio(angle_2, max_angle_2);
+        if distance_sq <= 1.0 {
+            return self;
+        }
+        let scale = distance_sq.sqrt().recip();
+        let clamped = (swing_axis * angle_1 + other_axis * angle_2) * scale;
+        Self::from_scaled_axis(clamped) * twist
     }
 
     /// Returns the rotation axis and angle of `self`.
@@ -232,6 +553,61 @@ impl Quat {
         }
     }
 
+    /// Returns the rotation angles (in radians) for the given Euler rotation sequence, such that
+    /// `Quat::from_euler(euler, a, b, c)` gives the same rotation as `self`.
+    ///
+    /// For Tait-Bryan sequences such as `EulerRot::XYZ` the second angle is in the range
+    /// `[-PI/2, PI/2]`, for proper Euler sequences such as `EulerRot::ZXZ` it is in `[0, PI]`. The
+    /// first and third angles are in `[-PI, PI]`.
+    ///
+    /// When the rotation is in gimbal lock, i.e. the first and third axes are aligned, only the
+    /// sum or difference of the first and third angles is defined. In this case the third angle
+    /// is set to zero and the first angle holds the whole rotation about that axis.
+    pub fn to_euler(self, euler: EulerRot) -> (f32, f32, f32) {
+        // Based on "Quaternion to Euler angles conversion: A direct, general and computationally
+        // efficient method" by Bernardes and Viollet, which is formulated for extrinsic rotations.
+        // An intrinsic sequence `i, j, k` is the extrinsic sequence `k, j, i` with the angles
+        // reversed.
+        const EPSILON: f32 = 1.0e-6;
+        glam_assert!(self.is_normalized());
+
+        let (k, j, i) = euler.axes();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        // +1 for an even permutation of the axes, -1 for an odd one
+        let sign = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+
+        let q: [f32; 4] = self.0.into();
+        let w = q[3];
+        let (a, b, c, d) = if proper {
+            (w, q[i], q[j], q[k] * sign)
+        } else {
+            (w - q[j], q[i] + q[k] * sign, q[j] + w, q[k] * sign - q[i])
+        };
+
+        let mut second = 2.0 * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+
+        let (first, mut third) = if second.abs() <= EPSILON {
+            // gimbal lock, only the sum of the first and third angles is known
+            (0.0, 2.0 * half_sum)
+        } else if (second - core::f32::consts::PI).abs() <= EPSILON {
+            // gimbal lock, only the difference of the first and third angles is known
+            (0.0, 2.0 * half_diff)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        if !proper {
+            third *= sign;
+            second -= core::f32::consts::FRAC_PI_2;
+        }
+
+        // the third extrinsic angle is the first intrinsic one
+        (wrap_angle(third), second, wrap_angle(first))
+    }
+
     /// Returns the quaternion conjugate of `self`. For a unit quaternion the
     /// conjugate is also the inverse.
     #[inline]
@@ -287,6 +663,31 @@ impl Quat {
         Self(self.0.mul(inv_len))
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(Self(self.0.mul(rcp)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns a quaternion with all
+    /// elements set to zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize()
+            .unwrap_or_else(|| Self::from_xyzw(0.0, 0.0, 0.0, 0.0))
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -341,6 +742,12 @@ impl Quat {
         self.0.abs_diff_eq(other.0, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> crate::DQuat {
+        crate::DQuat::from_xyzw(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
     /// Performs a linear interpolation between `self` and `other` based on
     /// the value `s`.
     ///
@@ -441,6 +848,176 @@ impl Quat {
         }
     }
 
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// For a normalized quaternion the result has a zero `w` and its vector part is the rotation
+    /// axis scaled by half of the rotation angle.
+    #[inline]
+    pub fn ln(self) -> Self {
+        let (x, y, z, w) = self.0.into();
+        let v = Vec3::new(x, y, z);
+        let v_len = v.length();
+        let len = (v_len * v_len + w * w).sqrt();
+        let scale = if v_len > 0.0 {
+            v_len.atan2(w) / v_len
+        } else {
+            0.0
+        };
+        (v * scale).extend(len.ln()).into()
+    }
+
+    /// Returns the exponential of `self`.
+    ///
+    /// This is the inverse of `Quat::ln`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        let (x, y, z, w) = self.0.into();
+        let v = Vec3::new(x, y, z);
+        let v_len = v.length();
+        let (sin, cos) = scalar_sin_cos(v_len);
+        let exp_w = w.exp();
+        let scale = if v_len > 0.0 { sin / v_len } else { 1.0 };
+        (v * (scale * exp_w)).extend(cos * exp_w).into()
+    }
+
+    /// Returns `self` raised to the power `n`.
+    ///
+    /// For a normalized quaternion this scales the rotation angle by `n`, keeping the same
+    /// rotation axis.
+    #[inline]
+    pub fn powf(self, n: f32) -> Self {
+        (self.ln() * n).exp()
+    }
+
+    /// Creates a quaternion from a rotation vector, whose direction is the rotation axis and
+    /// whose length is the rotation angle (in radians).
+    #[inline]
+    pub fn from_scaled_axis(v: Vec3) -> Self {
+        Self::from((v * 0.5).extend(0.0)).exp()
+    }
+
+    /// Returns the rotation vector of `self`, whose direction is the rotation axis and whose
+    /// length is the rotation angle (in radians).
+    ///
+    /// The angle is in the range `[0, 2*PI]`, negate `self` first if its `w` is negative to
+    /// get an angle no larger than `PI`.
+    #[inline]
+    pub fn to_scaled_axis(self) -> Vec3 {
+        glam_assert!(self.is_normalized());
+        let (x, y, z, _) = self.ln().0.into();
+        Vec3::new(x, y, z) * 2.0
+    }
+
+    /// Performs a spherical cubic interpolation between `self` and `end` based on the value `s`,
+    /// using the tangents `start_tangent` and `end_tangent`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `end`.
+    ///
+    /// Interpolating each segment of a sequence of keyframes using tangents computed with
+    /// `Quat::squad_tangent` gives a path through the keyframes with a continuous angular
+    /// velocity. Consecutive keyframes should have a non-negative dot product so each segment
+    /// takes the shortest path.
+    ///
+    /// All four quaternions must be normalized. The result is normalized, the intermediate
+    /// interpolations are renormalized so rounding errors don't build up along the path.
+    #[inline]
+    pub fn squad(self, end: Self, start_tangent: Self, end_tangent: Self, s: f32) -> Self {
+        // Based on "Quaternion Calculus and Fast Animation" by Ken Shoemake
+        // `slerp` uses approximations when SSE2 is enabled, so the results are renormalized
+        let path = self.slerp(end, s).normalize();
+        let tangent = start_tangent.slerp(end_tangent, s).normalize();
+        path.slerp(tangent, 2.0 * s * (1.0 - s)).normalize()
+    }
+
+    /// Computes the tangent at the keyframe `self` for use with `Quat::squad`, given the
+    /// previous keyframe `prev` and the next keyframe `next`.
+    ///
+    /// For the first and last keyframes of a sequence pass `self` as the missing neighbour.
+    #[inline]
+    pub fn squad_tangent(self, prev: Self, next: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(prev.is_normalized());
+        glam_assert!(next.is_normalized());
+        // move the neighbours into the same hemisphere as `self` so the tangent follows the
+        // shortest path
+        let prev = if self.dot(prev) < 0.0 { -prev } else { prev };
+        let next = if self.dot(next) < 0.0 { -next } else { next };
+        let inv = self.conjugate();
+        let sum = (inv * next).ln() + (inv * prev).ln();
+        (self * (sum * -0.25).exp()).normalize()
+    }
+
+    /// Computes the weighted average rotation of the given normalized quaternions.
+    ///
+    /// The result is the rotation that minimizes the weighted sum of squared chordal distances
+    /// to the inputs, found as the eigenvector of the largest eigenvalue of the sum of the
+    /// weighted outer products of the quaternions. This is accurate for any spread of rotations
+    /// and independent of the order of the inputs and of their signs, so `q` and `-q` count as
+    /// the same rotation.
+    ///
+    /// If `weights` is `None` every quaternion has a weight of `1.0`, otherwise it must have
+    /// the same length as `quats` and contain no negative weights. The result is in the same
+    /// hemisphere as the first quaternion. If `quats` is empty or all weights are zero the
+    /// result is the identity.
+    pub fn average(quats: &[Self], weights: Option<&[f32]>) -> Self {
+        // Based on "Averaging Quaternions" by F. Landis Markley, Yang Cheng, John L. Crassidis
+        // and Yaakov Oshman
+        glam_assert!(weights.map_or(true, |weights| weights.len() == quats.len()));
+        let mut m = [[0.0; 4]; 4];
+        for (i, q) in quats.iter().enumerate() {
+            glam_assert!(q.is_normalized());
+            let weight = weights.map_or(1.0, |weights| weights[i]);
+            glam_assert!(weight >= 0.0);
+            let q: [f32; 4] = (*q).into();
+            for row in 0..4 {
+                for col in row..4 {
+                    m[row][col] += weight * q[row] * q[col];
+                }
+            }
+        }
+        if m[0][0] + m[1][1] + m[2][2] + m[3][3] <= 0.0 {
+            return Self::identity();
+        }
+        let average = Self::from(symmetric_eigen_max4(m)).normalize();
+        if average.dot(quats[0]) < 0.0 {
+            -average
+        } else {
+            average
+        }
+    }
+
+    /// Computes an approximate weighted average rotation of the given normalized quaternions by
+    /// normalizing their weighted sum.
+    ///
+    /// Each quaternion is flipped into the hemisphere of the first one before summing, so `q`
+    /// and `-q` count as the same rotation. This is much cheaper than `Quat::average` and close
+    /// to it when the rotations are near each other, but loses accuracy as they spread apart.
+    ///
+    /// If `weights` is `None` every quaternion has a weight of `1.0`, otherwise it must have
+    /// the same length as `quats`. If `quats` is empty or the weighted sum is zero the result is
+    /// the identity.
+    pub fn average_approx(quats: &[Self], weights: Option<&[f32]>) -> Self {
+        glam_assert!(weights.map_or(true, |weights| weights.len() == quats.len()));
+        let first = match quats.first() {
+            Some(first) => *first,
+            None => return Self::identity(),
+        };
+        let mut sum = Vec4::zero();
+        for (i, q) in quats.iter().enumerate() {
+            glam_assert!(q.is_normalized());
+            let weight = weights.map_or(1.0, |weights| weights[i]);
+            let weight = if q.dot(first) < 0.0 { -weight } else { weight };
+            sum += q.0 * weight;
+        }
+        let length_sq = sum.length_squared();
+        if length_sq > 0.0 {
+            Self(sum / length_sq.sqrt())
+        } else {
+            Self::identity()
+        }
+    }
+
     #[inline]
     /// Multiplies a quaternion and a 3D vector, rotating it.
     pub fn mul_vec3a(self, other: Vec3A) -> Vec3A {
@@ -474,6 +1051,30 @@ impl Quat {
         self.mul_vec3a(Vec3A::from(other)).into()
     }
 
+    #[inline]
+    /// Multiplies a quaternion and the four vectors of a `Vec3x4`, rotating them.
+    pub fn mul_vec3x4(self, other: Vec3x4) -> Vec3x4 {
+        glam_assert!(self.is_normalized());
+        let q = Vec4x4::splat(self.0);
+        let w = q.w;
+        let b = q.truncate();
+        let b2 = b.dot(b);
+        other * (w * w - b2) + b * (other.dot(b) * 2.0) + b.cross(other) * (w * 2.0)
+    }
+
+    #[inline]
+    /// Multiplies a quaternion and the eight vectors of a `Vec3x8`, rotating them.
+    pub fn mul_vec3x8(self, other: Vec3x8) -> Vec3x8 {
+        glam_assert!(self.is_normalized());
+        let w = F32x8::splat(self.0.w);
+        let b = Vec3x8::splat(self.0.truncate());
+        let b2 = b.dot_lanes(b);
+        let two = F32x8::splat(2.0);
+        other.mul_lanes(w * w - b2)
+            + b.mul_lanes(other.dot_lanes(b) * two)
+            + b.cross(other).mul_lanes(w * two)
+    }
+
     #[inline]
     /// Multiplies two quaternions.
     /// If they each represent a rotation, the result will represent the combined rotation.
@@ -518,7 +1119,75 @@ impl Quat {
             Self(Vec4(_mm_add_ps(result0, result1)))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            // NEON port of the `rtm::quat_mul` based SSE2 version above
+            let lhs = self.0.into();
+            let rhs = other.0.into();
+
+            const CONTROL_WZYX: [f32; 4] = [1.0, -1.0, 1.0, -1.0];
+            const CONTROL_ZWXY: [f32; 4] = [1.0, 1.0, -1.0, -1.0];
+            const CONTROL_YXWZ: [f32; 4] = [-1.0, 1.0, 1.0, -1.0];
+
+            let l_yxwz = vrev64q_f32(rhs);
+            let l_wzyx = vextq_f32(l_yxwz, l_yxwz, 2);
+            let l_zwxy = vextq_f32(rhs, rhs, 2);
+
+            let lxrw_lyrw_lzrw_lwrw = vmulq_laneq_f32(rhs, lhs, 3);
+            let lwrx_lzrx_lyrx_lxrx = vmulq_laneq_f32(l_wzyx, lhs, 0);
+            let lzry_lwry_lxry_lyry = vmulq_laneq_f32(l_zwxy, lhs, 1);
+            let lyrz_lxrz_lwrz_lzrz = vmulq_laneq_f32(l_yxwz, lhs, 2);
+
+            let result0 = vmlaq_f32(
+                lxrw_lyrw_lzrw_lwrw,
+                lwrx_lzrx_lyrx_lxrx,
+                vld1q_f32(CONTROL_WZYX.as_ptr()),
+            );
+            let result1 = vmlaq_f32(
+                vmulq_f32(lzry_lwry_lxry_lyry, vld1q_f32(CONTROL_ZWXY.as_ptr())),
+                lyrz_lxrz_lwrz_lzrz,
+                vld1q_f32(CONTROL_YXWZ.as_ptr()),
+            );
+            Self::from(vaddq_f32(result0, result1))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            // Based on https://github.com/nfrechette/rtm `rtm::quat_mul`
+            let lhs = self.0.into();
+            let rhs = other.0.into();
+
+            const CONTROL_WZYX: v128 = f32x4(1.0, -1.0, 1.0, -1.0);
+            const CONTROL_ZWXY: v128 = f32x4(1.0, 1.0, -1.0, -1.0);
+            const CONTROL_YXWZ: v128 = f32x4(-1.0, 1.0, 1.0, -1.0);
+
+            let r_xxxx = i32x4_shuffle::<0, 0, 0, 0>(lhs, lhs);
+            let r_yyyy = i32x4_shuffle::<1, 1, 1, 1>(lhs, lhs);
+            let r_zzzz = i32x4_shuffle::<2, 2, 2, 2>(lhs, lhs);
+            let r_wwww = i32x4_shuffle::<3, 3, 3, 3>(lhs, lhs);
+
+            let lxrw_lyrw_lzrw_lwrw = f32x4_mul(r_wwww, rhs);
+            let l_wzyx = i32x4_shuffle::<3, 2, 1, 0>(rhs, rhs);
+
+            let lwrx_lzrx_lyrx_lxrx = f32x4_mul(r_xxxx, l_wzyx);
+            let l_zwxy = i32x4_shuffle::<1, 0, 3, 2>(l_wzyx, l_wzyx);
+
+            let lwrx_nlzrx_lyrx_nlxrx = f32x4_mul(lwrx_lzrx_lyrx_lxrx, CONTROL_WZYX);
+
+            let lzry_lwry_lxry_lyry = f32x4_mul(r_yyyy, l_zwxy);
+            let l_yxwz = i32x4_shuffle::<3, 2, 1, 0>(l_zwxy, l_zwxy);
+
+            let lzry_lwry_nlxry_nlyry = f32x4_mul(lzry_lwry_lxry_lyry, CONTROL_ZWXY);
+
+            let lyrz_lxrz_lwrz_lzrz = f32x4_mul(r_zzzz, l_yxwz);
+            let result0 = f32x4_add(lxrw_lyrw_lzrw_lwrw, lwrx_nlzrx_lyrx_nlxrx);
+
+            let nlyrz_lxrz_lwrz_wlzrz = f32x4_mul(lyrz_lxrz_lwrz_lzrz, CONTROL_YXWZ);
+            let result1 = f32x4_add(lzry_lwry_nlxry_nlyry, nlyrz_lxrz_lwrz_wlzrz);
+            Self::from(f32x4_add(result0, result1))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             let (x0, y0, z0, w0) = self.0.into();
             let (x1, y1, z1, w1) = other.0.into();
@@ -532,6 +1201,34 @@ impl Quat {
     }
 }
 
+/// Wraps an angle in the range `[-2*PI, 2*PI]` into `[-PI, PI]`.
+#[inline]
+fn wrap_angle(angle: f32) -> f32 {
+    use core::f32::consts::PI;
+    if angle > PI {
+        angle - 2.0 * PI
+    } else if angle < -PI {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+/// Returns the eigenvector of the largest eigenvalue of the symmetric 4x4 matrix `m`, of which
+/// only the upper triangle is used.
+#[allow(clippy::needless_range_loop)]
+fn symmetric_eigen_max4(mut m: [[f32; 4]; 4]) -> [f32; 4] {
+    let v = symmetric_eigen_jacobi(&mut m, 4);
+    // the eigenvectors are the columns of `v`
+    let mut max = 0;
+    for i in 1..4 {
+        if m[i][i] > m[max][max] {
+            max = i;
+        }
+    }
+    [v[0][max], v[1][max], v[2][max], v[3][max]]
+}
+
 impl fmt::Debug for Quat {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         let a = self.as_ref();
@@ -625,6 +1322,22 @@ impl Mul<Vec3A> for Quat {
     }
 }
 
+impl Mul<Vec3x4> for Quat {
+    type Output = Vec3x4;
+    #[inline]
+    fn mul(self, other: Vec3x4) -> Self::Output {
+        self.mul_vec3x4(other)
+    }
+}
+
+impl Mul<Vec3x8> for Quat {
+    type Output = Vec3x8;
+    #[inline]
+    fn mul(self, other: Vec3x8) -> Self::Output {
+        self.mul_vec3x8(other)
+    }
+}
+
 impl Neg for Quat {
     type Output = Self;
     #[inline]
@@ -727,6 +1440,38 @@ impl From<__m128> for Quat {
     }
 }
 
+#[cfg(vec4_neon)]
+impl From<Quat> for float32x4_t {
+    #[inline]
+    fn from(q: Quat) -> Self {
+        q.0.into()
+    }
+}
+
+#[cfg(vec4_neon)]
+impl From<float32x4_t> for Quat {
+    #[inline]
+    fn from(t: float32x4_t) -> Self {
+        Self(Vec4::from(t))
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<Quat> for v128 {
+    #[inline]
+    fn from(q: Quat) -> Self {
+        q.0.into()
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<v128> for Quat {
+    #[inline]
+    fn from(t: v128) -> Self {
+        Self(Vec4::from(t))
+    }
+}
+
 impl Deref for Quat {
     type Target = super::XYZW;
     #[inline(always)]
@@ -754,3 +1499,24 @@ impl<'a> Product<&'a Self> for Quat {
         iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
     }
 }
+
+#[test]
+fn test_quat_private() {
+    // the SIMD versions of `from_rotation_axes` must match the scalar one exactly, for each of
+    // the four elements being the largest
+    let axes = [
+        super::Vec3::new(1.0, 0.2, -0.1),
+        super::Vec3::new(-0.1, 1.0, 0.3),
+        super::Vec3::new(0.2, -0.3, 1.0),
+    ];
+    for axis in axes.iter() {
+        for &angle in [0.3, 1.5, 2.9, -2.6].iter() {
+            let q = Quat::from_axis_angle(axis.normalize(), angle);
+            let m = Mat3A::from_quat(q);
+            assert_eq!(
+                Quat::from_rotation_axes_scalar(m.x_axis, m.y_axis, m.z_axis),
+                Quat::from_rotation_axes(m.x_axis, m.y_axis, m.z_axis)
+            );
+        }
+    }
+}
@@ -83,6 +83,28 @@ impl TransformSRT {
         }
     }
 
+    /// Returns the inverse of `self`, or `None` if the transform is not invertible because a
+    /// component of `scale` is zero or too small for its reciprocal to be finite.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        if self.scale.recip().is_finite() {
+            Some(self.inverse())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the inverse of `self`, or a transform with zero scale, which maps every point to
+    /// the origin, if the transform is not invertible.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or(Self {
+            scale: Vec3::zero(),
+            rotation: Quat::identity(),
+            translation: Vec3::zero(),
+        })
+    }
+
     #[inline]
     pub fn normalize(&self) -> Self {
         let rotation = self.rotation.normalize();
@@ -3,7 +3,7 @@ use core::{fmt, ops::*};
 
 /// A 2-dimensional vector mask.
 ///
-/// This type is typically created by comparison methods on `Vec2`.
+/// This type is typically created by comparison methods on `Vec2`, `IVec2` and `UVec2`.
 #[derive(Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[repr(C)]
 pub struct Vec2Mask(u32, u32);
@@ -152,6 +152,29 @@ impl Vec3 {
         self * self.length_recip()
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize().unwrap_or_else(Self::zero)
+    }
+
     /// Returns the vertical minimum of `self` and `other`.
     ///
     /// In other words, this computes
@@ -351,6 +374,97 @@ impl Vec3 {
         }
     }
 
+    /// Returns a `Vec3` containing the square root of each element of `self`.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self {
+            x: self.x.sqrt(),
+            y: self.y.sqrt(),
+            z: self.z.sqrt(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the sine of each element of `self`.
+    #[inline]
+    pub fn sin(self) -> Self {
+        Self {
+            x: self.x.sin(),
+            y: self.y.sin(),
+            z: self.z.sin(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the cosine of each element of `self`.
+    #[inline]
+    pub fn cos(self) -> Self {
+        Self {
+            x: self.x.cos(),
+            y: self.y.cos(),
+            z: self.z.cos(),
+        }
+    }
+
+    /// Returns a tuple of `Vec3`s containing the sine and the cosine of each element of `self`.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin_x, cos_x) = self.x.sin_cos();
+        let (sin_y, cos_y) = self.y.sin_cos();
+        let (sin_z, cos_z) = self.z.sin_cos();
+        (
+            Self {
+                x: sin_x,
+                y: sin_y,
+                z: sin_z,
+            },
+            Self {
+                x: cos_x,
+                y: cos_y,
+                z: cos_z,
+            },
+        )
+    }
+
+    /// Returns a `Vec3` containing the tangent of each element of `self`.
+    #[inline]
+    pub fn tan(self) -> Self {
+        Self {
+            x: self.x.tan(),
+            y: self.y.tan(),
+            z: self.z.tan(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the arcsine of each element of `self`.
+    #[inline]
+    pub fn asin(self) -> Self {
+        Self {
+            x: self.x.asin(),
+            y: self.y.asin(),
+            z: self.z.asin(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the arccosine of each element of `self`.
+    #[inline]
+    pub fn acos(self) -> Self {
+        Self {
+            x: self.x.acos(),
+            y: self.y.acos(),
+            z: self.z.acos(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the four quadrant arctangent of each element of `self` and
+    /// `other`, with `self` holding the `y` and `other` the `x` coordinates.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        Self {
+            x: self.x.atan2(other.x),
+            y: self.y.atan2(other.y),
+            z: self.z.atan2(other.z),
+        }
+    }
+
     /// Returns a `Vec3` containing `e^self` (the exponential function) for each element of `self`.
     #[inline]
     pub fn exp(self) -> Self {
@@ -361,6 +475,26 @@ impl Vec3 {
         }
     }
 
+    /// Returns a `Vec3` containing the natural logarithm of each element of `self`.
+    #[inline]
+    pub fn ln(self) -> Self {
+        Self {
+            x: self.x.ln(),
+            y: self.y.ln(),
+            z: self.z.ln(),
+        }
+    }
+
+    /// Returns a `Vec3` containing the base 2 logarithm of each element of `self`.
+    #[inline]
+    pub fn log2(self) -> Self {
+        Self {
+            x: self.x.log2(),
+            y: self.y.log2(),
+            z: self.z.log2(),
+        }
+    }
+
     /// Returns a `Vec3` containing each element of `self` raised to the power of `n`.
     #[inline]
     pub fn powf(self, n: f32) -> Self {
@@ -448,6 +582,28 @@ impl Vec3 {
         abs_diff_eq!(self, other, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> crate::DVec3 {
+        crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec3(self) -> crate::IVec3 {
+        crate::IVec3::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec3(self) -> crate::UVec3 {
+        crate::UVec3::new(self.x as u32, self.y as u32, self.z as u32)
+    }
+
     /// Returns the angle between two vectors, in radians.
     ///
     /// The vectors do not need to be unit length, but this function does
@@ -12,7 +12,8 @@ impl Vec3Mask {
     pub fn new(x: bool, y: bool, z: bool) -> Self {
         // A SSE2 mask can be any bit pattern but for the `Vec3Mask` implementation of select we
         // expect either 0 or 0xff_ff_ff_ff. This should be a safe assumption as this type can only
-        // be created via this function or by `Vec3` methods.
+        // be created via this function or by `Vec3`, `IVec3` or `UVec3`
+        // methods.
         const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
         Self(MASK[x as usize], MASK[y as usize], MASK[z as usize])
     }
@@ -4,6 +4,10 @@ use num_traits::Float;
 use super::{Vec2, Vec3, Vec3AMask, Vec4};
 use core::{fmt, ops::*};
 
+#[cfg(vec3a_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec3a_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec3a_sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec3a_sse2, target_arch = "x86_64"))]
@@ -54,6 +58,21 @@ impl Vec3A {
     /// Calculates the Vec3A dot product and returns answer in x lane of __m128.
     #[inline]
     unsafe fn dot_as_m128(self, other: Self) -> __m128 {
+        #[cfg(vec3a_sse41)]
+        {
+            _mm_dp_ps(self.0, other.0, 0b0111_0001)
+        }
+
+        #[cfg(not(vec3a_sse41))]
+        {
+            self.dot_as_m128_sse2(other)
+        }
+    }
+
+    /// SSE2 version of `dot_as_m128`, kept for testing the SSE4.1 version against.
+    #[inline]
+    #[cfg_attr(vec3a_sse41, allow(dead_code))]
+    pub(crate) unsafe fn dot_as_m128_sse2(self, other: Self) -> __m128 {
         let x2_y2_z2_w2 = _mm_mul_ps(self.0, other.0);
         let y2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_01);
         let z2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_10);
@@ -103,6 +122,74 @@ impl From<__m128> for Vec3A {
     }
 }
 
+#[cfg(vec3a_neon)]
+impl From<Vec3A> for float32x4_t {
+    #[inline]
+    fn from(t: Vec3A) -> Self {
+        // the `w` lane is set to `0.0`
+        let a = [t.0.x, t.0.y, t.0.z, 0.0];
+        unsafe { vld1q_f32(a.as_ptr()) }
+    }
+}
+
+#[cfg(vec3a_neon)]
+impl From<float32x4_t> for Vec3A {
+    #[inline]
+    fn from(t: float32x4_t) -> Self {
+        // the `w` lane is discarded
+        unsafe {
+            Self(Vec3::new(
+                vgetq_lane_f32(t, 0),
+                vgetq_lane_f32(t, 1),
+                vgetq_lane_f32(t, 2),
+            ))
+        }
+    }
+}
+
+#[cfg(vec3a_simd128)]
+impl From<Vec3A> for v128 {
+    #[inline]
+    fn from(t: Vec3A) -> Self {
+        // the `w` lane is set to `0.0`
+        f32x4(t.0.x, t.0.y, t.0.z, 0.0)
+    }
+}
+
+#[cfg(vec3a_simd128)]
+impl From<v128> for Vec3A {
+    #[inline]
+    fn from(t: v128) -> Self {
+        // the `w` lane is discarded
+        Self(Vec3::new(
+            f32x4_extract_lane::<0>(t),
+            f32x4_extract_lane::<1>(t),
+            f32x4_extract_lane::<2>(t),
+        ))
+    }
+}
+
+#[cfg(vec3a_simd128)]
+impl Vec3A {
+    /// Calculates the Vec3A dot product and returns answer in all lanes of v128.
+    #[inline]
+    fn dot_as_v128(self, other: Self) -> v128 {
+        // the `w` lane is zero so a 4 lane horizontal add can be used
+        let x2_y2_z2_0 = f32x4_mul(self.into(), other.into());
+        let z2_0_x2_y2 = i32x4_shuffle::<2, 3, 0, 1>(x2_y2_z2_0, x2_y2_z2_0);
+        let x2z2_y2 = f32x4_add(x2_y2_z2_0, z2_0_x2_y2);
+        let y2_x2z2 = i32x4_shuffle::<1, 0, 3, 2>(x2z2_y2, x2z2_y2);
+        f32x4_add(x2z2_y2, y2_x2z2)
+    }
+}
+
+/// Returns the `[y, z, x, y]` lanes of `v`.
+#[cfg(vec3a_neon)]
+#[inline]
+unsafe fn neon_yzx(v: float32x4_t) -> float32x4_t {
+    vcombine_f32(vget_low_f32(vextq_f32(v, v, 1)), vget_low_f32(v))
+}
+
 /// Creates a `Vec3`.
 #[inline]
 pub fn vec3a(x: f32, y: f32, z: f32) -> Vec3A {
@@ -209,7 +296,17 @@ impl Vec3A {
             _mm_cvtss_f32(self.dot_as_m128(other))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            vaddvq_f32(vmulq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            f32x4_extract_lane::<0>(self.dot_as_v128(other))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.dot(other.0)
         }
@@ -219,7 +316,12 @@ impl Vec3A {
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn dot_as_vec3(self, other: Self) -> Self {
-        #[cfg(vec3a_sse2)]
+        #[cfg(vec3a_sse41)]
+        unsafe {
+            Vec3A(_mm_dp_ps(self.0, other.0, 0b0111_1111))
+        }
+
+        #[cfg(all(vec3a_sse2, not(vec3a_sse41)))]
         unsafe {
             let dot_in_x = self.dot_as_m128(other);
             Vec3A(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
@@ -249,7 +351,27 @@ impl Vec3A {
             Self(_mm_shuffle_ps(sub, sub, 0b01_01_00_10))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            // (self * other.yzx() - self.yzx() * other).yzx()
+            let lhs = self.into();
+            let rhs = other.into();
+            let sub = vsubq_f32(vmulq_f32(lhs, neon_yzx(rhs)), vmulq_f32(neon_yzx(lhs), rhs));
+            Self::from(neon_yzx(sub))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            // (self.zxy() * other - self * other.zxy()).zxy()
+            let lhs = self.into();
+            let rhs = other.into();
+            let lhszxy = i32x4_shuffle::<2, 0, 1, 1>(lhs, lhs);
+            let rhszxy = i32x4_shuffle::<2, 0, 1, 1>(rhs, rhs);
+            let sub = f32x4_sub(f32x4_mul(lhszxy, rhs), f32x4_mul(rhszxy, lhs));
+            Self::from(i32x4_shuffle::<2, 0, 1, 1>(sub, sub))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.cross(other.0))
         }
@@ -321,12 +443,47 @@ impl Vec3A {
             unsafe { Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))) }
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            let v = self.into();
+            let dot = vaddvq_f32(vmulq_f32(v, v));
+            Self::from(vdivq_f32(v, vsqrtq_f32(vdupq_n_f32(dot))))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), f32x4_sqrt(self.dot_as_v128(self))))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.normalize())
         }
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize().unwrap_or_else(Self::zero)
+    }
+
     /// Returns the vertical minimum of `self` and `other`.
     ///
     /// In other words, this computes
@@ -339,7 +496,17 @@ impl Vec3A {
             Self(_mm_min_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vminnmq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_pmin(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.min(other.0))
         }
@@ -357,7 +524,17 @@ impl Vec3A {
             Self(_mm_max_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vmaxnmq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_pmax(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.max(other.0))
         }
@@ -412,7 +589,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmpeq_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vceqq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_eq(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmpeq(other.0))
         }
@@ -429,7 +616,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmpneq_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vmvnq_u32(vceqq_f32(self.into(), other.into())))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_ne(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmpne(other.0))
         }
@@ -446,7 +643,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmpge_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vcgeq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_ge(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmpge(other.0))
         }
@@ -463,7 +670,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmpgt_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vcgtq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_gt(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmpgt(other.0))
         }
@@ -480,7 +697,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmple_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vcleq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_le(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmple(other.0))
         }
@@ -497,7 +724,17 @@ impl Vec3A {
             Vec3AMask(_mm_cmplt_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3AMask::from(vcltq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3AMask::from(f32x4_lt(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.cmplt(other.0))
         }
@@ -530,12 +767,27 @@ impl Vec3A {
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
-        #[cfg(vec3a_sse2)]
+        #[cfg(vec3a_fma)]
+        unsafe {
+            Self(_mm_fmadd_ps(self.0, a.0, b.0))
+        }
+
+        #[cfg(all(vec3a_sse2, not(vec3a_fma)))]
         unsafe {
             Self(_mm_add_ps(_mm_mul_ps(self.0, a.0), b.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vmlaq_f32(b.into(), self.into(), a.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_add(f32x4_mul(self.into(), a.into()), b.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.mul_add(a.0, b.0))
         }
@@ -552,7 +804,17 @@ impl Vec3A {
             ))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vabsq_f32(self.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_abs(self.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.abs())
         }
@@ -568,7 +830,26 @@ impl Vec3A {
             Self(m128_round(self.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vrndaq_f32(self.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            // f32x4_nearest rounds half-way cases to even so round away from zero here
+            let v = self.into();
+            let trunc = f32x4_trunc(v);
+            let sign_one = v128_or(f32x4_splat(1.0), v128_and(v, f32x4_splat(-0.0)));
+            let round_away = f32x4_ge(f32x4_abs(f32x4_sub(v, trunc)), f32x4_splat(0.5));
+            Self::from(v128_bitselect(
+                f32x4_add(trunc, sign_one),
+                trunc,
+                round_away,
+            ))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.round())
         }
@@ -584,7 +865,17 @@ impl Vec3A {
             Self(m128_floor(self.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vrndmq_f32(self.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_floor(self.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.floor())
         }
@@ -600,16 +891,216 @@ impl Vec3A {
             Self(m128_ceil(self.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vrndpq_f32(self.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_ceil(self.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.ceil())
         }
     }
 
+    /// Returns a `Vec3A` containing the square root of each element of `self`.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            Self(_mm_sqrt_ps(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the sine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn sin(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            Self(m128_sin_cos(self.0).0)
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.sin(), self.y.sin(), self.z.sin())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the cosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn cos(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            Self(m128_sin_cos(self.0).1)
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.cos(), self.y.cos(), self.z.cos())
+        }
+    }
+
+    /// Returns a tuple of `Vec3A`s containing the sine and the cosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            let (sin, cos) = m128_sin_cos(self.0);
+            (Self(sin), Self(cos))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            let (sin_x, cos_x) = self.x.sin_cos();
+            let (sin_y, cos_y) = self.y.sin_cos();
+            let (sin_z, cos_z) = self.z.sin_cos();
+            (
+                Self::new(sin_x, sin_y, sin_z),
+                Self::new(cos_x, cos_y, cos_z),
+            )
+        }
+    }
+
+    /// Returns a `Vec3A` containing the tangent of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 4 ULP is used.
+    #[inline]
+    pub fn tan(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_tan;
+            Self(m128_tan(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.tan(), self.y.tan(), self.z.tan())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the arcsine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 3 ULP is used.
+    #[inline]
+    pub fn asin(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_asin;
+            Self(m128_asin(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.asin(), self.y.asin(), self.z.asin())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the arccosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn acos(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_acos;
+            Self(m128_acos(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.acos(), self.y.acos(), self.z.acos())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the four quadrant arctangent of each element of `self` and
+    /// `other`, with `self` holding the `y` and `other` the `x` coordinates.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 4 ULP is used.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_atan2;
+            Self(m128_atan2(self.0, other.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(
+                self.x.atan2(other.x),
+                self.y.atan2(other.y),
+                self.z.atan2(other.z),
+            )
+        }
+    }
+
     /// Returns a `Vec3A` containing `e^self` (the exponential function) for each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
     #[inline]
     pub fn exp(self) -> Self {
-        Self::new(self.x.exp(), self.y.exp(), self.z.exp())
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_exp;
+            Self(m128_exp(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.exp(), self.y.exp(), self.z.exp())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the natural logarithm of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 1 ULP is used.
+    #[inline]
+    pub fn ln(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_ln;
+            Self(m128_ln(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.ln(), self.y.ln(), self.z.ln())
+        }
+    }
+
+    /// Returns a `Vec3A` containing the base 2 logarithm of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn log2(self) -> Self {
+        #[cfg(vec3a_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_log2;
+            Self(m128_log2(self.0))
+        }
+
+        #[cfg(vec3a_f32)]
+        {
+            Self::new(self.x.log2(), self.y.log2(), self.z.log2())
+        }
     }
 
     /// Returns a `Vec3A` containing each element of `self` raised to the power of `n`.
@@ -628,7 +1119,19 @@ impl Vec3A {
             Vec3AMask(_mm_cmpunord_ps(self.0, self.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            let v = self.into();
+            Vec3AMask::from(vmvnq_u32(vceqq_f32(v, v)))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            let v = self.into();
+            Vec3AMask::from(f32x4_ne(v, v))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3AMask(self.0.is_nan_mask())
         }
@@ -715,6 +1218,28 @@ impl Vec3A {
         abs_diff_eq!(self, other, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> crate::DVec3 {
+        crate::DVec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec3(self) -> crate::IVec3 {
+        crate::IVec3::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec3(self) -> crate::UVec3 {
+        crate::UVec3::new(self.x as u32, self.y as u32, self.z as u32)
+    }
+
     /// Returns the angle between two vectors, in radians.
     ///
     /// The vectors do not need to be unit length, but this function does
@@ -774,7 +1299,17 @@ impl Div<Vec3A> for Vec3A {
             Self(_mm_div_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vdivq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.div(other.0))
         }
@@ -789,7 +1324,17 @@ impl DivAssign<Vec3A> for Vec3A {
             self.0 = unsafe { _mm_div_ps(self.0, other.0) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.div_assign(other.0);
         }
@@ -805,7 +1350,17 @@ impl Div<f32> for Vec3A {
             Self(_mm_div_ps(self.0, _mm_set1_ps(other)))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vdivq_f32(self.into(), vdupq_n_f32(other)))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), f32x4_splat(other)))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.div(other))
         }
@@ -820,7 +1375,17 @@ impl DivAssign<f32> for Vec3A {
             self.0 = unsafe { _mm_div_ps(self.0, _mm_set1_ps(other)) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.div_assign(other)
         }
@@ -836,7 +1401,17 @@ impl Div<Vec3A> for f32 {
             Vec3A(_mm_div_ps(_mm_set1_ps(self), other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3A::from(vdivq_f32(vdupq_n_f32(self), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3A::from(f32x4_div(f32x4_splat(self), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3A(self.div(other.0))
         }
@@ -852,7 +1427,17 @@ impl Mul<Vec3A> for Vec3A {
             Self(_mm_mul_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vmulq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_mul(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.mul(other.0))
         }
@@ -867,7 +1452,17 @@ impl MulAssign<Vec3A> for Vec3A {
             self.0 = unsafe { _mm_mul_ps(self.0, other.0) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.mul_assign(other.0);
         }
@@ -883,7 +1478,17 @@ impl Mul<f32> for Vec3A {
             Self(_mm_mul_ps(self.0, _mm_set1_ps(other)))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vmulq_f32(self.into(), vdupq_n_f32(other)))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_mul(self.into(), f32x4_splat(other)))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.mul(other))
         }
@@ -898,7 +1503,17 @@ impl MulAssign<f32> for Vec3A {
             self.0 = unsafe { _mm_mul_ps(self.0, _mm_set1_ps(other)) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.mul_assign(other);
         }
@@ -914,7 +1529,17 @@ impl Mul<Vec3A> for f32 {
             Vec3A(_mm_mul_ps(_mm_set1_ps(self), other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Vec3A::from(vmulq_f32(vdupq_n_f32(self), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Vec3A::from(f32x4_mul(f32x4_splat(self), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Vec3A(self.mul(other.0))
         }
@@ -930,7 +1555,17 @@ impl Add for Vec3A {
             Self(_mm_add_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vaddq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_add(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.add(other.0))
         }
@@ -945,7 +1580,17 @@ impl AddAssign for Vec3A {
             self.0 = unsafe { _mm_add_ps(self.0, other.0) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self + other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self + other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.add_assign(other.0);
         }
@@ -961,7 +1606,17 @@ impl Sub for Vec3A {
             Self(_mm_sub_ps(self.0, other.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vsubq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_sub(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.sub(other.0))
         }
@@ -976,7 +1631,17 @@ impl SubAssign for Vec3A {
             self.0 = unsafe { _mm_sub_ps(self.0, other.0) };
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        {
+            *self = *self - other;
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            *self = *self - other;
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             self.0.sub_assign(other.0);
         }
@@ -992,7 +1657,17 @@ impl Neg for Vec3A {
             Self(_mm_sub_ps(ZERO.0, self.0))
         }
 
-        #[cfg(vec3a_f32)]
+        #[cfg(vec3a_neon)]
+        unsafe {
+            Self::from(vnegq_f32(self.into()))
+        }
+
+        #[cfg(vec3a_simd128)]
+        {
+            Self::from(f32x4_neg(self.into()))
+        }
+
+        #[cfg(all(vec3a_f32, not(any(vec3a_neon, vec3a_simd128))))]
         {
             Self(self.0.neg())
         }
@@ -1138,3 +1813,20 @@ fn test_vec3_private() {
         vec3a(-0.5, 1.0, -5.0)
     );
 }
+
+#[test]
+#[cfg(vec3a_sse2)]
+fn test_vec3a_sse2_dot() {
+    // `dot_as_m128` is replaced by `_mm_dp_ps` when SSE4.1 is enabled, which may sum the lanes
+    // in a different order
+    let values = [
+        -8388609.0, -3.7, -2.5, -1.3, -0.5, 0.0, 0.4, 1.5, 2.6, 8.2, 1.0e6,
+    ];
+    for v in values.windows(3) {
+        let a = vec3a(v[0], v[1], v[2]);
+        let b = vec3a(v[2], -v[0], v[1]);
+        let expected = unsafe { Vec3A(a.dot_as_m128_sse2(b)).x };
+        assert!((expected - a.dot(b)).abs() <= expected.abs() * 1.0e-6);
+        assert_eq!(Vec3A::splat(a.dot(b)), a.dot_as_vec3(b));
+    }
+}
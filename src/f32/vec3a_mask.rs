@@ -3,6 +3,10 @@ use super::Vec3A;
 use super::Vec3Mask;
 use core::{fmt, ops::*};
 
+#[cfg(vec3a_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec3a_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec3a_sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec3a_sse2, target_arch = "x86_64"))]
@@ -294,6 +298,34 @@ impl From<Vec3AMask> for __m128 {
     }
 }
 
+#[cfg(vec3a_neon)]
+impl From<uint32x4_t> for Vec3AMask {
+    #[inline]
+    fn from(t: uint32x4_t) -> Self {
+        // the `w` lane is discarded
+        unsafe {
+            Self(Vec3Mask(
+                vgetq_lane_u32(t, 0),
+                vgetq_lane_u32(t, 1),
+                vgetq_lane_u32(t, 2),
+            ))
+        }
+    }
+}
+
+#[cfg(vec3a_simd128)]
+impl From<v128> for Vec3AMask {
+    #[inline]
+    fn from(t: v128) -> Self {
+        // the `w` lane is discarded
+        Self(Vec3Mask(
+            u32x4_extract_lane::<0>(t),
+            u32x4_extract_lane::<1>(t),
+            u32x4_extract_lane::<2>(t),
+        ))
+    }
+}
+
 impl AsRef<[u32; 3]> for Vec3AMask {
     #[inline]
     fn as_ref(&self) -> &[u32; 3] {
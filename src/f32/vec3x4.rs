@@ -0,0 +1,533 @@
+use super::{Vec3, Vec3A, Vec3ASwizzles, Vec4, Vec4Mask, Vec4x4};
+use core::{fmt, ops::*};
+
+/// Four 3-dimensional vectors stored in structure of arrays layout.
+///
+/// Each component holds the value of that component for all four vectors, so one SIMD operation
+/// processes four vectors at once. Lane `i` of `x`, `y` and `z` makes up vector `i`. This makes
+/// better use of SIMD than `Vec3A` when the same operation is applied to many vectors.
+///
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(C)]
+pub struct Vec3x4 {
+    pub x: Vec4,
+    pub y: Vec4,
+    pub z: Vec4,
+}
+
+impl fmt::Display for Vec3x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d] = self.to_array();
+        write!(f, "[{}, {}, {}, {}]", a, b, c, d)
+    }
+}
+
+impl Vec3x4 {
+    /// Creates a `Vec3x4` from the given components.
+    #[inline]
+    pub fn new(x: Vec4, y: Vec4, z: Vec4) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a `Vec3x4` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vec3::zero())
+    }
+
+    /// Creates a `Vec3x4` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(Vec3::one())
+    }
+
+    /// Creates a `Vec3x4` with all four vectors set to `v`.
+    #[inline]
+    pub fn splat(v: Vec3) -> Self {
+        Self {
+            x: Vec4::splat(v.x),
+            y: Vec4::splat(v.y),
+            z: Vec4::splat(v.z),
+        }
+    }
+
+    /// Creates a `Vec3x4` from four vectors.
+    #[inline]
+    pub fn from_array(a: [Vec3; 4]) -> Self {
+        Self {
+            x: Vec4::new(a[0].x, a[1].x, a[2].x, a[3].x),
+            y: Vec4::new(a[0].y, a[1].y, a[2].y, a[3].y),
+            z: Vec4::new(a[0].z, a[1].z, a[2].z, a[3].z),
+        }
+    }
+
+    /// Returns the four vectors stored in `self`.
+    #[inline]
+    pub fn to_array(self) -> [Vec3; 4] {
+        let [a, b, c, d] = self.to_array_vec3a();
+        [a.into(), b.into(), c.into(), d.into()]
+    }
+
+    /// Creates a `Vec3x4` from four `Vec3A` vectors.
+    #[inline]
+    pub fn from_array_vec3a(a: [Vec3A; 4]) -> Self {
+        // the swizzles fill the unused lane so the vectors can be transposed as a 4x4 matrix
+        Vec4x4::from_array([a[0].xyzz(), a[1].xyzz(), a[2].xyzz(), a[3].xyzz()]).truncate()
+    }
+
+    /// Returns the four vectors stored in `self` as `Vec3A`.
+    #[inline]
+    pub fn to_array_vec3a(self) -> [Vec3A; 4] {
+        let [a, b, c, d] = self.extend(Vec4::zero()).to_array();
+        [a.into(), b.into(), c.into(), d.into()]
+    }
+
+    /// Loads the first four vectors of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        Self::from_array([slice[0], slice[1], slice[2], slice[3]])
+    }
+
+    /// Stores the four vectors of `self` in the first four elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        slice[..4].copy_from_slice(&self.to_array());
+    }
+
+    /// Loads the first four vectors of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_slice_vec3a(slice: &[Vec3A]) -> Self {
+        Self::from_array_vec3a([slice[0], slice[1], slice[2], slice[3]])
+    }
+
+    /// Stores the four vectors of `self` in the first four elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice_vec3a(self, slice: &mut [Vec3A]) {
+        slice[..4].copy_from_slice(&self.to_array_vec3a());
+    }
+
+    /// Loads the elements of `slice` at the given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn gather(slice: &[Vec3], indices: [usize; 4]) -> Self {
+        Self::from_array([
+            slice[indices[0]],
+            slice[indices[1]],
+            slice[indices[2]],
+            slice[indices[3]],
+        ])
+    }
+
+    /// Stores the four vectors of `self` in the elements of `slice` at the given `indices`.
+    ///
+    /// If an index is repeated the later vector is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn scatter(self, slice: &mut [Vec3], indices: [usize; 4]) {
+        let a = self.to_array();
+        slice[indices[0]] = a[0];
+        slice[indices[1]] = a[1];
+        slice[indices[2]] = a[2];
+        slice[indices[3]] = a[3];
+    }
+
+    /// Loads the elements of `slice` at the given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn gather_vec3a(slice: &[Vec3A], indices: [usize; 4]) -> Self {
+        Self::from_array_vec3a([
+            slice[indices[0]],
+            slice[indices[1]],
+            slice[indices[2]],
+            slice[indices[3]],
+        ])
+    }
+
+    /// Stores the four vectors of `self` in the elements of `slice` at the given `indices`.
+    ///
+    /// If an index is repeated the later vector is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn scatter_vec3a(self, slice: &mut [Vec3A], indices: [usize; 4]) {
+        let a = self.to_array_vec3a();
+        slice[indices[0]] = a[0];
+        slice[indices[1]] = a[1];
+        slice[indices[2]] = a[2];
+        slice[indices[3]] = a[3];
+    }
+
+    /// Creates a `Vec4x4` from `self` and the given `w` components.
+    #[inline]
+    pub fn extend(self, w: Vec4) -> Vec4x4 {
+        Vec4x4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Returns the dot product of each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> Vec4 {
+        let d = self.x * other.x;
+        let d = self.y.mul_add(other.y, d);
+        self.z.mul_add(other.z, d)
+    }
+
+    /// Returns the cross product of each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - other.y * self.z,
+            y: self.z * other.x - other.z * self.x,
+            z: self.x * other.y - other.x * self.y,
+        }
+    }
+
+    /// Returns the length of each vector in `self`.
+    #[inline]
+    pub fn length(self) -> Vec4 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the squared length of each vector in `self`.
+    #[inline]
+    pub fn length_squared(self) -> Vec4 {
+        self.dot(self)
+    }
+
+    /// Returns `1.0 / length()` of each vector in `self`.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> Vec4 {
+        self.length().recip()
+    }
+
+    /// Returns the Euclidean distance between each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn distance(self, other: Self) -> Vec4 {
+        (self - other).length()
+    }
+
+    /// Returns the squared Euclidean distance between each pair of vectors in `self` and
+    /// `other`.
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> Vec4 {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0 if possible, else zero.
+    ///
+    /// In particular, vectors with a length of zero, very close to zero or not finite are set to
+    /// zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.length_recip();
+        // the comparisons are false for NaN lanes
+        let valid = rcp.cmplt(Vec4::splat(core::f32::INFINITY)) & rcp.cmpgt(Vec4::zero());
+        Self::select(valid, self * rcp, Self::zero())
+    }
+
+    /// Returns the element-wise minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the element-wise maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Returns the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Performs a linear interpolation between each pair of vectors in `self` and `other` using
+    /// the lanes of `s`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: Vec4) -> Self {
+        self + (other - self) * s
+    }
+
+    /// Creates a `Vec3x4` from the vectors of `if_true` where the lanes of `mask` are set and the
+    /// vectors of `if_false` elsewhere.
+    #[inline]
+    pub fn select(mask: Vec4Mask, if_true: Self, if_false: Self) -> Self {
+        Self {
+            x: mask.select(if_true.x, if_false.x),
+            y: mask.select(if_true.y, if_false.y),
+            z: mask.select(if_true.z, if_false.z),
+        }
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` and `other` are equal.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec4Mask {
+        self.x.cmpeq(other.x) & self.y.cmpeq(other.y) & self.z.cmpeq(other.z)
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` are length `1.0`.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized_mask(self) -> Vec4Mask {
+        (self.length_squared() - Vec4::one())
+            .abs()
+            .cmple(Vec4::splat(1e-6))
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.x.abs_diff_eq(other.x, max_abs_diff)
+            && self.y.abs_diff_eq(other.y, max_abs_diff)
+            && self.z.abs_diff_eq(other.z, max_abs_diff)
+    }
+}
+
+impl From<[Vec3; 4]> for Vec3x4 {
+    #[inline]
+    fn from(a: [Vec3; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec3x4> for [Vec3; 4] {
+    #[inline]
+    fn from(v: Vec3x4) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<[Vec3A; 4]> for Vec3x4 {
+    #[inline]
+    fn from(a: [Vec3A; 4]) -> Self {
+        Self::from_array_vec3a(a)
+    }
+}
+
+impl From<Vec3x4> for [Vec3A; 4] {
+    #[inline]
+    fn from(v: Vec3x4) -> Self {
+        v.to_array_vec3a()
+    }
+}
+
+impl Add for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign for Vec3x4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign for Vec3x4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Vec3x4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl MulAssign<Vec3x4> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Vec4) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl MulAssign<Vec4> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Vec4) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self * Vec4::splat(other)
+    }
+}
+
+impl MulAssign<f32> for Vec3x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec3x4> for f32 {
+    type Output = Vec3x4;
+    #[inline]
+    fn mul(self, other: Vec3x4) -> Vec3x4 {
+        other * self
+    }
+}
+
+impl Div<Vec3x4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl DivAssign<Vec3x4> for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Div<Vec4> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Vec4) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<Vec4> for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, other: Vec4) {
+        *self = *self / other;
+    }
+}
+
+impl Div<f32> for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<f32> for Vec3x4 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for Vec3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
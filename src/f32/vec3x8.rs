@@ -0,0 +1,966 @@
+use super::{Vec3, Vec3A, Vec3x4, Vec4, Vec4Mask};
+#[cfg(all(vec3x8_avx, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec3x8_avx, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+use core::{fmt, ops::*};
+
+/// Eight `f32` lanes, a 256 bit register with AVX and a pair of `Vec4` otherwise.
+#[cfg(vec3x8_avx)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub(crate) struct F32x8(__m256);
+
+#[cfg(not(vec3x8_avx))]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct F32x8(Vec4, Vec4);
+
+/// A mask for each of the eight lanes of a `F32x8`.
+#[cfg(vec3x8_avx)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub(crate) struct M32x8(__m256);
+
+#[cfg(not(vec3x8_avx))]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct M32x8(Vec4Mask, Vec4Mask);
+
+impl F32x8 {
+    #[inline]
+    pub(crate) fn splat(v: f32) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_set1_ps(v))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(Vec4::splat(v), Vec4::splat(v))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_halves(a: [Vec4; 2]) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_insertf128_ps(
+                _mm256_castps128_ps256(a[0].0),
+                a[1].0,
+                1,
+            ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(a[0], a[1])
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_halves(self) -> [Vec4; 2] {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            [
+                Vec4(_mm256_castps256_ps128(self.0)),
+                Vec4(_mm256_extractf128_ps(self.0, 1)),
+            ]
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            [self.0, self.1]
+        }
+    }
+
+    /// Per lane multiplication/addition of the three inputs: b + (self * a)
+    #[inline]
+    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
+        #[cfg(all(vec3x8_avx, vec4_fma))]
+        unsafe {
+            Self(_mm256_fmadd_ps(self.0, a.0, b.0))
+        }
+
+        #[cfg(all(vec3x8_avx, not(vec4_fma)))]
+        unsafe {
+            Self(_mm256_add_ps(_mm256_mul_ps(self.0, a.0), b.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0.mul_add(a.0, b.0), self.1.mul_add(a.1, b.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sqrt(self) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_sqrt_ps(self.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0.sqrt(), self.1.sqrt())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn recip(self) -> Self {
+        Self::splat(1.0) / self
+    }
+
+    #[inline]
+    pub(crate) fn min(self, other: Self) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_min_ps(self.0, other.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0.min(other.0), self.1.min(other.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn max(self, other: Self) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_max_ps(self.0, other.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0.max(other.0), self.1.max(other.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn abs(self) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_and_ps(
+                self.0,
+                _mm256_castsi256_ps(_mm256_set1_epi32(0x7f_ff_ff_ff)),
+            ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0.abs(), self.1.abs())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn cmpeq(self, other: Self) -> M32x8 {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            M32x8(_mm256_cmp_ps(self.0, other.0, _CMP_EQ_OQ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            M32x8(self.0.cmpeq(other.0), self.1.cmpeq(other.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn cmplt(self, other: Self) -> M32x8 {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            M32x8(_mm256_cmp_ps(self.0, other.0, _CMP_LT_OQ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            M32x8(self.0.cmplt(other.0), self.1.cmplt(other.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn cmple(self, other: Self) -> M32x8 {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            M32x8(_mm256_cmp_ps(self.0, other.0, _CMP_LE_OQ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            M32x8(self.0.cmple(other.0), self.1.cmple(other.1))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn cmpgt(self, other: Self) -> M32x8 {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            M32x8(_mm256_cmp_ps(self.0, other.0, _CMP_GT_OQ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            M32x8(self.0.cmpgt(other.0), self.1.cmpgt(other.1))
+        }
+    }
+}
+
+macro_rules! impl_f32x8_binop {
+    ($trait:ident, $func:ident, $avx:ident) => {
+        impl $trait for F32x8 {
+            type Output = Self;
+            #[inline]
+            fn $func(self, other: Self) -> Self {
+                #[cfg(vec3x8_avx)]
+                unsafe {
+                    Self($avx(self.0, other.0))
+                }
+
+                #[cfg(not(vec3x8_avx))]
+                {
+                    Self(self.0.$func(other.0), self.1.$func(other.1))
+                }
+            }
+        }
+    };
+}
+
+impl_f32x8_binop!(Add, add, _mm256_add_ps);
+impl_f32x8_binop!(Sub, sub, _mm256_sub_ps);
+impl_f32x8_binop!(Mul, mul, _mm256_mul_ps);
+impl_f32x8_binop!(Div, div, _mm256_div_ps);
+
+impl Neg for F32x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        // subtracted from zero like `Vec4` so zero lanes get the same sign
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_sub_ps(_mm256_setzero_ps(), self.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(-self.0, -self.1)
+        }
+    }
+}
+
+impl M32x8 {
+    #[inline]
+    pub(crate) fn from_halves(a: [Vec4Mask; 2]) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_insertf128_ps(
+                _mm256_castps128_ps256(a[0].0),
+                a[1].0,
+                1,
+            ))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(a[0], a[1])
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_halves(self) -> [Vec4Mask; 2] {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            [
+                Vec4Mask(_mm256_castps256_ps128(self.0)),
+                Vec4Mask(_mm256_extractf128_ps(self.0, 1)),
+            ]
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            [self.0, self.1]
+        }
+    }
+
+    #[inline]
+    pub(crate) fn all(self) -> bool {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            _mm256_movemask_ps(self.0) == 0xff
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            self.0.all() && self.1.all()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn select(self, if_true: F32x8, if_false: F32x8) -> F32x8 {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            F32x8(_mm256_blendv_ps(if_false.0, if_true.0, self.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            F32x8(
+                self.0.select(if_true.0, if_false.0),
+                self.1.select(if_true.1, if_false.1),
+            )
+        }
+    }
+}
+
+impl BitAnd for M32x8 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        #[cfg(vec3x8_avx)]
+        unsafe {
+            Self(_mm256_and_ps(self.0, other.0))
+        }
+
+        #[cfg(not(vec3x8_avx))]
+        {
+            Self(self.0 & other.0, self.1 & other.1)
+        }
+    }
+}
+
+/// Eight 3-dimensional vectors stored in structure of arrays layout.
+///
+/// This is the eight lane version of `Vec3x4`. With AVX each component is a 256 bit register,
+/// otherwise it is stored as two `Vec4` and each operation is performed on both halves. Lanes
+/// `0..4` make up the first `Vec3x4` half and lanes `4..8` the second.
+///
+/// Per lane values such as the result of `dot` are returned as the two `Vec4` halves, and masks
+/// as the two `Vec4Mask` halves.
+///
+/// This type is 32 byte aligned with AVX, otherwise it has the alignment of `Vec4`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Vec3x8 {
+    pub(crate) x: F32x8,
+    pub(crate) y: F32x8,
+    pub(crate) z: F32x8,
+}
+
+impl Default for Vec3x8 {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Vec3x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmpeq_lanes(*other).all()
+    }
+}
+
+impl fmt::Debug for Vec3x8 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let [lo, hi] = self.to_halves();
+        fmt.debug_tuple("Vec3x8").field(&lo).field(&hi).finish()
+    }
+}
+
+impl fmt::Display for Vec3x8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g, h, i] = self.to_array();
+        write!(
+            f,
+            "[{}, {}, {}, {}, {}, {}, {}, {}]",
+            a, b, c, d, e, g, h, i
+        )
+    }
+}
+
+impl Vec3x8 {
+    /// Creates a `Vec3x8` from the two halves of each component.
+    #[inline]
+    pub fn new(x: [Vec4; 2], y: [Vec4; 2], z: [Vec4; 2]) -> Self {
+        Self {
+            x: F32x8::from_halves(x),
+            y: F32x8::from_halves(y),
+            z: F32x8::from_halves(z),
+        }
+    }
+
+    /// Creates a `Vec3x8` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vec3::zero())
+    }
+
+    /// Creates a `Vec3x8` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(Vec3::one())
+    }
+
+    /// Creates a `Vec3x8` with all eight vectors set to `v`.
+    #[inline]
+    pub fn splat(v: Vec3) -> Self {
+        Self {
+            x: F32x8::splat(v.x),
+            y: F32x8::splat(v.y),
+            z: F32x8::splat(v.z),
+        }
+    }
+
+    /// Creates a `Vec3x8` from the vectors in lanes `0..4` and `4..8`.
+    #[inline]
+    pub fn from_halves(a: [Vec3x4; 2]) -> Self {
+        Self::new([a[0].x, a[1].x], [a[0].y, a[1].y], [a[0].z, a[1].z])
+    }
+
+    /// Returns the vectors in lanes `0..4` and `4..8` of `self`.
+    #[inline]
+    pub fn to_halves(self) -> [Vec3x4; 2] {
+        let [x0, x1] = self.x.to_halves();
+        let [y0, y1] = self.y.to_halves();
+        let [z0, z1] = self.z.to_halves();
+        [Vec3x4::new(x0, y0, z0), Vec3x4::new(x1, y1, z1)]
+    }
+
+    /// Creates a `Vec3x8` from eight vectors.
+    #[inline]
+    pub fn from_array(a: [Vec3; 8]) -> Self {
+        Self::from_slice(&a)
+    }
+
+    /// Returns the eight vectors stored in `self`.
+    #[inline]
+    pub fn to_array(self) -> [Vec3; 8] {
+        let mut a = [Vec3::zero(); 8];
+        self.write_to_slice(&mut a);
+        a
+    }
+
+    /// Creates a `Vec3x8` from eight `Vec3A` vectors.
+    #[inline]
+    pub fn from_array_vec3a(a: [Vec3A; 8]) -> Self {
+        Self::from_slice_vec3a(&a)
+    }
+
+    /// Returns the eight vectors stored in `self` as `Vec3A`.
+    #[inline]
+    pub fn to_array_vec3a(self) -> [Vec3A; 8] {
+        let mut a = [Vec3A::zero(); 8];
+        self.write_to_slice_vec3a(&mut a);
+        a
+    }
+
+    /// Loads the first eight vectors of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Vec3]) -> Self {
+        let slice = &slice[..8];
+        Self::from_halves([
+            Vec3x4::from_slice(&slice[..4]),
+            Vec3x4::from_slice(&slice[4..]),
+        ])
+    }
+
+    /// Stores the eight vectors of `self` in the first eight elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec3]) {
+        let slice = &mut slice[..8];
+        let [lo, hi] = self.to_halves();
+        lo.write_to_slice(&mut slice[..4]);
+        hi.write_to_slice(&mut slice[4..]);
+    }
+
+    /// Loads the first eight vectors of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    pub fn from_slice_vec3a(slice: &[Vec3A]) -> Self {
+        let slice = &slice[..8];
+        Self::from_halves([
+            Vec3x4::from_slice_vec3a(&slice[..4]),
+            Vec3x4::from_slice_vec3a(&slice[4..]),
+        ])
+    }
+
+    /// Stores the eight vectors of `self` in the first eight elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 8 elements long.
+    #[inline]
+    pub fn write_to_slice_vec3a(self, slice: &mut [Vec3A]) {
+        let slice = &mut slice[..8];
+        let [lo, hi] = self.to_halves();
+        lo.write_to_slice_vec3a(&mut slice[..4]);
+        hi.write_to_slice_vec3a(&mut slice[4..]);
+    }
+
+    /// Loads the elements of `slice` at the given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn gather(slice: &[Vec3], indices: [usize; 8]) -> Self {
+        let [a, b, c, d, e, f, g, h] = indices;
+        Self::from_halves([
+            Vec3x4::gather(slice, [a, b, c, d]),
+            Vec3x4::gather(slice, [e, f, g, h]),
+        ])
+    }
+
+    /// Stores the eight vectors of `self` in the elements of `slice` at the given `indices`.
+    ///
+    /// If an index is repeated the later vector is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn scatter(self, slice: &mut [Vec3], indices: [usize; 8]) {
+        let [a, b, c, d, e, f, g, h] = indices;
+        let [lo, hi] = self.to_halves();
+        lo.scatter(slice, [a, b, c, d]);
+        hi.scatter(slice, [e, f, g, h]);
+    }
+
+    /// Loads the elements of `slice` at the given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn gather_vec3a(slice: &[Vec3A], indices: [usize; 8]) -> Self {
+        let [a, b, c, d, e, f, g, h] = indices;
+        Self::from_halves([
+            Vec3x4::gather_vec3a(slice, [a, b, c, d]),
+            Vec3x4::gather_vec3a(slice, [e, f, g, h]),
+        ])
+    }
+
+    /// Stores the eight vectors of `self` in the elements of `slice` at the given `indices`.
+    ///
+    /// If an index is repeated the later vector is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn scatter_vec3a(self, slice: &mut [Vec3A], indices: [usize; 8]) {
+        let [a, b, c, d, e, f, g, h] = indices;
+        let [lo, hi] = self.to_halves();
+        lo.scatter_vec3a(slice, [a, b, c, d]);
+        hi.scatter_vec3a(slice, [e, f, g, h]);
+    }
+
+    /// Returns the `x` components of the eight vectors as two halves.
+    #[inline]
+    pub fn x(self) -> [Vec4; 2] {
+        self.x.to_halves()
+    }
+
+    /// Returns the `y` components of the eight vectors as two halves.
+    #[inline]
+    pub fn y(self) -> [Vec4; 2] {
+        self.y.to_halves()
+    }
+
+    /// Returns the `z` components of the eight vectors as two halves.
+    #[inline]
+    pub fn z(self) -> [Vec4; 2] {
+        self.z.to_halves()
+    }
+
+    #[inline]
+    pub(crate) fn mul_lanes(self, other: F32x8) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn dot_lanes(self, other: Self) -> F32x8 {
+        let d = self.x * other.x;
+        let d = self.y.mul_add(other.y, d);
+        self.z.mul_add(other.z, d)
+    }
+
+    #[inline]
+    fn cmpeq_lanes(self, other: Self) -> M32x8 {
+        self.x.cmpeq(other.x) & self.y.cmpeq(other.y) & self.z.cmpeq(other.z)
+    }
+
+    #[inline]
+    fn select_lanes(mask: M32x8, if_true: Self, if_false: Self) -> Self {
+        Self {
+            x: mask.select(if_true.x, if_false.x),
+            y: mask.select(if_true.y, if_false.y),
+            z: mask.select(if_true.z, if_false.z),
+        }
+    }
+
+    /// Returns the dot product of each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> [Vec4; 2] {
+        self.dot_lanes(other).to_halves()
+    }
+
+    /// Returns the cross product of each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - other.y * self.z,
+            y: self.z * other.x - other.z * self.x,
+            z: self.x * other.y - other.x * self.y,
+        }
+    }
+
+    /// Returns the length of each vector in `self`.
+    #[inline]
+    pub fn length(self) -> [Vec4; 2] {
+        self.dot_lanes(self).sqrt().to_halves()
+    }
+
+    /// Returns the squared length of each vector in `self`.
+    #[inline]
+    pub fn length_squared(self) -> [Vec4; 2] {
+        self.dot(self)
+    }
+
+    /// Returns `1.0 / length()` of each vector in `self`.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> [Vec4; 2] {
+        self.dot_lanes(self).sqrt().recip().to_halves()
+    }
+
+    /// Returns the Euclidean distance between each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn distance(self, other: Self) -> [Vec4; 2] {
+        (self - other).length()
+    }
+
+    /// Returns the squared Euclidean distance between each pair of vectors in `self` and
+    /// `other`.
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> [Vec4; 2] {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self.mul_lanes(self.dot_lanes(self).sqrt().recip())
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0 if possible, else zero.
+    ///
+    /// In particular, vectors with a length of zero, very close to zero or not finite are set to
+    /// zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.dot_lanes(self).sqrt().recip();
+        // the comparisons are false for NaN lanes
+        let valid = rcp.cmplt(F32x8::splat(core::f32::INFINITY)) & rcp.cmpgt(F32x8::splat(0.0));
+        Self::select_lanes(valid, self.mul_lanes(rcp), Self::zero())
+    }
+
+    /// Returns the element-wise minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the element-wise maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Returns the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Performs a linear interpolation between each pair of vectors in `self` and `other` using
+    /// the lanes of `s`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: [Vec4; 2]) -> Self {
+        self + (other - self) * s
+    }
+
+    /// Creates a `Vec3x8` from the vectors of `if_true` where the lanes of `mask` are set and the
+    /// vectors of `if_false` elsewhere.
+    #[inline]
+    pub fn select(mask: [Vec4Mask; 2], if_true: Self, if_false: Self) -> Self {
+        Self::select_lanes(M32x8::from_halves(mask), if_true, if_false)
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` and `other` are equal.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> [Vec4Mask; 2] {
+        self.cmpeq_lanes(other).to_halves()
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` are length `1.0`.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized_mask(self) -> [Vec4Mask; 2] {
+        (self.dot_lanes(self) - F32x8::splat(1.0))
+            .abs()
+            .cmple(F32x8::splat(1e-6))
+            .to_halves()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let max_abs_diff = F32x8::splat(max_abs_diff);
+        let d = self - other;
+        (d.x.abs().cmple(max_abs_diff)
+            & d.y.abs().cmple(max_abs_diff)
+            & d.z.abs().cmple(max_abs_diff))
+        .all()
+    }
+}
+
+impl From<[Vec3; 8]> for Vec3x8 {
+    #[inline]
+    fn from(a: [Vec3; 8]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec3x8> for [Vec3; 8] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<[Vec3A; 8]> for Vec3x8 {
+    #[inline]
+    fn from(a: [Vec3A; 8]) -> Self {
+        Self::from_array_vec3a(a)
+    }
+}
+
+impl From<Vec3x8> for [Vec3A; 8] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        v.to_array_vec3a()
+    }
+}
+
+impl From<[Vec3x4; 2]> for Vec3x8 {
+    #[inline]
+    fn from(a: [Vec3x4; 2]) -> Self {
+        Self::from_halves(a)
+    }
+}
+
+impl From<Vec3x8> for [Vec3x4; 2] {
+    #[inline]
+    fn from(v: Vec3x8) -> Self {
+        v.to_halves()
+    }
+}
+
+impl Add for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign for Vec3x8 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign for Vec3x8 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Vec3x8> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl MulAssign<Vec3x8> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<[Vec4; 2]> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: [Vec4; 2]) -> Self {
+        self.mul_lanes(F32x8::from_halves(other))
+    }
+}
+
+impl MulAssign<[Vec4; 2]> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, other: [Vec4; 2]) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self.mul_lanes(F32x8::splat(other))
+    }
+}
+
+impl MulAssign<f32> for Vec3x8 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec3x8> for f32 {
+    type Output = Vec3x8;
+    #[inline]
+    fn mul(self, other: Vec3x8) -> Vec3x8 {
+        other * self
+    }
+}
+
+impl Div<Vec3x8> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl DivAssign<Vec3x8> for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Div<[Vec4; 2]> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: [Vec4; 2]) -> Self {
+        let other = F32x8::from_halves(other);
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<[Vec4; 2]> for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, other: [Vec4; 2]) {
+        *self = *self / other;
+    }
+}
+
+impl Div<f32> for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        let other = F32x8::splat(other);
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<f32> for Vec3x8 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for Vec3x8 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
@@ -4,6 +4,10 @@ use num_traits::Float;
 use super::{Vec2, Vec3, Vec3A, Vec4Mask};
 use core::{fmt, ops::*};
 
+#[cfg(vec4_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec4_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec4_sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec4_sse2, target_arch = "x86_64"))]
@@ -88,6 +92,45 @@ impl From<__m128> for Vec4 {
     }
 }
 
+#[cfg(vec4_neon)]
+impl From<Vec4> for float32x4_t {
+    #[inline]
+    fn from(t: Vec4) -> Self {
+        unsafe { vld1q_f32(t.as_ref().as_ptr()) }
+    }
+}
+
+#[cfg(vec4_neon)]
+impl From<float32x4_t> for Vec4 {
+    #[inline]
+    fn from(t: float32x4_t) -> Self {
+        let mut out = Vec4::zero();
+        unsafe { vst1q_f32(out.as_mut().as_mut_ptr(), t) };
+        out
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<Vec4> for v128 {
+    #[inline]
+    fn from(t: Vec4) -> Self {
+        f32x4(t.x, t.y, t.z, t.w)
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<v128> for Vec4 {
+    #[inline]
+    fn from(t: v128) -> Self {
+        Self {
+            x: f32x4_extract_lane::<0>(t),
+            y: f32x4_extract_lane::<1>(t),
+            z: f32x4_extract_lane::<2>(t),
+            w: f32x4_extract_lane::<3>(t),
+        }
+    }
+}
+
 /// Creates a `Vec4`.
 #[inline]
 pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
@@ -177,6 +220,22 @@ impl Vec4 {
     #[cfg(vec4_sse2)]
     #[inline]
     unsafe fn dot_as_m128(self, other: Self) -> __m128 {
+        #[cfg(vec4_sse41)]
+        {
+            _mm_dp_ps(self.0, other.0, 0b1111_0001)
+        }
+
+        #[cfg(not(vec4_sse41))]
+        {
+            self.dot_as_m128_sse2(other)
+        }
+    }
+
+    /// SSE2 version of `dot_as_m128`, kept for testing the SSE4.1 version against.
+    #[cfg(vec4_sse2)]
+    #[inline]
+    #[cfg_attr(vec4_sse41, allow(dead_code))]
+    pub(crate) unsafe fn dot_as_m128_sse2(self, other: Self) -> __m128 {
         let x2_y2_z2_w2 = _mm_mul_ps(self.0, other.0);
         let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
         let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
@@ -188,12 +247,29 @@ impl Vec4 {
     #[cfg(vec4_sse2)]
     #[inline]
     pub(crate) fn dot_as_vec4(self, other: Self) -> Self {
+        #[cfg(vec4_sse41)]
+        unsafe {
+            Self(_mm_dp_ps(self.0, other.0, 0b1111_1111))
+        }
+
+        #[cfg(not(vec4_sse41))]
         unsafe {
             let dot_in_x = self.dot_as_m128(other);
             Self(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
         }
     }
 
+    /// Calculates the Vec4 dot product and returns answer in all lanes of v128.
+    #[cfg(vec4_simd128)]
+    #[inline]
+    fn dot_as_v128(self, other: Self) -> v128 {
+        let x2_y2_z2_w2 = f32x4_mul(self.into(), other.into());
+        let z2_w2_x2_y2 = i32x4_shuffle::<2, 3, 0, 1>(x2_y2_z2_w2, x2_y2_z2_w2);
+        let x2z2_y2w2 = f32x4_add(x2_y2_z2_w2, z2_w2_x2_y2);
+        let y2w2_x2z2 = i32x4_shuffle::<1, 0, 3, 2>(x2z2_y2w2, x2z2_y2w2);
+        f32x4_add(x2z2_y2w2, y2w2_x2z2)
+    }
+
     /// Computes the 4D dot product of `self` and `other`.
     #[inline]
     pub fn dot(self, other: Self) -> f32 {
@@ -202,7 +278,17 @@ impl Vec4 {
             _mm_cvtss_f32(self.dot_as_m128(other))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            vaddvq_f32(vmulq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            f32x4_extract_lane::<0>(self.dot_as_v128(other))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
         }
@@ -277,12 +363,47 @@ impl Vec4 {
             unsafe { Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))) }
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let v = self.into();
+            let dot = vaddvq_f32(vmulq_f32(v, v));
+            Self::from(vdivq_f32(v, vsqrtq_f32(vdupq_n_f32(dot))))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), f32x4_sqrt(self.dot_as_v128(self))))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self * self.length_recip()
         }
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize().unwrap_or_else(Self::zero)
+    }
+
     /// Returns the vertical minimum of `self` and `other`.
     ///
     /// In other words, this computes
@@ -295,7 +416,17 @@ impl Vec4 {
             Self(_mm_min_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vminnmq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_pmin(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.min(other.x),
@@ -318,7 +449,17 @@ impl Vec4 {
             Self(_mm_max_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vmaxnmq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_pmax(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.max(other.x),
@@ -342,7 +483,20 @@ impl Vec4 {
             _mm_cvtss_f32(v)
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            vminnmvq_f32(self.into())
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let v = self.into();
+            let v = f32x4_pmin(v, i32x4_shuffle::<2, 3, 0, 0>(v, v));
+            let v = f32x4_pmin(v, i32x4_shuffle::<1, 0, 0, 0>(v, v));
+            f32x4_extract_lane::<0>(v)
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x.min(self.y.min(self.z.min(self.w)))
         }
@@ -361,7 +515,20 @@ impl Vec4 {
             _mm_cvtss_f32(v)
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            vmaxnmvq_f32(self.into())
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let v = self.into();
+            let v = f32x4_pmax(v, i32x4_shuffle::<2, 3, 0, 0>(v, v));
+            let v = f32x4_pmax(v, i32x4_shuffle::<1, 0, 0, 0>(v, v));
+            f32x4_extract_lane::<0>(v)
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x.max(self.y.max(self.z.min(self.w)))
         }
@@ -378,7 +545,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmpeq_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vceqq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_eq(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.eq(&other.x),
@@ -400,7 +577,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmpneq_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vmvnq_u32(vceqq_f32(self.into(), other.into())))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_ne(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.ne(&other.x),
@@ -422,7 +609,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmpge_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vcgeq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_ge(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.ge(&other.x),
@@ -444,7 +641,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmpgt_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vcgtq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_gt(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.gt(&other.x),
@@ -466,7 +673,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmple_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vcleq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_le(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.le(&other.x),
@@ -488,7 +705,17 @@ impl Vec4 {
             Vec4Mask(_mm_cmplt_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4Mask::from(vcltq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4Mask::from(f32x4_lt(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.lt(&other.x),
@@ -548,12 +775,27 @@ impl Vec4 {
     /// Per element multiplication/addition of the three inputs: b + (self * a)
     #[inline]
     pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
-        #[cfg(vec4_sse2)]
+        #[cfg(vec4_fma)]
+        unsafe {
+            Self(_mm_fmadd_ps(self.0, a.0, b.0))
+        }
+
+        #[cfg(all(vec4_sse2, not(vec4_fma)))]
         unsafe {
             Self(_mm_add_ps(_mm_mul_ps(self.0, a.0), b.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vmlaq_f32(b.into(), self.into(), a.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_add(f32x4_mul(self.into(), a.into()), b.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: (self.x * a.x) + b.x,
@@ -575,7 +817,17 @@ impl Vec4 {
             ))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vabsq_f32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_abs(self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.abs(),
@@ -596,7 +848,26 @@ impl Vec4 {
             Self(m128_round(self.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vrndaq_f32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            // f32x4_nearest rounds half-way cases to even so round away from zero here
+            let v = self.into();
+            let trunc = f32x4_trunc(v);
+            let sign_one = v128_or(f32x4_splat(1.0), v128_and(v, f32x4_splat(-0.0)));
+            let round_away = f32x4_ge(f32x4_abs(f32x4_sub(v, trunc)), f32x4_splat(0.5));
+            Self::from(v128_bitselect(
+                f32x4_add(trunc, sign_one),
+                trunc,
+                round_away,
+            ))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.round(),
@@ -617,7 +888,17 @@ impl Vec4 {
             Self(m128_floor(self.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vrndmq_f32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_floor(self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.floor(),
@@ -638,7 +919,17 @@ impl Vec4 {
             Self(m128_ceil(self.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vrndpq_f32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_ceil(self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x.ceil(),
@@ -649,10 +940,202 @@ impl Vec4 {
         }
     }
 
+    /// Returns a `Vec4` containing the square root of each element of `self`.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sqrt_ps(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt(), self.w.sqrt())
+        }
+    }
+
+    /// Returns a `Vec4` containing the sine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn sin(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            Self(m128_sin_cos(self.0).0)
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.sin(), self.y.sin(), self.z.sin(), self.w.sin())
+        }
+    }
+
+    /// Returns a `Vec4` containing the cosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn cos(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            Self(m128_sin_cos(self.0).1)
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.cos(), self.y.cos(), self.z.cos(), self.w.cos())
+        }
+    }
+
+    /// Returns a tuple of `Vec4`s containing the sine and the cosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_sin_cos;
+            let (sin, cos) = m128_sin_cos(self.0);
+            (Self(sin), Self(cos))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            let (sin_x, cos_x) = self.x.sin_cos();
+            let (sin_y, cos_y) = self.y.sin_cos();
+            let (sin_z, cos_z) = self.z.sin_cos();
+            let (sin_w, cos_w) = self.w.sin_cos();
+            (
+                Self::new(sin_x, sin_y, sin_z, sin_w),
+                Self::new(cos_x, cos_y, cos_z, cos_w),
+            )
+        }
+    }
+
+    /// Returns a `Vec4` containing the tangent of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 4 ULP is used.
+    #[inline]
+    pub fn tan(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_tan;
+            Self(m128_tan(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.tan(), self.y.tan(), self.z.tan(), self.w.tan())
+        }
+    }
+
+    /// Returns a `Vec4` containing the arcsine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 3 ULP is used.
+    #[inline]
+    pub fn asin(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_asin;
+            Self(m128_asin(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.asin(), self.y.asin(), self.z.asin(), self.w.asin())
+        }
+    }
+
+    /// Returns a `Vec4` containing the arccosine of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn acos(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_acos;
+            Self(m128_acos(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.acos(), self.y.acos(), self.z.acos(), self.w.acos())
+        }
+    }
+
+    /// Returns a `Vec4` containing the four quadrant arctangent of each element of `self` and
+    /// `other`, with `self` holding the `y` and `other` the `x` coordinates.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 4 ULP is used.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_atan2;
+            Self(m128_atan2(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(
+                self.x.atan2(other.x),
+                self.y.atan2(other.y),
+                self.z.atan2(other.z),
+                self.w.atan2(other.w),
+            )
+        }
+    }
+
     /// Returns a `Vec4` containing `e^self` (the exponential function) for each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
     #[inline]
     pub fn exp(self) -> Self {
-        Self::new(self.x.exp(), self.y.exp(), self.z.exp(), self.w.exp())
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_exp;
+            Self(m128_exp(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.exp(), self.y.exp(), self.z.exp(), self.w.exp())
+        }
+    }
+
+    /// Returns a `Vec4` containing the natural logarithm of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 1 ULP is used.
+    #[inline]
+    pub fn ln(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_ln;
+            Self(m128_ln(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.ln(), self.y.ln(), self.z.ln(), self.w.ln())
+        }
+    }
+
+    /// Returns a `Vec4` containing the base 2 logarithm of each element of `self`.
+    ///
+    /// With SSE2 a polynomial approximation with a maximum error of 2 ULP is used.
+    #[inline]
+    pub fn log2(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            use crate::f32::funcs::sse2::m128_log2;
+            Self(m128_log2(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self::new(self.x.log2(), self.y.log2(), self.z.log2(), self.w.log2())
+        }
     }
 
     /// Returns a `Vec4` containing each element of `self` raised to the power of `n`.
@@ -676,7 +1159,19 @@ impl Vec4 {
             Vec4Mask(_mm_cmpunord_ps(self.0, self.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let v = self.into();
+            Vec4Mask::from(vmvnq_u32(vceqq_f32(v, v)))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            let v = self.into();
+            Vec4Mask::from(f32x4_ne(v, v))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4Mask::new(
                 self.x.is_nan(),
@@ -772,6 +1267,28 @@ impl Vec4 {
     pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
         abs_diff_eq!(self, other, max_abs_diff)
     }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> crate::DVec4 {
+        crate::DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec4(self) -> crate::IVec4 {
+        crate::IVec4::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec4(self) -> crate::UVec4 {
+        crate::UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
 }
 
 impl AsRef<[f32; 4]> for Vec4 {
@@ -816,7 +1333,17 @@ impl Div<Vec4> for Vec4 {
             Self(_mm_div_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vdivq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x / other.x,
@@ -836,7 +1363,17 @@ impl DivAssign<Vec4> for Vec4 {
             self.0 = unsafe { _mm_div_ps(self.0, other.0) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x /= other.x;
             self.y /= other.y;
@@ -855,7 +1392,17 @@ impl Div<f32> for Vec4 {
             Self(_mm_div_ps(self.0, _mm_set1_ps(other)))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vdivq_f32(self.into(), vdupq_n_f32(other)))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_div(self.into(), f32x4_splat(other)))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x / other,
@@ -875,7 +1422,17 @@ impl DivAssign<f32> for Vec4 {
             self.0 = unsafe { _mm_div_ps(self.0, _mm_set1_ps(other)) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self / other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x /= other;
             self.y /= other;
@@ -894,7 +1451,17 @@ impl Div<Vec4> for f32 {
             Vec4(_mm_div_ps(_mm_set1_ps(self), other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4::from(vdivq_f32(vdupq_n_f32(self), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4::from(f32x4_div(f32x4_splat(self), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4 {
                 x: self / other.x,
@@ -915,7 +1482,17 @@ impl Mul<Vec4> for Vec4 {
             Self(_mm_mul_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vmulq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_mul(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x * other.x,
@@ -935,7 +1512,17 @@ impl MulAssign<Vec4> for Vec4 {
             self.0 = unsafe { _mm_mul_ps(self.0, other.0) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x *= other.x;
             self.y *= other.y;
@@ -954,7 +1541,17 @@ impl Mul<f32> for Vec4 {
             Self(_mm_mul_ps(self.0, _mm_set1_ps(other)))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vmulq_f32(self.into(), vdupq_n_f32(other)))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_mul(self.into(), f32x4_splat(other)))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x * other,
@@ -974,7 +1571,17 @@ impl MulAssign<f32> for Vec4 {
             self.0 = unsafe { _mm_mul_ps(self.0, _mm_set1_ps(other)) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self * other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x *= other;
             self.y *= other;
@@ -993,7 +1600,17 @@ impl Mul<Vec4> for f32 {
             Vec4(_mm_mul_ps(_mm_set1_ps(self), other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4::from(vmulq_f32(vdupq_n_f32(self), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4::from(f32x4_mul(f32x4_splat(self), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4 {
                 x: self * other.x,
@@ -1014,7 +1631,17 @@ impl Add for Vec4 {
             Self(_mm_add_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vaddq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_add(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x + other.x,
@@ -1034,7 +1661,17 @@ impl AddAssign for Vec4 {
             self.0 = unsafe { _mm_add_ps(self.0, other.0) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self + other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self + other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x += other.x;
             self.y += other.y;
@@ -1053,7 +1690,17 @@ impl Sub for Vec4 {
             Self(_mm_sub_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vsubq_f32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_sub(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: self.x - other.x,
@@ -1073,7 +1720,17 @@ impl SubAssign for Vec4 {
             self.0 = unsafe { _mm_sub_ps(self.0, other.0) };
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        {
+            *self = *self - other;
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            *self = *self - other;
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             self.x -= other.x;
             self.y -= other.y;
@@ -1092,7 +1749,17 @@ impl Neg for Vec4 {
             Self(_mm_sub_ps(ZERO.0, self.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vnegq_f32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(f32x4_neg(self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self {
                 x: -self.x,
@@ -1290,3 +1957,20 @@ fn test_vec4_private() {
         vec4(-0.5, 1.0, -5.0, -1.0)
     );
 }
+
+#[test]
+#[cfg(vec4_sse2)]
+fn test_vec4_sse2_dot() {
+    // `dot_as_m128` is replaced by `_mm_dp_ps` when SSE4.1 is enabled, which may sum the lanes
+    // in a different order
+    let values = [
+        -8388609.0, -3.7, -2.5, -1.3, -0.5, 0.0, 0.4, 1.5, 2.6, 8.2, 1.0e6,
+    ];
+    for v in values.windows(4) {
+        let a = vec4(v[0], v[1], v[2], v[3]);
+        let b = vec4(v[3], v[1], -v[0], v[2]);
+        let expected = unsafe { Vec4(a.dot_as_m128_sse2(b)).x };
+        assert!((expected - a.dot(b)).abs() <= expected.abs() * 1.0e-6);
+        assert_eq!(Vec4::splat(a.dot(b)), a.dot_as_vec4(b));
+    }
+}
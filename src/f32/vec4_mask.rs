@@ -1,6 +1,10 @@
 use crate::Vec4;
 use core::{fmt, ops::*};
 
+#[cfg(vec4_neon)]
+use core::arch::aarch64::*;
+#[cfg(vec4_simd128)]
+use core::arch::wasm32::*;
 #[cfg(all(vec4_sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec4_sse2, target_arch = "x86_64"))]
@@ -10,7 +14,8 @@ use core::{cmp::Ordering, hash};
 
 /// A 4-dimensional vector mask.
 ///
-/// This type is typically created by comparison methods on `Vec4`.  It is
+/// This type is typically created by comparison methods on `Vec4`, `IVec4` and
+/// `UVec4`.  It is
 /// essentially a vector of four boolean values.
 #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
 #[derive(Clone, Copy)]
@@ -110,7 +115,18 @@ impl Vec4Mask {
             _mm_movemask_ps(self.0) as u32
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            let bits = [1, 2, 4, 8];
+            vaddvq_u32(vandq_u32(self.into(), vld1q_u32(bits.as_ptr())))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            i32x4_bitmask(self.into()) as u32
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             (self.0 & 0x1) | (self.1 & 0x1) << 1 | (self.2 & 0x1) << 2 | (self.3 & 0x1) << 3
         }
@@ -126,7 +142,17 @@ impl Vec4Mask {
             _mm_movemask_ps(self.0) != 0
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            vmaxvq_u32(self.into()) != 0
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            v128_any_true(self.into())
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             ((self.0 | self.1 | self.2 | self.3) & 0x1) != 0
         }
@@ -142,7 +168,17 @@ impl Vec4Mask {
             _mm_movemask_ps(self.0) == 0xf
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            vminvq_u32(self.into()) != 0
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            i32x4_all_true(self.into())
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             ((self.0 & self.1 & self.2 & self.3) & 0x1) != 0
         }
@@ -166,7 +202,17 @@ impl Vec4Mask {
             ))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Vec4::from(vbslq_f32(self.into(), if_true.into(), if_false.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Vec4::from(v128_bitselect(if_true.into(), if_false.into(), self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Vec4 {
                 x: if self.0 != 0 { if_true.x } else { if_false.x },
@@ -187,7 +233,17 @@ impl BitAnd for Vec4Mask {
             Self(_mm_and_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vandq_u32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(v128_and(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self(
                 self.0 & other.0,
@@ -226,7 +282,17 @@ impl BitOr for Vec4Mask {
             Self(_mm_or_ps(self.0, other.0))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vorrq_u32(self.into(), other.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(v128_or(self.into(), other.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self(
                 self.0 | other.0,
@@ -268,7 +334,17 @@ impl Not for Vec4Mask {
             ))
         }
 
-        #[cfg(vec4_f32)]
+        #[cfg(vec4_neon)]
+        unsafe {
+            Self::from(vmvnq_u32(self.into()))
+        }
+
+        #[cfg(vec4_simd128)]
+        {
+            Self::from(v128_not(self.into()))
+        }
+
+        #[cfg(all(vec4_f32, not(any(vec4_neon, vec4_simd128))))]
         {
             Self(!self.0, !self.1, !self.2, !self.3)
         }
@@ -333,3 +409,42 @@ impl AsRef<[u32; 4]> for Vec4Mask {
         unsafe { &*(self as *const Self as *const [u32; 4]) }
     }
 }
+
+#[cfg(vec4_neon)]
+impl From<Vec4Mask> for uint32x4_t {
+    #[inline]
+    fn from(t: Vec4Mask) -> Self {
+        unsafe { vld1q_u32(t.as_ref().as_ptr()) }
+    }
+}
+
+#[cfg(vec4_neon)]
+impl From<uint32x4_t> for Vec4Mask {
+    #[inline]
+    fn from(t: uint32x4_t) -> Self {
+        let mut out = Self::default();
+        unsafe { vst1q_u32(&mut out as *mut Self as *mut u32, t) };
+        out
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<Vec4Mask> for v128 {
+    #[inline]
+    fn from(t: Vec4Mask) -> Self {
+        u32x4(t.0, t.1, t.2, t.3)
+    }
+}
+
+#[cfg(vec4_simd128)]
+impl From<v128> for Vec4Mask {
+    #[inline]
+    fn from(t: v128) -> Self {
+        Self(
+            u32x4_extract_lane::<0>(t),
+            u32x4_extract_lane::<1>(t),
+            u32x4_extract_lane::<2>(t),
+            u32x4_extract_lane::<3>(t),
+        )
+    }
+}
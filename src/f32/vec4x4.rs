@@ -0,0 +1,464 @@
+use super::{Mat4, Vec3x4, Vec4, Vec4Mask, Vec4Swizzles};
+use core::{fmt, ops::*};
+
+/// Four 4-dimensional vectors stored in structure of arrays layout.
+///
+/// Each component holds the value of that component for all four vectors, so one SIMD operation
+/// processes four vectors at once. Lane `i` of `x`, `y`, `z` and `w` makes up vector `i`.
+///
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(C)]
+pub struct Vec4x4 {
+    pub x: Vec4,
+    pub y: Vec4,
+    pub z: Vec4,
+    pub w: Vec4,
+}
+
+impl fmt::Display for Vec4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d] = self.to_array();
+        write!(f, "[{}, {}, {}, {}]", a, b, c, d)
+    }
+}
+
+impl Vec4x4 {
+    /// Creates a `Vec4x4` from the given components.
+    #[inline]
+    pub fn new(x: Vec4, y: Vec4, z: Vec4, w: Vec4) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a `Vec4x4` with all elements set to `0.0`.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vec4::zero())
+    }
+
+    /// Creates a `Vec4x4` with all elements set to `1.0`.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(Vec4::one())
+    }
+
+    /// Creates a `Vec4x4` with all four vectors set to `v`.
+    #[inline]
+    pub fn splat(v: Vec4) -> Self {
+        Self {
+            x: v.xxxx(),
+            y: v.yyyy(),
+            z: v.zzzz(),
+            w: v.wwww(),
+        }
+    }
+
+    /// Creates a `Vec4x4` from four vectors.
+    #[inline]
+    pub fn from_array(a: [Vec4; 4]) -> Self {
+        let t = Mat4::from_cols(a[0], a[1], a[2], a[3]).transpose();
+        Self {
+            x: t.x_axis,
+            y: t.y_axis,
+            z: t.z_axis,
+            w: t.w_axis,
+        }
+    }
+
+    /// Returns the four vectors stored in `self`.
+    #[inline]
+    pub fn to_array(self) -> [Vec4; 4] {
+        let t = Mat4::from_cols(self.x, self.y, self.z, self.w).transpose();
+        [t.x_axis, t.y_axis, t.z_axis, t.w_axis]
+    }
+
+    /// Loads the first four vectors of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn from_slice(slice: &[Vec4]) -> Self {
+        Self::from_array([slice[0], slice[1], slice[2], slice[3]])
+    }
+
+    /// Stores the four vectors of `self` in the first four elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than 4 elements long.
+    #[inline]
+    pub fn write_to_slice(self, slice: &mut [Vec4]) {
+        slice[..4].copy_from_slice(&self.to_array());
+    }
+
+    /// Loads the elements of `slice` at the given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn gather(slice: &[Vec4], indices: [usize; 4]) -> Self {
+        Self::from_array([
+            slice[indices[0]],
+            slice[indices[1]],
+            slice[indices[2]],
+            slice[indices[3]],
+        ])
+    }
+
+    /// Stores the four vectors of `self` in the elements of `slice` at the given `indices`.
+    ///
+    /// If an index is repeated the later vector is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    #[inline]
+    pub fn scatter(self, slice: &mut [Vec4], indices: [usize; 4]) {
+        let a = self.to_array();
+        slice[indices[0]] = a[0];
+        slice[indices[1]] = a[1];
+        slice[indices[2]] = a[2];
+        slice[indices[3]] = a[3];
+    }
+
+    /// Creates a `Vec3x4` from the `x`, `y` and `z` components of `self`, discarding `w`.
+    #[inline]
+    pub fn truncate(self) -> Vec3x4 {
+        Vec3x4::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the dot product of each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> Vec4 {
+        let d = self.x * other.x;
+        let d = self.y.mul_add(other.y, d);
+        let d = self.z.mul_add(other.z, d);
+        self.w.mul_add(other.w, d)
+    }
+
+    /// Returns the length of each vector in `self`.
+    #[inline]
+    pub fn length(self) -> Vec4 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the squared length of each vector in `self`.
+    #[inline]
+    pub fn length_squared(self) -> Vec4 {
+        self.dot(self)
+    }
+
+    /// Returns `1.0 / length()` of each vector in `self`.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> Vec4 {
+        self.length().recip()
+    }
+
+    /// Returns the Euclidean distance between each pair of vectors in `self` and `other`.
+    #[inline]
+    pub fn distance(self, other: Self) -> Vec4 {
+        (self - other).length()
+    }
+
+    /// Returns the squared Euclidean distance between each pair of vectors in `self` and
+    /// `other`.
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> Vec4 {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0.
+    ///
+    /// For valid results the vectors must not be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Returns `self` with each vector normalized to length 1.0 if possible, else zero.
+    ///
+    /// In particular, vectors with a length of zero, very close to zero or not finite are set to
+    /// zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let rcp = self.length_recip();
+        // the comparisons are false for NaN lanes
+        let valid = rcp.cmplt(Vec4::splat(core::f32::INFINITY)) & rcp.cmpgt(Vec4::zero());
+        Self::select(valid, self * rcp, Self::zero())
+    }
+
+    /// Returns the element-wise minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+            w: self.w.min(other.w),
+        }
+    }
+
+    /// Returns the element-wise maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+            w: self.w.max(other.w),
+        }
+    }
+
+    /// Returns the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs(),
+        }
+    }
+
+    /// Performs a linear interpolation between each pair of vectors in `self` and `other` using
+    /// the lanes of `s`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: Vec4) -> Self {
+        self + (other - self) * s
+    }
+
+    /// Creates a `Vec4x4` from the vectors of `if_true` where the lanes of `mask` are set and the
+    /// vectors of `if_false` elsewhere.
+    #[inline]
+    pub fn select(mask: Vec4Mask, if_true: Self, if_false: Self) -> Self {
+        Self {
+            x: mask.select(if_true.x, if_false.x),
+            y: mask.select(if_true.y, if_false.y),
+            z: mask.select(if_true.z, if_false.z),
+            w: mask.select(if_true.w, if_false.w),
+        }
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` and `other` are equal.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec4Mask {
+        self.x.cmpeq(other.x)
+            & self.y.cmpeq(other.y)
+            & self.z.cmpeq(other.z)
+            & self.w.cmpeq(other.w)
+    }
+
+    /// Returns a mask with the lanes set where the vectors of `self` are length `1.0`.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized_mask(self) -> Vec4Mask {
+        (self.length_squared() - Vec4::one())
+            .abs()
+            .cmple(Vec4::splat(1e-6))
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.x.abs_diff_eq(other.x, max_abs_diff)
+            && self.y.abs_diff_eq(other.y, max_abs_diff)
+            && self.z.abs_diff_eq(other.z, max_abs_diff)
+            && self.w.abs_diff_eq(other.w, max_abs_diff)
+    }
+}
+
+impl From<[Vec4; 4]> for Vec4x4 {
+    #[inline]
+    fn from(a: [Vec4; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec4x4> for [Vec4; 4] {
+    #[inline]
+    fn from(v: Vec4x4) -> Self {
+        v.to_array()
+    }
+}
+
+impl Add for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl AddAssign for Vec4x4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl SubAssign for Vec4x4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Vec4x4> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w,
+        }
+    }
+}
+
+impl MulAssign<Vec4x4> for Vec4x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec4> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Vec4) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+impl MulAssign<Vec4> for Vec4x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Vec4) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<f32> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        self * Vec4::splat(other)
+    }
+}
+
+impl MulAssign<f32> for Vec4x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec4x4> for f32 {
+    type Output = Vec4x4;
+    #[inline]
+    fn mul(self, other: Vec4x4) -> Vec4x4 {
+        other * self
+    }
+}
+
+impl Div<Vec4x4> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+            w: self.w / other.w,
+        }
+    }
+}
+
+impl DivAssign<Vec4x4> for Vec4x4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Div<Vec4> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Vec4) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl DivAssign<Vec4> for Vec4x4 {
+    #[inline]
+    fn div_assign(&mut self, other: Vec4) {
+        *self = *self / other;
+    }
+}
+
+impl Div<f32> for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl DivAssign<f32> for Vec4x4 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for Vec4x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
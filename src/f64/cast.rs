@@ -0,0 +1,36 @@
+use super::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+#[repr(C)]
+pub union F64x4Cast {
+    pub f64x4: [f64; 4],
+    pub f64x2x2: [[f64; 2]; 2],
+    pub dvec4: DVec4,
+    pub dquat: DQuat,
+    pub dmat2: DMat2,
+}
+
+#[repr(C)]
+pub union F64x16Cast {
+    pub f64x4x4: [[f64; 4]; 4],
+    pub f64x16: [f64; 16],
+    pub dmat4: DMat4,
+}
+
+#[repr(C)]
+pub union F64x3Cast {
+    pub f64x3: [f64; 3],
+    pub dvec3: DVec3,
+}
+
+#[repr(C)]
+pub union F64x9Cast {
+    pub f64x3x3: [[f64; 3]; 3],
+    pub f64x9: [f64; 9],
+    pub dmat3: DMat3,
+}
+
+#[repr(C)]
+pub union F64x2Cast {
+    pub f64x2: [f64; 2],
+    pub dvec2: DVec2,
+}
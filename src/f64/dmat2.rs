@@ -0,0 +1,343 @@
+use super::{scalar_sin_cos, DVec2};
+use core::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DMat2 = const_dmat2!([0.0; 4]);
+const IDENTITY: DMat2 = const_dmat2!([1.0, 0.0], [0.0, 1.0]);
+
+/// Creates a `DMat2` from two column vectors.
+#[inline]
+pub fn dmat2(x_axis: DVec2, y_axis: DVec2) -> DMat2 {
+    DMat2::from_cols(x_axis, y_axis)
+}
+
+/// A 2x2 column major matrix.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct DMat2 {
+    pub x_axis: DVec2,
+    pub y_axis: DVec2,
+}
+
+impl Default for DMat2 {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for DMat2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+    }
+}
+
+impl DMat2 {
+    /// Creates a 2x2 matrix with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a 2x2 identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates a 2x2 matrix from two column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: DVec2, y_axis: DVec2) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Creates a 2x2 matrix from a `[f64; 4]` stored in column major order.  If
+    /// your data is stored in row major you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array(m: &[f64; 4]) -> Self {
+        Self {
+            x_axis: DVec2::new(m[0], m[1]),
+            y_axis: DVec2::new(m[2], m[3]),
+        }
+    }
+
+    /// Creates a `[f64; 4]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f64; 4] {
+        [self.x_axis.x, self.x_axis.y, self.y_axis.x, self.y_axis.y]
+    }
+
+    /// Creates a 2x2 matrix from a `[[f64; 2]; 2]` stored in column major
+    /// order.  If your data is in row major order you will need to `transpose`
+    /// the returned matrix.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f64; 2]; 2]) -> Self {
+        Self {
+            x_axis: m[0].into(),
+            y_axis: m[1].into(),
+        }
+    }
+
+    /// Creates a `[[f64; 2]; 2]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f64; 2]; 2] {
+        [self.x_axis.into(), self.y_axis.into()]
+    }
+
+    /// Creates a 2x2 matrix containing the given `scale` and rotation of
+    /// `angle` (in radians).
+    #[inline]
+    pub fn from_scale_angle(scale: DVec2, angle: f64) -> Self {
+        let (sin, cos) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec2::new(cos * scale.x, sin * scale.x),
+            y_axis: DVec2::new(-sin * scale.y, cos * scale.y),
+        }
+    }
+
+    /// Creates a 2x2 matrix containing a rotation of `angle` (in radians).
+    #[inline]
+    pub fn from_angle(angle: f64) -> Self {
+        let (sin, cos) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec2::new(cos, sin),
+            y_axis: DVec2::new(-sin, cos),
+        }
+    }
+
+    /// Creates a 2x2 matrix containing the given non-uniform `scale`.
+    #[inline]
+    pub fn from_scale(scale: DVec2) -> Self {
+        Self {
+            x_axis: DVec2::new(scale.x, 0.0),
+            y_axis: DVec2::new(0.0, scale.y),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self {
+            x_axis: DVec2::new(self.x_axis.x, self.y_axis.x),
+            y_axis: DVec2::new(self.x_axis.y, self.y_axis.y),
+        }
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.x_axis.x * self.y_axis.y - self.x_axis.y * self.y_axis.x
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If the matrix is not invertible the returned matrix will be invalid.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let det = self.determinant();
+        glam_assert!(det != 0.0);
+        let inv_det = det.recip();
+        Self {
+            x_axis: DVec2::new(self.y_axis.y * inv_det, self.x_axis.y * -inv_det),
+            y_axis: DVec2::new(self.y_axis.x * -inv_det, self.x_axis.x * inv_det),
+        }
+    }
+
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f64::EPSILON` times the product of the column lengths, which is the largest
+    /// determinant possible for columns of those lengths. This detects nearly singular matrices
+    /// regardless of their scale. `None` is also returned if `self` or the computed inverse
+    /// contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let max_det = self.x_axis.length() * self.y_axis.length();
+        if det.abs() > core::f64::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Transforms a `DVec2`.
+    #[inline]
+    pub fn mul_vec2(&self, other: DVec2) -> DVec2 {
+        DVec2::new(
+            (self.x_axis.x * other.x) + (self.y_axis.x * other.y),
+            (self.x_axis.y * other.x) + (self.y_axis.y * other.y),
+        )
+    }
+
+    /// Multiplies two 2x2 matrices.
+    #[inline]
+    pub fn mul_mat2(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec2(other.x_axis),
+            y_axis: self.mul_vec2(other.y_axis),
+        }
+    }
+
+    /// Adds two 2x2 matrices.
+    #[inline]
+    pub fn add_mat2(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis + other.x_axis,
+            y_axis: self.y_axis + other.y_axis,
+        }
+    }
+
+    /// Subtracts two 2x2 matrices.
+    #[inline]
+    pub fn sub_mat2(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis - other.x_axis,
+            y_axis: self.y_axis - other.y_axis,
+        }
+    }
+
+    /// Multiplies a 2x2 matrix by a scalar.
+    #[inline]
+    pub fn mul_scalar(&self, other: f64) -> Self {
+        let s = DVec2::splat(other);
+        Self {
+            x_axis: self.x_axis * s,
+            y_axis: self.y_axis * s,
+        }
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DMat2`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(&self) -> crate::Mat2 {
+        crate::Mat2::from_cols(self.x_axis.as_f32(), self.y_axis.as_f32())
+    }
+}
+
+impl AsRef<[f64; 4]> for DMat2 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        unsafe { &*(self as *const Self as *const [f64; 4]) }
+    }
+}
+
+impl AsMut<[f64; 4]> for DMat2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 4]) }
+    }
+}
+
+impl Add<DMat2> for DMat2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.add_mat2(&other)
+    }
+}
+
+impl Sub<DMat2> for DMat2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.sub_mat2(&other)
+    }
+}
+
+impl Mul<DMat2> for DMat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat2(&other)
+    }
+}
+
+impl Mul<DVec2> for DMat2 {
+    type Output = DVec2;
+    #[inline]
+    fn mul(self, other: DVec2) -> DVec2 {
+        self.mul_vec2(other)
+    }
+}
+
+impl Mul<DMat2> for f64 {
+    type Output = DMat2;
+    #[inline]
+    fn mul(self, other: DMat2) -> DMat2 {
+        other.mul_scalar(self)
+    }
+}
+
+impl Mul<f64> for DMat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        self.mul_scalar(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DMat2 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DMat2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
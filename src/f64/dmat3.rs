@@ -0,0 +1,503 @@
+use super::{scalar_sin_cos, DQuat, DVec2, DVec3, DVec3Swizzles};
+use crate::EulerRot;
+use core::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DMat3 = const_dmat3!([0.0; 9]);
+const IDENTITY: DMat3 = const_dmat3!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+
+/// Creates a `DMat3` from three column vectors.
+#[inline]
+pub fn dmat3(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> DMat3 {
+    DMat3 {
+        x_axis,
+        y_axis,
+        z_axis,
+    }
+}
+
+#[inline]
+fn quat_to_axes(rotation: DQuat) -> (DVec3, DVec3, DVec3) {
+    glam_assert!(rotation.is_normalized());
+    let (x, y, z, w) = rotation.into();
+    let x2 = x + x;
+    let y2 = y + y;
+    let z2 = z + z;
+    let xx = x * x2;
+    let xy = x * y2;
+    let xz = x * z2;
+    let yy = y * y2;
+    let yz = y * z2;
+    let zz = z * z2;
+    let wx = w * x2;
+    let wy = w * y2;
+    let wz = w * z2;
+
+    let x_axis = DVec3::new(1.0 - (yy + zz), xy + wz, xz - wy);
+    let y_axis = DVec3::new(xy - wz, 1.0 - (xx + zz), yz + wx);
+    let z_axis = DVec3::new(xz + wy, yz - wx, 1.0 - (xx + yy));
+    (x_axis, y_axis, z_axis)
+}
+
+/// A 3x3 column major matrix.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct DMat3 {
+    pub x_axis: DVec3,
+    pub y_axis: DVec3,
+    pub z_axis: DVec3,
+}
+
+impl Default for DMat3 {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for DMat3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
+
+impl DMat3 {
+    /// Creates a 3x3 matrix with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a 3x3 identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 matrix from a `[f64; 9]` stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array(m: &[f64; 9]) -> Self {
+        DMat3 {
+            x_axis: DVec3::new(m[0], m[1], m[2]),
+            y_axis: DVec3::new(m[3], m[4], m[5]),
+            z_axis: DVec3::new(m[6], m[7], m[8]),
+        }
+    }
+
+    /// Creates a `[f64; 9]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f64; 9] {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+        [m00, m01, m02, m10, m11, m12, m20, m21, m22]
+    }
+
+    /// Creates a 3x3 matrix from a `[[f64; 3]; 3]` stored in column major order.
+    /// If your data is in row major order you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f64; 3]; 3]) -> Self {
+        DMat3 {
+            x_axis: m[0].into(),
+            y_axis: m[1].into(),
+            z_axis: m[2].into(),
+        }
+    }
+
+    /// Creates a `[[f64; 3]; 3]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f64; 3]; 3] {
+        [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+    }
+
+    /// Creates a 3x3 homogeneous transformation matrix from the given `scale`,
+    /// rotation `angle` (in radians) and `translation`.
+    ///
+    /// The resulting matrix can be used to transform 2D points and vectors.
+    #[inline]
+    pub fn from_scale_angle_translation(scale: DVec2, angle: f64, translation: DVec2) -> Self {
+        let (sin, cos) = scalar_sin_cos(angle);
+        let (scale_x, scale_y) = scale.into();
+        Self {
+            x_axis: DVec3::new(cos * scale_x, sin * scale_x, 0.0),
+            y_axis: DVec3::new(-sin * scale_y, cos * scale_y, 0.0),
+            z_axis: translation.extend(1.0),
+        }
+    }
+
+    #[inline]
+    /// Creates a 3x3 rotation matrix from the given quaternion.
+    pub fn from_quat(rotation: DQuat) -> Self {
+        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from a normalized rotation `axis` and
+    /// `angle` (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (sin, cos) = scalar_sin_cos(angle);
+        let (x, y, z) = axis.into();
+        let (xsin, ysin, zsin) = (axis * sin).into();
+        let (x2, y2, z2) = (axis * axis).into();
+        let omc = 1.0 - cos;
+        let xyomc = x * y * omc;
+        let xzomc = x * z * omc;
+        let yzomc = y * z * omc;
+        Self {
+            x_axis: DVec3::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin),
+            y_axis: DVec3::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin),
+            z_axis: DVec3::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from the given Euler angles (in radians).
+    #[inline]
+    pub fn from_rotation_ypr(yaw: f64, pitch: f64, roll: f64) -> Self {
+        let quat = DQuat::from_rotation_ypr(yaw, pitch, roll);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 3x3 rotation matrix from the given Euler rotation sequence and the angles (in
+    /// radians).
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f64, b: f64, c: f64) -> Self {
+        let quat = DQuat::from_euler(euler, a, b, c);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the x axis.
+    #[inline]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec3::unit_x(),
+            y_axis: DVec3::new(0.0, cosa, sina),
+            z_axis: DVec3::new(0.0, -sina, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the y axis.
+    #[inline]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec3::new(cosa, 0.0, -sina),
+            y_axis: DVec3::unit_y(),
+            z_axis: DVec3::new(sina, 0.0, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the z axis.
+    #[inline]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec3::new(cosa, sina, 0.0),
+            y_axis: DVec3::new(-sina, cosa, 0.0),
+            z_axis: DVec3::unit_z(),
+        }
+    }
+
+    /// Creates a 3x3 non-uniform scale matrix.
+    #[inline]
+    pub fn from_scale(scale: DVec3) -> Self {
+        // TODO: should have a affine 2D scale and a 3d scale?
+        // Do not panic as long as any component is non-zero
+        glam_assert!(scale.cmpne(DVec3::zero()).any());
+        let (x, y, z) = scale.into();
+        Self {
+            x_axis: DVec3::new(x, 0.0, 0.0),
+            y_axis: DVec3::new(0.0, y, 0.0),
+            z_axis: DVec3::new(0.0, 0.0, z),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self {
+            x_axis: DVec3 {
+                x: self.x_axis.x,
+                y: self.y_axis.x,
+                z: self.z_axis.x,
+            },
+            y_axis: DVec3 {
+                x: self.x_axis.y,
+                y: self.y_axis.y,
+                z: self.z_axis.y,
+            },
+            z_axis: DVec3 {
+                x: self.x_axis.z,
+                y: self.y_axis.z,
+                z: self.z_axis.z,
+            },
+        }
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If the matrix is not invertible the returned matrix will be invalid.
+    pub fn inverse(&self) -> Self {
+        let tmp0 = self.y_axis.cross(self.z_axis);
+        let tmp1 = self.z_axis.cross(self.x_axis);
+        let tmp2 = self.x_axis.cross(self.y_axis);
+        let det = self.z_axis.dot_as_vec3(tmp2);
+        glam_assert!(det.cmpne(DVec3::zero()).all());
+        let inv_det = det.recip();
+        // TODO: Work out if it's possible to get rid of the transpose
+        DMat3::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+    }
+
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f64::EPSILON` times the product of the column lengths, which is the largest
+    /// determinant possible for columns of those lengths. This detects nearly singular matrices
+    /// regardless of their scale. `None` is also returned if `self` or the computed inverse
+    /// contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let max_det = self.x_axis.length() * self.y_axis.length() * self.z_axis.length();
+        if det.abs() > core::f64::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Transforms a `DVec3`.
+    #[inline]
+    pub fn mul_vec3(&self, other: DVec3) -> DVec3 {
+        let mut res = self.x_axis * other.xxx();
+        res = self.y_axis.mul_add(other.yyy(), res);
+        res = self.z_axis.mul_add(other.zzz(), res);
+        res
+    }
+
+    /// Multiplies two 3x3 matrices.
+    #[inline]
+    pub fn mul_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec3(other.x_axis),
+            y_axis: self.mul_vec3(other.y_axis),
+            z_axis: self.mul_vec3(other.z_axis),
+        }
+    }
+
+    /// Adds two 3x3 matrices.
+    #[inline]
+    pub fn add_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis + other.x_axis,
+            y_axis: self.y_axis + other.y_axis,
+            z_axis: self.z_axis + other.z_axis,
+        }
+    }
+
+    /// Subtracts two 3x3 matrices.
+    #[inline]
+    pub fn sub_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis - other.x_axis,
+            y_axis: self.y_axis - other.y_axis,
+            z_axis: self.z_axis - other.z_axis,
+        }
+    }
+
+    #[inline]
+    /// Multiplies a 3x3 matrix by a scalar.
+    pub fn mul_scalar(&self, other: f64) -> Self {
+        let s = DVec3::splat(other);
+        Self {
+            x_axis: self.x_axis * s,
+            y_axis: self.y_axis * s,
+            z_axis: self.z_axis * s,
+        }
+    }
+
+    /// Transforms the given `DVec2` as 2D point.
+    /// This is the equivalent of multiplying the `DVec2` as a `DVec3` where `z`
+    /// is `1.0`.
+    #[inline]
+    pub fn transform_point2(&self, other: DVec2) -> DVec2 {
+        let mut res = self.x_axis.mul(DVec3::splat(other.x));
+        res = self.y_axis.mul_add(DVec3::splat(other.y), res);
+        res = self.z_axis.add(res);
+        res = res.mul(res.zzz().recip());
+        res.xy()
+    }
+
+    /// Transforms the given `DVec2` as 2D vector.
+    /// This is the equivalent of multiplying the `DVec2` as a `DVec3` where `z`
+    /// is `0.0`.
+    #[inline]
+    pub fn transform_vector2(&self, other: DVec2) -> DVec2 {
+        let mut res = self.x_axis.mul(DVec3::splat(other.x));
+        res = self.y_axis.mul_add(DVec3::splat(other.y), res);
+        res.xy()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DMat3`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(&self) -> crate::Mat3 {
+        crate::Mat3::from_cols(
+            self.x_axis.as_f32(),
+            self.y_axis.as_f32(),
+            self.z_axis.as_f32(),
+        )
+    }
+}
+
+impl AsRef<[f64; 9]> for DMat3 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 9] {
+        unsafe { &*(self as *const Self as *const [f64; 9]) }
+    }
+}
+
+impl AsMut<[f64; 9]> for DMat3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 9] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 9]) }
+    }
+}
+
+impl Add<DMat3> for DMat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.add_mat3(&other)
+    }
+}
+
+impl Sub<DMat3> for DMat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.sub_mat3(&other)
+    }
+}
+
+impl Mul<DMat3> for DMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat3(&other)
+    }
+}
+
+impl Mul<DVec3> for DMat3 {
+    type Output = DVec3;
+    #[inline]
+    fn mul(self, other: DVec3) -> DVec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl Mul<DMat3> for f64 {
+    type Output = DMat3;
+    #[inline]
+    fn mul(self, other: DMat3) -> DMat3 {
+        other.mul_scalar(self)
+    }
+}
+
+impl Mul<f64> for DMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        self.mul_scalar(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DMat3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DMat3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
@@ -0,0 +1,899 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{scalar_sin_cos, DMat3, DQuat, DVec3, DVec3Swizzles, DVec4, DVec4Swizzles};
+use crate::EulerRot;
+use core::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DMat4 = const_dmat4!([0.0; 16]);
+const IDENTITY: DMat4 = const_dmat4!(
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0]
+);
+
+/// Creates a `DMat4` from four column vectors.
+#[inline]
+pub fn dmat4(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4) -> DMat4 {
+    DMat4 {
+        x_axis,
+        y_axis,
+        z_axis,
+        w_axis,
+    }
+}
+
+#[inline]
+fn quat_to_axes(rotation: DQuat) -> (DVec4, DVec4, DVec4) {
+    glam_assert!(rotation.is_normalized());
+    let (x, y, z, w) = rotation.into();
+    let x2 = x + x;
+    let y2 = y + y;
+    let z2 = z + z;
+    let xx = x * x2;
+    let xy = x * y2;
+    let xz = x * z2;
+    let yy = y * y2;
+    let yz = y * z2;
+    let zz = z * z2;
+    let wx = w * x2;
+    let wy = w * y2;
+    let wz = w * z2;
+
+    let x_axis = DVec4::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
+    let y_axis = DVec4::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
+    let z_axis = DVec4::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
+    (x_axis, y_axis, z_axis)
+}
+
+/// A 4x4 column major matrix.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct DMat4 {
+    pub x_axis: DVec4,
+    pub y_axis: DVec4,
+    pub z_axis: DVec4,
+    pub w_axis: DVec4,
+}
+
+impl Default for DMat4 {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl fmt::Display for DMat4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            self.x_axis, self.y_axis, self.z_axis, self.w_axis
+        )
+    }
+}
+
+impl DMat4 {
+    /// Creates a 4x4 matrix with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a 4x4 identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates a 4x4 matrix from four column vectors.
+    #[inline]
+    pub fn from_cols(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a 4x4 matrix from a `[f64; 16]` stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the
+    /// returned matrix.
+    #[inline]
+    pub fn from_cols_array(m: &[f64; 16]) -> Self {
+        DMat4 {
+            x_axis: DVec4::new(m[0], m[1], m[2], m[3]),
+            y_axis: DVec4::new(m[4], m[5], m[6], m[7]),
+            z_axis: DVec4::new(m[8], m[9], m[10], m[11]),
+            w_axis: DVec4::new(m[12], m[13], m[14], m[15]),
+        }
+    }
+
+    /// Creates a `[f64; 16]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array(&self) -> [f64; 16] {
+        *self.as_ref()
+    }
+
+    /// Creates a 4x4 matrix from a `[[f64; 4]; 4]` stored in column major
+    /// order.  If your data is in row major order you will need to `transpose`
+    /// the returned matrix.
+    #[inline]
+    pub fn from_cols_array_2d(m: &[[f64; 4]; 4]) -> Self {
+        DMat4 {
+            x_axis: m[0].into(),
+            y_axis: m[1].into(),
+            z_axis: m[2].into(),
+            w_axis: m[3].into(),
+        }
+    }
+
+    /// Creates a `[[f64; 4]; 4]` storing data in column major order.
+    /// If you require data in row major order `transpose` the matrix first.
+    #[inline]
+    pub fn to_cols_array_2d(&self) -> [[f64; 4]; 4] {
+        [
+            self.x_axis.into(),
+            self.y_axis.into(),
+            self.z_axis.into(),
+            self.w_axis.into(),
+        ]
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix from the given `scale`,
+    /// `rotation` and `translation`.
+    #[inline]
+    pub fn from_scale_rotation_translation(
+        scale: DVec3,
+        rotation: DQuat,
+        translation: DVec3,
+    ) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+        let (scale_x, scale_y, scale_z) = scale.into();
+        Self {
+            x_axis: x_axis * scale_x,
+            y_axis: y_axis * scale_y,
+            z_axis: z_axis * scale_z,
+            w_axis: translation.extend(1.0),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix from the given `translation`.
+    #[inline]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis: translation.extend(1.0),
+        }
+    }
+
+    /// Extracts `scale`, `rotation` and `translation` from `self`. The input matrix is expected to
+    /// be a 4x4 homogeneous transformation matrix otherwise the output will be invalid.
+    pub fn to_scale_rotation_translation(&self) -> (DVec3, DQuat, DVec3) {
+        let det = self.determinant();
+        glam_assert!(det != 0.0);
+
+        let scale = DVec3::new(
+            self.x_axis.length() * det.signum(),
+            self.y_axis.length(),
+            self.z_axis.length(),
+        );
+        glam_assert!(scale.cmpne(DVec3::zero()).all());
+
+        let inv_scale = scale.recip();
+
+        let rotation = DQuat::from_rotation_mat3(&DMat3::from_cols(
+            self.x_axis.xyz() * inv_scale.xxx(),
+            self.y_axis.xyz() * inv_scale.yyy(),
+            self.z_axis.xyz() * inv_scale.zzz(),
+        ));
+
+        let translation = self.w_axis.xyz();
+
+        (scale, rotation, translation)
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix from the given `rotation`.
+    #[inline]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix from the given `translation`.
+    #[inline]
+    pub fn from_translation(translation: DVec3) -> Self {
+        Self {
+            x_axis: DVec4::unit_x(),
+            y_axis: DVec4::unit_y(),
+            z_axis: DVec4::unit_z(),
+            w_axis: translation.extend(1.0),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation
+    /// around a normalized rotation `axis` of `angle` (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (sin, cos) = scalar_sin_cos(angle);
+        let (x, y, z) = axis.into();
+        let (xsin, ysin, zsin) = (axis * sin).into();
+        let (x2, y2, z2) = (axis * axis).into();
+        let omc = 1.0 - cos;
+        let xyomc = x * y * omc;
+        let xzomc = x * z * omc;
+        let yzomc = y * z * omc;
+        Self {
+            x_axis: DVec4::new(x2 * omc + cos, xyomc + zsin, xzomc - ysin, 0.0),
+            y_axis: DVec4::new(xyomc - zsin, y2 * omc + cos, yzomc + xsin, 0.0),
+            z_axis: DVec4::new(xzomc + ysin, yzomc - xsin, z2 * omc + cos, 0.0),
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation
+    /// around the given Euler angles (in radians).
+    #[inline]
+    pub fn from_rotation_ypr(yaw: f64, pitch: f64, roll: f64) -> Self {
+        let quat = DQuat::from_rotation_ypr(yaw, pitch, roll);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation from the given Euler
+    /// rotation sequence and the angles (in radians).
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f64, b: f64, c: f64) -> Self {
+        let quat = DQuat::from_euler(euler, a, b, c);
+        Self::from_quat(quat)
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation
+    /// around the x axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec4::unit_x(),
+            y_axis: DVec4::new(0.0, cosa, sina, 0.0),
+            z_axis: DVec4::new(0.0, -sina, cosa, 0.0),
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation
+    /// around the y axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec4::new(cosa, 0.0, -sina, 0.0),
+            y_axis: DVec4::unit_y(),
+            z_axis: DVec4::new(sina, 0.0, cosa, 0.0),
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing a rotation
+    /// around the z axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (sina, cosa) = scalar_sin_cos(angle);
+        Self {
+            x_axis: DVec4::new(cosa, sina, 0.0, 0.0),
+            y_axis: DVec4::new(-sina, cosa, 0.0, 0.0),
+            z_axis: DVec4::unit_z(),
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Creates a 4x4 homogeneous transformation matrix containing the given
+    /// non-uniform `scale`.
+    #[inline]
+    pub fn from_scale(scale: DVec3) -> Self {
+        // Do not panic as long as any component is non-zero
+        glam_assert!(scale.cmpne(DVec3::zero()).any());
+        let (x, y, z) = scale.into();
+        Self {
+            x_axis: DVec4::new(x, 0.0, 0.0, 0.0),
+            y_axis: DVec4::new(0.0, y, 0.0, 0.0),
+            z_axis: DVec4::new(0.0, 0.0, z, 0.0),
+            w_axis: DVec4::unit_w(),
+        }
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite()
+            && self.y_axis.is_finite()
+            && self.z_axis.is_finite()
+            && self.w_axis.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan() || self.w_axis.is_nan()
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        Self {
+            x_axis: DVec4::new(m00, m10, m20, m30),
+            y_axis: DVec4::new(m01, m11, m21, m31),
+            z_axis: DVec4::new(m02, m12, m22, m32),
+            w_axis: DVec4::new(m03, m13, m23, m33),
+        }
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let a2323 = m22 * m33 - m23 * m32;
+        let a1323 = m21 * m33 - m23 * m31;
+        let a1223 = m21 * m32 - m22 * m31;
+        let a0323 = m20 * m33 - m23 * m30;
+        let a0223 = m20 * m32 - m22 * m30;
+        let a0123 = m20 * m31 - m21 * m30;
+
+        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If the matrix is not invertible the returned matrix will be invalid.
+    pub fn inverse(&self) -> Self {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let coef00 = m22 * m33 - m32 * m23;
+        let coef02 = m12 * m33 - m32 * m13;
+        let coef03 = m12 * m23 - m22 * m13;
+
+        let coef04 = m21 * m33 - m31 * m23;
+        let coef06 = m11 * m33 - m31 * m13;
+        let coef07 = m11 * m23 - m21 * m13;
+
+        let coef08 = m21 * m32 - m31 * m22;
+        let coef10 = m11 * m32 - m31 * m12;
+        let coef11 = m11 * m22 - m21 * m12;
+
+        let coef12 = m20 * m33 - m30 * m23;
+        let coef14 = m10 * m33 - m30 * m13;
+        let coef15 = m10 * m23 - m20 * m13;
+
+        let coef16 = m20 * m32 - m30 * m22;
+        let coef18 = m10 * m32 - m30 * m12;
+        let coef19 = m10 * m22 - m20 * m12;
+
+        let coef20 = m20 * m31 - m30 * m21;
+        let coef22 = m10 * m31 - m30 * m11;
+        let coef23 = m10 * m21 - m20 * m11;
+
+        let fac0 = DVec4::new(coef00, coef00, coef02, coef03);
+        let fac1 = DVec4::new(coef04, coef04, coef06, coef07);
+        let fac2 = DVec4::new(coef08, coef08, coef10, coef11);
+        let fac3 = DVec4::new(coef12, coef12, coef14, coef15);
+        let fac4 = DVec4::new(coef16, coef16, coef18, coef19);
+        let fac5 = DVec4::new(coef20, coef20, coef22, coef23);
+
+        let vec0 = DVec4::new(m10, m00, m00, m00);
+        let vec1 = DVec4::new(m11, m01, m01, m01);
+        let vec2 = DVec4::new(m12, m02, m02, m02);
+        let vec3 = DVec4::new(m13, m03, m03, m03);
+
+        let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
+        let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
+        let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
+        let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
+
+        let sign_a = DVec4::new(1.0, -1.0, 1.0, -1.0);
+        let sign_b = DVec4::new(-1.0, 1.0, -1.0, 1.0);
+
+        let inverse = Self {
+            x_axis: inv0 * sign_a,
+            y_axis: inv1 * sign_b,
+            z_axis: inv2 * sign_a,
+            w_axis: inv3 * sign_b,
+        };
+
+        let col0 = DVec4::new(
+            inverse.x_axis.x,
+            inverse.y_axis.x,
+            inverse.z_axis.x,
+            inverse.w_axis.x,
+        );
+
+        let dot0 = self.x_axis * col0;
+        let dot1 = dot0.x + dot0.y + dot0.z + dot0.w;
+
+        glam_assert!(dot1 != 0.0);
+
+        let rcp_det = 1.0 / dot1;
+        inverse * rcp_det
+    }
+
+    /// Returns the inverse of `self`, or `None` if the matrix is not invertible.
+    ///
+    /// The matrix is considered not invertible if the magnitude of its determinant is not
+    /// greater than `f64::EPSILON` times the product of the column lengths of its upper 3x3 part
+    /// and the length of its bottom row. For an affine matrix this is the largest determinant
+    /// possible for a linear part with those column lengths. This detects nearly singular matrices
+    /// regardless of their scale, and the translation doesn't affect the tolerance. `None` is also
+    /// returned if `self` or the computed inverse contain infinities or `NaN`s.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let bottom_row = DVec4::new(self.x_axis.w, self.y_axis.w, self.z_axis.w, self.w_axis.w);
+        let max_det = self.x_axis.truncate().length()
+            * self.y_axis.truncate().length()
+            * self.z_axis.truncate().length()
+            * bottom_row.length();
+        if det.abs() > core::f64::EPSILON * max_det {
+            let inverse = self.inverse();
+            if inverse.is_finite() {
+                return Some(inverse);
+            }
+        }
+        None
+    }
+
+    /// Returns the inverse of `self`, or the zero matrix if the matrix is not invertible.
+    ///
+    /// See `try_inverse` for how invertibility is determined.
+    #[inline]
+    pub fn inverse_or_zero(&self) -> Self {
+        self.try_inverse().unwrap_or_else(Self::zero)
+    }
+
+    /// Creates a left-handed view matrix using a camera position, an up direction, and a camera
+    /// direction.
+    #[inline]
+    // TODO: make public at some point
+    fn look_to_lh(eye: DVec3, dir: DVec3, up: DVec3) -> Self {
+        let f = dir.normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+        let (fx, fy, fz) = f.into();
+        let (sx, sy, sz) = s.into();
+        let (ux, uy, uz) = u.into();
+        DMat4::from_cols(
+            DVec4::new(sx, ux, fx, 0.0),
+            DVec4::new(sy, uy, fy, 0.0),
+            DVec4::new(sz, uz, fz, 0.0),
+            DVec4::new(-s.dot(eye), -u.dot(eye), -f.dot(eye), 1.0),
+        )
+    }
+
+    /// Creates a left-handed view matrix using a camera position, an up direction, and a focal
+    /// point.
+    #[inline]
+    pub fn look_at_lh(eye: DVec3, center: DVec3, up: DVec3) -> Self {
+        glam_assert!(up.is_normalized());
+        DMat4::look_to_lh(eye, center - eye, up)
+    }
+
+    /// Creates a right-handed view matrix using a camera position, an up direction, and a focal
+    /// point.
+    #[inline]
+    pub fn look_at_rh(eye: DVec3, center: DVec3, up: DVec3) -> Self {
+        glam_assert!(up.is_normalized());
+        DMat4::look_to_lh(eye, eye - center, up)
+    }
+
+    /// Creates a right-handed perspective projection matrix with [-1,1] depth range.
+    /// This is the same as the OpenGL `gluPerspective` function.
+    /// See https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluPerspective.xml
+    pub fn perspective_rh_gl(
+        fov_y_radians: f64,
+        aspect_ratio: f64,
+        z_near: f64,
+        z_far: f64,
+    ) -> Self {
+        let inv_length = 1.0 / (z_near - z_far);
+        let f = 1.0 / (0.5 * fov_y_radians).tan();
+        let a = f / aspect_ratio;
+        let b = (z_near + z_far) * inv_length;
+        let c = (2.0 * z_near * z_far) * inv_length;
+        DMat4::from_cols(
+            DVec4::new(a, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, f, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, b, -1.0),
+            DVec4::new(0.0, 0.0, c, 0.0),
+        )
+    }
+
+    /// Creates a left-handed perspective projection matrix with [0,1] depth range.
+    pub fn perspective_lh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64, z_far: f64) -> Self {
+        glam_assert!(z_near > 0.0 && z_far > 0.0);
+        let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
+        let h = cos_fov / sin_fov;
+        let w = h / aspect_ratio;
+        let r = z_far / (z_far - z_near);
+        DMat4::from_cols(
+            DVec4::new(w, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, h, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, 1.0),
+            DVec4::new(0.0, 0.0, -r * z_near, 0.0),
+        )
+    }
+
+    /// Creates a right-handed perspective projection matrix with [0,1] depth range.
+    pub fn perspective_rh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64, z_far: f64) -> Self {
+        glam_assert!(z_near > 0.0 && z_far > 0.0);
+        let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
+        let h = cos_fov / sin_fov;
+        let w = h / aspect_ratio;
+        let r = z_far / (z_near - z_far);
+        DMat4::from_cols(
+            DVec4::new(w, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, h, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, -1.0),
+            DVec4::new(0.0, 0.0, r * z_near, 0.0),
+        )
+    }
+
+    /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
+    pub fn perspective_infinite_lh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64) -> Self {
+        glam_assert!(z_near > 0.0);
+        let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
+        let h = cos_fov / sin_fov;
+        let w = h / aspect_ratio;
+        DMat4::from_cols(
+            DVec4::new(w, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, h, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, 1.0, 1.0),
+            DVec4::new(0.0, 0.0, -z_near, 0.0),
+        )
+    }
+
+    /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
+    pub fn perspective_infinite_reverse_lh(
+        fov_y_radians: f64,
+        aspect_ratio: f64,
+        z_near: f64,
+    ) -> Self {
+        glam_assert!(z_near > 0.0);
+        let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
+        let h = cos_fov / sin_fov;
+        let w = h / aspect_ratio;
+        DMat4::from_cols(
+            DVec4::new(w, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, h, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, 0.0, 1.0),
+            DVec4::new(0.0, 0.0, z_near, 0.0),
+        )
+    }
+
+    /// Creates an infinite right-handed perspective projection matrix with
+    /// [0,1] depth range.
+    pub fn perspective_infinite_rh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64) -> Self {
+        let f = 1.0 / (0.5 * fov_y_radians).tan();
+        DMat4::from_cols(
+            DVec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, f, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, -1.0, -1.0),
+            DVec4::new(0.0, 0.0, -z_near, 0.0),
+        )
+    }
+
+    /// Creates an infinite reverse right-handed perspective projection matrix
+    /// with [0,1] depth range.
+    pub fn perspective_infinite_reverse_rh(
+        fov_y_radians: f64,
+        aspect_ratio: f64,
+        z_near: f64,
+    ) -> Self {
+        let f = 1.0 / (0.5 * fov_y_radians).tan();
+        DMat4::from_cols(
+            DVec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, f, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, 0.0, -1.0),
+            DVec4::new(0.0, 0.0, z_near, 0.0),
+        )
+    }
+
+    /// Creates a right-handed orthographic projection matrix with [-1,1] depth
+    /// range.  This is the same as the OpenGL `glOrtho` function in OpenGL.
+    /// See
+    /// https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/glOrtho.xml
+    pub fn orthographic_rh_gl(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        let a = 2.0 / (right - left);
+        let b = 2.0 / (top - bottom);
+        let c = -2.0 / (far - near);
+        let tx = -(right + left) / (right - left);
+        let ty = -(top + bottom) / (top - bottom);
+        let tz = -(far + near) / (far - near);
+
+        DMat4::from_cols(
+            DVec4::new(a, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, b, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, c, 0.0),
+            DVec4::new(tx, ty, tz, 1.0),
+        )
+    }
+
+    /// Creates a left-handed orthographic projection matrix with [0,1] depth range.
+    pub fn orthographic_lh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (top - bottom);
+        let r = 1.0 / (far - near);
+        DMat4::from_cols(
+            DVec4::new(rcp_width + rcp_width, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, rcp_height + rcp_height, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, 0.0),
+            DVec4::new(
+                -(left + right) * rcp_width,
+                -(top + bottom) * rcp_height,
+                -r * near,
+                1.0,
+            ),
+        )
+    }
+
+    /// Creates a right-handed orthographic projection matrix with [0,1] depth range.
+    pub fn orthographic_rh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (top - bottom);
+        let r = 1.0 / (near - far);
+        DMat4::from_cols(
+            DVec4::new(rcp_width + rcp_width, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, rcp_height + rcp_height, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, 0.0),
+            DVec4::new(
+                -(left + right) * rcp_width,
+                -(top + bottom) * rcp_height,
+                r * near,
+                1.0,
+            ),
+        )
+    }
+
+    /// Transforms a 4D vector.
+    #[inline]
+    pub fn mul_vec4(&self, other: DVec4) -> DVec4 {
+        let mut res = self.x_axis * other.xxxx();
+        res = self.y_axis.mul_add(other.yyyy(), res);
+        res = self.z_axis.mul_add(other.zzzz(), res);
+        res = self.w_axis.mul_add(other.wwww(), res);
+        res
+    }
+
+    /// Multiplies two 4x4 matrices.
+    #[inline]
+    pub fn mul_mat4(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec4(other.x_axis),
+            y_axis: self.mul_vec4(other.y_axis),
+            z_axis: self.mul_vec4(other.z_axis),
+            w_axis: self.mul_vec4(other.w_axis),
+        }
+    }
+
+    /// Adds two 4x4 matrices.
+    #[inline]
+    pub fn add_mat4(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis + other.x_axis,
+            y_axis: self.y_axis + other.y_axis,
+            z_axis: self.z_axis + other.z_axis,
+            w_axis: self.w_axis + other.w_axis,
+        }
+    }
+
+    /// Subtracts two 4x4 matrices.
+    #[inline]
+    pub fn sub_mat4(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis - other.x_axis,
+            y_axis: self.y_axis - other.y_axis,
+            z_axis: self.z_axis - other.z_axis,
+            w_axis: self.w_axis - other.w_axis,
+        }
+    }
+
+    /// Multiplies this matrix by a scalar value.
+    #[inline]
+    pub fn mul_scalar(&self, other: f64) -> Self {
+        let s = DVec4::splat(other);
+        Self {
+            x_axis: self.x_axis * s,
+            y_axis: self.y_axis * s,
+            z_axis: self.z_axis * s,
+            w_axis: self.w_axis * s,
+        }
+    }
+
+    /// Transforms the given `DVec3` as 3D point.
+    ///
+    /// This is the equivalent of multiplying the `DVec3` as a `DVec4` where `w` is `1.0`.
+    #[inline]
+    pub fn transform_point3(&self, other: DVec3) -> DVec3 {
+        let mut res = self.x_axis.mul(DVec4::splat(other.x));
+        res = self.y_axis.mul_add(DVec4::splat(other.y), res);
+        res = self.z_axis.mul_add(DVec4::splat(other.z), res);
+        res = self.w_axis.add(res);
+        res = res.mul(res.wwww().recip());
+        res.xyz()
+    }
+
+    /// Transforms the give `DVec3` as 3D vector.
+    ///
+    /// This is the equivalent of multiplying the `DVec3` as a `DVec4` where `w` is `0.0`.
+    #[inline]
+    pub fn transform_vector3(&self, other: DVec3) -> DVec3 {
+        let mut res = self.x_axis.mul(DVec4::splat(other.x));
+        res = self.y_axis.mul_add(DVec4::splat(other.y), res);
+        res = self.z_axis.mul_add(DVec4::splat(other.z), res);
+        res.xyz()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self` and `other` is less
+    /// than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DMat4`'s contain similar elements. It works best when
+    /// comparing with a known value. The `max_abs_diff` that should be used used depends on the
+    /// values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+            && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(&self) -> crate::Mat4 {
+        crate::Mat4::from_cols(
+            self.x_axis.as_f32(),
+            self.y_axis.as_f32(),
+            self.z_axis.as_f32(),
+            self.w_axis.as_f32(),
+        )
+    }
+}
+
+impl AsRef<[f64; 16]> for DMat4 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 16] {
+        unsafe { &*(self as *const Self as *const [f64; 16]) }
+    }
+}
+
+impl AsMut<[f64; 16]> for DMat4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 16] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 16]) }
+    }
+}
+
+impl Add<DMat4> for DMat4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.add_mat4(&other)
+    }
+}
+
+impl Sub<DMat4> for DMat4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.sub_mat4(&other)
+    }
+}
+
+impl Mul<DMat4> for DMat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat4(&other)
+    }
+}
+
+impl Mul<DVec4> for DMat4 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, other: DVec4) -> DVec4 {
+        self.mul_vec4(other)
+    }
+}
+
+impl Mul<DMat4> for f64 {
+    type Output = DMat4;
+    #[inline]
+    fn mul(self, other: DMat4) -> DMat4 {
+        other.mul_scalar(self)
+    }
+}
+
+impl Mul<f64> for DMat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        self.mul_scalar(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DMat4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DMat4 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
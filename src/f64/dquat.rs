@@ -0,0 +1,1141 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{scalar_acos, scalar_sin_cos, DMat3, DMat4, DVec2, DVec3, DVec4, DVec4Swizzles};
+use crate::EulerRot;
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Deref, Div, Mul, MulAssign, Neg, Sub},
+};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+#[cfg(feature = "std")]
+const ZERO: DQuat = const_dquat!([0.0, 0.0, 0.0, 0.0]);
+const IDENTITY: DQuat = const_dquat!([0.0, 0.0, 0.0, 1.0]);
+
+/// A quaternion representing an orientation.
+///
+/// This quaternion is intended to be of unit length but may denormalize due to
+/// floating point "error creep" which can occur when successive quaternion
+/// operations are applied.
+#[cfg(doc)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DQuat {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+#[cfg(not(doc))]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DQuat(pub(crate) DVec4);
+
+/// Creates a `DQuat` from `x`, `y`, `z` and `w` values.
+///
+/// This should generally not be called manually unless you know what you are doing. Use one of
+/// the other constructors instead such as `identity` or `from_axis_angle`.
+#[inline]
+pub fn dquat(x: f64, y: f64, z: f64, w: f64) -> DQuat {
+    DQuat::from_xyzw(x, y, z, w)
+}
+
+impl DQuat {
+    /// Creates a new rotation quaternion.
+    ///
+    /// This should generally not be called manually unless you know what you are doing. Use one of
+    /// the other constructors instead such as `identity` or `from_axis_angle`.
+    ///
+    /// `from_xyzw` is mostly used by unit tests and `serde` deserialization.
+    #[inline]
+    pub fn from_xyzw(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self(DVec4::new(x, y, z, w))
+    }
+
+    #[inline]
+    pub const fn identity() -> Self {
+        IDENTITY
+    }
+
+    /// Creates a rotation quaternion from an unaligned `&[f64]`.
+    ///
+    /// # Preconditions
+    ///
+    /// The resulting quaternion is expected to be of unit length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` length is less than 4.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f64]) -> Self {
+        #[allow(clippy::let_and_return)]
+        let q = Self(DVec4::from_slice_unaligned(slice));
+        glam_assert!(q.is_normalized());
+        q
+    }
+
+    /// Writes the quaternion to an unaligned `&mut [f64]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` length is less than 4.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f64]) {
+        self.0.write_to_slice_unaligned(slice)
+    }
+
+    /// Create a quaterion for a normalized rotation axis and angle (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        Self((axis * s).extend(c))
+    }
+
+    /// Creates a quaternion from the angle (in radians) around the x axis.
+    #[inline]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        Self::from_xyzw(s, 0.0, 0.0, c)
+    }
+
+    /// Creates a quaternion from the angle (in radians) around the y axis.
+    #[inline]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        Self::from_xyzw(0.0, s, 0.0, c)
+    }
+
+    /// Creates a quaternion from the angle (in radians) around the z axis.
+    #[inline]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        Self::from_xyzw(0.0, 0.0, s, c)
+    }
+
+    #[inline]
+    /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
+    /// in radians.
+    ///
+    /// This is equivalent to `from_euler(EulerRot::YXZ, yaw, pitch, roll)`.
+    pub fn from_rotation_ypr(yaw: f64, pitch: f64, roll: f64) -> Self {
+        // Self::from_rotation_y(yaw) * Self::from_rotation_x(pitch) * Self::from_rotation_z(roll)
+        let (y0, w0) = scalar_sin_cos(yaw * 0.5);
+        let (x1, w1) = scalar_sin_cos(pitch * 0.5);
+        let (z2, w2) = scalar_sin_cos(roll * 0.5);
+
+        let x3 = w0 * x1;
+        let y3 = y0 * w1;
+        let z3 = -y0 * x1;
+        let w3 = w0 * w1;
+
+        let x4 = x3 * w2 + y3 * z2;
+        let y4 = -x3 * z2 + y3 * w2;
+        let z4 = w3 * z2 + z3 * w2;
+        let w4 = w3 * w2 - z3 * z2;
+
+        Self(DVec4::new(x4, y4, z4, w4))
+    }
+
+    /// Creates a quaternion from the given Euler rotation sequence and the angles (in radians).
+    ///
+    /// The angles `a`, `b` and `c` are applied about the first, second and third axis of `euler`
+    /// respectively, e.g. `EulerRot::XYZ` gives `Rx(a) * Ry(b) * Rz(c)`.
+    #[inline]
+    pub fn from_euler(euler: EulerRot, a: f64, b: f64, c: f64) -> Self {
+        let (i, j, k) = euler.axes();
+        Self::from_rotation_axis_index(i, a)
+            * Self::from_rotation_axis_index(j, b)
+            * Self::from_rotation_axis_index(k, c)
+    }
+
+    #[inline]
+    fn from_rotation_axis_index(axis: usize, angle: f64) -> Self {
+        let (s, c) = scalar_sin_cos(angle * 0.5);
+        let mut v = [0.0, 0.0, 0.0, c];
+        v[axis] = s;
+        Self(DVec4::from(v))
+    }
+
+    #[inline]
+    fn from_rotation_axes(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+        // Based on https://github.com/microsoft/DirectXMath `XMQuaternionRotationMatrix`
+        let (m00, m01, m02) = x_axis.into();
+        let (m10, m11, m12) = y_axis.into();
+        let (m20, m21, m22) = z_axis.into();
+        if m22 <= 0.0 {
+            // x^2 + y^2 >= z^2 + w^2
+            let dif10 = m11 - m00;
+            let omm22 = 1.0 - m22;
+            if dif10 <= 0.0 {
+                // x^2 >= y^2
+                let four_xsq = omm22 - dif10;
+                let inv4x = 0.5 / four_xsq.sqrt();
+                Self::from_xyzw(
+                    four_xsq * inv4x,
+                    (m01 + m10) * inv4x,
+                    (m02 + m20) * inv4x,
+                    (m12 - m21) * inv4x,
+                )
+            } else {
+                // y^2 >= x^2
+                let four_ysq = omm22 + dif10;
+                let inv4y = 0.5 / four_ysq.sqrt();
+                Self::from_xyzw(
+                    (m01 + m10) * inv4y,
+                    four_ysq * inv4y,
+                    (m12 + m21) * inv4y,
+                    (m20 - m02) * inv4y,
+                )
+            }
+        } else {
+            // z^2 + w^2 >= x^2 + y^2
+            let sum10 = m11 + m00;
+            let opm22 = 1.0 + m22;
+            if sum10 <= 0.0 {
+                // z^2 >= w^2
+                let four_zsq = opm22 - sum10;
+                let inv4z = 0.5 / four_zsq.sqrt();
+                Self::from_xyzw(
+                    (m02 + m20) * inv4z,
+                    (m12 + m21) * inv4z,
+                    four_zsq * inv4z,
+                    (m01 - m10) * inv4z,
+                )
+            } else {
+                // w^2 >= z^2
+                let four_wsq = opm22 + sum10;
+                let inv4w = 0.5 / four_wsq.sqrt();
+                Self::from_xyzw(
+                    (m12 - m21) * inv4w,
+                    (m20 - m02) * inv4w,
+                    (m01 - m10) * inv4w,
+                    four_wsq * inv4w,
+                )
+            }
+        }
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix.
+    #[inline]
+    pub fn from_rotation_mat3(mat: &DMat3) -> Self {
+        Self::from_rotation_axes(mat.x_axis, mat.y_axis, mat.z_axis)
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
+    #[inline]
+    pub fn from_rotation_mat4(mat: &DMat4) -> Self {
+        Self::from_rotation_axes(mat.x_axis.xyz(), mat.y_axis.xyz(), mat.z_axis.xyz())
+    }
+
+    /// Returns the shortest rotation that transforms the normalized vector `from` onto the
+    /// normalized vector `to`.
+    ///
+    /// If `from` and `to` are antiparallel the result is a half turn about an arbitrary axis
+    /// perpendicular to `from`.
+    pub fn from_rotation_arc(from: DVec3, to: DVec3) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        const ONE_MINUS_EPSILON: f64 = 1.0 - 2.0 * core::f64::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPSILON {
+            // parallel
+            Self::identity()
+        } else if dot < -ONE_MINUS_EPSILON {
+            // antiparallel, so any axis perpendicular to `from` gives a shortest rotation
+            let other = if from.x.abs() < 0.9 {
+                DVec3::unit_x()
+            } else {
+                DVec3::unit_y()
+            };
+            Self::from_axis_angle(from.cross(other).normalize(), core::f64::consts::PI)
+        } else {
+            // the half way quaternion between the identity and the rotation by twice the angle
+            let c = from.cross(to);
+            Self::from_xyzw(c.x, c.y, c.z, 1.0 + dot).normalize()
+        }
+    }
+
+    /// Returns the shortest rotation that transforms the normalized vector `from` onto either
+    /// the normalized vector `to` or `-to`, whichever is closer.
+    ///
+    /// The rotation is in the plane of `from` and `to` and is never more than a quarter turn, so
+    /// if `from` and `to` are parallel or antiparallel the result is the identity. This is useful
+    /// when the sign of a direction doesn't matter, such as when aligning with a line or an axis.
+    #[inline]
+    pub fn from_rotation_arc_colinear(from: DVec3, to: DVec3) -> Self {
+        if from.dot(to) < 0.0 {
+            Self::from_rotation_arc(from, -to)
+        } else {
+            Self::from_rotation_arc(from, to)
+        }
+    }
+
+    /// Returns the rotation about the z axis that transforms the normalized 2D vector `from` onto
+    /// the normalized 2D vector `to`.
+    ///
+    /// If `from` and `to` are antiparallel the result is a half turn about the z axis.
+    pub fn from_rotation_arc_2d(from: DVec2, to: DVec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        const ONE_MINUS_EPSILON: f64 = 1.0 - 2.0 * core::f64::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPSILON {
+            Self::identity()
+        } else if dot < -ONE_MINUS_EPSILON {
+            Self::from_rotation_z(core::f64::consts::PI)
+        } else {
+            Self::from_xyzw(0.0, 0.0, from.perp_dot(to), 1.0 + dot).normalize()
+        }
+    }
+
+    /// Returns the rotation that transforms the positive z axis onto `forward` and the positive
+    /// y axis onto the plane of `forward` and `up`.
+    ///
+    /// This is the orientation of a left-handed camera, i.e. the inverse of the rotation of
+    /// `DMat4::look_at_lh(eye, eye + forward, up)`. Neither vector needs to be normalized. If
+    /// `forward` is zero the result is the identity, and if `up` is zero or parallel to `forward`
+    /// the result is the shortest rotation of the positive z axis onto `forward`.
+    pub fn look_rotation(forward: DVec3, up: DVec3) -> Self {
+        const EPSILON_SQUARED: f64 = 1.0e-12;
+        let forward_length_sq = forward.length_squared();
+        if forward_length_sq < EPSILON_SQUARED {
+            return Self::identity();
+        }
+        let forward = forward / forward_length_sq.sqrt();
+        let side = up.cross(forward);
+        let side_length_sq = side.length_squared();
+        if side_length_sq <= EPSILON_SQUARED * up.length_squared() {
+            return Self::from_rotation_arc(DVec3::unit_z(), forward);
+        }
+        let side = side / side_length_sq.sqrt();
+        Self::from_rotation_axes(side, forward.cross(side), forward)
+    }
+
+    /// Returns the rotation that transforms the negative z axis onto `forward` and the positive
+    /// y axis onto the plane of `forward` and `up`.
+    ///
+    /// This is the orientation of a right-handed camera, i.e. the inverse of the rotation of
+    /// `DMat4::look_at_rh(eye, eye + forward, up)`. Degenerate inputs are handled the same way as
+    /// `DQuat::look_rotation`.
+    #[inline]
+    pub fn look_rotation_rh(forward: DVec3, up: DVec3) -> Self {
+        Self::look_rotation(-forward, up)
+    }
+
+    /// Decomposes `self` into a swing and a twist about the normalized `axis`, such that
+    /// `self == swing * twist`.
+    ///
+    /// The twist is the rotation of `self` about `axis` and the swing is the remaining rotation
+    /// about an axis perpendicular to `axis`. The twist is returned with a non-negative `w`, so
+    /// its angle is in the range `[-PI, PI]`. If `self` is a half turn about an axis perpendicular
+    /// to `axis` the twist is undefined and the identity is returned.
+    pub fn swing_twist(self, axis: DVec3) -> (Self, Self) {
+        glam_assert!(self.is_normalized());
+        glam_assert!(axis.is_normalized());
+        const EPSILON_SQUARED: f64 = 1.0e-12;
+        let (x, y, z, w) = self.0.into();
+        let projected = axis * DVec3::new(x, y, z).dot(axis);
+        let twist = Self::from_xyzw(projected.x, projected.y, projected.z, w);
+        let length_sq = twist.length_squared();
+        if length_sq < EPSILON_SQUARED {
+            return (self, Self::identity());
+        }
+        let twist = twist * (length_sq.sqrt().recip() * if w < 0.0 { -1.0 } else { 1.0 });
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Limits the twist of `self` about the normalized `axis` to the range `[min_angle,
+    /// max_angle]` (in radians), leaving the swing unchanged.
+    ///
+    /// The angles must be in the range `[-PI, PI]`. See `DQuat::swing_twist` for the definition of
+    /// the twist.
+    pub fn clamp_twist(self, axis: DVec3, min_angle: f64, max_angle: f64) -> Self {
+        glam_assert!(min_angle <= max_angle);
+        let (swing, twist) = self.swing_twist(axis);
+        let (x, y, z, w) = twist.0.into();
+        let angle = DVec3::new(x, y, z).dot(axis).atan2(w) * 2.0;
+        if angle < min_angle {
+            swing * Self::from_axis_angle(axis, min_angle)
+        } else if angle > max_angle {
+            swing * Self::from_axis_angle(axis, max_angle)
+        } else {
+            self
+        }
+    }
+
+    /// Limits the swing of `self` away from the normalized `twist_axis` to an elliptical cone,
+    /// leaving the twist unchanged.
+    ///
+    /// The cone allows a swing of up to `max_angle_1` (in radians) about the normalized
+    /// `swing_axis`, which must be perpendicular to `twist_axis`, and up to `max_angle_2` about
+    /// `twist_axis.cross(swing_axis)`. A swing outside of the cone is scaled back towards the
+    /// identity until it is on the boundary. See `DQuat::swing_twist` for the definition of the
+    /// swing.
+    pub fn clamp_swing(
+        self,
+        twist_axis: DVec3,
+        swing_axis: DVec3,
+        max_angle_1: f64,
+        max_angle_2: f64,
+    ) -> Self {
+        glam_assert!(swing_axis.is_normalized());
+        glam_assert!(twist_axis.dot(swing_axis).abs() < 1.0e-4);
+        glam_assert!(max_angle_1 >= 0.0 && max_angle_2 >= 0.0);
+        let (swing, twist) = self.swing_twist(twist_axis);
+        // take the shortest path so the swing angle is at most PI
+        let swing = if swing.w < 0.0 { -swing } else { swing };
+        let scaled_axis = swing.to_scaled_axis();
+        let other_axis = twist_axis.cross(swing_axis);
+        let angle_1 = scaled_axis.dot(swing_axis);
+        let angle_2 = scaled_axis.dot(other_axis);
+        // the squared distance of the swing from the identity relative to the cone boundary,
+        // where a zero angle is always within the limit even if the limit is zero
+        let ratio = |angle: f64, max_angle: f64| {
+            if angle == 0.0 {
+                0.0
+            } else {
+                let r = angle / max_angle;
+                r * r
+            }
+        };
+        let distance_sq = ratio(angle_1, max_angle_1) + ratio(angle_2, max_angle_2);
+        if distance_sq <= 1.0 {
+            return self;
+        }
+        let scale = distance_sq.sqrt().recip();
+        let clamped = (swing_axis * angle_1 + other_axis * angle_2) * scale;
+        Self::from_scaled_axis(clamped) * twist
+    }
+
+    /// Returns the rotation axis and angle of `self`.
+    #[inline]
+    pub fn to_axis_angle(self) -> (DVec3, f64) {
+        const EPSILON: f64 = 1.0e-8;
+        const EPSILON_SQUARED: f64 = EPSILON * EPSILON;
+        let (x, y, z, w) = self.0.into();
+        let angle = scalar_acos(w) * 2.0;
+        let scale_sq = (1.0 - w * w).max(0.0);
+        if scale_sq >= EPSILON_SQUARED {
+            (DVec3::new(x, y, z) / scale_sq.sqrt(), angle)
+        } else {
+            (DVec3::unit_x(), angle)
+        }
+    }
+
+    /// Returns the rotation angles (in radians) for the given Euler rotation sequence, such that
+    /// `DQuat::from_euler(euler, a, b, c)` gives the same rotation as `self`.
+    ///
+    /// For Tait-Bryan sequences such as `EulerRot::XYZ` the second angle is in the range
+    /// `[-PI/2, PI/2]`, for proper Euler sequences such as `EulerRot::ZXZ` it is in `[0, PI]`. The
+    /// first and third angles are in `[-PI, PI]`.
+    ///
+    /// When the rotation is in gimbal lock, i.e. the first and third axes are aligned, only the
+    /// sum or difference of the first and third angles is defined. In this case the third angle
+    /// is set to zero and the first angle holds the whole rotation about that axis.
+    pub fn to_euler(self, euler: EulerRot) -> (f64, f64, f64) {
+        // Based on "Quaternion to Euler angles conversion: A direct, general and computationally
+        // efficient method" by Bernardes and Viollet, which is formulated for extrinsic rotations.
+        // An intrinsic sequence `i, j, k` is the extrinsic sequence `k, j, i` with the angles
+        // reversed.
+        const EPSILON: f64 = 1.0e-12;
+        glam_assert!(self.is_normalized());
+
+        let (k, j, i) = euler.axes();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        // +1 for an even permutation of the axes, -1 for an odd one
+        let sign = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+
+        let q: [f64; 4] = self.0.into();
+        let w = q[3];
+        let (a, b, c, d) = if proper {
+            (w, q[i], q[j], q[k] * sign)
+        } else {
+            (w - q[j], q[i] + q[k] * sign, q[j] + w, q[k] * sign - q[i])
+        };
+
+        let mut second = 2.0 * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+
+        let (first, mut third) = if second.abs() <= EPSILON {
+            // gimbal lock, only the sum of the first and third angles is known
+            (0.0, 2.0 * half_sum)
+        } else if (second - core::f64::consts::PI).abs() <= EPSILON {
+            // gimbal lock, only the difference of the first and third angles is known
+            (0.0, 2.0 * half_diff)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        if !proper {
+            third *= sign;
+            second -= core::f64::consts::FRAC_PI_2;
+        }
+
+        // the third extrinsic angle is the first intrinsic one
+        (wrap_angle(third), second, wrap_angle(first))
+    }
+
+    /// Returns the quaternion conjugate of `self`. For a unit quaternion the
+    /// conjugate is also the inverse.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self::from_xyzw(-self.0.x, -self.0.y, -self.0.z, self.0.w)
+    }
+
+    /// Computes the dot product of `self` and `other`. The dot product is
+    /// equal to the the cosine of the angle between two quaterion rotations.
+    #[inline]
+    pub fn dot(self, other: Self) -> f64 {
+        self.0.dot(other.0)
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        self.0.length()
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `DQuat::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.0.length_squared()
+    }
+
+    /// Computes `1.0 / DQuat::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.0.length_recip()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let inv_len = self.0.length_recip();
+        Self(self.0.mul(inv_len))
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(Self(self.0.mul(rcp)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns a quaternion with all
+    /// elements set to zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize()
+            .unwrap_or_else(|| Self::from_xyzw(0.0, 0.0, 0.0, 0.0))
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    #[inline]
+    pub fn is_near_identity(self) -> bool {
+        // Based on https://github.com/nfrechette/rtm `rtm::quat_near_identity`
+        const THRESHOLD_ANGLE: f64 = 0.002_847_144_6;
+        // An error threshold of 1.e-6 is used by default.
+        // (1.0 - 1.e-6).acos() * 2.0 = 0.00284714461 rad
+        // (1.0 - 1.e-7).acos() * 2.0 = 0.00097656250 rad
+        //
+        // We don't really care about the angle value itself, only if it's close to 0.
+        // This will happen whenever quat.w is close to 1.0.
+        // If the quat.w is close to -1.0, the angle will be near 2*PI which is close to
+        // a negative 0 rotation. By forcing quat.w to be positive, we'll end up with
+        // the shortest path.
+        let positive_w_angle = scalar_acos(self.0.w.abs()) * 2.0;
+        positive_w_angle < THRESHOLD_ANGLE
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DQuat`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        self.0.abs_diff_eq(other.0, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(self) -> crate::Quat {
+        crate::Quat::from_xyzw(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, end: Self, s: f64) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        let start = self.0;
+        let end = end.0;
+        let dot = start.dot(end);
+        let bias = if dot >= 0.0 { 1.0 } else { -1.0 };
+        let interpolated = start + (s * ((end * bias) - start));
+        Self(interpolated.normalize())
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// Note that a rotation can be represented by two quaternions: `q` and
+    /// `-q`. The slerp path between `q` and `end` will be different from the
+    /// path between `-q` and `end`. One path will take the long way around and
+    /// one will take the short way. In order to correct for this, the `dot`
+    /// product between `self` and `end` should be positive. If the `dot`
+    /// product is negative, slerp between `-self` and `end`.
+    #[inline]
+    pub fn slerp(self, end: Self, s: f64) -> Self {
+        // http://number-none.com/product/Understanding%20Slerp,%20Then%20Not%20Using%20It/
+
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        const DOT_THRESHOLD: f64 = 0.9995;
+
+        let dot = self.dot(end);
+
+        if dot > DOT_THRESHOLD {
+            // assumes lerp returns a normalized quaternion
+            self.lerp(end, s)
+        } else {
+            // assumes scalar_acos clamps the input to [-1.0, 1.0]
+            let theta = scalar_acos(dot);
+            let scale1 = f64::sin(theta * (1.0 - s));
+            let scale2 = f64::sin(theta * s);
+            let theta_sin = f64::sin(theta);
+
+            DQuat((self.0 * scale1 + end.0 * scale2) * theta_sin.recip())
+        }
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// For a normalized quaternion the result has a zero `w` and its vector part is the rotation
+    /// axis scaled by half of the rotation angle.
+    #[inline]
+    pub fn ln(self) -> Self {
+        let (x, y, z, w) = self.0.into();
+        let v = DVec3::new(x, y, z);
+        let v_len = v.length();
+        let len = (v_len * v_len + w * w).sqrt();
+        let scale = if v_len > 0.0 {
+            v_len.atan2(w) / v_len
+        } else {
+            0.0
+        };
+        (v * scale).extend(len.ln()).into()
+    }
+
+    /// Returns the exponential of `self`.
+    ///
+    /// This is the inverse of `DQuat::ln`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        let (x, y, z, w) = self.0.into();
+        let v = DVec3::new(x, y, z);
+        let v_len = v.length();
+        let (sin, cos) = scalar_sin_cos(v_len);
+        let exp_w = w.exp();
+        let scale = if v_len > 0.0 { sin / v_len } else { 1.0 };
+        (v * (scale * exp_w)).extend(cos * exp_w).into()
+    }
+
+    /// Returns `self` raised to the power `n`.
+    ///
+    /// For a normalized quaternion this scales the rotation angle by `n`, keeping the same
+    /// rotation axis.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        (self.ln() * n).exp()
+    }
+
+    /// Creates a quaternion from a rotation vector, whose direction is the rotation axis and
+    /// whose length is the rotation angle (in radians).
+    #[inline]
+    pub fn from_scaled_axis(v: DVec3) -> Self {
+        Self::from((v * 0.5).extend(0.0)).exp()
+    }
+
+    /// Returns the rotation vector of `self`, whose direction is the rotation axis and whose
+    /// length is the rotation angle (in radians).
+    ///
+    /// The angle is in the range `[0, 2*PI]`, negate `self` first if its `w` is negative to
+    /// get an angle no larger than `PI`.
+    #[inline]
+    pub fn to_scaled_axis(self) -> DVec3 {
+        glam_assert!(self.is_normalized());
+        let (x, y, z, _) = self.ln().0.into();
+        DVec3::new(x, y, z) * 2.0
+    }
+
+    /// Performs a spherical cubic interpolation between `self` and `end` based on the value `s`,
+    /// using the tangents `start_tangent` and `end_tangent`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result will
+    /// be equal to `end`.
+    ///
+    /// Interpolating each segment of a sequence of keyframes using tangents computed with
+    /// `DQuat::squad_tangent` gives a path through the keyframes with a continuous angular
+    /// velocity. Consecutive keyframes should have a non-negative dot product so each segment
+    /// takes the shortest path.
+    ///
+    /// All four quaternions must be normalized. The result is normalized, the intermediate
+    /// interpolations are renormalized so rounding errors don't build up along the path.
+    #[inline]
+    pub fn squad(self, end: Self, start_tangent: Self, end_tangent: Self, s: f64) -> Self {
+        // Based on "Quaternion Calculus and Fast Animation" by Ken Shoemake
+        let path = self.slerp(end, s).normalize();
+        let tangent = start_tangent.slerp(end_tangent, s).normalize();
+        path.slerp(tangent, 2.0 * s * (1.0 - s)).normalize()
+    }
+
+    /// Computes the tangent at the keyframe `self` for use with `DQuat::squad`, given the
+    /// previous keyframe `prev` and the next keyframe `next`.
+    ///
+    /// For the first and last keyframes of a sequence pass `self` as the missing neighbour.
+    #[inline]
+    pub fn squad_tangent(self, prev: Self, next: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(prev.is_normalized());
+        glam_assert!(next.is_normalized());
+        // move the neighbours into the same hemisphere as `self` so the tangent follows the
+        // shortest path
+        let prev = if self.dot(prev) < 0.0 { -prev } else { prev };
+        let next = if self.dot(next) < 0.0 { -next } else { next };
+        let inv = self.conjugate();
+        let sum = (inv * next).ln() + (inv * prev).ln();
+        (self * (sum * -0.25).exp()).normalize()
+    }
+
+    /// Computes the weighted average rotation of the given normalized quaternions.
+    ///
+    /// The result is the rotation that minimizes the weighted sum of squared chordal distances
+    /// to the inputs, found as the eigenvector of the largest eigenvalue of the sum of the
+    /// weighted outer products of the quaternions. This is accurate for any spread of rotations
+    /// and independent of the order of the inputs and of their signs, so `q` and `-q` count as
+    /// the same rotation.
+    ///
+    /// If `weights` is `None` every quaternion has a weight of `1.0`, otherwise it must have
+    /// the same length as `quats` and contain no negative weights. The result is in the same
+    /// hemisphere as the first quaternion. If `quats` is empty or all weights are zero the
+    /// result is the identity.
+    pub fn average(quats: &[Self], weights: Option<&[f64]>) -> Self {
+        // Based on "Averaging Quaternions" by F. Landis Markley, Yang Cheng, John L. Crassidis
+        // and Yaakov Oshman
+        glam_assert!(weights.map_or(true, |weights| weights.len() == quats.len()));
+        let mut m = [[0.0; 4]; 4];
+        for (i, q) in quats.iter().enumerate() {
+            glam_assert!(q.is_normalized());
+            let weight = weights.map_or(1.0, |weights| weights[i]);
+            glam_assert!(weight >= 0.0);
+            let q: [f64; 4] = (*q).into();
+            for row in 0..4 {
+                for col in row..4 {
+                    m[row][col] += weight * q[row] * q[col];
+                }
+            }
+        }
+        if m[0][0] + m[1][1] + m[2][2] + m[3][3] <= 0.0 {
+            return Self::identity();
+        }
+        let average = Self::from(symmetric_eigen_max4(m)).normalize();
+        if average.dot(quats[0]) < 0.0 {
+            -average
+        } else {
+            average
+        }
+    }
+
+    /// Computes an approximate weighted average rotation of the given normalized quaternions by
+    /// normalizing their weighted sum.
+    ///
+    /// Each quaternion is flipped into the hemisphere of the first one before summing, so `q`
+    /// and `-q` count as the same rotation. This is much cheaper than `DQuat::average` and close
+    /// to it when the rotations are near each other, but loses accuracy as they spread apart.
+    ///
+    /// If `weights` is `None` every quaternion has a weight of `1.0`, otherwise it must have
+    /// the same length as `quats`. If `quats` is empty or the weighted sum is zero the result is
+    /// the identity.
+    pub fn average_approx(quats: &[Self], weights: Option<&[f64]>) -> Self {
+        glam_assert!(weights.map_or(true, |weights| weights.len() == quats.len()));
+        let first = match quats.first() {
+            Some(first) => *first,
+            None => return Self::identity(),
+        };
+        let mut sum = DVec4::zero();
+        for (i, q) in quats.iter().enumerate() {
+            glam_assert!(q.is_normalized());
+            let weight = weights.map_or(1.0, |weights| weights[i]);
+            let weight = if q.dot(first) < 0.0 { -weight } else { weight };
+            sum += q.0 * weight;
+        }
+        let length_sq = sum.length_squared();
+        if length_sq > 0.0 {
+            Self(sum / length_sq.sqrt())
+        } else {
+            Self::identity()
+        }
+    }
+
+    #[inline]
+    /// Multiplies a quaternion and a 3D vector, rotating it.
+    pub fn mul_vec3(self, other: DVec3) -> DVec3 {
+        glam_assert!(self.is_normalized());
+
+        let w = self.0.w;
+        let b = DVec3::from(self.0);
+        let b2 = b.dot(b);
+        other * (w * w - b2) + b * (other.dot(b) * 2.0) + b.cross(other) * (w * 2.0)
+    }
+
+    #[inline]
+    /// Multiplies two quaternions.
+    /// If they each represent a rotation, the result will represent the combined rotation.
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    pub fn mul_quat(self, other: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(other.is_normalized());
+
+        let (x0, y0, z0, w0) = self.0.into();
+        let (x1, y1, z1, w1) = other.0.into();
+        Self::from_xyzw(
+            w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+}
+
+/// Wraps an angle in the range `[-2*PI, 2*PI]` into `[-PI, PI]`.
+#[inline]
+fn wrap_angle(angle: f64) -> f64 {
+    use core::f64::consts::PI;
+    if angle > PI {
+        angle - 2.0 * PI
+    } else if angle < -PI {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+/// Returns the eigenvector of the largest eigenvalue of the symmetric 4x4 matrix `m`, of which
+/// only the upper triangle is used, using the cyclic Jacobi method.
+#[allow(clippy::needless_range_loop)]
+fn symmetric_eigen_max4(mut m: [[f64; 4]; 4]) -> [f64; 4] {
+    const MAX_SWEEPS: usize = 16;
+    for row in 1..4 {
+        for col in 0..row {
+            m[row][col] = m[col][row];
+        }
+    }
+    let mut v = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let trace = m[0][0] + m[1][1] + m[2][2] + m[3][3];
+    for _ in 0..MAX_SWEEPS {
+        let mut off_diagonal = 0.0;
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                off_diagonal += m[p][q].abs();
+            }
+        }
+        if off_diagonal <= core::f64::EPSILON * trace {
+            break;
+        }
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                if m[p][q] == 0.0 {
+                    continue;
+                }
+                // the Jacobi rotation in the p, q plane that zeroes m[p][q]
+                let theta = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = (t * t + 1.0).sqrt().recip();
+                let s = t * c;
+                for k in 0..4 {
+                    let (mkp, mkq) = (m[k][p], m[k][q]);
+                    m[k][p] = c * mkp - s * mkq;
+                    m[k][q] = s * mkp + c * mkq;
+                }
+                for k in 0..4 {
+                    let (mpk, mqk) = (m[p][k], m[q][k]);
+                    m[p][k] = c * mpk - s * mqk;
+                    m[q][k] = s * mpk + c * mqk;
+                }
+                for row in v.iter_mut() {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+    // the eigenvectors are the columns of `v`
+    let mut max = 0;
+    for i in 1..4 {
+        if m[i][i] > m[max][max] {
+            max = i;
+        }
+    }
+    [v[0][max], v[1][max], v[2][max], v[3][max]]
+}
+
+impl fmt::Debug for DQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("DQuat")
+            .field(&a[0])
+            .field(&a[1])
+            .field(&a[2])
+            .field(&a[3])
+            .finish()
+    }
+}
+
+impl fmt::Display for DQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, z, w) = self.0.into();
+        write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+    }
+}
+
+impl Add<DQuat> for DQuat {
+    type Output = Self;
+    #[inline]
+    /// Adds two quaternions.
+    /// The sum is not guaranteed to be normalized.
+    ///
+    /// NB: Addition is not the same as combining the rotations represented by the two quaternions!
+    /// That corresponds to multiplication.
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<DQuat> for DQuat {
+    type Output = Self;
+    #[inline]
+    /// Subtracts the other quaternion from self.
+    /// The difference is not guaranteed to be normalized.
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<f64> for DQuat {
+    type Output = Self;
+    #[inline]
+    /// Multiplies a quaternion with an f64.
+    /// The product is not guaranteed to be normalized.
+    fn mul(self, other: f64) -> Self {
+        Self(self.0 * other)
+    }
+}
+
+impl Div<f64> for DQuat {
+    type Output = Self;
+    #[inline]
+    /// Divides a quaternion by an f64.
+    /// The quotient is not guaranteed to be normalized.
+    fn div(self, other: f64) -> Self {
+        Self(self.0 / other)
+    }
+}
+
+impl Mul<DQuat> for DQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_quat(other)
+    }
+}
+
+impl MulAssign<DQuat> for DQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_quat(other);
+    }
+}
+
+impl Mul<DVec3> for DQuat {
+    type Output = DVec3;
+    #[inline]
+    fn mul(self, other: DVec3) -> Self::Output {
+        self.mul_vec3(other)
+    }
+}
+
+impl Neg for DQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-1.0 * self.0)
+    }
+}
+
+impl Default for DQuat {
+    #[inline]
+    fn default() -> Self {
+        IDENTITY
+    }
+}
+
+impl PartialEq for DQuat {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.cmpeq(other.0).all()
+    }
+}
+
+impl PartialOrd for DQuat {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl AsRef<[f64; 4]> for DQuat {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        self.0.as_ref()
+    }
+}
+
+impl AsMut<[f64; 4]> for DQuat {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 4] {
+        self.0.as_mut()
+    }
+}
+
+impl From<DVec4> for DQuat {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        Self(v)
+    }
+}
+
+impl From<DQuat> for DVec4 {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DQuat {
+    #[inline]
+    fn from(t: (f64, f64, f64, f64)) -> Self {
+        DQuat::from_xyzw(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<DQuat> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0.into()
+    }
+}
+
+impl From<[f64; 4]> for DQuat {
+    #[inline]
+    fn from(a: [f64; 4]) -> Self {
+        Self(a.into())
+    }
+}
+
+impl From<DQuat> for [f64; 4] {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        q.0.into()
+    }
+}
+
+impl Deref for DQuat {
+    type Target = super::XYZW;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self as *const Self::Target) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DQuat {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DQuat {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(IDENTITY, |a, &b| Self::mul(a, b))
+    }
+}
@@ -0,0 +1,688 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use crate::f64::{DVec2Mask, DVec3};
+use core::{f64, fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DVec2 = const_dvec2!([0.0; 2]);
+const ONE: DVec2 = const_dvec2!([1.0; 2]);
+const X_AXIS: DVec2 = const_dvec2!([1.0, 0.0]);
+const Y_AXIS: DVec2 = const_dvec2!([0.0, 1.0]);
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(C)]
+pub struct DVec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Creates a `DVec2`.
+#[inline]
+pub fn dvec2(x: f64, y: f64) -> DVec2 {
+    DVec2 { x, y }
+}
+
+impl DVec2 {
+    /// Performs `is_nan` on each element of self, returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x.is_nan(), y.is_nan()]`.
+    #[inline]
+    pub fn is_nan_mask(self) -> DVec2Mask {
+        DVec2Mask::new(self.x.is_nan(), self.y.is_nan())
+    }
+
+    /// Returns a `DVec2` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Returns a `DVec2` containing the reciprocal `1.0/n` of each element of `self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self {
+            x: self.x.recip(),
+            y: self.y.recip(),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: f64) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DVec2`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        abs_diff_eq!(self, other, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(self) -> crate::Vec2 {
+        crate::Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec2(self) -> crate::IVec2 {
+        crate::IVec2::new(self.x as i32, self.y as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec2(self) -> crate::UVec2 {
+        crate::UVec2::new(self.x as u32, self.y as u32)
+    }
+
+    /// Creates a new `DVec2`.
+    #[inline]
+    pub fn new(x: f64, y: f64) -> DVec2 {
+        DVec2 { x, y }
+    }
+
+    /// Creates a `DVec2` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> DVec2 {
+        ZERO
+    }
+
+    /// Creates a `DVec2` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> DVec2 {
+        ONE
+    }
+
+    /// Creates a `DVec2` with values `[x: 1.0, y: 0.0]`.
+    #[inline]
+    pub const fn unit_x() -> DVec2 {
+        X_AXIS
+    }
+
+    /// Creates a `DVec2` with values `[x: 0.0, y: 1.0]`.
+    #[inline]
+    pub const fn unit_y() -> DVec2 {
+        Y_AXIS
+    }
+
+    /// Creates a `DVec2` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f64) -> DVec2 {
+        Self { x: v, y: v }
+    }
+
+    /// Creates a `DVec3` from `self` and the given `z` value.
+    #[inline]
+    pub fn extend(self, z: f64) -> DVec3 {
+        DVec3::new(self.x, self.y, z)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: DVec2) -> f64 {
+        (self.x * other.x) + (self.y * other.y)
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `DVec2::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / DVec2::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between two points.
+    #[inline]
+    pub fn distance(self, other: DVec2) -> f64 {
+        (self - other).length()
+    }
+
+    /// Compute the squared Euclidean distance between two points.
+    #[inline]
+    pub fn distance_squared(self, other: DVec2) -> f64 {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> DVec2 {
+        self * self.length_recip()
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<DVec2> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> DVec2 {
+        self.try_normalize().unwrap_or_else(DVec2::zero)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: DVec2) -> DVec2 {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: DVec2) -> DVec2 {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2]`.
+    #[inline]
+    pub fn cmpeq(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.eq(&other.x), self.y.eq(&other.y))
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2]`.
+    #[inline]
+    pub fn cmpne(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.ne(&other.x), self.y.ne(&other.y))
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2]`.
+    #[inline]
+    pub fn cmpge(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.ge(&other.x), self.y.ge(&other.y))
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2]`.
+    #[inline]
+    pub fn cmpgt(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.gt(&other.x), self.y.gt(&other.y))
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2]`.
+    #[inline]
+    pub fn cmple(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.le(&other.x), self.y.le(&other.y))
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `DVec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2]`.
+    #[inline]
+    pub fn cmplt(self, other: DVec2) -> DVec2Mask {
+        DVec2Mask::new(self.x.lt(&other.x), self.y.lt(&other.y))
+    }
+
+    /// Creates a `DVec2` from the first two values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than two elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f64]) -> Self {
+        Self {
+            x: slice[0],
+            y: slice[1],
+        }
+    }
+
+    /// Writes the elements of `self` to the first two elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than two elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f64]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+    }
+
+    /// Returns a `DVec2` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+
+    /// Returns a `DVec2` containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases away from 0.0.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+        }
+    }
+
+    /// Returns a `DVec2` containing the largest integer less than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+        }
+    }
+
+    /// Returns a `DVec2` containing the smallest integer greater than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+        }
+    }
+
+    /// Returns a `DVec2` containing `e^self` (the exponential function) for each element of `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self {
+            x: self.x.exp(),
+            y: self.y.exp(),
+        }
+    }
+
+    /// Returns a `DVec2` containing each element of `self` raised to the power of `n`.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        Self {
+            x: self.x.powf(n),
+            y: self.y.powf(n),
+        }
+    }
+
+    /// Returns a `DVec2` that is equal to `self` rotated by 90 degrees.
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// The perpendicular dot product of the vector and `other`.
+    #[inline]
+    pub fn perp_dot(self, other: DVec2) -> f64 {
+        (self.x * other.y) - (self.y * other.x)
+    }
+
+    /// Returns the angle between two vectors, in radians.
+    ///
+    /// The vectors do not need to be unit length, but this function does
+    /// perform a `sqrt`.
+    #[inline]
+    pub fn angle_between(self, other: Self) -> f64 {
+        let angle = crate::f64::funcs::scalar_acos(
+            self.dot(other) / (self.dot(self) * other.dot(other)).sqrt(),
+        );
+
+        if self.perp_dot(other) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+impl fmt::Display for DVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+impl Div<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: DVec2) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+        }
+    }
+}
+
+impl DivAssign<DVec2> for DVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: DVec2) {
+        self.x /= other.x;
+        self.y /= other.y;
+    }
+}
+
+impl Div<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f64) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+        }
+    }
+}
+
+impl DivAssign<f64> for DVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        self.x /= other;
+        self.y /= other;
+    }
+}
+
+impl Div<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn div(self, other: DVec2) -> DVec2 {
+        DVec2 {
+            x: self / other.x,
+            y: self / other.y,
+        }
+    }
+}
+
+impl Mul<DVec2> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: DVec2) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+}
+
+impl MulAssign<DVec2> for DVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: DVec2) {
+        self.x *= other.x;
+        self.y *= other.y;
+    }
+}
+
+impl Mul<f64> for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+impl MulAssign<f64> for DVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+    }
+}
+
+impl Mul<DVec2> for f64 {
+    type Output = DVec2;
+    #[inline]
+    fn mul(self, other: DVec2) -> DVec2 {
+        DVec2 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+impl Add for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign for DVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: DVec2) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for DVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: DVec2) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Neg for DVec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl AsRef<[f64; 2]> for DVec2 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 2] {
+        unsafe { &*(self as *const DVec2 as *const [f64; 2]) }
+    }
+}
+
+impl AsMut<[f64; 2]> for DVec2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 2] {
+        unsafe { &mut *(self as *mut DVec2 as *mut [f64; 2]) }
+    }
+}
+
+impl fmt::Debug for DVec2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("DVec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+
+impl Index<usize> for DVec2 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for DVec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(f64, f64)> for DVec2 {
+    #[inline]
+    fn from(t: (f64, f64)) -> Self {
+        Self { x: t.0, y: t.1 }
+    }
+}
+
+impl From<DVec2> for (f64, f64) {
+    #[inline]
+    fn from(v: DVec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<[f64; 2]> for DVec2 {
+    #[inline]
+    fn from(a: [f64; 2]) -> Self {
+        Self { x: a[0], y: a[1] }
+    }
+}
+
+impl From<DVec2> for [f64; 2] {
+    #[inline]
+    fn from(v: DVec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DVec2 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DVec2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
@@ -0,0 +1,185 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{DVec2, DVec3, DVec4};
+
+pub trait DVec2Swizzles {
+    fn xxxx(self) -> DVec4;
+    fn xxxy(self) -> DVec4;
+    fn xxyx(self) -> DVec4;
+    fn xxyy(self) -> DVec4;
+    fn xyxx(self) -> DVec4;
+    fn xyxy(self) -> DVec4;
+    fn xyyx(self) -> DVec4;
+    fn xyyy(self) -> DVec4;
+    fn yxxx(self) -> DVec4;
+    fn yxxy(self) -> DVec4;
+    fn yxyx(self) -> DVec4;
+    fn yxyy(self) -> DVec4;
+    fn yyxx(self) -> DVec4;
+    fn yyxy(self) -> DVec4;
+    fn yyyx(self) -> DVec4;
+    fn yyyy(self) -> DVec4;
+    fn xxx(self) -> DVec3;
+    fn xxy(self) -> DVec3;
+    fn xyx(self) -> DVec3;
+    fn xyy(self) -> DVec3;
+    fn yxx(self) -> DVec3;
+    fn yxy(self) -> DVec3;
+    fn yyx(self) -> DVec3;
+    fn yyy(self) -> DVec3;
+    fn xx(self) -> DVec2;
+    fn yx(self) -> DVec2;
+    fn yy(self) -> DVec2;
+}
+
+impl DVec2Swizzles for DVec2 {
+    #[inline]
+    fn xxxx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxyx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xyxx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyyx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yxxx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxyx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yyxx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyyx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xxx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xx(self) -> DVec2 {
+        DVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yx(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+}
@@ -0,0 +1,775 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{DVec2, DVec3Mask, DVec4};
+use core::{fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DVec3 = const_dvec3!([0.0; 3]);
+const ONE: DVec3 = const_dvec3!([1.0; 3]);
+const X_AXIS: DVec3 = const_dvec3!([1.0, 0.0, 0.0]);
+const Y_AXIS: DVec3 = const_dvec3!([0.0, 1.0, 0.0]);
+const Z_AXIS: DVec3 = const_dvec3!([0.0, 0.0, 1.0]);
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(C)]
+pub struct DVec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// Creates a `DVec3`.
+#[inline]
+pub fn dvec3(x: f64, y: f64, z: f64) -> DVec3 {
+    DVec3::new(x, y, z)
+}
+
+impl DVec3 {
+    /// Creates a new `DVec3`.
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a `DVec3` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a `DVec3` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates a `DVec3` with values `[x: 1.0, y: 0.0, z: 0.0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates a `DVec3` with values `[x: 0.0, y: 1.0, z: 0.0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates a `DVec3` with values `[x: 0.0, y: 0.0, z: 1.0]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Z_AXIS
+    }
+
+    /// Creates a `DVec3` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f64) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    /// Creates a `DVec4` from `self` and the given `w` value.
+    #[inline]
+    pub fn extend(self, w: f64) -> DVec4 {
+        DVec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Creates a `DVec2` from the `x` and `y` elements of `self`, discarding `z`.
+    ///
+    /// Truncation may also be performed by using `self.xy()` or `DVec2::from()`.
+    #[inline]
+    pub fn truncate(self) -> DVec2 {
+        DVec2::new(self.x, self.y)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f64 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+    }
+
+    /// Returns DVec3 dot in all lanes of DVec3
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn dot_as_vec3(self, other: Self) -> Self {
+        let dot = self.dot(other);
+        DVec3::new(dot, dot, dot)
+    }
+
+    /// Computes the cross product of `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - other.y * self.z,
+            y: self.z * other.x - other.z * self.x,
+            z: self.x * other.y - other.x * self.y,
+        }
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `DVec3::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / DVec3::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between two points in space.
+    #[inline]
+    pub fn distance(self, other: DVec3) -> f64 {
+        (self - other).length()
+    }
+
+    /// Compute the squared Euclidean distance between two points in space.
+    #[inline]
+    pub fn distance_squared(self, other: DVec3) -> f64 {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize().unwrap_or_else(Self::zero)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y.min(self.z))
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y.max(self.z))
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.eq(&other.x),
+            self.y.eq(&other.y),
+            self.z.eq(&other.z),
+        )
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.ne(&other.x),
+            self.y.ne(&other.y),
+            self.z.ne(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.ge(&other.x),
+            self.y.ge(&other.y),
+            self.z.ge(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.gt(&other.x),
+            self.y.gt(&other.y),
+            self.z.gt(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.le(&other.x),
+            self.y.le(&other.y),
+            self.z.le(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> DVec3Mask {
+        DVec3Mask::new(
+            self.x.lt(&other.x),
+            self.y.lt(&other.y),
+            self.z.lt(&other.z),
+        )
+    }
+
+    /// Creates a `DVec3` from the first three values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f64]) -> Self {
+        Self::new(slice[0], slice[1], slice[2])
+    }
+
+    /// Writes the elements of `self` to the first three elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f64]) {
+        let a = self.as_ref();
+        slice[0] = a[0];
+        slice[1] = a[1];
+        slice[2] = a[2];
+    }
+
+    /// Per element multiplication/addition of the three inputs: b + (self * a)
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
+        Self {
+            x: (self.x * a.x) + b.x,
+            y: (self.y * a.y) + b.y,
+            z: (self.z * a.z) + b.z,
+        }
+    }
+
+    /// Returns a `DVec3` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Returns a `DVec3` containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases away from 0.0.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round(),
+        }
+    }
+
+    /// Returns a `DVec3` containing the largest integer less than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+            z: self.z.floor(),
+        }
+    }
+
+    /// Returns a `DVec3` containing the smallest integer greater than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+            z: self.z.ceil(),
+        }
+    }
+
+    /// Returns a `DVec3` containing `e^self` (the exponential function) for each element of `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self {
+            x: self.x.exp(),
+            y: self.y.exp(),
+            z: self.z.exp(),
+        }
+    }
+
+    /// Returns a `DVec3` containing each element of `self` raised to the power of `n`.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        Self {
+            x: self.x.powf(n),
+            y: self.y.powf(n),
+            z: self.z.powf(n),
+        }
+    }
+
+    /// Performs `is_nan()` on each element of self, returning a `DVec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x.is_nan(), y.is_nan(), z.is_nan()]`.
+    #[inline]
+    pub fn is_nan_mask(self) -> DVec3Mask {
+        DVec3Mask::new(self.x.is_nan(), self.y.is_nan(), self.z.is_nan())
+    }
+
+    /// Returns a `DVec3` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    /// Returns a `DVec3` containing the reciprocal `1.0/n` of each element of `self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self {
+            x: self.x.recip(),
+            y: self.y.recip(),
+            z: self.z.recip(),
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: f64) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DVec3`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        abs_diff_eq!(self, other, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(self) -> crate::Vec3 {
+        crate::Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec3(self) -> crate::IVec3 {
+        crate::IVec3::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec3(self) -> crate::UVec3 {
+        crate::UVec3::new(self.x as u32, self.y as u32, self.z as u32)
+    }
+
+    /// Returns the angle between two vectors, in radians.
+    ///
+    /// The vectors do not need to be unit length, but this function does
+    /// perform a `sqrt`.
+    #[inline]
+    pub fn angle_between(self, other: Self) -> f64 {
+        crate::f64::funcs::scalar_acos(self.dot(other) / (self.dot(self) * other.dot(other)).sqrt())
+    }
+}
+
+impl AsRef<[f64; 3]> for DVec3 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 3] {
+        unsafe { &*(self as *const DVec3 as *const [f64; 3]) }
+    }
+}
+
+impl AsMut<[f64; 3]> for DVec3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 3] {
+        unsafe { &mut *(self as *mut DVec3 as *mut [f64; 3]) }
+    }
+}
+
+impl fmt::Debug for DVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("DVec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+impl fmt::Display for DVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+    }
+}
+
+impl Div<DVec3> for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl DivAssign<DVec3> for DVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+        self.z /= other.z;
+    }
+}
+
+impl Div<f64> for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f64) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<f64> for DVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+    }
+}
+
+impl Div<DVec3> for f64 {
+    type Output = DVec3;
+    #[inline]
+    fn div(self, other: DVec3) -> DVec3 {
+        DVec3 {
+            x: self / other.x,
+            y: self / other.y,
+            z: self / other.z,
+        }
+    }
+}
+
+impl Mul<DVec3> for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl MulAssign<DVec3> for DVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.x *= other.x;
+        self.y *= other.y;
+        self.z *= other.z;
+    }
+}
+
+impl Mul<f64> for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl MulAssign<f64> for DVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+impl Mul<DVec3> for f64 {
+    type Output = DVec3;
+    #[inline]
+    fn mul(self, other: DVec3) -> DVec3 {
+        DVec3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+impl Add for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign for DVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl Sub for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign for DVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl Neg for DVec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Index<usize> for DVec3 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for DVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(f64, f64, f64)> for DVec3 {
+    #[inline]
+    fn from(t: (f64, f64, f64)) -> Self {
+        Self::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<DVec3> for (f64, f64, f64) {
+    #[inline]
+    fn from(v: DVec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl From<[f64; 3]> for DVec3 {
+    #[inline]
+    fn from(a: [f64; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<DVec3> for [f64; 3] {
+    #[inline]
+    fn from(v: DVec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<DVec3> for DVec2 {
+    /// Creates a `DVec2` from the `x` and `y` elements of the `DVec3`, discarding `z`.
+    #[inline]
+    fn from(v: DVec3) -> Self {
+        DVec2 { x: v.x, y: v.y }
+    }
+}
+
+#[test]
+fn test_vec3_private() {
+    assert_eq!(
+        dvec3(1.0, 1.0, 1.0).mul_add(dvec3(0.5, 2.0, -4.0), dvec3(-1.0, -1.0, -1.0)),
+        dvec3(-0.5, 1.0, -5.0)
+    );
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DVec3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DVec3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
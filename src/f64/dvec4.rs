@@ -0,0 +1,808 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{DVec2, DVec3, DVec4Mask};
+use core::{fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: DVec4 = const_dvec4!([0.0; 4]);
+const ONE: DVec4 = const_dvec4!([1.0; 4]);
+const X_AXIS: DVec4 = const_dvec4!([1.0, 0.0, 0.0, 0.0]);
+const Y_AXIS: DVec4 = const_dvec4!([0.0, 1.0, 0.0, 0.0]);
+const Z_AXIS: DVec4 = const_dvec4!([0.0, 0.0, 1.0, 0.0]);
+const W_AXIS: DVec4 = const_dvec4!([0.0, 0.0, 0.0, 1.0]);
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(C)]
+pub struct DVec4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+/// Creates a `DVec4`.
+#[inline]
+pub fn dvec4(x: f64, y: f64, z: f64, w: f64) -> DVec4 {
+    DVec4::new(x, y, z, w)
+}
+
+impl DVec4 {
+    /// Creates a new `DVec4`.
+    #[inline]
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a `DVec4` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a `DVec4` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates a `DVec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates a `DVec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates a `DVec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Z_AXIS
+    }
+
+    /// Creates a `DVec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    #[inline]
+    pub const fn unit_w() -> Self {
+        W_AXIS
+    }
+
+    /// Creates a `DVec4` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: f64) -> Self {
+        Self {
+            x: v,
+            y: v,
+            z: v,
+            w: v,
+        }
+    }
+
+    /// Creates a `DVec3` from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    ///
+    /// Truncation to `DVec3` may also be performed by using `self.xyz()` or `DVec3::from()`.
+    #[inline]
+    pub fn truncate(self) -> DVec3 {
+        self.into()
+    }
+
+    /// Computes the 4D dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f64 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+    }
+
+    /// Computes the 4D length of `self`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared 4D length of `self`.
+    ///
+    /// This is generally faster than `DVec4::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / DVec4::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_recip(self) -> f64 {
+        self.length().recip()
+    }
+
+    /// Computes the Euclidean distance between two points in space.
+    #[inline]
+    pub fn distance(self, other: DVec4) -> f64 {
+        (self - other).length()
+    }
+
+    /// Compute the squared euclidean distance between two points in space.
+    #[inline]
+    pub fn distance_squared(self, other: DVec4) -> f64 {
+        (self - other).length_squared()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * self.length_recip()
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be `None`.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > 0.0 {
+            Some(self * rcp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    ///
+    /// In particular, if the length of `self` is zero, very close to zero or not finite, the
+    /// result will be zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.try_normalize().unwrap_or_else(Self::zero)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+            w: self.w.min(other.w),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+            w: self.w.max(other.w),
+        }
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> f64 {
+        self.x.min(self.y.min(self.z.min(self.w)))
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> f64 {
+        self.x.max(self.y.max(self.z.min(self.w)))
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.eq(&other.x),
+            self.y.eq(&other.y),
+            self.z.eq(&other.z),
+            self.w.eq(&other.w),
+        )
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.ne(&other.x),
+            self.y.ne(&other.y),
+            self.z.ne(&other.z),
+            self.w.ne(&other.w),
+        )
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.ge(&other.x),
+            self.y.ge(&other.y),
+            self.z.ge(&other.z),
+            self.w.ge(&other.w),
+        )
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.gt(&other.x),
+            self.y.gt(&other.y),
+            self.z.gt(&other.z),
+            self.w.gt(&other.w),
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.le(&other.x),
+            self.y.le(&other.y),
+            self.z.le(&other.z),
+            self.w.le(&other.w),
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.lt(&other.x),
+            self.y.lt(&other.y),
+            self.z.lt(&other.z),
+            self.w.lt(&other.w),
+        )
+    }
+
+    /// Creates a `DVec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f64]) -> Self {
+        Self {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+            w: slice[3],
+        }
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f64]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+        slice[2] = self.z;
+        slice[3] = self.w;
+    }
+
+    /// Per element multiplication/addition of the three inputs: b + (self * a)
+    #[inline]
+    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
+        Self {
+            x: (self.x * a.x) + b.x,
+            y: (self.y * a.y) + b.y,
+            z: (self.z * a.z) + b.z,
+            w: (self.w * a.w) + b.w,
+        }
+    }
+
+    /// Returns a `DVec4` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs(),
+        }
+    }
+
+    /// Returns a `DVec4` containing the nearest integer to a number for each element of `self`.
+    /// Round half-way cases away from 0.0.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round(),
+            w: self.w.round(),
+        }
+    }
+
+    /// Returns a `DVec4` containing the largest integer less than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+            z: self.z.floor(),
+            w: self.w.floor(),
+        }
+    }
+
+    /// Returns a `DVec4` containing the smallest integer greater than or equal to a number for each
+    /// element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+            z: self.z.ceil(),
+            w: self.w.ceil(),
+        }
+    }
+
+    /// Returns a `DVec4` containing `e^self` (the exponential function) for each element of `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        Self::new(self.x.exp(), self.y.exp(), self.z.exp(), self.w.exp())
+    }
+
+    /// Returns a `DVec4` containing each element of `self` raised to the power of `n`.
+    #[inline]
+    pub fn powf(self, n: f64) -> Self {
+        Self::new(
+            self.x.powf(n),
+            self.y.powf(n),
+            self.z.powf(n),
+            self.w.powf(n),
+        )
+    }
+
+    /// Performs `is_nan` on each element of self, returning a `DVec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x.is_nan(), y.is_nan(), z.is_nan(), w.is_nan()]`.
+    #[inline]
+    pub fn is_nan_mask(self) -> DVec4Mask {
+        DVec4Mask::new(
+            self.x.is_nan(),
+            self.y.is_nan(),
+            self.z.is_nan(),
+            self.w.is_nan(),
+        )
+    }
+
+    /// Returns a `DVec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    #[inline]
+    pub fn signum(self) -> Self {
+        DVec4 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+            w: self.w.signum(),
+        }
+    }
+
+    /// Returns a `DVec4` containing the reciprocal `1.0/n` of each element of `self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        // TODO: Optimize
+        Self::one() / self
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: f64) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns `true` if any elements are `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DVec4`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        abs_diff_eq!(self, other, max_abs_diff)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_f32(self) -> crate::Vec4 {
+        crate::Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_ivec4(self) -> crate::IVec4 {
+        crate::IVec4::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    ///
+    /// Elements are truncated towards zero.
+    #[inline]
+    pub fn as_uvec4(self) -> crate::UVec4 {
+        crate::UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
+}
+
+impl AsRef<[f64; 4]> for DVec4 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 4] {
+        unsafe { &*(self as *const Self as *const [f64; 4]) }
+    }
+}
+
+impl AsMut<[f64; 4]> for DVec4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 4]) }
+    }
+}
+
+impl fmt::Debug for DVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("DVec4")
+            .field(&a[0])
+            .field(&a[1])
+            .field(&a[2])
+            .field(&a[3])
+            .finish()
+    }
+}
+
+impl fmt::Display for DVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        write!(fmt, "[{}, {}, {}, {}]", a[0], a[1], a[2], a[3])
+    }
+}
+
+impl Div<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+            w: self.w / other.w,
+        }
+    }
+}
+
+impl DivAssign<DVec4> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+        self.z /= other.z;
+        self.w /= other.w;
+    }
+}
+
+impl Div<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f64) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl DivAssign<f64> for DVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+        self.w /= other;
+    }
+}
+
+impl Div<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn div(self, other: DVec4) -> DVec4 {
+        DVec4 {
+            x: self / other.x,
+            y: self / other.y,
+            z: self / other.z,
+            w: self / other.w,
+        }
+    }
+}
+
+impl Mul<DVec4> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w,
+        }
+    }
+}
+
+impl MulAssign<DVec4> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.x *= other.x;
+        self.y *= other.y;
+        self.z *= other.z;
+        self.w *= other.w;
+    }
+}
+
+impl Mul<f64> for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+impl MulAssign<f64> for DVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+        self.w *= other;
+    }
+}
+
+impl Mul<DVec4> for f64 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, other: DVec4) -> DVec4 {
+        DVec4 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
+        }
+    }
+}
+
+impl Add for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl AddAssign for DVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
+impl Sub for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl SubAssign for DVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
+impl Neg for DVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl Index<usize> for DVec4 {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for DVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DVec4 {
+    #[inline]
+    fn from(t: (f64, f64, f64, f64)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<DVec4> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<[f64; 4]> for DVec4 {
+    #[inline]
+    fn from(a: [f64; 4]) -> Self {
+        Self {
+            x: a[0],
+            y: a[1],
+            z: a[2],
+            w: a[3],
+        }
+    }
+}
+
+impl From<DVec4> for [f64; 4] {
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl From<DVec4> for DVec3 {
+    /// Creates a `DVec3` from the `x`, `y` and `z` elements of the `DVec4`, discarding `z`.
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        DVec3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<DVec4> for DVec2 {
+    /// Creates a `DVec2` from the `x` and `y` elements of the `DVec4`, discarding `z`.
+    #[inline]
+    fn from(v: DVec4) -> Self {
+        DVec2 { x: v.x, y: v.y }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for DVec4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for DVec4 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
+
+#[test]
+fn test_dvec4_private() {
+    assert_eq!(
+        dvec4(1.0, 1.0, 1.0, 1.0)
+            .mul_add(dvec4(0.5, 2.0, -4.0, 0.0), dvec4(-1.0, -1.0, -1.0, -1.0)),
+        dvec4(-0.5, 1.0, -5.0, -1.0)
+    );
+}
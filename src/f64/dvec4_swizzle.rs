@@ -0,0 +1,1988 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{DVec2, DVec3, DVec4};
+
+pub trait DVec4Swizzles {
+    fn xxxx(self) -> DVec4;
+    fn xxxy(self) -> DVec4;
+    fn xxxz(self) -> DVec4;
+    fn xxxw(self) -> DVec4;
+    fn xxyx(self) -> DVec4;
+    fn xxyy(self) -> DVec4;
+    fn xxyz(self) -> DVec4;
+    fn xxyw(self) -> DVec4;
+    fn xxzx(self) -> DVec4;
+    fn xxzy(self) -> DVec4;
+    fn xxzz(self) -> DVec4;
+    fn xxzw(self) -> DVec4;
+    fn xxwx(self) -> DVec4;
+    fn xxwy(self) -> DVec4;
+    fn xxwz(self) -> DVec4;
+    fn xxww(self) -> DVec4;
+    fn xyxx(self) -> DVec4;
+    fn xyxy(self) -> DVec4;
+    fn xyxz(self) -> DVec4;
+    fn xyxw(self) -> DVec4;
+    fn xyyx(self) -> DVec4;
+    fn xyyy(self) -> DVec4;
+    fn xyyz(self) -> DVec4;
+    fn xyyw(self) -> DVec4;
+    fn xyzx(self) -> DVec4;
+    fn xyzy(self) -> DVec4;
+    fn xyzz(self) -> DVec4;
+    fn xywx(self) -> DVec4;
+    fn xywy(self) -> DVec4;
+    fn xywz(self) -> DVec4;
+    fn xyww(self) -> DVec4;
+    fn xzxx(self) -> DVec4;
+    fn xzxy(self) -> DVec4;
+    fn xzxz(self) -> DVec4;
+    fn xzxw(self) -> DVec4;
+    fn xzyx(self) -> DVec4;
+    fn xzyy(self) -> DVec4;
+    fn xzyz(self) -> DVec4;
+    fn xzyw(self) -> DVec4;
+    fn xzzx(self) -> DVec4;
+    fn xzzy(self) -> DVec4;
+    fn xzzz(self) -> DVec4;
+    fn xzzw(self) -> DVec4;
+    fn xzwx(self) -> DVec4;
+    fn xzwy(self) -> DVec4;
+    fn xzwz(self) -> DVec4;
+    fn xzww(self) -> DVec4;
+    fn xwxx(self) -> DVec4;
+    fn xwxy(self) -> DVec4;
+    fn xwxz(self) -> DVec4;
+    fn xwxw(self) -> DVec4;
+    fn xwyx(self) -> DVec4;
+    fn xwyy(self) -> DVec4;
+    fn xwyz(self) -> DVec4;
+    fn xwyw(self) -> DVec4;
+    fn xwzx(self) -> DVec4;
+    fn xwzy(self) -> DVec4;
+    fn xwzz(self) -> DVec4;
+    fn xwzw(self) -> DVec4;
+    fn xwwx(self) -> DVec4;
+    fn xwwy(self) -> DVec4;
+    fn xwwz(self) -> DVec4;
+    fn xwww(self) -> DVec4;
+    fn yxxx(self) -> DVec4;
+    fn yxxy(self) -> DVec4;
+    fn yxxz(self) -> DVec4;
+    fn yxxw(self) -> DVec4;
+    fn yxyx(self) -> DVec4;
+    fn yxyy(self) -> DVec4;
+    fn yxyz(self) -> DVec4;
+    fn yxyw(self) -> DVec4;
+    fn yxzx(self) -> DVec4;
+    fn yxzy(self) -> DVec4;
+    fn yxzz(self) -> DVec4;
+    fn yxzw(self) -> DVec4;
+    fn yxwx(self) -> DVec4;
+    fn yxwy(self) -> DVec4;
+    fn yxwz(self) -> DVec4;
+    fn yxww(self) -> DVec4;
+    fn yyxx(self) -> DVec4;
+    fn yyxy(self) -> DVec4;
+    fn yyxz(self) -> DVec4;
+    fn yyxw(self) -> DVec4;
+    fn yyyx(self) -> DVec4;
+    fn yyyy(self) -> DVec4;
+    fn yyyz(self) -> DVec4;
+    fn yyyw(self) -> DVec4;
+    fn yyzx(self) -> DVec4;
+    fn yyzy(self) -> DVec4;
+    fn yyzz(self) -> DVec4;
+    fn yyzw(self) -> DVec4;
+    fn yywx(self) -> DVec4;
+    fn yywy(self) -> DVec4;
+    fn yywz(self) -> DVec4;
+    fn yyww(self) -> DVec4;
+    fn yzxx(self) -> DVec4;
+    fn yzxy(self) -> DVec4;
+    fn yzxz(self) -> DVec4;
+    fn yzxw(self) -> DVec4;
+    fn yzyx(self) -> DVec4;
+    fn yzyy(self) -> DVec4;
+    fn yzyz(self) -> DVec4;
+    fn yzyw(self) -> DVec4;
+    fn yzzx(self) -> DVec4;
+    fn yzzy(self) -> DVec4;
+    fn yzzz(self) -> DVec4;
+    fn yzzw(self) -> DVec4;
+    fn yzwx(self) -> DVec4;
+    fn yzwy(self) -> DVec4;
+    fn yzwz(self) -> DVec4;
+    fn yzww(self) -> DVec4;
+    fn ywxx(self) -> DVec4;
+    fn ywxy(self) -> DVec4;
+    fn ywxz(self) -> DVec4;
+    fn ywxw(self) -> DVec4;
+    fn ywyx(self) -> DVec4;
+    fn ywyy(self) -> DVec4;
+    fn ywyz(self) -> DVec4;
+    fn ywyw(self) -> DVec4;
+    fn ywzx(self) -> DVec4;
+    fn ywzy(self) -> DVec4;
+    fn ywzz(self) -> DVec4;
+    fn ywzw(self) -> DVec4;
+    fn ywwx(self) -> DVec4;
+    fn ywwy(self) -> DVec4;
+    fn ywwz(self) -> DVec4;
+    fn ywww(self) -> DVec4;
+    fn zxxx(self) -> DVec4;
+    fn zxxy(self) -> DVec4;
+    fn zxxz(self) -> DVec4;
+    fn zxxw(self) -> DVec4;
+    fn zxyx(self) -> DVec4;
+    fn zxyy(self) -> DVec4;
+    fn zxyz(self) -> DVec4;
+    fn zxyw(self) -> DVec4;
+    fn zxzx(self) -> DVec4;
+    fn zxzy(self) -> DVec4;
+    fn zxzz(self) -> DVec4;
+    fn zxzw(self) -> DVec4;
+    fn zxwx(self) -> DVec4;
+    fn zxwy(self) -> DVec4;
+    fn zxwz(self) -> DVec4;
+    fn zxww(self) -> DVec4;
+    fn zyxx(self) -> DVec4;
+    fn zyxy(self) -> DVec4;
+    fn zyxz(self) -> DVec4;
+    fn zyxw(self) -> DVec4;
+    fn zyyx(self) -> DVec4;
+    fn zyyy(self) -> DVec4;
+    fn zyyz(self) -> DVec4;
+    fn zyyw(self) -> DVec4;
+    fn zyzx(self) -> DVec4;
+    fn zyzy(self) -> DVec4;
+    fn zyzz(self) -> DVec4;
+    fn zyzw(self) -> DVec4;
+    fn zywx(self) -> DVec4;
+    fn zywy(self) -> DVec4;
+    fn zywz(self) -> DVec4;
+    fn zyww(self) -> DVec4;
+    fn zzxx(self) -> DVec4;
+    fn zzxy(self) -> DVec4;
+    fn zzxz(self) -> DVec4;
+    fn zzxw(self) -> DVec4;
+    fn zzyx(self) -> DVec4;
+    fn zzyy(self) -> DVec4;
+    fn zzyz(self) -> DVec4;
+    fn zzyw(self) -> DVec4;
+    fn zzzx(self) -> DVec4;
+    fn zzzy(self) -> DVec4;
+    fn zzzz(self) -> DVec4;
+    fn zzzw(self) -> DVec4;
+    fn zzwx(self) -> DVec4;
+    fn zzwy(self) -> DVec4;
+    fn zzwz(self) -> DVec4;
+    fn zzww(self) -> DVec4;
+    fn zwxx(self) -> DVec4;
+    fn zwxy(self) -> DVec4;
+    fn zwxz(self) -> DVec4;
+    fn zwxw(self) -> DVec4;
+    fn zwyx(self) -> DVec4;
+    fn zwyy(self) -> DVec4;
+    fn zwyz(self) -> DVec4;
+    fn zwyw(self) -> DVec4;
+    fn zwzx(self) -> DVec4;
+    fn zwzy(self) -> DVec4;
+    fn zwzz(self) -> DVec4;
+    fn zwzw(self) -> DVec4;
+    fn zwwx(self) -> DVec4;
+    fn zwwy(self) -> DVec4;
+    fn zwwz(self) -> DVec4;
+    fn zwww(self) -> DVec4;
+    fn wxxx(self) -> DVec4;
+    fn wxxy(self) -> DVec4;
+    fn wxxz(self) -> DVec4;
+    fn wxxw(self) -> DVec4;
+    fn wxyx(self) -> DVec4;
+    fn wxyy(self) -> DVec4;
+    fn wxyz(self) -> DVec4;
+    fn wxyw(self) -> DVec4;
+    fn wxzx(self) -> DVec4;
+    fn wxzy(self) -> DVec4;
+    fn wxzz(self) -> DVec4;
+    fn wxzw(self) -> DVec4;
+    fn wxwx(self) -> DVec4;
+    fn wxwy(self) -> DVec4;
+    fn wxwz(self) -> DVec4;
+    fn wxww(self) -> DVec4;
+    fn wyxx(self) -> DVec4;
+    fn wyxy(self) -> DVec4;
+    fn wyxz(self) -> DVec4;
+    fn wyxw(self) -> DVec4;
+    fn wyyx(self) -> DVec4;
+    fn wyyy(self) -> DVec4;
+    fn wyyz(self) -> DVec4;
+    fn wyyw(self) -> DVec4;
+    fn wyzx(self) -> DVec4;
+    fn wyzy(self) -> DVec4;
+    fn wyzz(self) -> DVec4;
+    fn wyzw(self) -> DVec4;
+    fn wywx(self) -> DVec4;
+    fn wywy(self) -> DVec4;
+    fn wywz(self) -> DVec4;
+    fn wyww(self) -> DVec4;
+    fn wzxx(self) -> DVec4;
+    fn wzxy(self) -> DVec4;
+    fn wzxz(self) -> DVec4;
+    fn wzxw(self) -> DVec4;
+    fn wzyx(self) -> DVec4;
+    fn wzyy(self) -> DVec4;
+    fn wzyz(self) -> DVec4;
+    fn wzyw(self) -> DVec4;
+    fn wzzx(self) -> DVec4;
+    fn wzzy(self) -> DVec4;
+    fn wzzz(self) -> DVec4;
+    fn wzzw(self) -> DVec4;
+    fn wzwx(self) -> DVec4;
+    fn wzwy(self) -> DVec4;
+    fn wzwz(self) -> DVec4;
+    fn wzww(self) -> DVec4;
+    fn wwxx(self) -> DVec4;
+    fn wwxy(self) -> DVec4;
+    fn wwxz(self) -> DVec4;
+    fn wwxw(self) -> DVec4;
+    fn wwyx(self) -> DVec4;
+    fn wwyy(self) -> DVec4;
+    fn wwyz(self) -> DVec4;
+    fn wwyw(self) -> DVec4;
+    fn wwzx(self) -> DVec4;
+    fn wwzy(self) -> DVec4;
+    fn wwzz(self) -> DVec4;
+    fn wwzw(self) -> DVec4;
+    fn wwwx(self) -> DVec4;
+    fn wwwy(self) -> DVec4;
+    fn wwwz(self) -> DVec4;
+    fn wwww(self) -> DVec4;
+    fn xxx(self) -> DVec3;
+    fn xxy(self) -> DVec3;
+    fn xxz(self) -> DVec3;
+    fn xxw(self) -> DVec3;
+    fn xyx(self) -> DVec3;
+    fn xyy(self) -> DVec3;
+    fn xyz(self) -> DVec3;
+    fn xyw(self) -> DVec3;
+    fn xzx(self) -> DVec3;
+    fn xzy(self) -> DVec3;
+    fn xzz(self) -> DVec3;
+    fn xzw(self) -> DVec3;
+    fn xwx(self) -> DVec3;
+    fn xwy(self) -> DVec3;
+    fn xwz(self) -> DVec3;
+    fn xww(self) -> DVec3;
+    fn yxx(self) -> DVec3;
+    fn yxy(self) -> DVec3;
+    fn yxz(self) -> DVec3;
+    fn yxw(self) -> DVec3;
+    fn yyx(self) -> DVec3;
+    fn yyy(self) -> DVec3;
+    fn yyz(self) -> DVec3;
+    fn yyw(self) -> DVec3;
+    fn yzx(self) -> DVec3;
+    fn yzy(self) -> DVec3;
+    fn yzz(self) -> DVec3;
+    fn yzw(self) -> DVec3;
+    fn ywx(self) -> DVec3;
+    fn ywy(self) -> DVec3;
+    fn ywz(self) -> DVec3;
+    fn yww(self) -> DVec3;
+    fn zxx(self) -> DVec3;
+    fn zxy(self) -> DVec3;
+    fn zxz(self) -> DVec3;
+    fn zxw(self) -> DVec3;
+    fn zyx(self) -> DVec3;
+    fn zyy(self) -> DVec3;
+    fn zyz(self) -> DVec3;
+    fn zyw(self) -> DVec3;
+    fn zzx(self) -> DVec3;
+    fn zzy(self) -> DVec3;
+    fn zzz(self) -> DVec3;
+    fn zzw(self) -> DVec3;
+    fn zwx(self) -> DVec3;
+    fn zwy(self) -> DVec3;
+    fn zwz(self) -> DVec3;
+    fn zww(self) -> DVec3;
+    fn wxx(self) -> DVec3;
+    fn wxy(self) -> DVec3;
+    fn wxz(self) -> DVec3;
+    fn wxw(self) -> DVec3;
+    fn wyx(self) -> DVec3;
+    fn wyy(self) -> DVec3;
+    fn wyz(self) -> DVec3;
+    fn wyw(self) -> DVec3;
+    fn wzx(self) -> DVec3;
+    fn wzy(self) -> DVec3;
+    fn wzz(self) -> DVec3;
+    fn wzw(self) -> DVec3;
+    fn wwx(self) -> DVec3;
+    fn wwy(self) -> DVec3;
+    fn wwz(self) -> DVec3;
+    fn www(self) -> DVec3;
+    fn xx(self) -> DVec2;
+    fn xy(self) -> DVec2;
+    fn xz(self) -> DVec2;
+    fn xw(self) -> DVec2;
+    fn yx(self) -> DVec2;
+    fn yy(self) -> DVec2;
+    fn yz(self) -> DVec2;
+    fn yw(self) -> DVec2;
+    fn zx(self) -> DVec2;
+    fn zy(self) -> DVec2;
+    fn zz(self) -> DVec2;
+    fn zw(self) -> DVec2;
+    fn wx(self) -> DVec2;
+    fn wy(self) -> DVec2;
+    fn wz(self) -> DVec2;
+    fn ww(self) -> DVec2;
+}
+
+impl DVec4Swizzles for DVec4 {
+    #[inline]
+    fn xxxx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxxz(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn xxxw(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn xxyx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xxyz(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn xxyw(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn xxzx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn xxzy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn xxzz(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn xxzw(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn xxwx(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn xxwy(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn xxwz(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn xxww(self) -> DVec4 {
+        DVec4::new(self.x, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn xyxx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyxz(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn xyxw(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn xyyx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xyyz(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn xyyw(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn xyzx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn xyzy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn xyzz(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn xywx(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn xywy(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn xywz(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn xyww(self) -> DVec4 {
+        DVec4::new(self.x, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn xzxx(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn xzxy(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn xzxz(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn xzxw(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn xzyx(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn xzyy(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn xzyz(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn xzyw(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn xzzx(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn xzzy(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn xzzz(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn xzzw(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn xzwx(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn xzwy(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn xzwz(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn xzww(self) -> DVec4 {
+        DVec4::new(self.x, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn xwxx(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn xwxy(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn xwxz(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn xwxw(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn xwyx(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn xwyy(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn xwyz(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn xwyw(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn xwzx(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn xwzy(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn xwzz(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn xwzw(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn xwwx(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn xwwy(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn xwwz(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn xwww(self) -> DVec4 {
+        DVec4::new(self.x, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn yxxx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxxz(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn yxxw(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn yxyx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yxyz(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn yxyw(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn yxzx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn yxzy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn yxzz(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn yxzw(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn yxwx(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn yxwy(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn yxwz(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn yxww(self) -> DVec4 {
+        DVec4::new(self.y, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn yyxx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyxz(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn yyxw(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn yyyx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yyyz(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn yyyw(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn yyzx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn yyzy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn yyzz(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn yyzw(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn yywx(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn yywy(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn yywz(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn yyww(self) -> DVec4 {
+        DVec4::new(self.y, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn yzxx(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn yzxy(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn yzxz(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn yzxw(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn yzyx(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn yzyy(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn yzyz(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn yzyw(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn yzzx(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn yzzy(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn yzzz(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn yzzw(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn yzwx(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn yzwy(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn yzwz(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn yzww(self) -> DVec4 {
+        DVec4::new(self.y, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn ywxx(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn ywxy(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn ywxz(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn ywxw(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn ywyx(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn ywyy(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn ywyz(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn ywyw(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn ywzx(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn ywzy(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn ywzz(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn ywzw(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn ywwx(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn ywwy(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn ywwz(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn ywww(self) -> DVec4 {
+        DVec4::new(self.y, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn zxxx(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn zxxy(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn zxxz(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn zxxw(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn zxyx(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn zxyy(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn zxyz(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn zxyw(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn zxzx(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn zxzy(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn zxzz(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn zxzw(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn zxwx(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn zxwy(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn zxwz(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn zxww(self) -> DVec4 {
+        DVec4::new(self.z, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn zyxx(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn zyxy(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn zyxz(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn zyxw(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn zyyx(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn zyyy(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn zyyz(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn zyyw(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn zyzx(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn zyzy(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn zyzz(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn zyzw(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn zywx(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn zywy(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn zywz(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn zyww(self) -> DVec4 {
+        DVec4::new(self.z, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn zzxx(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn zzxy(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn zzxz(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn zzxw(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn zzyx(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn zzyy(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn zzyz(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn zzyw(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn zzzx(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn zzzy(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn zzzz(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn zzzw(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn zzwx(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn zzwy(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn zzwz(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn zzww(self) -> DVec4 {
+        DVec4::new(self.z, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn zwxx(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn zwxy(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn zwxz(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn zwxw(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn zwyx(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn zwyy(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn zwyz(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn zwyw(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn zwzx(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn zwzy(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn zwzz(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn zwzw(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn zwwx(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn zwwy(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn zwwz(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn zwww(self) -> DVec4 {
+        DVec4::new(self.z, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn wxxx(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn wxxy(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn wxxz(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn wxxw(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn wxyx(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn wxyy(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn wxyz(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn wxyw(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn wxzx(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn wxzy(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn wxzz(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn wxzw(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn wxwx(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn wxwy(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn wxwz(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn wxww(self) -> DVec4 {
+        DVec4::new(self.w, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn wyxx(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn wyxy(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn wyxz(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn wyxw(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn wyyx(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn wyyy(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn wyyz(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn wyyw(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn wyzx(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn wyzy(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn wyzz(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn wyzw(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn wywx(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn wywy(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn wywz(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn wyww(self) -> DVec4 {
+        DVec4::new(self.w, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn wzxx(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn wzxy(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn wzxz(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn wzxw(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn wzyx(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn wzyy(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn wzyz(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn wzyw(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn wzzx(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn wzzy(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn wzzz(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn wzzw(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn wzwx(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn wzwy(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn wzwz(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn wzww(self) -> DVec4 {
+        DVec4::new(self.w, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn wwxx(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn wwxy(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn wwxz(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn wwxw(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn wwyx(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn wwyy(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn wwyz(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn wwyw(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn wwzx(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn wwzy(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn wwzz(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn wwzw(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn wwwx(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn wwwy(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn wwwz(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn wwww(self) -> DVec4 {
+        DVec4::new(self.w, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn xxx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xxz(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xxw(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xyz(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xyw(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xzx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xzy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzz(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xzw(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xwx(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xwy(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xwz(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xww(self) -> DVec3 {
+        DVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxz(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yxw(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyz(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yyw(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yzx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yzy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yzz(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yzw(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn ywx(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn ywy(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn ywz(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yww(self) -> DVec3 {
+        DVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zxx(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zxy(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zxz(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zxw(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zyx(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zyy(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zyz(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zyw(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zzx(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zzy(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zzz(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zzw(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zwx(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zwy(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zwz(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zww(self) -> DVec3 {
+        DVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wxx(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wxy(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wxz(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wxw(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wyx(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wyy(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wyz(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wyw(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wzx(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wzy(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wzz(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wzw(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wwx(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wwy(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wwz(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn www(self) -> DVec3 {
+        DVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xx(self) -> DVec2 {
+        DVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn xy(self) -> DVec2 {
+        DVec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn xz(self) -> DVec2 {
+        DVec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn xw(self) -> DVec2 {
+        DVec2 {
+            x: self.x,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn yx(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn yz(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn yw(self) -> DVec2 {
+        DVec2 {
+            x: self.y,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn zx(self) -> DVec2 {
+        DVec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn zy(self) -> DVec2 {
+        DVec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn zz(self) -> DVec2 {
+        DVec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn zw(self) -> DVec2 {
+        DVec2 {
+            x: self.z,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn wx(self) -> DVec2 {
+        DVec2 {
+            x: self.w,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn wy(self) -> DVec2 {
+        DVec2 {
+            x: self.w,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn wz(self) -> DVec2 {
+        DVec2 {
+            x: self.w,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn ww(self) -> DVec2 {
+        DVec2 {
+            x: self.w,
+            y: self.w,
+        }
+    }
+}
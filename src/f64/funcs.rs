@@ -0,0 +1,22 @@
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+#[inline]
+pub(crate) fn scalar_sin_cos(x: f64) -> (f64, f64) {
+    x.sin_cos()
+}
+
+#[inline]
+pub fn scalar_acos(value: f64) -> f64 {
+    // Clamp input to [-1,1] so rounding error in the caller can't produce a NaN.
+    value.max(-1.0).min(1.0).acos()
+}
+
+#[test]
+fn test_scalar_acos() {
+    assert_eq!(scalar_acos(1.0), 0.0);
+    assert_eq!(scalar_acos(1.0 + 1e-12), 0.0);
+    assert_eq!(scalar_acos(-1.0), core::f64::consts::PI);
+    assert_eq!(scalar_acos(-1.0 - 1e-12), core::f64::consts::PI);
+    assert!((scalar_acos(0.5) - core::f64::consts::FRAC_PI_3).abs() <= 1e-15);
+}
@@ -0,0 +1,46 @@
+use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use bytemuck::{Pod, Zeroable};
+
+unsafe impl Pod for DMat2 {}
+unsafe impl Zeroable for DMat2 {}
+unsafe impl Pod for DMat3 {}
+unsafe impl Zeroable for DMat3 {}
+unsafe impl Pod for DMat4 {}
+unsafe impl Zeroable for DMat4 {}
+
+unsafe impl Pod for DQuat {}
+unsafe impl Zeroable for DQuat {}
+
+unsafe impl Pod for DVec2 {}
+unsafe impl Zeroable for DVec2 {}
+unsafe impl Pod for DVec3 {}
+unsafe impl Zeroable for DVec3 {}
+unsafe impl Pod for DVec4 {}
+unsafe impl Zeroable for DVec4 {}
+
+#[cfg(test)]
+mod test {
+    use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use bytemuck;
+    use core::mem;
+
+    macro_rules! test_t {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                let t = <$t>::default();
+                let b = bytemuck::bytes_of(&t);
+                assert_eq!(t.as_ref().as_ptr() as usize, b.as_ptr() as usize);
+                assert_eq!(b.len(), mem::size_of_val(&t));
+            }
+        };
+    }
+
+    test_t!(dmat2, DMat2);
+    test_t!(dmat3, DMat3);
+    test_t!(dmat4, DMat4);
+    test_t!(dquat, DQuat);
+    test_t!(dvec2, DVec2);
+    test_t!(dvec3, DVec3);
+    test_t!(dvec4, DVec4);
+}
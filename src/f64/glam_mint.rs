@@ -0,0 +1,310 @@
+use super::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use mint;
+
+impl From<mint::Point2<f64>> for DVec2 {
+    fn from(v: mint::Point2<f64>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<DVec2> for mint::Point2<f64> {
+    fn from(v: DVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Point3<f64>> for DVec3 {
+    fn from(v: mint::Point3<f64>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<DVec3> for mint::Point3<f64> {
+    fn from(v: DVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector2<f64>> for DVec2 {
+    fn from(v: mint::Vector2<f64>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<DVec2> for mint::Vector2<f64> {
+    fn from(v: DVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Vector3<f64>> for DVec3 {
+    fn from(v: mint::Vector3<f64>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<DVec3> for mint::Vector3<f64> {
+    fn from(v: DVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector4<f64>> for DVec4 {
+    fn from(v: mint::Vector4<f64>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<DVec4> for mint::Vector4<f64> {
+    fn from(v: DVec4) -> Self {
+        let (x, y, z, w) = v.into();
+        Self { x, y, z, w }
+    }
+}
+
+impl From<mint::Quaternion<f64>> for DQuat {
+    fn from(q: mint::Quaternion<f64>) -> Self {
+        Self::from_xyzw(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl From<DQuat> for mint::Quaternion<f64> {
+    fn from(q: DQuat) -> Self {
+        let (x, y, z, s) = q.into();
+        Self {
+            s,
+            v: mint::Vector3 { x, y, z },
+        }
+    }
+}
+
+impl From<mint::RowMatrix2<f64>> for DMat2 {
+    fn from(m: mint::RowMatrix2<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into()).transpose()
+    }
+}
+
+impl From<DMat2> for mint::RowMatrix2<f64> {
+    fn from(m: DMat2) -> Self {
+        let mt = m.transpose();
+        Self {
+            x: mt.x_axis.into(),
+            y: mt.y_axis.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix2<f64>> for DMat2 {
+    fn from(m: mint::ColumnMatrix2<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into())
+    }
+}
+
+impl From<DMat2> for mint::ColumnMatrix2<f64> {
+    fn from(m: DMat2) -> Self {
+        Self {
+            x: m.x_axis.into(),
+            y: m.y_axis.into(),
+        }
+    }
+}
+
+impl From<mint::RowMatrix3<f64>> for DMat3 {
+    fn from(m: mint::RowMatrix3<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into()).transpose()
+    }
+}
+
+impl From<DMat3> for mint::RowMatrix3<f64> {
+    fn from(m: DMat3) -> Self {
+        let mt = m.transpose();
+        Self {
+            x: mt.x_axis.into(),
+            y: mt.y_axis.into(),
+            z: mt.z_axis.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<f64>> for DMat3 {
+    fn from(m: mint::ColumnMatrix3<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl From<DMat3> for mint::ColumnMatrix3<f64> {
+    fn from(m: DMat3) -> Self {
+        Self {
+            x: m.x_axis.into(),
+            y: m.y_axis.into(),
+            z: m.z_axis.into(),
+        }
+    }
+}
+
+impl From<mint::RowMatrix4<f64>> for DMat4 {
+    fn from(m: mint::RowMatrix4<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into()).transpose()
+    }
+}
+
+impl From<DMat4> for mint::RowMatrix4<f64> {
+    fn from(m: DMat4) -> Self {
+        let mt = m.transpose();
+        Self {
+            x: mt.x_axis.into(),
+            y: mt.y_axis.into(),
+            z: mt.z_axis.into(),
+            w: mt.w_axis.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f64>> for DMat4 {
+    fn from(m: mint::ColumnMatrix4<f64>) -> Self {
+        Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
+
+impl From<DMat4> for mint::ColumnMatrix4<f64> {
+    fn from(m: DMat4) -> Self {
+        Self {
+            x: m.x_axis.into(),
+            y: m.y_axis.into(),
+            z: m.z_axis.into(),
+            w: m.w_axis.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mint;
+
+    #[test]
+    fn test_point2() {
+        use crate::DVec2;
+        let m = mint::Point2 { x: 1.0, y: 2.0 };
+        let g = DVec2::from(m);
+        assert_eq!(g, DVec2::new(1.0, 2.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_point3() {
+        use crate::DVec3;
+        let m = mint::Point3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let g = DVec3::from(m);
+        assert_eq!(g, DVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector2() {
+        use crate::DVec2;
+        let m = mint::Vector2 { x: 1.0, y: 2.0 };
+        let g = DVec2::from(m);
+        assert_eq!(g, DVec2::new(1.0, 2.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector3() {
+        use crate::DVec3;
+        let m = mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let g = DVec3::from(m);
+        assert_eq!(g, DVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector4() {
+        use crate::DVec4;
+        let m = mint::Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        };
+        let g = DVec4::from(m);
+        assert_eq!(g, DVec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_quaternion() {
+        use crate::DQuat;
+        let m = mint::Quaternion {
+            v: mint::Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            s: 4.0,
+        };
+        let g = DQuat::from(m);
+        assert_eq!(g, DQuat::from((1.0, 2.0, 3.0, 4.0)));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_matrix2() {
+        use crate::DMat2;
+        let g = DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
+        let m = mint::ColumnMatrix2::from(g);
+        assert_eq!(g, DMat2::from(m));
+        let mt = mint::RowMatrix2::from(g);
+        assert_eq!(mt, mint::RowMatrix2::from([[1.0, 3.0], [2.0, 4.0]]));
+        assert_eq!(g, DMat2::from(mt));
+    }
+
+    #[test]
+    fn test_matrix3() {
+        use crate::DMat3;
+        let g = DMat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let m = mint::ColumnMatrix3::from(g);
+        assert_eq!(g, DMat3::from(m));
+        let mt = mint::RowMatrix3::from(g);
+        assert_eq!(
+            mt,
+            mint::RowMatrix3::from([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]])
+        );
+        assert_eq!(g, DMat3::from(mt));
+    }
+
+    #[test]
+    fn test_matrix4() {
+        use crate::DMat4;
+        let g = DMat4::from_cols_array_2d(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        let m = mint::ColumnMatrix4::from(g);
+        assert_eq!(g, DMat4::from(m));
+        let mt = mint::RowMatrix4::from(g);
+        assert_eq!(
+            mt,
+            mint::RowMatrix4::from([
+                [1.0, 5.0, 9.0, 13.0],
+                [2.0, 6.0, 10.0, 14.0],
+                [3.0, 7.0, 11.0, 15.0],
+                [4.0, 8.0, 12.0, 16.0]
+            ])
+        );
+        assert_eq!(g, DMat4::from(mt));
+    }
+}
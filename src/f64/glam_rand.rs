@@ -0,0 +1,59 @@
+use super::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<DMat2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMat2 {
+        DMat2::from_cols_array(&rng.gen())
+    }
+}
+
+impl Distribution<DMat3> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMat3 {
+        DMat3::from_cols_array(&rng.gen())
+    }
+}
+
+impl Distribution<DMat4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMat4 {
+        DMat4::from_cols_array(&rng.gen())
+    }
+}
+
+impl Distribution<DQuat> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DQuat {
+        use core::f64::consts::PI;
+        let yaw = -PI + rng.gen::<f64>() * 2.0 * PI;
+        let pitch = -PI + rng.gen::<f64>() * 2.0 * PI;
+        let roll = -PI + rng.gen::<f64>() * 2.0 * PI;
+        DQuat::from_rotation_ypr(yaw, pitch, roll)
+    }
+}
+
+impl Distribution<DVec2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVec2 {
+        rng.gen::<[f64; 2]>().into()
+    }
+}
+
+impl Distribution<DVec3> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVec3 {
+        rng.gen::<[f64; 3]>().into()
+    }
+}
+
+impl Distribution<DVec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVec4 {
+        rng.gen::<[f64; 4]>().into()
+    }
+}
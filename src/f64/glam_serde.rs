@@ -0,0 +1,365 @@
+use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use core::fmt;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTupleStruct, Serializer},
+};
+
+impl Serialize for DVec2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y) = (*self).into();
+        let mut state = serializer.serialize_tuple_struct("DVec2", 2)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.end()
+    }
+}
+
+impl Serialize for DVec3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z) = (*self).into();
+        // 3 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("DVec3", 3)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.end()
+    }
+}
+
+impl Serialize for DVec4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z, w) = (*self).into();
+        // 4 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("DVec4", 4)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.serialize_field(&w)?;
+        state.end()
+    }
+}
+
+impl Serialize for DQuat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z, w) = (*self).into();
+        // 4 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("DQuat", 4)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.serialize_field(&w)?;
+        state.end()
+    }
+}
+
+impl Serialize for DMat2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let f: &[f64; 4] = self.as_ref();
+        let mut state = serializer.serialize_tuple_struct("DMat2", 4)?;
+        state.serialize_field(&f[0])?;
+        state.serialize_field(&f[1])?;
+        state.serialize_field(&f[2])?;
+        state.serialize_field(&f[3])?;
+        state.end()
+    }
+}
+
+impl Serialize for DMat3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+
+        let mut state = serializer.serialize_tuple_struct("DMat3", 9)?;
+        state.serialize_field(&m00)?;
+        state.serialize_field(&m01)?;
+        state.serialize_field(&m02)?;
+        state.serialize_field(&m10)?;
+        state.serialize_field(&m11)?;
+        state.serialize_field(&m12)?;
+        state.serialize_field(&m20)?;
+        state.serialize_field(&m21)?;
+        state.serialize_field(&m22)?;
+        state.end()
+    }
+}
+
+impl Serialize for DMat4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple_struct("DMat4", 16)?;
+        for f in self.as_ref() {
+            state.serialize_field(f)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DVec2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec2Visitor;
+
+        impl<'de> Visitor<'de> for Vec2Visitor {
+            type Value = DVec2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DVec2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DVec2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(DVec2::new(x, y))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DVec2", 2, Vec2Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DVec3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec3Visitor;
+
+        impl<'de> Visitor<'de> for Vec3Visitor {
+            type Value = DVec3;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DVec2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DVec3, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(DVec3::new(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DVec3", 3, Vec3Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DVec4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec4Visitor;
+
+        impl<'de> Visitor<'de> for Vec4Visitor {
+            type Value = DVec4;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DVec2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DVec4, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let w = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(DVec4::new(x, y, z, w))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DVec4", 4, Vec4Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DQuat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct QuatVisitor;
+
+        impl<'de> Visitor<'de> for QuatVisitor {
+            type Value = DQuat;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DVec2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DQuat, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let w = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(DQuat::from_xyzw(x, y, z, w))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DQuat", 4, QuatVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DMat2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Mat2Visitor;
+
+        impl<'de> Visitor<'de> for Mat2Visitor {
+            type Value = DMat2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DMat2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DMat2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 4] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let x = DVec2::new(f[0], f[1]);
+                let y = DVec2::new(f[2], f[3]);
+                Ok(DMat2::from_cols(x, y))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DMat2", 4, Mat2Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DMat3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Mat3Visitor;
+
+        impl<'de> Visitor<'de> for Mat3Visitor {
+            type Value = DMat3;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DMat3")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DMat3, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 9] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let x = DVec3::new(f[0], f[1], f[2]);
+                let y = DVec3::new(f[3], f[4], f[5]);
+                let z = DVec3::new(f[6], f[7], f[8]);
+                Ok(DMat3::from_cols(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DMat3", 9, Mat3Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DMat4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Mat4Visitor;
+
+        impl<'de> Visitor<'de> for Mat4Visitor {
+            type Value = DMat4;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DMat4")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<DMat4, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 16] };
+                for (i, v) in f.iter_mut().enumerate() {
+                    *v = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let x = DVec4::new(f[0], f[1], f[2], f[3]);
+                let y = DVec4::new(f[4], f[5], f[6], f[7]);
+                let z = DVec4::new(f[8], f[9], f[10], f[11]);
+                let w = DVec4::new(f[12], f[13], f[14], f[15]);
+                Ok(DMat4::from_cols(x, y, z, w))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("DMat4", 16, Mat4Visitor)
+    }
+}
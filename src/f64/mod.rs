@@ -0,0 +1,49 @@
+mod cast;
+mod dmat2;
+mod dmat3;
+mod dmat4;
+mod dquat;
+mod dvec2;
+mod dvec2_mask;
+mod dvec2_swizzle;
+mod dvec3;
+mod dvec3_mask;
+mod dvec3_swizzle;
+mod dvec4;
+mod dvec4_mask;
+mod dvec4_swizzle;
+mod funcs;
+
+pub use cast::{F64x16Cast, F64x2Cast, F64x3Cast, F64x4Cast, F64x9Cast};
+pub use dmat2::*;
+pub use dmat3::*;
+pub use dmat4::*;
+pub use dquat::*;
+pub use dvec2::*;
+pub use dvec2_mask::*;
+pub use dvec2_swizzle::*;
+pub use dvec3::*;
+pub use dvec3_mask::*;
+pub use dvec3_swizzle::*;
+pub use dvec4::*;
+pub use dvec4_mask::*;
+pub use dvec4_swizzle::*;
+pub(crate) use funcs::{scalar_acos, scalar_sin_cos};
+
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct XYZW {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+#[cfg(feature = "bytemuck")]
+mod glam_bytemuck;
+#[cfg(feature = "mint")]
+mod glam_mint;
+#[cfg(feature = "rand")]
+mod glam_rand;
+#[cfg(feature = "serde")]
+mod glam_serde;
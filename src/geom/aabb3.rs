@@ -0,0 +1,141 @@
+use crate::{Affine3A, Mat4, Vec3, Vec3A};
+use core::{f32, fmt};
+
+/// A 3D axis aligned bounding box, defined by its `min` and `max` corners.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Aabb3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl fmt::Display for Aabb3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+impl Aabb3 {
+    /// Creates a bounding box from the given `min` and `max` corners.
+    #[inline]
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        glam_assert!(min.cmple(max).all());
+        Self { min, max }
+    }
+
+    /// Creates an empty bounding box, with `min` set to positive infinity and `max` set to
+    /// negative infinity.
+    ///
+    /// Growing an empty bounding box by a point results in a bounding box containing only that
+    /// point, which makes this a useful starting value when accumulating bounds.
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            min: Vec3::splat(f32::INFINITY),
+            max: Vec3::splat(f32::NEG_INFINITY),
+        }
+    }
+
+    /// Creates a bounding box from the given `center` and `half_extents`.
+    #[inline]
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Creates the smallest bounding box containing all of the given `points`.
+    ///
+    /// If `points` is empty the result is `Aabb3::empty()`.
+    pub fn from_points(points: &[Vec3]) -> Self {
+        points
+            .iter()
+            .fold(Self::empty(), |aabb, &point| aabb.grow(point))
+    }
+
+    /// Returns `true` if `min` is greater than `max` on any axis.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
+    /// Returns the center of the bounding box.
+    #[inline]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns half of the size of the bounding box on each axis.
+    #[inline]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns the size of the bounding box on each axis.
+    #[inline]
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the bounding box.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        (point.cmpge(self.min) & point.cmple(self.max)).all()
+    }
+
+    /// Returns the point inside or on the boundary of the bounding box closest to `point`.
+    ///
+    /// If `point` is inside the bounding box it is returned unchanged.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Returns the distance from `point` to the bounding box, which is zero if `point` is inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the smallest bounding box containing both `self` and `other`.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Returns the smallest bounding box containing both `self` and `point`.
+    #[inline]
+    pub fn grow(&self, point: Vec3) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Returns the bounding box of `self` after it has been transformed by `transform`.
+    ///
+    /// The result fully contains the transformed box but is generally larger than it when the
+    /// transform contains a rotation.
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        // Based on "Transforming Axis-Aligned Bounding Boxes" by Jim Arvo, Graphics Gems 1990
+        let m = &transform.matrix3;
+        let half_extents = Vec3A::from(self.half_extents());
+        let center = transform.transform_point3a(self.center().into());
+        let half_extents = m.x_axis.abs() * half_extents.x
+            + m.y_axis.abs() * half_extents.y
+            + m.z_axis.abs() * half_extents.z;
+        Self {
+            min: (center - half_extents).into(),
+            max: (center + half_extents).into(),
+        }
+    }
+
+    /// Returns the bounding box of `self` after it has been transformed by `transform`.
+    ///
+    /// The given `Mat4` must be an affine transform.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        self.transform_affine3a(&Affine3A::from_mat4(*transform))
+    }
+}
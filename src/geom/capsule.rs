@@ -0,0 +1,84 @@
+use super::{closest_point_on_segment, max_scale, Aabb3};
+use crate::{Affine3A, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D capsule, made of all points within `radius` of the line segment from `a` to `b`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Capsule {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub radius: f32,
+}
+
+impl fmt::Display for Capsule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.a, self.b, self.radius)
+    }
+}
+
+impl Capsule {
+    /// Creates a capsule from the segment end points `a` and `b` and a non-negative `radius`.
+    #[inline]
+    pub fn new(a: Vec3, b: Vec3, radius: f32) -> Self {
+        glam_assert!(radius >= 0.0);
+        Self { a, b, radius }
+    }
+
+    /// Returns `true` if `point` is inside or on the surface of the capsule.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        closest_point_on_segment(self.a, self.b, point).distance_squared(point)
+            <= self.radius * self.radius
+    }
+
+    /// Returns the point inside or on the surface of the capsule closest to `point`.
+    ///
+    /// If `point` is inside the capsule it is returned unchanged.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let on_segment = closest_point_on_segment(self.a, self.b, point);
+        let offset = point - on_segment;
+        let distance_sq = offset.length_squared();
+        if distance_sq <= self.radius * self.radius {
+            point
+        } else {
+            on_segment + offset * (self.radius / distance_sq.sqrt())
+        }
+    }
+
+    /// Returns the distance from `point` to the capsule, which is zero if `point` is inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        let on_segment = closest_point_on_segment(self.a, self.b, point);
+        (on_segment.distance(point) - self.radius).max(0.0)
+    }
+
+    /// Returns the axis aligned bounding box of the capsule.
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        let radius = Vec3::splat(self.radius);
+        Aabb3::new(self.a.min(self.b) - radius, self.a.max(self.b) + radius)
+    }
+
+    /// Returns the capsule transformed by `transform`.
+    ///
+    /// The radius is scaled by the largest scale factor of `transform`, so under non-uniform
+    /// scale the result contains the transformed capsule rather than matching it exactly.
+    #[inline]
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        Self::new(
+            transform.transform_point3(self.a),
+            transform.transform_point3(self.b),
+            self.radius * max_scale(transform),
+        )
+    }
+
+    /// Returns the capsule transformed by `transform`.
+    ///
+    /// The given `Mat4` must be an affine transform.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        self.transform_affine3a(&Affine3A::from_mat4(*transform))
+    }
+}
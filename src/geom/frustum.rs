@@ -0,0 +1,215 @@
+use super::{Aabb3, Plane, Sphere};
+use crate::{Mat4, Vec3, Vec3A, Vec4};
+use core::fmt;
+
+/// The depth range of clip space, used to interpret the depth of projection matrices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ClipDepth {
+    /// OpenGL style depth in `[-1, 1]` with the near plane at `-1`, as produced by
+    /// `Mat4::perspective_rh_gl` and `Mat4::orthographic_rh_gl`.
+    NegativeOneToOne,
+    /// Direct3D, Metal and Vulkan style depth in `[0, 1]` with the near plane at `0`, as produced
+    /// by `Mat4::perspective_rh`, `Mat4::perspective_infinite_rh` and `Mat4::orthographic_rh`.
+    ZeroToOne,
+    /// Reversed-Z depth in `[0, 1]` with the near plane at `1` and the far plane at `0`, as
+    /// produced by `Mat4::perspective_infinite_reverse_rh`.
+    ZeroToOneReversed,
+}
+
+impl Default for ClipDepth {
+    #[inline]
+    fn default() -> Self {
+        ClipDepth::ZeroToOne
+    }
+}
+
+/// The result of testing whether a volume is inside a `Frustum`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Intersection {
+    /// The volume is completely outside of the frustum.
+    Outside,
+    /// The volume is completely inside of the frustum.
+    Inside,
+    /// The volume is partially inside of the frustum.
+    Intersecting,
+}
+
+/// A view frustum made of six planes with normals pointing into the frustum.
+///
+/// The planes are stored in the order left, right, bottom, top, near and far, and can be
+/// accessed by index using the `LEFT`, `RIGHT`, `BOTTOM`, `TOP`, `NEAR` and `FAR` constants.
+///
+/// If the frustum was built from a projection with an infinite far plane, the far plane has a
+/// zero normal and a positive `d`, so every point is in front of it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl fmt::Display for Frustum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}, {}, {}]",
+            self.planes[0],
+            self.planes[1],
+            self.planes[2],
+            self.planes[3],
+            self.planes[4],
+            self.planes[5]
+        )
+    }
+}
+
+impl Frustum {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    /// Creates a frustum from the given planes, in the order left, right, bottom, top, near and
+    /// far.
+    #[inline]
+    pub fn from_planes(planes: [Plane; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Extracts the frustum planes from a projection or view-projection matrix.
+    ///
+    /// The planes are in the space `view_proj` transforms from, i.e. world space for a
+    /// view-projection matrix and view space for a projection matrix. `depth` must match the
+    /// depth range the matrix was built for.
+    pub fn from_mat4(view_proj: &Mat4, depth: ClipDepth) -> Self {
+        // Based on "Fast Extraction of Viewing Frustum Planes from the World-View-Projection
+        // Matrix" by Gil Gribb and Klaus Hartmann
+        let rows = view_proj.transpose();
+        let (r0, r1, r2, r3) = (rows.x_axis, rows.y_axis, rows.z_axis, rows.w_axis);
+        let (near, far) = match depth {
+            ClipDepth::NegativeOneToOne => (r3 + r2, r3 - r2),
+            ClipDepth::ZeroToOne => (r2, r3 - r2),
+            ClipDepth::ZeroToOneReversed => (r3 - r2, r2),
+        };
+        Self {
+            planes: [
+                plane_from_vec4(r3 + r0),
+                plane_from_vec4(r3 - r0),
+                plane_from_vec4(r3 + r1),
+                plane_from_vec4(r3 - r1),
+                plane_from_vec4(near),
+                far_plane_from_vec4(far, r3),
+            ],
+        }
+    }
+
+    /// Returns `true` if the far plane is at infinity.
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        self.planes[Self::FAR].normal == Vec3::zero()
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the frustum.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Returns `true` if `sphere` is at least partially inside the frustum.
+    ///
+    /// This is conservative, spheres near the corners of the frustum may be reported as
+    /// intersecting when they are just outside.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let center = Vec3A::from(sphere.center);
+        self.planes
+            .iter()
+            .all(|plane| Vec3A::from(plane.normal).dot(center) + plane.d >= -sphere.radius)
+    }
+
+    /// Tests whether `aabb` is inside, outside or intersecting the frustum.
+    ///
+    /// This is conservative, boxes near the corners of the frustum may be reported as
+    /// intersecting when they are just outside.
+    pub fn intersects_aabb(&self, aabb: &Aabb3) -> Intersection {
+        let center = Vec3A::from(aabb.center());
+        let half_extents = Vec3A::from(aabb.half_extents());
+        let mut result = Intersection::Inside;
+        for plane in self.planes.iter() {
+            let normal = Vec3A::from(plane.normal);
+            // the projected radius of the box onto the plane normal
+            let radius = half_extents.dot(normal.abs());
+            let distance = normal.dot(center) + plane.d;
+            if distance < -radius {
+                return Intersection::Outside;
+            }
+            if distance < radius {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns the eight corners of the frustum.
+    ///
+    /// The near corners come first, followed by the far corners, each in the order bottom left,
+    /// bottom right, top right and top left. If the far plane is at infinity the far corners are
+    /// not finite.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let p = &self.planes;
+        let (l, r, b, t) = (
+            &p[Self::LEFT],
+            &p[Self::RIGHT],
+            &p[Self::BOTTOM],
+            &p[Self::TOP],
+        );
+        let (n, f) = (&p[Self::NEAR], &p[Self::FAR]);
+        [
+            intersect_planes(n, l, b),
+            intersect_planes(n, r, b),
+            intersect_planes(n, r, t),
+            intersect_planes(n, l, t),
+            intersect_planes(f, l, b),
+            intersect_planes(f, r, b),
+            intersect_planes(f, r, t),
+            intersect_planes(f, l, t),
+        ]
+    }
+}
+
+/// Normalizes a plane given as `[a, b, c, d]`.
+#[inline]
+fn plane_from_vec4(v: Vec4) -> Plane {
+    let normal = v.truncate();
+    let length = normal.length();
+    Plane {
+        normal: normal / length,
+        d: v.w / length,
+    }
+}
+
+/// Normalizes the far plane given as `[a, b, c, d]`, unless its normal is zero relative to the
+/// `w` row of the matrix as it is for an infinite projection. That plane is at infinity and gets
+/// a zero normal and a positive `d` so every point is in front of it.
+#[inline]
+fn far_plane_from_vec4(v: Vec4, w_row: Vec4) -> Plane {
+    if v.truncate().length() <= core::f32::EPSILON * w_row.truncate().length() {
+        Plane {
+            normal: Vec3::zero(),
+            d: 1.0,
+        }
+    } else {
+        plane_from_vec4(v)
+    }
+}
+
+/// Returns the point where three planes meet.
+#[inline]
+fn intersect_planes(a: &Plane, b: &Plane, c: &Plane) -> Vec3 {
+    let bc = b.normal.cross(c.normal);
+    let ca = c.normal.cross(a.normal);
+    let ab = a.normal.cross(b.normal);
+    (bc * a.d + ca * b.d + ab * c.d) / -a.normal.dot(bc)
+}
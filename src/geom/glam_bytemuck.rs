@@ -0,0 +1,60 @@
+use super::{Aabb3, Capsule, Frustum, Obb, Plane, Ray3, Sphere, Triangle3, Viewport};
+use bytemuck::{Pod, Zeroable};
+
+unsafe impl Pod for Aabb3 {}
+unsafe impl Zeroable for Aabb3 {}
+unsafe impl Pod for Capsule {}
+unsafe impl Zeroable for Capsule {}
+unsafe impl Pod for Frustum {}
+unsafe impl Zeroable for Frustum {}
+unsafe impl Pod for Obb {}
+unsafe impl Zeroable for Obb {}
+unsafe impl Pod for Plane {}
+unsafe impl Zeroable for Plane {}
+unsafe impl Pod for Ray3 {}
+unsafe impl Zeroable for Ray3 {}
+unsafe impl Pod for Sphere {}
+unsafe impl Zeroable for Sphere {}
+unsafe impl Pod for Triangle3 {}
+unsafe impl Zeroable for Triangle3 {}
+// `Viewport` is not `Pod` as its enum fields don't accept every bit pattern, but the first
+// variant of each has the discriminant zero.
+unsafe impl Zeroable for Viewport {}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Aabb3, Capsule, Frustum, Obb, Plane, Ray3, Sphere, Triangle3, Viewport};
+    use bytemuck;
+    use core::mem;
+
+    macro_rules! test_t {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                let t = bytemuck::Zeroable::zeroed();
+                let b = bytemuck::bytes_of::<$t>(&t);
+                assert_eq!(&t as *const $t as usize, b.as_ptr() as usize);
+                assert_eq!(b.len(), mem::size_of::<$t>());
+                assert_eq!(mem::align_of::<$t>(), 4);
+            }
+        };
+    }
+
+    test_t!(aabb3, Aabb3);
+    test_t!(capsule, Capsule);
+    test_t!(frustum, Frustum);
+    test_t!(obb, Obb);
+    test_t!(plane, Plane);
+    test_t!(ray3, Ray3);
+    test_t!(sphere, Sphere);
+    test_t!(triangle3, Triangle3);
+
+    #[test]
+    fn viewport() {
+        use super::super::{ClipDepth, ViewportOrigin};
+        let v: Viewport = bytemuck::Zeroable::zeroed();
+        assert_eq!(ViewportOrigin::TopLeft, v.origin);
+        assert_eq!(ClipDepth::NegativeOneToOne, v.clip_depth);
+        assert_eq!(crate::Vec2::zero(), v.size);
+    }
+}
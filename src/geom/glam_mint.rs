@@ -0,0 +1,135 @@
+use super::{Aabb3, Capsule, Obb, Plane, Ray3, Sphere, Triangle3};
+use mint;
+
+// Primitives convert to and from a tuple of their fields using the matching `mint` types, e.g.
+// an `Aabb3` converts to `(mint::Point3<f32>, mint::Point3<f32>)`.
+macro_rules! impl_mint {
+    ($t:ident, $($field:ident: $mint_t:ty),+) => {
+        impl From<($($mint_t),+)> for $t {
+            fn from(($($field),+): ($($mint_t),+)) -> Self {
+                Self {
+                    $($field: $field.into()),+
+                }
+            }
+        }
+
+        impl From<$t> for ($($mint_t),+) {
+            fn from(v: $t) -> Self {
+                ($(v.$field.into()),+)
+            }
+        }
+    };
+}
+
+impl_mint!(Aabb3, min: mint::Point3<f32>, max: mint::Point3<f32>);
+impl_mint!(
+    Capsule,
+    a: mint::Point3<f32>,
+    b: mint::Point3<f32>,
+    radius: f32
+);
+impl_mint!(
+    Obb,
+    center: mint::Point3<f32>,
+    axes: mint::ColumnMatrix3<f32>,
+    half_extents: mint::Vector3<f32>
+);
+impl_mint!(Plane, normal: mint::Vector3<f32>, d: f32);
+impl_mint!(Ray3, origin: mint::Point3<f32>, direction: mint::Vector3<f32>);
+impl_mint!(Sphere, center: mint::Point3<f32>, radius: f32);
+impl_mint!(
+    Triangle3,
+    a: mint::Point3<f32>,
+    b: mint::Point3<f32>,
+    c: mint::Point3<f32>
+);
+
+#[cfg(test)]
+mod test {
+    use super::super::{Aabb3, Capsule, Obb, Plane, Ray3, Sphere, Triangle3};
+    use crate::{vec3, Mat3};
+    use mint;
+
+    fn point3(x: f32, y: f32, z: f32) -> mint::Point3<f32> {
+        mint::Point3 { x, y, z }
+    }
+
+    fn vector3(x: f32, y: f32, z: f32) -> mint::Vector3<f32> {
+        mint::Vector3 { x, y, z }
+    }
+
+    #[test]
+    fn test_aabb3() {
+        let m = (point3(1.0, 2.0, 3.0), point3(4.0, 5.0, 6.0));
+        let g = Aabb3::from(m);
+        assert_eq!(g, Aabb3::new(vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_capsule() {
+        let m = (point3(1.0, 2.0, 3.0), point3(4.0, 5.0, 6.0), 0.5);
+        let g = Capsule::from(m);
+        assert_eq!(
+            g,
+            Capsule::new(vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0), 0.5)
+        );
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_obb() {
+        let axes = Mat3::from_rotation_z(1.0);
+        let m = (
+            point3(1.0, 2.0, 3.0),
+            mint::ColumnMatrix3::from(axes),
+            vector3(4.0, 5.0, 6.0),
+        );
+        let g = Obb::from(m);
+        assert_eq!(g, Obb::new(vec3(1.0, 2.0, 3.0), axes, vec3(4.0, 5.0, 6.0)));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_plane() {
+        let m = (vector3(0.0, 1.0, 0.0), -2.0);
+        let g = Plane::from(m);
+        assert_eq!(g, Plane::new(vec3(0.0, 1.0, 0.0), -2.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_ray3() {
+        let m = (point3(1.0, 2.0, 3.0), vector3(0.0, 0.0, 1.0));
+        let g = Ray3::from(m);
+        assert_eq!(g, Ray3::new(vec3(1.0, 2.0, 3.0), vec3(0.0, 0.0, 1.0)));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_sphere() {
+        let m = (point3(1.0, 2.0, 3.0), 4.0);
+        let g = Sphere::from(m);
+        assert_eq!(g, Sphere::new(vec3(1.0, 2.0, 3.0), 4.0));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_triangle3() {
+        let m = (
+            point3(1.0, 2.0, 3.0),
+            point3(4.0, 5.0, 6.0),
+            point3(7.0, 8.0, 9.0),
+        );
+        let g = Triangle3::from(m);
+        assert_eq!(
+            g,
+            Triangle3::new(
+                vec3(1.0, 2.0, 3.0),
+                vec3(4.0, 5.0, 6.0),
+                vec3(7.0, 8.0, 9.0)
+            )
+        );
+        assert_eq!(m, g.into());
+    }
+}
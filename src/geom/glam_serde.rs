@@ -0,0 +1,199 @@
+use super::{
+    Aabb3, Capsule, ClipDepth, Frustum, Obb, Plane, Ray3, Sphere, Triangle3, Viewport,
+    ViewportOrigin,
+};
+use crate::{Mat3, Vec2, Vec3};
+use core::fmt;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTupleStruct, Serializer},
+};
+
+// Primitives are serialized as a tuple of their fields, e.g. an `Aabb3` is written as
+// `[[min.x, min.y, min.z], [max.x, max.y, max.z]]`. Each field is given with its index in the
+// tuple, which is reported if the field is missing.
+macro_rules! impl_serde {
+    ($t:ident, $len:expr, $($index:expr => $field:ident: $field_t:ty),+) => {
+        impl Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_tuple_struct(stringify!($t), $len)?;
+                $(state.serialize_field(&self.$field)?;)+
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct PrimitiveVisitor;
+
+                impl<'de> Visitor<'de> for PrimitiveVisitor {
+                    type Value = $t;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(concat!("struct ", stringify!($t)))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$t, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        $(
+                            let $field: $field_t = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length($index, &self))?;
+                        )+
+                        Ok($t { $($field),+ })
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($t), $len, PrimitiveVisitor)
+            }
+        }
+    };
+}
+
+impl_serde!(Aabb3, 2, 0 => min: Vec3, 1 => max: Vec3);
+impl_serde!(Capsule, 3, 0 => a: Vec3, 1 => b: Vec3, 2 => radius: f32);
+impl_serde!(Obb, 3, 0 => center: Vec3, 1 => axes: Mat3, 2 => half_extents: Vec3);
+impl_serde!(Plane, 2, 0 => normal: Vec3, 1 => d: f32);
+impl_serde!(Ray3, 2, 0 => origin: Vec3, 1 => direction: Vec3);
+impl_serde!(Sphere, 2, 0 => center: Vec3, 1 => radius: f32);
+impl_serde!(Triangle3, 3, 0 => a: Vec3, 1 => b: Vec3, 2 => c: Vec3);
+impl_serde!(
+    Viewport,
+    4,
+    0 => offset: Vec2,
+    1 => size: Vec2,
+    2 => origin: ViewportOrigin,
+    3 => clip_depth: ClipDepth
+);
+
+// Fieldless enums are serialized as unit variants, which most formats write as the variant
+// name, e.g. `"TopLeft"`.
+macro_rules! impl_serde_enum {
+    ($t:ident, $($index:expr => $variant:ident),+) => {
+        impl Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $($t::$variant => serializer.serialize_unit_variant(
+                        stringify!($t),
+                        $index,
+                        stringify!($variant),
+                    ),)+
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const VARIANTS: &[&str] = &[$(stringify!($variant)),+];
+
+                // the variant is identified by either its name or its index
+                struct Variant($t);
+
+                impl<'de> Deserialize<'de> for Variant {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        struct VariantVisitor;
+
+                        impl<'de> Visitor<'de> for VariantVisitor {
+                            type Value = Variant;
+
+                            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                                formatter.write_str(concat!("variant of ", stringify!($t)))
+                            }
+
+                            fn visit_u64<E>(self, value: u64) -> Result<Variant, E>
+                            where
+                                E: de::Error,
+                            {
+                                match value {
+                                    $($index => Ok(Variant($t::$variant)),)+
+                                    _ => Err(de::Error::invalid_value(
+                                        de::Unexpected::Unsigned(value),
+                                        &self,
+                                    )),
+                                }
+                            }
+
+                            fn visit_str<E>(self, value: &str) -> Result<Variant, E>
+                            where
+                                E: de::Error,
+                            {
+                                match value {
+                                    $(stringify!($variant) => Ok(Variant($t::$variant)),)+
+                                    _ => Err(de::Error::unknown_variant(value, VARIANTS)),
+                                }
+                            }
+                        }
+
+                        deserializer.deserialize_identifier(VariantVisitor)
+                    }
+                }
+
+                struct EnumVisitor;
+
+                impl<'de> Visitor<'de> for EnumVisitor {
+                    type Value = $t;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(concat!("enum ", stringify!($t)))
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<$t, A::Error>
+                    where
+                        A: de::EnumAccess<'de>,
+                    {
+                        let (Variant(value), variant) = data.variant()?;
+                        de::VariantAccess::unit_variant(variant)?;
+                        Ok(value)
+                    }
+                }
+
+                deserializer.deserialize_enum(stringify!($t), VARIANTS, EnumVisitor)
+            }
+        }
+    };
+}
+
+impl_serde_enum!(
+    ClipDepth,
+    0 => NegativeOneToOne,
+    1 => ZeroToOne,
+    2 => ZeroToOneReversed
+);
+impl_serde_enum!(ViewportOrigin, 0 => TopLeft, 1 => BottomLeft);
+
+// A frustum is written as the array of its six planes.
+impl Serialize for Frustum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.planes.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Frustum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let planes = <[Plane; 6]>::deserialize(deserializer)?;
+        Ok(Frustum { planes })
+    }
+}
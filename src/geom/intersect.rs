@@ -0,0 +1,311 @@
+//! Intersection and overlap queries between the geometric primitives.
+//!
+//! Ray queries return the closest hit along the ray, if any. Overlap queries return `true` if
+//! the two primitives touch or overlap.
+//!
+//! The queries are computed using `Vec3A` and `Mat3A`, so they use SSE2 where it is available
+//! and fall back to scalar math otherwise.
+
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use super::{Aabb3, Obb, Plane, Ray3, Sphere, Triangle3};
+use crate::{Mat3A, Vec3, Vec3A};
+
+// Tolerance used to reject rays parallel to a plane or triangle and to guard the separating axis
+// tests against near parallel edges.
+const EPSILON: f32 = 1.0e-6;
+
+/// The result of a ray query.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RayHit {
+    /// The distance along the ray to the hit point, in units of the ray direction.
+    pub distance: f32,
+    /// The hit point.
+    pub point: Vec3,
+    /// The normalized surface normal at the hit point.
+    pub normal: Vec3,
+}
+
+/// The result of a ray vs triangle query.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TriangleHit {
+    /// The distance along the ray to the hit point, in units of the ray direction.
+    pub distance: f32,
+    /// The hit point.
+    pub point: Vec3,
+    /// The normalized triangle normal, facing the ray origin.
+    pub normal: Vec3,
+    /// The barycentric coordinates of the hit point, i.e. the weights of the triangle vertices
+    /// `a`, `b` and `c` which sum to one.
+    pub barycentric: Vec3,
+}
+
+/// Intersects a ray with an axis aligned bounding box using the slab test.
+///
+/// If the ray origin is inside the box the hit is where the ray exits the box. The normal
+/// always points out of the box.
+pub fn ray_aabb3(ray: &Ray3, aabb: &Aabb3) -> Option<RayHit> {
+    let origin = Vec3A::from(ray.origin);
+    let direction = Vec3A::from(ray.direction);
+    let min = Vec3A::from(aabb.min);
+    let max = Vec3A::from(aabb.max);
+
+    // a ray parallel to a slab misses if it starts outside of it, otherwise that slab doesn't
+    // limit the distance. This also avoids the `0 * inf = NaN` of an origin on the slab plane.
+    let parallel = direction.cmpeq(Vec3A::zero());
+    if (parallel & (origin.cmplt(min) | origin.cmpgt(max))).any() {
+        return None;
+    }
+
+    let inv_direction = direction.recip();
+    let t1 = (min - origin) * inv_direction;
+    let t2 = (max - origin) * inv_direction;
+    let near = parallel.select(Vec3A::splat(core::f32::NEG_INFINITY), t1.min(t2));
+    let far = parallel.select(Vec3A::splat(core::f32::INFINITY), t1.max(t2));
+    let t_near = near.max_element();
+    let t_far = far.min_element();
+    if t_near > t_far || t_far < 0.0 {
+        return None;
+    }
+
+    // pick the axis of the slab the ray enters last, or exits first if it starts inside
+    let (distance, axis_mask, sign) = if t_near >= 0.0 {
+        (t_near, near.cmpeq(Vec3A::splat(t_near)), -1.0)
+    } else {
+        (t_far, far.cmpeq(Vec3A::splat(t_far)), 1.0)
+    };
+    let bitmask = axis_mask.bitmask();
+    let axis = if bitmask & 1 != 0 {
+        0
+    } else if bitmask & 2 != 0 {
+        1
+    } else {
+        2
+    };
+    let mut normal = Vec3::zero();
+    normal[axis] = if direction[axis] < 0.0 { -sign } else { sign };
+
+    Some(RayHit {
+        distance,
+        point: ray.at(distance),
+        normal,
+    })
+}
+
+/// Intersects a ray with a sphere.
+///
+/// If the ray origin is inside the sphere the hit is where the ray exits the sphere. The normal
+/// always points out of the sphere. Returns `None` if the radius of the sphere is not positive.
+pub fn ray_sphere(ray: &Ray3, sphere: &Sphere) -> Option<RayHit> {
+    // Based on "Real-Time Collision Detection" by Christer Ericson, section 5.3.2
+    if sphere.radius <= 0.0 {
+        // a point has no normal
+        return None;
+    }
+    let center = Vec3A::from(sphere.center);
+    let offset = Vec3A::from(ray.origin) - center;
+    let direction = Vec3A::from(ray.direction);
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - sphere.radius * sphere.radius;
+    if c > 0.0 && b > 0.0 {
+        // origin outside and pointing away from the sphere
+        return None;
+    }
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let distance = if c > 0.0 { -b - root } else { -b + root };
+    let point = Vec3A::from(ray.origin) + direction * distance;
+    Some(RayHit {
+        distance,
+        point: point.into(),
+        normal: ((point - center) / sphere.radius).into(),
+    })
+}
+
+/// Intersects a ray with a plane.
+///
+/// Returns `None` if the ray is parallel to the plane or points away from it. The normal is the
+/// plane normal facing the ray origin.
+pub fn ray_plane(ray: &Ray3, plane: &Plane) -> Option<RayHit> {
+    let normal = Vec3A::from(plane.normal);
+    let denom = normal.dot(Vec3A::from(ray.direction));
+    if denom.abs() < EPSILON {
+        return None;
+    }
+    let distance = -(normal.dot(Vec3A::from(ray.origin)) + plane.d) / denom;
+    if distance < 0.0 {
+        return None;
+    }
+    Some(RayHit {
+        distance,
+        point: ray.at(distance),
+        normal: if denom < 0.0 {
+            plane.normal
+        } else {
+            -plane.normal
+        },
+    })
+}
+
+/// Intersects a ray with a triangle using the Möller-Trumbore algorithm.
+///
+/// Both faces of the triangle are hit. Returns `None` if the ray is parallel to the triangle,
+/// misses it, or if the triangle is degenerate.
+pub fn ray_triangle3(ray: &Ray3, triangle: &Triangle3) -> Option<TriangleHit> {
+    // Based on "Fast, Minimum Storage Ray/Triangle Intersection" by Möller and Trumbore
+    let a = Vec3A::from(triangle.a);
+    let edge1 = Vec3A::from(triangle.b) - a;
+    let edge2 = Vec3A::from(triangle.c) - a;
+    let direction = Vec3A::from(ray.direction);
+    let p = direction.cross(edge2);
+    let det = edge1.dot(p);
+    // the determinant scales with the area of the triangle
+    if det.abs() <= EPSILON * (edge1.length() * edge2.length()) {
+        return None;
+    }
+    let inv_det = det.recip();
+
+    let t = Vec3A::from(ray.origin) - a;
+    let u = t.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = t.cross(edge1);
+    let v = direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let distance = edge2.dot(q) * inv_det;
+    if distance < 0.0 {
+        return None;
+    }
+
+    // a positive determinant means the ray hits the front face
+    let normal = edge1.cross(edge2).normalize();
+    Some(TriangleHit {
+        distance,
+        point: ray.at(distance),
+        normal: if det > 0.0 { normal } else { -normal }.into(),
+        barycentric: Vec3::new(1.0 - u - v, u, v),
+    })
+}
+
+/// Returns `true` if two spheres overlap.
+#[inline]
+pub fn sphere_sphere(a: &Sphere, b: &Sphere) -> bool {
+    let radius = a.radius + b.radius;
+    Vec3A::from(a.center).distance_squared(Vec3A::from(b.center)) <= radius * radius
+}
+
+/// Returns `true` if a sphere and an axis aligned bounding box overlap.
+#[inline]
+pub fn sphere_aabb3(sphere: &Sphere, aabb: &Aabb3) -> bool {
+    let center = Vec3A::from(sphere.center);
+    let closest = center.max(aabb.min.into()).min(aabb.max.into());
+    closest.distance_squared(center) <= sphere.radius * sphere.radius
+}
+
+/// Returns `true` if two axis aligned bounding boxes overlap.
+#[inline]
+pub fn aabb3_aabb3(a: &Aabb3, b: &Aabb3) -> bool {
+    let (a_min, a_max) = (Vec3A::from(a.min), Vec3A::from(a.max));
+    let (b_min, b_max) = (Vec3A::from(b.min), Vec3A::from(b.max));
+    (a_min.cmple(b_max) & b_min.cmple(a_max)).all()
+}
+
+/// Returns `true` if two oriented bounding boxes overlap, using the separating axis test.
+pub fn obb_obb(a: &Obb, b: &Obb) -> bool {
+    // Based on "Real-Time Collision Detection" by Christer Ericson, section 4.4.1
+    let a_inv = Mat3A::from(a.axes).transpose();
+    let a_extents = Vec3A::from(a.half_extents);
+    let b_extents = Vec3A::from(b.half_extents);
+
+    // columns of the rotation of `b` expressed in the frame of `a`, i.e. `r[j][i]` is the dot
+    // product of axis `i` of `a` and axis `j` of `b`
+    let r = [
+        a_inv.mul_vec3a(b.axes.x_axis.into()),
+        a_inv.mul_vec3a(b.axes.y_axis.into()),
+        a_inv.mul_vec3a(b.axes.z_axis.into()),
+    ];
+    // the epsilon avoids false negatives when two edges are parallel and their cross product is
+    // near zero
+    let abs_r = [
+        r[0].abs() + Vec3A::splat(EPSILON),
+        r[1].abs() + Vec3A::splat(EPSILON),
+        r[2].abs() + Vec3A::splat(EPSILON),
+    ];
+    let t = a_inv.mul_vec3a((b.center - a.center).into());
+
+    // axes of `a`
+    let rb = abs_r[0] * b_extents.x + abs_r[1] * b_extents.y + abs_r[2] * b_extents.z;
+    if t.abs().cmpgt(a_extents + rb).any() {
+        return false;
+    }
+
+    // axes of `b`
+    let ra = Vec3A::new(
+        a_extents.dot(abs_r[0]),
+        a_extents.dot(abs_r[1]),
+        a_extents.dot(abs_r[2]),
+    );
+    let tb = Vec3A::new(t.dot(r[0]), t.dot(r[1]), t.dot(r[2]));
+    if tb.abs().cmpgt(ra + b_extents).any() {
+        return false;
+    }
+
+    // cross products of the axes of `a` and `b`
+    for i in 0..3 {
+        let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+        for j in 0..3 {
+            let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+            let ra = a_extents[i1] * abs_r[j][i2] + a_extents[i2] * abs_r[j][i1];
+            let rb = b_extents[j1] * abs_r[j2][i] + b_extents[j2] * abs_r[j1][i];
+            let distance = (t[i2] * r[j][i1] - t[i1] * r[j][i2]).abs();
+            if distance > ra + rb {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Returns `true` if a triangle and an axis aligned bounding box overlap, using the separating
+/// axis test.
+pub fn triangle3_aabb3(triangle: &Triangle3, aabb: &Aabb3) -> bool {
+    // Based on "Fast 3D Triangle-Box Overlap Testing" by Tomas Akenine-Möller
+    let center = Vec3A::from(aabb.center());
+    let extents = Vec3A::from(aabb.half_extents());
+
+    // move the box to the origin
+    let v0 = Vec3A::from(triangle.a) - center;
+    let v1 = Vec3A::from(triangle.b) - center;
+    let v2 = Vec3A::from(triangle.c) - center;
+
+    // face normals of the box, which reduces to an overlap test of the bounds of the triangle
+    if v0.min(v1).min(v2).cmpgt(extents).any() || v0.max(v1).max(v2).cmplt(-extents).any() {
+        return false;
+    }
+
+    // cross products of the box axes and the triangle edges
+    let edges = [v1 - v0, v2 - v1, v0 - v2];
+    let box_axes = [Vec3A::unit_x(), Vec3A::unit_y(), Vec3A::unit_z()];
+    for &box_axis in box_axes.iter() {
+        for &edge in edges.iter() {
+            let axis = box_axis.cross(edge);
+            let p = Vec3A::new(v0.dot(axis), v1.dot(axis), v2.dot(axis));
+            let r = extents.dot(axis.abs());
+            if p.min_element() > r || p.max_element() < -r {
+                return false;
+            }
+        }
+    }
+
+    // the plane of the triangle
+    let normal = edges[0].cross(edges[1]);
+    normal.dot(v0).abs() <= extents.dot(normal.abs())
+}
@@ -0,0 +1,62 @@
+//! Geometric primitives built on top of the `glam` vector and matrix types.
+//!
+//! All primitives store their data using `Vec3`, so they have no padding and a predictable
+//! memory layout. Transforming a primitive by an `Affine3A` or an affine `Mat4` returns a new
+//! primitive in the transformed space.
+
+mod aabb3;
+mod capsule;
+mod frustum;
+pub mod intersect;
+mod obb;
+mod plane;
+mod ray3;
+mod sphere;
+mod triangle3;
+mod viewport;
+
+pub use aabb3::Aabb3;
+pub use capsule::Capsule;
+pub use frustum::{ClipDepth, Frustum, Intersection};
+pub use obb::Obb;
+pub use plane::Plane;
+pub use ray3::Ray3;
+pub use sphere::Sphere;
+pub use triangle3::Triangle3;
+pub use viewport::{project_point3, screen_ray, unproject_point3, Viewport, ViewportOrigin};
+
+#[cfg(feature = "bytemuck")]
+mod glam_bytemuck;
+
+#[cfg(feature = "mint")]
+mod glam_mint;
+
+#[cfg(feature = "serde")]
+mod glam_serde;
+
+use crate::{Affine3A, Vec3};
+
+/// Returns the closest point to `point` on the line segment from `a` to `b`.
+#[inline]
+pub(crate) fn closest_point_on_segment(a: Vec3, b: Vec3, point: Vec3) -> Vec3 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq > 0.0 {
+        let t = ((point - a).dot(ab) / len_sq).max(0.0).min(1.0);
+        a + ab * t
+    } else {
+        a
+    }
+}
+
+/// Returns the largest scale factor applied by the linear part of `transform`, used to scale
+/// radii of round primitives.
+///
+/// This is the spectral norm of the linear part, the square root of the largest eigenvalue of
+/// `MᵀM`. Unlike the longest column it also accounts for the stretch caused by shear.
+#[inline]
+pub(crate) fn max_scale(transform: &Affine3A) -> f32 {
+    let m = &transform.matrix3;
+    let (values, _) = m.transpose().mul_mat3a(m).symmetric_eigen();
+    values.x.max(0.0).sqrt()
+}
@@ -0,0 +1,126 @@
+use super::Aabb3;
+use crate::{Affine3A, Mat3, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D oriented bounding box.
+///
+/// The box is centered on `center`, its local x, y and z axes are the columns of `axes` and it
+/// extends `half_extents` along each of them. The `axes` are expected to be orthonormal.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Obb {
+    pub center: Vec3,
+    pub axes: Mat3,
+    pub half_extents: Vec3,
+}
+
+impl fmt::Display for Obb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.center, self.axes, self.half_extents)
+    }
+}
+
+impl Obb {
+    /// Creates an oriented bounding box from the given `center`, orthonormal `axes` and
+    /// `half_extents`.
+    #[inline]
+    pub fn new(center: Vec3, axes: Mat3, half_extents: Vec3) -> Self {
+        glam_assert!(axes.x_axis.is_normalized());
+        glam_assert!(axes.y_axis.is_normalized());
+        glam_assert!(axes.z_axis.is_normalized());
+        Self {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+
+    /// Creates an oriented bounding box matching the given axis aligned bounding box.
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb3) -> Self {
+        Self::new(aabb.center(), Mat3::identity(), aabb.half_extents())
+    }
+
+    /// Creates the smallest oriented bounding box with the given orthonormal `axes` containing all
+    /// of the given `points`.
+    ///
+    /// `points` must not be empty.
+    pub fn from_points_with_axes(points: &[Vec3], axes: Mat3) -> Self {
+        glam_assert!(!points.is_empty());
+        let inv_axes = axes.transpose();
+        let mut local = Aabb3::empty();
+        for &point in points {
+            local = local.grow(inv_axes.mul_vec3(point));
+        }
+        Self::new(axes.mul_vec3(local.center()), axes, local.half_extents())
+    }
+
+    /// Transforms `point` into the local space of the box, where the box is centered on the
+    /// origin and aligned with the coordinate axes.
+    #[inline]
+    pub fn to_local(&self, point: Vec3) -> Vec3 {
+        let offset = point - self.center;
+        Vec3::new(
+            offset.dot(self.axes.x_axis),
+            offset.dot(self.axes.y_axis),
+            offset.dot(self.axes.z_axis),
+        )
+    }
+
+    /// Returns `true` if `point` is inside or on the boundary of the box.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.to_local(point).abs().cmple(self.half_extents).all()
+    }
+
+    /// Returns the point inside or on the boundary of the box closest to `point`.
+    ///
+    /// If `point` is inside the box it is returned unchanged.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let local = self
+            .to_local(point)
+            .max(-self.half_extents)
+            .min(self.half_extents);
+        self.center + self.axes.mul_vec3(local)
+    }
+
+    /// Returns the distance from `point` to the box, which is zero if `point` is inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the axis aligned bounding box of the oriented box.
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        let half_extents = self.axes.x_axis.abs() * self.half_extents.x
+            + self.axes.y_axis.abs() * self.half_extents.y
+            + self.axes.z_axis.abs() * self.half_extents.z;
+        Aabb3::from_center_half_extents(self.center, half_extents)
+    }
+
+    /// Returns the box transformed by `transform`.
+    ///
+    /// Scale is moved from the axes into `half_extents` so the resulting axes remain
+    /// normalized. The transform must not contain shear.
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        let x_axis = transform.transform_vector3(self.axes.x_axis);
+        let y_axis = transform.transform_vector3(self.axes.y_axis);
+        let z_axis = transform.transform_vector3(self.axes.z_axis);
+        let scale = Vec3::new(x_axis.length(), y_axis.length(), z_axis.length());
+        Self::new(
+            transform.transform_point3(self.center),
+            Mat3::from_cols(x_axis / scale.x, y_axis / scale.y, z_axis / scale.z),
+            self.half_extents * scale,
+        )
+    }
+
+    /// Returns the box transformed by `transform`.
+    ///
+    /// The given `Mat4` must be an affine transform without shear.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        self.transform_affine3a(&Affine3A::from_mat4(*transform))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Affine3A, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D plane containing all points `p` where `normal.dot(p) + d == 0`.
+///
+/// The `normal` is expected to be normalized, in which case `d` is the negated distance of the
+/// plane from the origin along `normal`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.normal, self.d)
+    }
+}
+
+impl Plane {
+    /// Creates a plane from the given normalized `normal` and `d`.
+    #[inline]
+    pub fn new(normal: Vec3, d: f32) -> Self {
+        glam_assert!(normal.is_normalized());
+        Self { normal, d }
+    }
+
+    /// Creates a plane passing through `point` with the given normalized `normal`.
+    #[inline]
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Creates a plane passing through the points `a`, `b` and `c`.
+    ///
+    /// The normal faces the side from which the points appear in counter-clockwise order. The
+    /// points must not be collinear.
+    #[inline]
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        let normal = (b - a).cross(c - a).normalize();
+        Self::from_point_normal(a, normal)
+    }
+
+    /// Returns the signed distance from the plane to `point`, which is positive on the side
+    /// `normal` points towards.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Returns the distance from the plane to `point`.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.signed_distance(point).abs()
+    }
+
+    /// Returns the point on the plane closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Returns the plane transformed by `transform`.
+    ///
+    /// The normal is transformed by the inverse transpose of the linear part of `transform` so it
+    /// stays perpendicular to the plane when the transform contains non-uniform scale.
+    #[inline]
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        let point = transform.transform_point3(self.normal * -self.d);
+        let normal = transform
+            .matrix3
+            .inverse()
+            .transpose()
+            .mul_vec3(self.normal)
+            .normalize();
+        Self::from_point_normal(point, normal)
+    }
+
+    /// Returns the plane transformed by `transform`.
+    ///
+    /// The given `Mat4` must be an affine transform.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        self.transform_affine3a(&Affine3A::from_mat4(*transform))
+    }
+}
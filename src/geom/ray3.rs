@@ -0,0 +1,78 @@
+use crate::{Affine3A, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D ray starting at `origin` and extending infinitely along `direction`.
+///
+/// The `direction` is expected to be normalized so that ray parameters correspond to distances.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Ray3 {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl fmt::Display for Ray3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.origin, self.direction)
+    }
+}
+
+impl Ray3 {
+    /// Creates a ray from the given `origin` and normalized `direction`.
+    #[inline]
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        glam_assert!(direction.is_normalized());
+        Self { origin, direction }
+    }
+
+    /// Creates a ray starting at `from` and passing through `to`.
+    ///
+    /// The points must not be equal.
+    #[inline]
+    pub fn from_points(from: Vec3, to: Vec3) -> Self {
+        Self::new(from, (to - from).normalize())
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    #[inline]
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the point on the ray closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let t = (point - self.origin).dot(self.direction).max(0.0);
+        self.at(t)
+    }
+
+    /// Returns the distance from `point` to the closest point on the ray.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the ray transformed by `transform`.
+    ///
+    /// The transformed direction is renormalized, so distances along the returned ray are
+    /// measured in the transformed space.
+    #[inline]
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        Self {
+            origin: transform.transform_point3(self.origin),
+            direction: transform.transform_vector3(self.direction).normalize(),
+        }
+    }
+
+    /// Returns the ray transformed by `transform`.
+    ///
+    /// The transformed direction is renormalized, so distances along the returned ray are
+    /// measured in the transformed space.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        Self {
+            origin: transform.transform_point3(self.origin),
+            direction: transform.transform_vector3(self.direction).normalize(),
+        }
+    }
+}
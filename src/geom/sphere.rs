@@ -0,0 +1,115 @@
+use super::{max_scale, Aabb3};
+use crate::{Affine3A, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D sphere defined by its `center` and `radius`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl fmt::Display for Sphere {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.center, self.radius)
+    }
+}
+
+impl Sphere {
+    /// Creates a sphere from the given `center` and non-negative `radius`.
+    #[inline]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        glam_assert!(radius >= 0.0);
+        Self { center, radius }
+    }
+
+    /// Creates a sphere containing all of the given `points`.
+    ///
+    /// The sphere is centered on the bounding box of the points, so it is not necessarily the
+    /// smallest enclosing sphere. `points` must not be empty.
+    pub fn from_points(points: &[Vec3]) -> Self {
+        glam_assert!(!points.is_empty());
+        let center = Aabb3::from_points(points).center();
+        let radius_sq = points
+            .iter()
+            .fold(0.0, |r, &point| point.distance_squared(center).max(r));
+        Self::new(center, radius_sq.sqrt())
+    }
+
+    /// Returns `true` if `point` is inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.distance_squared(self.center) <= self.radius * self.radius
+    }
+
+    /// Returns the point inside or on the surface of the sphere closest to `point`.
+    ///
+    /// If `point` is inside the sphere it is returned unchanged.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let offset = point - self.center;
+        let distance_sq = offset.length_squared();
+        if distance_sq <= self.radius * self.radius {
+            point
+        } else {
+            self.center + offset * (self.radius / distance_sq.sqrt())
+        }
+    }
+
+    /// Returns the distance from `point` to the sphere, which is zero if `point` is inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        (point.distance(self.center) - self.radius).max(0.0)
+    }
+
+    /// Returns the smallest sphere containing both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let offset = other.center - self.center;
+        let distance = offset.length();
+        if distance + other.radius <= self.radius {
+            *self
+        } else if distance + self.radius <= other.radius {
+            *other
+        } else {
+            let radius = (distance + self.radius + other.radius) * 0.5;
+            let center = self.center + offset * ((radius - self.radius) / distance);
+            Self { center, radius }
+        }
+    }
+
+    /// Returns the smallest sphere containing both `self` and `point`.
+    #[inline]
+    pub fn grow(&self, point: Vec3) -> Self {
+        self.union(&Self {
+            center: point,
+            radius: 0.0,
+        })
+    }
+
+    /// Returns the axis aligned bounding box of the sphere.
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        Aabb3::from_center_half_extents(self.center, Vec3::splat(self.radius))
+    }
+
+    /// Returns the sphere transformed by `transform`.
+    ///
+    /// The radius is scaled by the largest scale factor of `transform`, so under non-uniform
+    /// scale the result contains the transformed sphere rather than matching it exactly.
+    #[inline]
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        Self {
+            center: transform.transform_point3(self.center),
+            radius: self.radius * max_scale(transform),
+        }
+    }
+
+    /// Returns the sphere transformed by `transform`.
+    ///
+    /// The given `Mat4` must be an affine transform.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        self.transform_affine3a(&Affine3A::from_mat4(*transform))
+    }
+}
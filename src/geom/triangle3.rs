@@ -0,0 +1,153 @@
+use super::Aabb3;
+use crate::{Affine3A, Mat4, Vec3};
+use core::fmt;
+
+/// A 3D triangle defined by its three vertices.
+///
+/// The front face of the triangle is the side from which the vertices appear in
+/// counter-clockwise order.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Triangle3 {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl fmt::Display for Triangle3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.a, self.b, self.c)
+    }
+}
+
+impl Triangle3 {
+    /// Creates a triangle from the given vertices.
+    #[inline]
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Creates a triangle from the first three elements of `points`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` length is less than 3.
+    #[inline]
+    pub fn from_points(points: &[Vec3]) -> Self {
+        Self::new(points[0], points[1], points[2])
+    }
+
+    /// Returns the normalized normal of the front face of the triangle.
+    ///
+    /// The triangle must not be degenerate.
+    #[inline]
+    pub fn normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// Returns the area of the triangle.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a).length() * 0.5
+    }
+
+    /// Returns the centroid of the triangle.
+    #[inline]
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) * (1.0 / 3.0)
+    }
+
+    /// Returns the point on the triangle closest to `point`.
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // Based on "Real-Time Collision Detection" by Christer Ericson, section 5.1.5
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        // vertex region outside a
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        // vertex region outside b
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        // edge region of ab
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            let v = d1 / (d1 - d3);
+            return a + ab * v;
+        }
+
+        // vertex region outside c
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        // edge region of ac
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            let w = d2 / (d2 - d6);
+            return a + ac * w;
+        }
+
+        // edge region of bc
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return b + (c - b) * w;
+        }
+
+        // inside the face region
+        let denom = 1.0 / (va + vb + vc);
+        let v = vb * denom;
+        let w = vc * denom;
+        a + ab * v + ac * w
+    }
+
+    /// Returns the distance from `point` to the closest point on the triangle.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the axis aligned bounding box of the triangle.
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        Aabb3::new(
+            self.a.min(self.b).min(self.c),
+            self.a.max(self.b).max(self.c),
+        )
+    }
+
+    /// Returns the triangle with each vertex transformed by `transform`.
+    #[inline]
+    pub fn transform_affine3a(&self, transform: &Affine3A) -> Self {
+        Self::new(
+            transform.transform_point3(self.a),
+            transform.transform_point3(self.b),
+            transform.transform_point3(self.c),
+        )
+    }
+
+    /// Returns the triangle with each vertex transformed by `transform`.
+    #[inline]
+    pub fn transform_mat4(&self, transform: &Mat4) -> Self {
+        Self::new(
+            transform.transform_point3(self.a),
+            transform.transform_point3(self.b),
+            transform.transform_point3(self.c),
+        )
+    }
+}
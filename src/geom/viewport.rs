@@ -0,0 +1,133 @@
+use super::{ClipDepth, Ray3};
+use crate::{Mat4, Vec2, Vec3, Vec4};
+
+/// The corner of the screen that pixel coordinates are measured from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ViewportOrigin {
+    /// Pixel coordinates start at the top left with y pointing down, as used by most windowing
+    /// systems, Direct3D, Metal and Vulkan.
+    TopLeft,
+    /// Pixel coordinates start at the bottom left with y pointing up, as used by OpenGL.
+    BottomLeft,
+}
+
+impl Default for ViewportOrigin {
+    #[inline]
+    fn default() -> Self {
+        ViewportOrigin::TopLeft
+    }
+}
+
+/// A rectangle of the screen that normalized device coordinates are mapped to.
+///
+/// Normalized device coordinates are expected to have y pointing up, as produced by the `Mat4`
+/// projection functions. Window depth is the value that would be written to a depth buffer in
+/// the range `[0, 1]`, so with `ClipDepth::ZeroToOneReversed` the near plane is at `1`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Viewport {
+    /// The position of the viewport in pixels, relative to `origin`.
+    pub offset: Vec2,
+    /// The width and height of the viewport in pixels.
+    pub size: Vec2,
+    /// The corner of the screen pixel coordinates are measured from.
+    pub origin: ViewportOrigin,
+    /// The depth range of the projection matrices used with this viewport.
+    pub clip_depth: ClipDepth,
+}
+
+impl Viewport {
+    /// Creates a viewport at `offset` with the given `size` in pixels.
+    #[inline]
+    pub fn new(offset: Vec2, size: Vec2, origin: ViewportOrigin, clip_depth: ClipDepth) -> Self {
+        glam_assert!(size.x > 0.0 && size.y > 0.0);
+        Self {
+            offset,
+            size,
+            origin,
+            clip_depth,
+        }
+    }
+
+    /// Converts normalized device coordinates to window coordinates.
+    #[inline]
+    pub fn ndc_to_window(&self, ndc: Vec3) -> Vec3 {
+        let x = (ndc.x + 1.0) * 0.5;
+        let y = match self.origin {
+            ViewportOrigin::TopLeft => (1.0 - ndc.y) * 0.5,
+            ViewportOrigin::BottomLeft => (ndc.y + 1.0) * 0.5,
+        };
+        let z = match self.clip_depth {
+            ClipDepth::NegativeOneToOne => (ndc.z + 1.0) * 0.5,
+            ClipDepth::ZeroToOne | ClipDepth::ZeroToOneReversed => ndc.z,
+        };
+        let pixel = self.offset + Vec2::new(x, y) * self.size;
+        Vec3::new(pixel.x, pixel.y, z)
+    }
+
+    /// Converts window coordinates to normalized device coordinates.
+    #[inline]
+    pub fn window_to_ndc(&self, window: Vec3) -> Vec3 {
+        let pixel = (Vec2::new(window.x, window.y) - self.offset) / self.size;
+        let x = pixel.x * 2.0 - 1.0;
+        let y = match self.origin {
+            ViewportOrigin::TopLeft => 1.0 - pixel.y * 2.0,
+            ViewportOrigin::BottomLeft => pixel.y * 2.0 - 1.0,
+        };
+        let z = match self.clip_depth {
+            ClipDepth::NegativeOneToOne => window.z * 2.0 - 1.0,
+            ClipDepth::ZeroToOne | ClipDepth::ZeroToOneReversed => window.z,
+        };
+        Vec3::new(x, y, z)
+    }
+
+    /// Returns the normalized device depth of the near and far planes.
+    #[inline]
+    fn ndc_near_far(&self) -> (f32, f32) {
+        match self.clip_depth {
+            ClipDepth::NegativeOneToOne => (-1.0, 1.0),
+            ClipDepth::ZeroToOne => (0.0, 1.0),
+            ClipDepth::ZeroToOneReversed => (1.0, 0.0),
+        }
+    }
+}
+
+/// Projects `point` to window coordinates using the given view-projection matrix.
+///
+/// The x and y of the result are in pixels and z is the window depth. Returns `None` if `point`
+/// is on or behind the plane of the camera, where the projection is undefined.
+pub fn project_point3(view_proj: &Mat4, viewport: &Viewport, point: Vec3) -> Option<Vec3> {
+    let clip = view_proj.mul_vec4(point.extend(1.0));
+    if clip.w <= 0.0 {
+        return None;
+    }
+    Some(viewport.ndc_to_window(clip.truncate() / clip.w))
+}
+
+/// Unprojects window coordinates back to a point using the inverse of the view-projection
+/// matrix.
+///
+/// This is the inverse of `project_point3`. The window depth must not be on an infinite far
+/// plane, use `screen_ray` to find the direction towards it instead.
+pub fn unproject_point3(view_proj_inverse: &Mat4, viewport: &Viewport, window: Vec3) -> Vec3 {
+    let ndc = viewport.window_to_ndc(window);
+    let point = view_proj_inverse.mul_vec4(ndc.extend(1.0));
+    glam_assert!(point.w != 0.0);
+    point.truncate() / point.w
+}
+
+/// Returns the ray starting on the near plane that passes through `pixel`, using the inverse of
+/// the view-projection matrix.
+///
+/// This supports projections with an infinite far plane.
+pub fn screen_ray(view_proj_inverse: &Mat4, viewport: &Viewport, pixel: Vec2) -> Ray3 {
+    let (near_z, far_z) = viewport.ndc_near_far();
+    let ndc = viewport.window_to_ndc(Vec3::new(pixel.x, pixel.y, 0.0));
+    let near = view_proj_inverse.mul_vec4(Vec4::new(ndc.x, ndc.y, near_z, 1.0));
+    let far = view_proj_inverse.mul_vec4(Vec4::new(ndc.x, ndc.y, far_z, 1.0));
+    // subtract in homogeneous coordinates so a far point at infinity, where w is zero, still
+    // gives a direction
+    let direction = far.truncate() * near.w - near.truncate() * far.w;
+    Ray3::new(near.truncate() / near.w, direction.normalize())
+}
@@ -0,0 +1,25 @@
+use super::{IVec2, IVec3, IVec4};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+pub union I32x4Cast {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub m128i: __m128i,
+    pub i32x4: [i32; 4],
+    pub ivec4: IVec4,
+}
+
+#[repr(C)]
+pub union I32x3Cast {
+    pub i32x3: [i32; 3],
+    pub ivec3: IVec3,
+}
+
+#[repr(C)]
+pub union I32x2Cast {
+    pub i32x2: [i32; 2],
+    pub ivec2: IVec2,
+}
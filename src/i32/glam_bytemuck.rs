@@ -0,0 +1,32 @@
+use super::{IVec2, IVec3, IVec4};
+use bytemuck::{Pod, Zeroable};
+
+unsafe impl Pod for IVec2 {}
+unsafe impl Zeroable for IVec2 {}
+unsafe impl Pod for IVec3 {}
+unsafe impl Zeroable for IVec3 {}
+unsafe impl Pod for IVec4 {}
+unsafe impl Zeroable for IVec4 {}
+
+#[cfg(test)]
+mod test {
+    use super::{IVec2, IVec3, IVec4};
+    use bytemuck;
+    use core::mem;
+
+    macro_rules! test_t {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                let t = <$t>::default();
+                let b = bytemuck::bytes_of(&t);
+                assert_eq!(t.as_ref().as_ptr() as usize, b.as_ptr() as usize);
+                assert_eq!(b.len(), mem::size_of_val(&t));
+            }
+        };
+    }
+
+    test_t!(ivec2, IVec2);
+    test_t!(ivec3, IVec3);
+    test_t!(ivec4, IVec4);
+}
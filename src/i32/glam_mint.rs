@@ -0,0 +1,122 @@
+use super::{IVec2, IVec3, IVec4};
+use mint;
+
+impl From<mint::Point2<i32>> for IVec2 {
+    fn from(v: mint::Point2<i32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<IVec2> for mint::Point2<i32> {
+    fn from(v: IVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Point3<i32>> for IVec3 {
+    fn from(v: mint::Point3<i32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<IVec3> for mint::Point3<i32> {
+    fn from(v: IVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector2<i32>> for IVec2 {
+    fn from(v: mint::Vector2<i32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<IVec2> for mint::Vector2<i32> {
+    fn from(v: IVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Vector3<i32>> for IVec3 {
+    fn from(v: mint::Vector3<i32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<IVec3> for mint::Vector3<i32> {
+    fn from(v: IVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector4<i32>> for IVec4 {
+    fn from(v: mint::Vector4<i32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<IVec4> for mint::Vector4<i32> {
+    fn from(v: IVec4) -> Self {
+        let (x, y, z, w) = v.into();
+        Self { x, y, z, w }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mint;
+
+    #[test]
+    fn test_point2() {
+        use crate::IVec2;
+        let m = mint::Point2 { x: 1, y: 2 };
+        let g = IVec2::from(m);
+        assert_eq!(g, IVec2::new(1, 2));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_point3() {
+        use crate::IVec3;
+        let m = mint::Point3 { x: 1, y: 2, z: 3 };
+        let g = IVec3::from(m);
+        assert_eq!(g, IVec3::new(1, 2, 3));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector2() {
+        use crate::IVec2;
+        let m = mint::Vector2 { x: 1, y: 2 };
+        let g = IVec2::from(m);
+        assert_eq!(g, IVec2::new(1, 2));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector3() {
+        use crate::IVec3;
+        let m = mint::Vector3 { x: 1, y: 2, z: 3 };
+        let g = IVec3::from(m);
+        assert_eq!(g, IVec3::new(1, 2, 3));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector4() {
+        use crate::IVec4;
+        let m = mint::Vector4 {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4,
+        };
+        let g = IVec4::from(m);
+        assert_eq!(g, IVec4::new(1, 2, 3, 4));
+        assert_eq!(m, g.into());
+    }
+}
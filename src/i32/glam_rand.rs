@@ -0,0 +1,27 @@
+use super::{IVec2, IVec3, IVec4};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<IVec2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IVec2 {
+        rng.gen::<[i32; 2]>().into()
+    }
+}
+
+impl Distribution<IVec3> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IVec3 {
+        rng.gen::<[i32; 3]>().into()
+    }
+}
+
+impl Distribution<IVec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IVec4 {
+        rng.gen::<[i32; 4]>().into()
+    }
+}
@@ -0,0 +1,804 @@
+use super::IVec3;
+use crate::u32::UVec2;
+use crate::{DVec2, Vec2, Vec2Mask};
+use core::{fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: IVec2 = const_ivec2!([0; 2]);
+const ONE: IVec2 = const_ivec2!([1; 2]);
+const X_AXIS: IVec2 = const_ivec2!([1, 0]);
+const Y_AXIS: IVec2 = const_ivec2!([0, 1]);
+
+/// A 2-dimensional vector of `i32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct IVec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Creates an `IVec2`.
+#[inline]
+pub fn ivec2(x: i32, y: i32) -> IVec2 {
+    IVec2::new(x, y)
+}
+
+impl IVec2 {
+    /// Creates a new `IVec2`.
+    #[inline]
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates an `IVec2` with all elements set to `0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates an `IVec2` with all elements set to `1`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates an `IVec2` with values `[x: 1, y: 0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates an `IVec2` with values `[x: 0, y: 1]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates an `IVec2` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: i32) -> Self {
+        Self { x: v, y: v }
+    }
+
+    /// Creates an `IVec3` from `self` and the given `z` value.
+    #[inline]
+    pub fn extend(self, z: i32) -> IVec3 {
+        IVec3::new(self.x, self.y, z)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> i32 {
+        (self.x * other.x) + (self.y * other.y)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    /// Component-wise clamping of values, similar to [`i32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// If the `glam-assert` feature is enabled, the function will panic if the contract is not
+    /// met, otherwise the behavior is undefined.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all());
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y)`.
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y)`.
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.eq(&other.x), self.y.eq(&other.y))
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.ne(&other.x), self.y.ne(&other.y))
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.ge(&other.x), self.y.ge(&other.y))
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.gt(&other.x), self.y.gt(&other.y))
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.le(&other.x), self.y.le(&other.y))
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `Vec2Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> Vec2Mask {
+        Vec2Mask::new(self.x.lt(&other.x), self.y.lt(&other.y))
+    }
+
+    /// Creates an `IVec2` from the first two values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than two elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[i32]) -> Self {
+        Self {
+            x: slice[0],
+            y: slice[1],
+        }
+    }
+
+    /// Writes the elements of `self` to the first two elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than two elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [i32]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+    }
+
+    /// Returns an `IVec2` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+
+    /// Returns an `IVec2` with elements representing the sign of `self`.
+    ///
+    ///  - `0` if the number is zero
+    ///  - `1` if the number is positive
+    ///  - `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(other.x), self.y.wrapping_add(other.y)]`.
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(other.x), self.y.wrapping_sub(other.y)]`.
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(other.x), self.y.wrapping_mul(other.y)]`.
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(other.x), self.y.saturating_add(other.y)]`.
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(other.x), self.y.saturating_sub(other.y)]`.
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(other.x), self.y.saturating_mul(other.y)]`.
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
+        }
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec2(self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_dvec2(self) -> DVec2 {
+        DVec2::new(self.x as f64, self.y as f64)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec2(self) -> UVec2 {
+        UVec2::new(self.x as u32, self.y as u32)
+    }
+}
+
+impl AsRef<[i32; 2]> for IVec2 {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 2] {
+        unsafe { &*(self as *const Self as *const [i32; 2]) }
+    }
+}
+
+impl AsMut<[i32; 2]> for IVec2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 2] {
+        unsafe { &mut *(self as *mut Self as *mut [i32; 2]) }
+    }
+}
+
+impl fmt::Debug for IVec2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("IVec2").field(&a[0]).field(&a[1]).finish()
+    }
+}
+
+impl fmt::Display for IVec2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        write!(fmt, "[{}, {}]", a[0], a[1])
+    }
+}
+
+impl Div<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+        }
+    }
+}
+
+impl DivAssign<IVec2> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+    }
+}
+
+impl Div<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: i32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+        }
+    }
+}
+
+impl DivAssign<i32> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: i32) {
+        self.x /= other;
+        self.y /= other;
+    }
+}
+
+impl Div<IVec2> for i32 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self / other.x,
+            y: self / other.y,
+        }
+    }
+}
+
+impl Mul<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+}
+
+impl MulAssign<IVec2> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.x *= other.x;
+        self.y *= other.y;
+    }
+}
+
+impl Mul<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: i32) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+impl MulAssign<i32> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: i32) {
+        self.x *= other;
+        self.y *= other;
+    }
+}
+
+impl Mul<IVec2> for i32 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+impl Add<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign<IVec2> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Add<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: i32) -> Self {
+        Self {
+            x: self.x + other,
+            y: self.y + other,
+        }
+    }
+}
+
+impl AddAssign<i32> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: i32) {
+        self.x += other;
+        self.y += other;
+    }
+}
+
+impl Add<IVec2> for i32 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self + other.x,
+            y: self + other.y,
+        }
+    }
+}
+
+impl Sub<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign<IVec2> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl Sub<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: i32) -> Self {
+        Self {
+            x: self.x - other,
+            y: self.y - other,
+        }
+    }
+}
+
+impl SubAssign<i32> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: i32) {
+        self.x -= other;
+        self.y -= other;
+    }
+}
+
+impl Sub<IVec2> for i32 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self - other.x,
+            y: self - other.y,
+        }
+    }
+}
+
+impl Rem<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+        }
+    }
+}
+
+impl RemAssign<IVec2> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        self.x %= other.x;
+        self.y %= other.y;
+    }
+}
+
+impl Rem<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: i32) -> Self {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+        }
+    }
+}
+
+impl RemAssign<i32> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, other: i32) {
+        self.x %= other;
+        self.y %= other;
+    }
+}
+
+impl Rem<IVec2> for i32 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self % other.x,
+            y: self % other.y,
+        }
+    }
+}
+
+impl Neg for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Not for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self {
+            x: !self.x,
+            y: !self.y,
+        }
+    }
+}
+
+impl BitAnd for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            x: self.x & other.x,
+            y: self.y & other.y,
+        }
+    }
+}
+
+impl BitAnd<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: i32) -> Self {
+        Self {
+            x: self.x & other,
+            y: self.y & other,
+        }
+    }
+}
+
+impl BitOr for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            x: self.x | other.x,
+            y: self.y | other.y,
+        }
+    }
+}
+
+impl BitOr<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: i32) -> Self {
+        Self {
+            x: self.x | other,
+            y: self.y | other,
+        }
+    }
+}
+
+impl BitXor for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            x: self.x ^ other.x,
+            y: self.y ^ other.y,
+        }
+    }
+}
+
+impl BitXor<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: i32) -> Self {
+        Self {
+            x: self.x ^ other,
+            y: self.y ^ other,
+        }
+    }
+}
+
+impl Shl<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: i32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+        }
+    }
+}
+
+impl Shr<i32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: i32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+        }
+    }
+}
+
+impl Shl<u32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: u32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+        }
+    }
+}
+
+impl Shr<u32> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: u32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+        }
+    }
+}
+
+impl Shl<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: Self) -> Self {
+        Self {
+            x: self.x << other.x,
+            y: self.y << other.y,
+        }
+    }
+}
+
+impl Shr<IVec2> for IVec2 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: Self) -> Self {
+        Self {
+            x: self.x >> other.x,
+            y: self.y >> other.y,
+        }
+    }
+}
+
+impl Index<usize> for IVec2 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for IVec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(i32, i32)> for IVec2 {
+    #[inline]
+    fn from(t: (i32, i32)) -> Self {
+        Self::new(t.0, t.1)
+    }
+}
+
+impl From<IVec2> for (i32, i32) {
+    #[inline]
+    fn from(v: IVec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<[i32; 2]> for IVec2 {
+    #[inline]
+    fn from(a: [i32; 2]) -> Self {
+        Self::new(a[0], a[1])
+    }
+}
+
+impl From<IVec2> for [i32; 2] {
+    #[inline]
+    fn from(v: IVec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for IVec2 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for IVec2 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
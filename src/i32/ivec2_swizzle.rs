@@ -0,0 +1,185 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{IVec2, IVec3, IVec4};
+
+pub trait IVec2Swizzles {
+    fn xxxx(self) -> IVec4;
+    fn xxxy(self) -> IVec4;
+    fn xxyx(self) -> IVec4;
+    fn xxyy(self) -> IVec4;
+    fn xyxx(self) -> IVec4;
+    fn xyxy(self) -> IVec4;
+    fn xyyx(self) -> IVec4;
+    fn xyyy(self) -> IVec4;
+    fn yxxx(self) -> IVec4;
+    fn yxxy(self) -> IVec4;
+    fn yxyx(self) -> IVec4;
+    fn yxyy(self) -> IVec4;
+    fn yyxx(self) -> IVec4;
+    fn yyxy(self) -> IVec4;
+    fn yyyx(self) -> IVec4;
+    fn yyyy(self) -> IVec4;
+    fn xxx(self) -> IVec3;
+    fn xxy(self) -> IVec3;
+    fn xyx(self) -> IVec3;
+    fn xyy(self) -> IVec3;
+    fn yxx(self) -> IVec3;
+    fn yxy(self) -> IVec3;
+    fn yyx(self) -> IVec3;
+    fn yyy(self) -> IVec3;
+    fn xx(self) -> IVec2;
+    fn yx(self) -> IVec2;
+    fn yy(self) -> IVec2;
+}
+
+impl IVec2Swizzles for IVec2 {
+    #[inline]
+    fn xxxx(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxyx(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xyxx(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyyx(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yxxx(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxyx(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yyxx(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyyx(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xxx(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xx(self) -> IVec2 {
+        IVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yx(self) -> IVec2 {
+        IVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> IVec2 {
+        IVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+}
@@ -0,0 +1,907 @@
+use super::{IVec2, IVec4};
+use crate::u32::UVec3;
+use crate::{DVec3, Vec3, Vec3Mask};
+use core::{fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: IVec3 = const_ivec3!([0; 3]);
+const ONE: IVec3 = const_ivec3!([1; 3]);
+const X_AXIS: IVec3 = const_ivec3!([1, 0, 0]);
+const Y_AXIS: IVec3 = const_ivec3!([0, 1, 0]);
+const Z_AXIS: IVec3 = const_ivec3!([0, 0, 1]);
+
+/// A 3-dimensional vector of `i32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct IVec3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// Creates an `IVec3`.
+#[inline]
+pub fn ivec3(x: i32, y: i32, z: i32) -> IVec3 {
+    IVec3::new(x, y, z)
+}
+
+impl IVec3 {
+    /// Creates a new `IVec3`.
+    #[inline]
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates an `IVec3` with all elements set to `0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates an `IVec3` with all elements set to `1`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates an `IVec3` with values `[x: 1, y: 0, z: 0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates an `IVec3` with values `[x: 0, y: 1, z: 0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates an `IVec3` with values `[x: 0, y: 0, z: 1]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Z_AXIS
+    }
+
+    /// Creates an `IVec3` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: i32) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    /// Creates an `IVec4` from `self` and the given `w` value.
+    #[inline]
+    pub fn extend(self, w: i32) -> IVec4 {
+        IVec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Creates an `IVec2` from the `x` and `y` elements of `self`, discarding `z`.
+    ///
+    /// Truncation may also be performed by using `self.xy()` or `IVec2::from()`.
+    #[inline]
+    pub fn truncate(self) -> IVec2 {
+        IVec2::new(self.x, self.y)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> i32 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Component-wise clamping of values, similar to [`i32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// If the `glam-assert` feature is enabled, the function will panic if the contract is not
+    /// met, otherwise the behavior is undefined.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all());
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z)`.
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z)`.
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.eq(&other.x),
+            self.y.eq(&other.y),
+            self.z.eq(&other.z),
+        )
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.ne(&other.x),
+            self.y.ne(&other.y),
+            self.z.ne(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.ge(&other.x),
+            self.y.ge(&other.y),
+            self.z.ge(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.gt(&other.x),
+            self.y.gt(&other.y),
+            self.z.gt(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.le(&other.x),
+            self.y.le(&other.y),
+            self.z.le(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.lt(&other.x),
+            self.y.lt(&other.y),
+            self.z.lt(&other.z),
+        )
+    }
+
+    /// Creates an `IVec3` from the first three values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[i32]) -> Self {
+        Self {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+        }
+    }
+
+    /// Writes the elements of `self` to the first three elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [i32]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+        slice[2] = self.z;
+    }
+
+    /// Returns an `IVec3` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Returns an `IVec3` with elements representing the sign of `self`.
+    ///
+    ///  - `0` if the number is zero
+    ///  - `1` if the number is positive
+    ///  - `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(other.x), self.y.wrapping_add(other.y), self.z.wrapping_add(other.z)]`.
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+            z: self.z.wrapping_add(other.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(other.x), self.y.wrapping_sub(other.y), self.z.wrapping_sub(other.z)]`.
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+            z: self.z.wrapping_sub(other.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(other.x), self.y.wrapping_mul(other.y), self.z.wrapping_mul(other.z)]`.
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+            z: self.z.wrapping_mul(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(other.x), self.y.saturating_add(other.y), self.z.saturating_add(other.z)]`.
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+            z: self.z.saturating_add(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(other.x), self.y.saturating_sub(other.y), self.z.saturating_sub(other.z)]`.
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+            z: self.z.saturating_sub(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(other.x), self.y.saturating_mul(other.y), self.z.saturating_mul(other.z)]`.
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
+            z: self.z.saturating_mul(other.z),
+        }
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec3(self) -> Vec3 {
+        Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_dvec3(self) -> DVec3 {
+        DVec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec3(self) -> UVec3 {
+        UVec3::new(self.x as u32, self.y as u32, self.z as u32)
+    }
+}
+
+impl AsRef<[i32; 3]> for IVec3 {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 3] {
+        unsafe { &*(self as *const Self as *const [i32; 3]) }
+    }
+}
+
+impl AsMut<[i32; 3]> for IVec3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 3] {
+        unsafe { &mut *(self as *mut Self as *mut [i32; 3]) }
+    }
+}
+
+impl fmt::Debug for IVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("IVec3")
+            .field(&a[0])
+            .field(&a[1])
+            .field(&a[2])
+            .finish()
+    }
+}
+
+impl fmt::Display for IVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        write!(fmt, "[{}, {}, {}]", a[0], a[1], a[2])
+    }
+}
+
+impl Div<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl DivAssign<IVec3> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+        self.z /= other.z;
+    }
+}
+
+impl Div<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: i32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<i32> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: i32) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+    }
+}
+
+impl Div<IVec3> for i32 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, other: IVec3) -> IVec3 {
+        IVec3 {
+            x: self / other.x,
+            y: self / other.y,
+            z: self / other.z,
+        }
+    }
+}
+
+impl Mul<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl MulAssign<IVec3> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.x *= other.x;
+        self.y *= other.y;
+        self.z *= other.z;
+    }
+}
+
+impl Mul<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: i32) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl MulAssign<i32> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: i32) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+impl Mul<IVec3> for i32 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, other: IVec3) -> IVec3 {
+        IVec3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+impl Add<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign<IVec3> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl Add<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: i32) -> Self {
+        Self {
+            x: self.x + other,
+            y: self.y + other,
+            z: self.z + other,
+        }
+    }
+}
+
+impl AddAssign<i32> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: i32) {
+        self.x += other;
+        self.y += other;
+        self.z += other;
+    }
+}
+
+impl Add<IVec3> for i32 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, other: IVec3) -> IVec3 {
+        IVec3 {
+            x: self + other.x,
+            y: self + other.y,
+            z: self + other.z,
+        }
+    }
+}
+
+impl Sub<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign<IVec3> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl Sub<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: i32) -> Self {
+        Self {
+            x: self.x - other,
+            y: self.y - other,
+            z: self.z - other,
+        }
+    }
+}
+
+impl SubAssign<i32> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: i32) {
+        self.x -= other;
+        self.y -= other;
+        self.z -= other;
+    }
+}
+
+impl Sub<IVec3> for i32 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, other: IVec3) -> IVec3 {
+        IVec3 {
+            x: self - other.x,
+            y: self - other.y,
+            z: self - other.z,
+        }
+    }
+}
+
+impl Rem<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+            z: self.z % other.z,
+        }
+    }
+}
+
+impl RemAssign<IVec3> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        self.x %= other.x;
+        self.y %= other.y;
+        self.z %= other.z;
+    }
+}
+
+impl Rem<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: i32) -> Self {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+            z: self.z % other,
+        }
+    }
+}
+
+impl RemAssign<i32> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, other: i32) {
+        self.x %= other;
+        self.y %= other;
+        self.z %= other;
+    }
+}
+
+impl Rem<IVec3> for i32 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, other: IVec3) -> IVec3 {
+        IVec3 {
+            x: self % other.x,
+            y: self % other.y,
+            z: self % other.z,
+        }
+    }
+}
+
+impl Neg for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Not for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self {
+            x: !self.x,
+            y: !self.y,
+            z: !self.z,
+        }
+    }
+}
+
+impl BitAnd for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            x: self.x & other.x,
+            y: self.y & other.y,
+            z: self.z & other.z,
+        }
+    }
+}
+
+impl BitAnd<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: i32) -> Self {
+        Self {
+            x: self.x & other,
+            y: self.y & other,
+            z: self.z & other,
+        }
+    }
+}
+
+impl BitOr for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            x: self.x | other.x,
+            y: self.y | other.y,
+            z: self.z | other.z,
+        }
+    }
+}
+
+impl BitOr<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: i32) -> Self {
+        Self {
+            x: self.x | other,
+            y: self.y | other,
+            z: self.z | other,
+        }
+    }
+}
+
+impl BitXor for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            x: self.x ^ other.x,
+            y: self.y ^ other.y,
+            z: self.z ^ other.z,
+        }
+    }
+}
+
+impl BitXor<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: i32) -> Self {
+        Self {
+            x: self.x ^ other,
+            y: self.y ^ other,
+            z: self.z ^ other,
+        }
+    }
+}
+
+impl Shl<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: i32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+            z: self.z << other,
+        }
+    }
+}
+
+impl Shr<i32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: i32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+            z: self.z >> other,
+        }
+    }
+}
+
+impl Shl<u32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: u32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+            z: self.z << other,
+        }
+    }
+}
+
+impl Shr<u32> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: u32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+            z: self.z >> other,
+        }
+    }
+}
+
+impl Shl<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: Self) -> Self {
+        Self {
+            x: self.x << other.x,
+            y: self.y << other.y,
+            z: self.z << other.z,
+        }
+    }
+}
+
+impl Shr<IVec3> for IVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: Self) -> Self {
+        Self {
+            x: self.x >> other.x,
+            y: self.y >> other.y,
+            z: self.z >> other.z,
+        }
+    }
+}
+
+impl Index<usize> for IVec3 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for IVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(i32, i32, i32)> for IVec3 {
+    #[inline]
+    fn from(t: (i32, i32, i32)) -> Self {
+        Self::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<IVec3> for (i32, i32, i32) {
+    #[inline]
+    fn from(v: IVec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl From<[i32; 3]> for IVec3 {
+    #[inline]
+    fn from(a: [i32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<IVec3> for [i32; 3] {
+    #[inline]
+    fn from(v: IVec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<IVec3> for IVec2 {
+    /// Creates an `IVec2` from the `x` and `y` elements of the `IVec3`, discarding `z`.
+    #[inline]
+    fn from(v: IVec3) -> Self {
+        IVec2::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for IVec3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for IVec3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
@@ -0,0 +1,720 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{IVec2, IVec3, IVec4};
+
+pub trait IVec3Swizzles {
+    fn xxxx(self) -> IVec4;
+    fn xxxy(self) -> IVec4;
+    fn xxxz(self) -> IVec4;
+    fn xxyx(self) -> IVec4;
+    fn xxyy(self) -> IVec4;
+    fn xxyz(self) -> IVec4;
+    fn xxzx(self) -> IVec4;
+    fn xxzy(self) -> IVec4;
+    fn xxzz(self) -> IVec4;
+    fn xyxx(self) -> IVec4;
+    fn xyxy(self) -> IVec4;
+    fn xyxz(self) -> IVec4;
+    fn xyyx(self) -> IVec4;
+    fn xyyy(self) -> IVec4;
+    fn xyyz(self) -> IVec4;
+    fn xyzx(self) -> IVec4;
+    fn xyzy(self) -> IVec4;
+    fn xyzz(self) -> IVec4;
+    fn xzxx(self) -> IVec4;
+    fn xzxy(self) -> IVec4;
+    fn xzxz(self) -> IVec4;
+    fn xzyx(self) -> IVec4;
+    fn xzyy(self) -> IVec4;
+    fn xzyz(self) -> IVec4;
+    fn xzzx(self) -> IVec4;
+    fn xzzy(self) -> IVec4;
+    fn xzzz(self) -> IVec4;
+    fn yxxx(self) -> IVec4;
+    fn yxxy(self) -> IVec4;
+    fn yxxz(self) -> IVec4;
+    fn yxyx(self) -> IVec4;
+    fn yxyy(self) -> IVec4;
+    fn yxyz(self) -> IVec4;
+    fn yxzx(self) -> IVec4;
+    fn yxzy(self) -> IVec4;
+    fn yxzz(self) -> IVec4;
+    fn yyxx(self) -> IVec4;
+    fn yyxy(self) -> IVec4;
+    fn yyxz(self) -> IVec4;
+    fn yyyx(self) -> IVec4;
+    fn yyyy(self) -> IVec4;
+    fn yyyz(self) -> IVec4;
+    fn yyzx(self) -> IVec4;
+    fn yyzy(self) -> IVec4;
+    fn yyzz(self) -> IVec4;
+    fn yzxx(self) -> IVec4;
+    fn yzxy(self) -> IVec4;
+    fn yzxz(self) -> IVec4;
+    fn yzyx(self) -> IVec4;
+    fn yzyy(self) -> IVec4;
+    fn yzyz(self) -> IVec4;
+    fn yzzx(self) -> IVec4;
+    fn yzzy(self) -> IVec4;
+    fn yzzz(self) -> IVec4;
+    fn zxxx(self) -> IVec4;
+    fn zxxy(self) -> IVec4;
+    fn zxxz(self) -> IVec4;
+    fn zxyx(self) -> IVec4;
+    fn zxyy(self) -> IVec4;
+    fn zxyz(self) -> IVec4;
+    fn zxzx(self) -> IVec4;
+    fn zxzy(self) -> IVec4;
+    fn zxzz(self) -> IVec4;
+    fn zyxx(self) -> IVec4;
+    fn zyxy(self) -> IVec4;
+    fn zyxz(self) -> IVec4;
+    fn zyyx(self) -> IVec4;
+    fn zyyy(self) -> IVec4;
+    fn zyyz(self) -> IVec4;
+    fn zyzx(self) -> IVec4;
+    fn zyzy(self) -> IVec4;
+    fn zyzz(self) -> IVec4;
+    fn zzxx(self) -> IVec4;
+    fn zzxy(self) -> IVec4;
+    fn zzxz(self) -> IVec4;
+    fn zzyx(self) -> IVec4;
+    fn zzyy(self) -> IVec4;
+    fn zzyz(self) -> IVec4;
+    fn zzzx(self) -> IVec4;
+    fn zzzy(self) -> IVec4;
+    fn zzzz(self) -> IVec4;
+    fn xxx(self) -> IVec3;
+    fn xxy(self) -> IVec3;
+    fn xxz(self) -> IVec3;
+    fn xyx(self) -> IVec3;
+    fn xyy(self) -> IVec3;
+    fn xzx(self) -> IVec3;
+    fn xzy(self) -> IVec3;
+    fn xzz(self) -> IVec3;
+    fn yxx(self) -> IVec3;
+    fn yxy(self) -> IVec3;
+    fn yxz(self) -> IVec3;
+    fn yyx(self) -> IVec3;
+    fn yyy(self) -> IVec3;
+    fn yyz(self) -> IVec3;
+    fn yzx(self) -> IVec3;
+    fn yzy(self) -> IVec3;
+    fn yzz(self) -> IVec3;
+    fn zxx(self) -> IVec3;
+    fn zxy(self) -> IVec3;
+    fn zxz(self) -> IVec3;
+    fn zyx(self) -> IVec3;
+    fn zyy(self) -> IVec3;
+    fn zyz(self) -> IVec3;
+    fn zzx(self) -> IVec3;
+    fn zzy(self) -> IVec3;
+    fn zzz(self) -> IVec3;
+    fn xx(self) -> IVec2;
+    fn xy(self) -> IVec2;
+    fn xz(self) -> IVec2;
+    fn yx(self) -> IVec2;
+    fn yy(self) -> IVec2;
+    fn yz(self) -> IVec2;
+    fn zx(self) -> IVec2;
+    fn zy(self) -> IVec2;
+    fn zz(self) -> IVec2;
+}
+
+impl IVec3Swizzles for IVec3 {
+    #[inline]
+    fn xxxx(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxxz(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn xxyx(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xxyz(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn xxzx(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn xxzy(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn xxzz(self) -> IVec4 {
+        IVec4::new(self.x, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn xyxx(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyxz(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn xyyx(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xyyz(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn xyzx(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn xyzy(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn xyzz(self) -> IVec4 {
+        IVec4::new(self.x, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn xzxx(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn xzxy(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn xzxz(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn xzyx(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn xzyy(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn xzyz(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn xzzx(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn xzzy(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn xzzz(self) -> IVec4 {
+        IVec4::new(self.x, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn yxxx(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxxz(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn yxyx(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yxyz(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn yxzx(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn yxzy(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn yxzz(self) -> IVec4 {
+        IVec4::new(self.y, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn yyxx(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyxz(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn yyyx(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yyyz(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn yyzx(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn yyzy(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn yyzz(self) -> IVec4 {
+        IVec4::new(self.y, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn yzxx(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn yzxy(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn yzxz(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn yzyx(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn yzyy(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn yzyz(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn yzzx(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn yzzy(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn yzzz(self) -> IVec4 {
+        IVec4::new(self.y, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn zxxx(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn zxxy(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn zxxz(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn zxyx(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn zxyy(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn zxyz(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn zxzx(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn zxzy(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn zxzz(self) -> IVec4 {
+        IVec4::new(self.z, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn zyxx(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn zyxy(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn zyxz(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn zyyx(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn zyyy(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn zyyz(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn zyzx(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn zyzy(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn zyzz(self) -> IVec4 {
+        IVec4::new(self.z, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn zzxx(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn zzxy(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn zzxz(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn zzyx(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn zzyy(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn zzyz(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn zzzx(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn zzzy(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn zzzz(self) -> IVec4 {
+        IVec4::new(self.z, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn xxx(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xxz(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzx(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xzy(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzz(self) -> IVec3 {
+        IVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxz(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyz(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yzx(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yzy(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yzz(self) -> IVec3 {
+        IVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zxx(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zxy(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zxz(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zyx(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zyy(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zyz(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zzx(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zzy(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zzz(self) -> IVec3 {
+        IVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xx(self) -> IVec2 {
+        IVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn xy(self) -> IVec2 {
+        IVec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn xz(self) -> IVec2 {
+        IVec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn yx(self) -> IVec2 {
+        IVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> IVec2 {
+        IVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn yz(self) -> IVec2 {
+        IVec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn zx(self) -> IVec2 {
+        IVec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn zy(self) -> IVec2 {
+        IVec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn zz(self) -> IVec2 {
+        IVec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+}
@@ -0,0 +1,1458 @@
+use super::{IVec2, IVec3};
+use crate::u32::UVec4;
+use crate::{DVec4, Vec4, Vec4Mask};
+use core::{fmt, ops::*};
+
+#[cfg(all(vec4_sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4_sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+#[cfg(vec4_sse2)]
+use crate::Align16;
+#[cfg(vec4_sse2)]
+use core::{
+    hash::{Hash, Hasher},
+    mem::MaybeUninit,
+};
+
+const ZERO: IVec4 = const_ivec4!([0; 4]);
+const ONE: IVec4 = const_ivec4!([1; 4]);
+const X_AXIS: IVec4 = const_ivec4!([1, 0, 0, 0]);
+const Y_AXIS: IVec4 = const_ivec4!([0, 1, 0, 0]);
+const Z_AXIS: IVec4 = const_ivec4!([0, 0, 1, 0]);
+const W_AXIS: IVec4 = const_ivec4!([0, 0, 0, 1]);
+
+/// A 4-dimensional vector of `i32` elements.
+///
+/// This type is 16 byte aligned.
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math"), not(doc)))]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct IVec4(pub(crate) __m128i);
+
+/// A 4-dimensional vector of `i32` elements.
+///
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabed.
+#[cfg(any(vec4_f32, doc))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+// if compiling with simd enabled assume alignment needs to match the simd type
+#[cfg_attr(any(vec4_sse2, vec4_f32_align16), repr(align(16)))]
+#[repr(C)]
+pub struct IVec4 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub w: i32,
+}
+
+#[cfg(all(vec4_sse2, not(doc)))]
+impl Default for IVec4 {
+    #[inline]
+    fn default() -> Self {
+        ZERO
+    }
+}
+
+#[cfg(all(vec4_sse2, not(doc)))]
+impl PartialEq for IVec4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmpeq(*other).all()
+    }
+}
+
+#[cfg(all(vec4_sse2, not(doc)))]
+impl Eq for IVec4 {}
+
+#[cfg(all(vec4_sse2, not(doc)))]
+impl Hash for IVec4 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+        self.w.hash(state);
+    }
+}
+
+#[cfg(vec4_sse2)]
+impl From<IVec4> for __m128i {
+    #[inline]
+    fn from(t: IVec4) -> Self {
+        t.0
+    }
+}
+
+#[cfg(vec4_sse2)]
+impl From<__m128i> for IVec4 {
+    #[inline]
+    fn from(t: __m128i) -> Self {
+        Self(t)
+    }
+}
+
+/// Creates an `IVec4`.
+#[inline]
+pub fn ivec4(x: i32, y: i32, z: i32, w: i32) -> IVec4 {
+    IVec4::new(x, y, z, w)
+}
+
+impl IVec4 {
+    /// Creates a new `IVec4`.
+    #[inline]
+    pub fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_set_epi32(w, z, y, x))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self { x, y, z, w }
+        }
+    }
+
+    /// Creates an `IVec4` with all elements set to `0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates an `IVec4` with all elements set to `1`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates an `IVec4` with values `[x: 1, y: 0, z: 0, w: 0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates an `IVec4` with values `[x: 0, y: 1, z: 0, w: 0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates an `IVec4` with values `[x: 0, y: 0, z: 1, w: 0]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Z_AXIS
+    }
+
+    /// Creates an `IVec4` with values `[x: 0, y: 0, z: 0, w: 1]`.
+    #[inline]
+    pub const fn unit_w() -> Self {
+        W_AXIS
+    }
+
+    /// Creates an `IVec4` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_set1_epi32(v))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: v,
+                y: v,
+                z: v,
+                w: v,
+            }
+        }
+    }
+
+    /// Creates an `IVec3` from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    ///
+    /// Truncation may also be performed by using `self.xyz()` or `IVec3::from()`.
+    #[inline]
+    pub fn truncate(self) -> IVec3 {
+        IVec3::new(self.x, self.y, self.z)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> i32 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            let mask = _mm_castps_si128(self.cmplt(other).0);
+            Self(_mm_or_si128(
+                _mm_and_si128(mask, self.0),
+                _mm_andnot_si128(mask, other.0),
+            ))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.min(other.x),
+                y: self.y.min(other.y),
+                z: self.z.min(other.z),
+                w: self.w.min(other.w),
+            }
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            let mask = _mm_castps_si128(self.cmpgt(other).0);
+            Self(_mm_or_si128(
+                _mm_and_si128(mask, self.0),
+                _mm_andnot_si128(mask, other.0),
+            ))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.max(other.x),
+                y: self.y.max(other.y),
+                z: self.z.max(other.z),
+                w: self.w.max(other.w),
+            }
+        }
+    }
+
+    /// Component-wise clamping of values, similar to [`i32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// If the `glam-assert` feature is enabled, the function will panic if the contract is not
+    /// met, otherwise the behavior is undefined.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all());
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_cmpeq_epi32(self.0, other.0)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.eq(&other.x),
+                self.y.eq(&other.y),
+                self.z.eq(&other.z),
+                self.w.eq(&other.w),
+            )
+        }
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_xor_si128(
+                _mm_cmpeq_epi32(self.0, other.0),
+                _mm_set1_epi32(-1),
+            )))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.ne(&other.x),
+                self.y.ne(&other.y),
+                self.z.ne(&other.z),
+                self.w.ne(&other.w),
+            )
+        }
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_xor_si128(
+                _mm_cmplt_epi32(self.0, other.0),
+                _mm_set1_epi32(-1),
+            )))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.ge(&other.x),
+                self.y.ge(&other.y),
+                self.z.ge(&other.z),
+                self.w.ge(&other.w),
+            )
+        }
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_cmpgt_epi32(self.0, other.0)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.gt(&other.x),
+                self.y.gt(&other.y),
+                self.z.gt(&other.z),
+                self.w.gt(&other.w),
+            )
+        }
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_xor_si128(
+                _mm_cmpgt_epi32(self.0, other.0),
+                _mm_set1_epi32(-1),
+            )))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.le(&other.x),
+                self.y.le(&other.y),
+                self.z.le(&other.z),
+                self.w.le(&other.w),
+            )
+        }
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> Vec4Mask {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4Mask(_mm_castsi128_ps(_mm_cmplt_epi32(self.0, other.0)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4Mask::new(
+                self.x.lt(&other.x),
+                self.y.lt(&other.y),
+                self.z.lt(&other.z),
+                self.w.lt(&other.w),
+            )
+        }
+    }
+
+    /// Creates an `IVec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[i32]) -> Self {
+        #[cfg(vec4_sse2)]
+        {
+            assert!(slice.len() >= 4);
+            unsafe { Self(_mm_loadu_si128(slice.as_ptr() as *const __m128i)) }
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: slice[0],
+                y: slice[1],
+                z: slice[2],
+                w: slice[3],
+            }
+        }
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [i32]) {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            assert!(slice.len() >= 4);
+            _mm_storeu_si128(slice.as_mut_ptr() as *mut __m128i, self.0);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            slice[0] = self.x;
+            slice[1] = self.y;
+            slice[2] = self.z;
+            slice[3] = self.w;
+        }
+    }
+
+    /// Returns an `IVec4` containing the absolute value of each element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            let sign = _mm_srai_epi32(self.0, 31);
+            Self(_mm_sub_epi32(_mm_xor_si128(self.0, sign), sign))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.abs(),
+                y: self.y.abs(),
+                z: self.z.abs(),
+                w: self.w.abs(),
+            }
+        }
+    }
+
+    /// Returns an `IVec4` with elements representing the sign of `self`.
+    ///
+    ///  - `0` if the number is zero
+    ///  - `1` if the number is positive
+    ///  - `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_or_si128(
+                _mm_srai_epi32(self.0, 31),
+                _mm_srli_epi32(_mm_sub_epi32(_mm_setzero_si128(), self.0), 31),
+            ))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.signum(),
+                y: self.y.signum(),
+                z: self.z.signum(),
+                w: self.w.signum(),
+            }
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(other.x), self.y.wrapping_add(other.y), self.z.wrapping_add(other.z), self.w.wrapping_add(other.w)]`.
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_add_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.wrapping_add(other.x),
+                y: self.y.wrapping_add(other.y),
+                z: self.z.wrapping_add(other.z),
+                w: self.w.wrapping_add(other.w),
+            }
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(other.x), self.y.wrapping_sub(other.y), self.z.wrapping_sub(other.z), self.w.wrapping_sub(other.w)]`.
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sub_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.wrapping_sub(other.x),
+                y: self.y.wrapping_sub(other.y),
+                z: self.z.wrapping_sub(other.z),
+                w: self.w.wrapping_sub(other.w),
+            }
+        }
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(other.x), self.y.wrapping_mul(other.y), self.z.wrapping_mul(other.z), self.w.wrapping_mul(other.w)]`.
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(mul_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x.wrapping_mul(other.x),
+                y: self.y.wrapping_mul(other.y),
+                z: self.z.wrapping_mul(other.z),
+                w: self.w.wrapping_mul(other.w),
+            }
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(other.x), self.y.saturating_add(other.y), self.z.saturating_add(other.z), self.w.saturating_add(other.w)]`.
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::new(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+            self.z.saturating_add(other.z),
+            self.w.saturating_add(other.w),
+        )
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(other.x), self.y.saturating_sub(other.y), self.z.saturating_sub(other.z), self.w.saturating_sub(other.w)]`.
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::new(
+            self.x.saturating_sub(other.x),
+            self.y.saturating_sub(other.y),
+            self.z.saturating_sub(other.z),
+            self.w.saturating_sub(other.w),
+        )
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(other.x), self.y.saturating_mul(other.y), self.z.saturating_mul(other.z), self.w.saturating_mul(other.w)]`.
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self::new(
+            self.x.saturating_mul(other.x),
+            self.y.saturating_mul(other.y),
+            self.z.saturating_mul(other.z),
+            self.w.saturating_mul(other.w),
+        )
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec4(self) -> Vec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Vec4(_mm_cvtepi32_ps(self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+        }
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_dvec4(self) -> DVec4 {
+        DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
+    /// Casts all elements of `self` to `u32`.
+    #[inline]
+    pub fn as_uvec4(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        {
+            UVec4(self.0)
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+        }
+    }
+}
+
+/// Multiplies the low 32 bits of each lane, SSE2 lacks `_mm_mullo_epi32`.
+#[cfg(vec4_sse2)]
+#[inline]
+unsafe fn mul_epi32(a: __m128i, b: __m128i) -> __m128i {
+    let mul20 = _mm_mul_epu32(a, b);
+    let mul31 = _mm_mul_epu32(_mm_srli_si128(a, 4), _mm_srli_si128(b, 4));
+    _mm_unpacklo_epi32(
+        _mm_shuffle_epi32(mul20, 0b00_00_10_00),
+        _mm_shuffle_epi32(mul31, 0b00_00_10_00),
+    )
+}
+
+impl AsRef<[i32; 4]> for IVec4 {
+    #[inline]
+    fn as_ref(&self) -> &[i32; 4] {
+        unsafe { &*(self as *const Self as *const [i32; 4]) }
+    }
+}
+
+impl AsMut<[i32; 4]> for IVec4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [i32; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [i32; 4]) }
+    }
+}
+
+impl fmt::Debug for IVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("IVec4")
+            .field(&a[0])
+            .field(&a[1])
+            .field(&a[2])
+            .field(&a[3])
+            .finish()
+    }
+}
+
+impl fmt::Display for IVec4 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        write!(fmt, "[{}, {}, {}, {}]", a[0], a[1], a[2], a[3])
+    }
+}
+
+impl Div<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self::new(
+            self.x / other.x,
+            self.y / other.y,
+            self.z / other.z,
+            self.w / other.w,
+        )
+    }
+}
+
+impl DivAssign<IVec4> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = self.div(other);
+    }
+}
+
+impl Div<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: i32) -> Self {
+        Self::new(
+            self.x / other,
+            self.y / other,
+            self.z / other,
+            self.w / other,
+        )
+    }
+}
+
+impl DivAssign<i32> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, other: i32) {
+        *self = self.div(other);
+    }
+}
+
+impl Div<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, other: IVec4) -> IVec4 {
+        IVec4::new(
+            self / other.x,
+            self / other.y,
+            self / other.z,
+            self / other.w,
+        )
+    }
+}
+
+impl Mul<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(mul_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x * other.x,
+                y: self.y * other.y,
+                z: self.z * other.z,
+                w: self.w * other.w,
+            }
+        }
+    }
+}
+
+impl MulAssign<IVec4> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.mul(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x *= other.x;
+            self.y *= other.y;
+            self.z *= other.z;
+            self.w *= other.w;
+        }
+    }
+}
+
+impl Mul<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(mul_epi32(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x * other,
+                y: self.y * other,
+                z: self.z * other,
+                w: self.w * other,
+            }
+        }
+    }
+}
+
+impl MulAssign<i32> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: i32) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.mul(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x *= other;
+            self.y *= other;
+            self.z *= other;
+            self.w *= other;
+        }
+    }
+}
+
+impl Mul<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, other: IVec4) -> IVec4 {
+        IVec4::splat(self).mul(other)
+    }
+}
+
+impl Add<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_add_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x + other.x,
+                y: self.y + other.y,
+                z: self.z + other.z,
+                w: self.w + other.w,
+            }
+        }
+    }
+}
+
+impl AddAssign<IVec4> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.add(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x += other.x;
+            self.y += other.y;
+            self.z += other.z;
+            self.w += other.w;
+        }
+    }
+}
+
+impl Add<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_add_epi32(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x + other,
+                y: self.y + other,
+                z: self.z + other,
+                w: self.w + other,
+            }
+        }
+    }
+}
+
+impl AddAssign<i32> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, other: i32) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.add(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x += other;
+            self.y += other;
+            self.z += other;
+            self.w += other;
+        }
+    }
+}
+
+impl Add<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, other: IVec4) -> IVec4 {
+        IVec4::splat(self).add(other)
+    }
+}
+
+impl Sub<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sub_epi32(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x - other.x,
+                y: self.y - other.y,
+                z: self.z - other.z,
+                w: self.w - other.w,
+            }
+        }
+    }
+}
+
+impl SubAssign<IVec4> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.sub(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x -= other.x;
+            self.y -= other.y;
+            self.z -= other.z;
+            self.w -= other.w;
+        }
+    }
+}
+
+impl Sub<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sub_epi32(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x - other,
+                y: self.y - other,
+                z: self.z - other,
+                w: self.w - other,
+            }
+        }
+    }
+}
+
+impl SubAssign<i32> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: i32) {
+        #[cfg(vec4_sse2)]
+        {
+            *self = self.sub(other);
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            self.x -= other;
+            self.y -= other;
+            self.z -= other;
+            self.w -= other;
+        }
+    }
+}
+
+impl Sub<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, other: IVec4) -> IVec4 {
+        IVec4::splat(self).sub(other)
+    }
+}
+
+impl Rem<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Self::new(
+            self.x % other.x,
+            self.y % other.y,
+            self.z % other.z,
+            self.w % other.w,
+        )
+    }
+}
+
+impl RemAssign<IVec4> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        *self = self.rem(other);
+    }
+}
+
+impl Rem<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: i32) -> Self {
+        Self::new(
+            self.x % other,
+            self.y % other,
+            self.z % other,
+            self.w % other,
+        )
+    }
+}
+
+impl RemAssign<i32> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, other: i32) {
+        *self = self.rem(other);
+    }
+}
+
+impl Rem<IVec4> for i32 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, other: IVec4) -> IVec4 {
+        IVec4::new(
+            self % other.x,
+            self % other.y,
+            self % other.z,
+            self % other.w,
+        )
+    }
+}
+
+impl Neg for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sub_epi32(_mm_setzero_si128(), self.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: -self.x,
+                y: -self.y,
+                z: -self.z,
+                w: -self.w,
+            }
+        }
+    }
+}
+
+impl Not for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_xor_si128(self.0, _mm_set1_epi32(-1)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: !self.x,
+                y: !self.y,
+                z: !self.z,
+                w: !self.w,
+            }
+        }
+    }
+}
+
+impl BitAnd for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_and_si128(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x & other.x,
+                y: self.y & other.y,
+                z: self.z & other.z,
+                w: self.w & other.w,
+            }
+        }
+    }
+}
+
+impl BitAnd<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_and_si128(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x & other,
+                y: self.y & other,
+                z: self.z & other,
+                w: self.w & other,
+            }
+        }
+    }
+}
+
+impl BitOr for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_or_si128(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x | other.x,
+                y: self.y | other.y,
+                z: self.z | other.z,
+                w: self.w | other.w,
+            }
+        }
+    }
+}
+
+impl BitOr<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_or_si128(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x | other,
+                y: self.y | other,
+                z: self.z | other,
+                w: self.w | other,
+            }
+        }
+    }
+}
+
+impl BitXor for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_xor_si128(self.0, other.0))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x ^ other.x,
+                y: self.y ^ other.y,
+                z: self.z ^ other.z,
+                w: self.w ^ other.w,
+            }
+        }
+    }
+}
+
+impl BitXor<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_xor_si128(self.0, _mm_set1_epi32(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x ^ other,
+                y: self.y ^ other,
+                z: self.z ^ other,
+                w: self.w ^ other,
+            }
+        }
+    }
+}
+
+impl Shl<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sll_epi32(self.0, _mm_cvtsi32_si128(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x << other,
+                y: self.y << other,
+                z: self.z << other,
+                w: self.w << other,
+            }
+        }
+    }
+}
+
+impl Shr<i32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: i32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sra_epi32(self.0, _mm_cvtsi32_si128(other)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x >> other,
+                y: self.y >> other,
+                z: self.z >> other,
+                w: self.w >> other,
+            }
+        }
+    }
+}
+
+impl Shl<u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: u32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sll_epi32(self.0, _mm_cvtsi32_si128(other as i32)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x << other,
+                y: self.y << other,
+                z: self.z << other,
+                w: self.w << other,
+            }
+        }
+    }
+}
+
+impl Shr<u32> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: u32) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_sra_epi32(self.0, _mm_cvtsi32_si128(other as i32)))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: self.x >> other,
+                y: self.y >> other,
+                z: self.z >> other,
+                w: self.w >> other,
+            }
+        }
+    }
+}
+
+impl Shl<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: Self) -> Self {
+        Self::new(
+            self.x << other.x,
+            self.y << other.y,
+            self.z << other.z,
+            self.w << other.w,
+        )
+    }
+}
+
+impl Shr<IVec4> for IVec4 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: Self) -> Self {
+        Self::new(
+            self.x >> other.x,
+            self.y >> other.y,
+            self.z >> other.z,
+            self.w >> other.w,
+        )
+    }
+}
+
+impl Index<usize> for IVec4 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for IVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for IVec4 {
+    #[inline]
+    fn from(t: (i32, i32, i32, i32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<IVec4> for (i32, i32, i32, i32) {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        #[cfg(vec4_sse2)]
+        {
+            let mut out: MaybeUninit<Align16<(i32, i32, i32, i32)>> = MaybeUninit::uninit();
+            unsafe {
+                _mm_store_si128(out.as_mut_ptr() as *mut __m128i, v.0);
+                out.assume_init().0
+            }
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            (v.x, v.y, v.z, v.w)
+        }
+    }
+}
+
+impl From<[i32; 4]> for IVec4 {
+    #[inline]
+    fn from(a: [i32; 4]) -> Self {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            Self(_mm_loadu_si128(a.as_ptr() as *const __m128i))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            Self {
+                x: a[0],
+                y: a[1],
+                z: a[2],
+                w: a[3],
+            }
+        }
+    }
+}
+
+impl From<IVec4> for [i32; 4] {
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        #[cfg(vec4_sse2)]
+        {
+            let mut out: MaybeUninit<Align16<[i32; 4]>> = MaybeUninit::uninit();
+            unsafe {
+                _mm_store_si128(out.as_mut_ptr() as *mut __m128i, v.0);
+                out.assume_init().0
+            }
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            [v.x, v.y, v.z, v.w]
+        }
+    }
+}
+
+impl From<IVec4> for IVec2 {
+    /// Creates an `IVec2` from the `x` and `y` elements of the `IVec4`, discarding `z` and `w`.
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        IVec2::new(v.x, v.y)
+    }
+}
+
+impl From<IVec4> for IVec3 {
+    /// Creates an `IVec3` from the `x`, `y` and `z` elements of the `IVec4`, discarding `w`.
+    #[inline]
+    fn from(v: IVec4) -> Self {
+        IVec3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(vec4_sse2)]
+impl Deref for IVec4 {
+    type Target = super::XYZW;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self as *const Self::Target) }
+    }
+}
+
+#[cfg(vec4_sse2)]
+impl DerefMut for IVec4 {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for IVec4 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for IVec4 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
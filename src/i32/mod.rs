@@ -0,0 +1,33 @@
+mod cast;
+mod ivec2;
+mod ivec2_swizzle;
+mod ivec3;
+mod ivec3_swizzle;
+mod ivec4;
+mod ivec4_swizzle;
+
+pub use cast::{I32x2Cast, I32x3Cast, I32x4Cast};
+pub use ivec2::*;
+pub use ivec2_swizzle::*;
+pub use ivec3::*;
+pub use ivec3_swizzle::*;
+pub use ivec4::*;
+pub use ivec4_swizzle::*;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct XYZW {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub w: i32,
+}
+
+#[cfg(feature = "bytemuck")]
+mod glam_bytemuck;
+#[cfg(feature = "mint")]
+mod glam_mint;
+#[cfg(feature = "rand")]
+mod glam_rand;
+#[cfg(feature = "serde")]
+mod glam_serde;
@@ -8,9 +8,18 @@
 `glam` is built with SIMD in mind. Currently only SSE2 on x86/x86_64 is
 supported as this is what stable Rust supports.
 
-* Single precision float `f32` support only (for now)
+* `f32` types, including `Vec2`, `Vec3`, `Vec3A`, `Vec4`, `Mat2`, `Mat3`,
+  `Mat3A`, `Mat4`, `Affine2`, `Affine3A`, `Quat` and `DualQuat`
+* `f64` types, including `DVec2`, `DVec3`, `DVec4`, `DMat2`, `DMat3`, `DMat4`
+  and `DQuat`
+* `i32` and `u32` vector types, including `IVec2`, `IVec3`, `IVec4`, `UVec2`,
+  `UVec3` and `UVec4`
+* geometric primitives in the `geom` module, including `Aabb3`, `Capsule`,
+  `Obb`, `Plane`, `Ray3`, `Sphere` and `Triangle3`
 * SSE2 storage and optimization for many types, including `Mat2`, `Mat4`,
-  `Quat`, `Vec3A` and `Vec4`
+  `Mat3A`, `Quat`, `Vec3A` and `Vec4`
+* structure of arrays `Vec3x4`, `Vec3x8` and `Vec4x4` types for processing
+  four or eight vectors at once
 * Scalar fallback implementations exist when SSE2 is not available
 * Most functionality includes unit tests and benchmarks
 
@@ -29,10 +38,43 @@ assert_eq!(v, x);
 
 Matrices are stored in memory in column-major order.
 
+## Double precision
+
+The `f64` types share the same method names as their `f32` counterparts but are
+prefixed with a `D`, for example `DVec3` and `DMat4`. They do not use SIMD and
+have native `f64` alignment. Use the `as_f64` and `as_f32` methods to convert
+between precisions.
+
+```
+use glam::{DVec3, Vec3};
+let v = Vec3::new(1.0, 2.0, 3.0);
+let d = v.as_f64();
+assert_eq!(d, DVec3::new(1.0, 2.0, 3.0));
+assert_eq!(d.as_f32(), v);
+```
+
+## Integer vectors
+
+The `i32` and `u32` vector types are prefixed with `I` and `U` respectively, for
+example `IVec3` and `UVec4`. They support the usual arithmetic operators as well
+as bitwise operators and shifts. Comparison methods return the same vector masks
+as the `f32` types. Use the `as_vec*`, `as_ivec*` and `as_uvec*` methods to
+convert between element types, float to integer conversions truncate towards
+zero.
+
+```
+use glam::{IVec3, Vec3};
+let v = Vec3::new(1.5, -2.5, 3.0);
+let i = v.as_ivec3();
+assert_eq!(i, IVec3::new(1, -2, 3));
+assert_eq!(i.as_vec3(), Vec3::new(1.0, -2.0, 3.0));
+```
+
 ## Size and alignment of types
 
 Some `glam` types use SIMD for storage meaning they are 16 byte aligned, these
-types include `Mat2`, `Mat4`, `Quat`, `Vec3A` and `Vec4`.
+types include `Affine2`, `Affine3A`, `Mat2`, `Mat3A`, `Mat4`, `Quat`, `Vec3A`
+and `Vec4`.
 
 When SSE2 is not available on the target architecture this type will still be 16
 byte aligned so that object sizes and layouts will not change between
@@ -94,7 +136,9 @@ this includes creating a vector of a different size from the vectors elements.
 The swizzle functions are implemented using traits to add them to each vector
 type. This is primarily because there are a lot of swizzle functions which can
 obfuscate the other vector functions in documentation and so on. The traits are
-`Vec2Swizzles`, `Vec3Swizzles`, `Vec3ASwizzles` and `Vec4Swizzles`.
+`Vec2Swizzles`, `Vec3Swizzles`, `Vec3ASwizzles` and `Vec4Swizzles`, plus
+`DVec2Swizzles`, `DVec3Swizzles` and `DVec4Swizzles` for the `f64` types and
+the equivalent `IVec*Swizzles` and `UVec*Swizzles` traits for the integer types.
 
 Note that `Vec2Swizzles`, `Vec3Swizzles` and `Vec4Swizzles` will return a `Vec3`
 type for 3 element swizzles. `Vec3ASwizzles` will return a `Vec3A` for 3 element
@@ -163,24 +207,58 @@ and benchmarks.
 
 The minimum supported version of Rust for `glam` is `1.36.0`.
 
+The NEON code paths on aarch64 require Rust `1.59.0` and the WebAssembly
+`simd128` code paths require Rust `1.54.0`. They are only enabled by `build.rs`
+on new enough compilers, older compilers use the scalar implementation instead.
+
 */
 #![doc(html_root_url = "https://docs.rs/glam/0.11.0")]
 #![cfg_attr(not(feature = "std"), no_std)]
+// the const_* macros rely on union casts to build constants
+#![allow(clippy::macro_metavars_in_unsafe)]
 
 #[macro_use]
 mod macros;
 
+mod euler;
+pub use self::euler::EulerRot;
+
 #[doc(hidden)]
 pub mod f32;
 
+#[doc(hidden)]
+pub mod f64;
+
+pub mod geom;
+
+#[doc(hidden)]
+pub mod i32;
+
+#[doc(hidden)]
+pub mod u32;
+
 pub use self::f32::{
-    mat2, mat3, mat4, quat, vec2, vec3, vec3a, vec4, Mat2, Mat3, Mat4, Quat, Vec2, Vec2Mask, Vec3,
-    Vec3A, Vec3AMask, Vec3Mask, Vec4, Vec4Mask,
+    mat2, mat3, mat3a, mat4, quat, vec2, vec3, vec3a, vec4, Affine2, Affine3A, DualQuat, Mat2,
+    Mat3, Mat3A, Mat4, Quat, Vec2, Vec2Mask, Vec3, Vec3A, Vec3AMask, Vec3Mask, Vec3x4, Vec3x8,
+    Vec4, Vec4Mask, Vec4x4,
 };
+pub use self::f64::{
+    dmat2, dmat3, dmat4, dquat, dvec2, dvec3, dvec4, DMat2, DMat3, DMat4, DQuat, DVec2, DVec2Mask,
+    DVec3, DVec3Mask, DVec4, DVec4Mask,
+};
+pub use self::i32::{ivec2, ivec3, ivec4, IVec2, IVec3, IVec4};
+pub use self::u32::{uvec2, uvec3, uvec4, UVec2, UVec3, UVec4};
 pub mod swizzles {
     pub use super::f32::{Vec2Swizzles, Vec3ASwizzles, Vec3Swizzles, Vec4Swizzles};
+    pub use super::f64::{DVec2Swizzles, DVec3Swizzles, DVec4Swizzles};
+    pub use super::i32::{IVec2Swizzles, IVec3Swizzles, IVec4Swizzles};
+    pub use super::u32::{UVec2Swizzles, UVec3Swizzles, UVec4Swizzles};
 }
-pub use swizzles::{Vec2Swizzles, Vec3ASwizzles, Vec3Swizzles, Vec4Swizzles};
+pub use swizzles::{
+    DVec2Swizzles, DVec3Swizzles, DVec4Swizzles, IVec2Swizzles, IVec3Swizzles, IVec4Swizzles,
+    UVec2Swizzles, UVec3Swizzles, UVec4Swizzles, Vec2Swizzles, Vec3ASwizzles, Vec3Swizzles,
+    Vec4Swizzles,
+};
 
 #[cfg(feature = "transform-types")]
 pub use self::f32::{TransformRT, TransformSRT};
@@ -75,6 +75,41 @@ macro_rules! const_mat3 {
     };
 }
 
+/// Creates a `Mat3A` from three column vectors that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_mat3a, Mat3A};
+/// const ZERO: Mat3A = const_mat3a!([0.0; 9]);
+/// const IDENTITY: Mat3A = const_mat3a!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+/// ```
+#[macro_export]
+macro_rules! const_mat3a {
+    ($f32x9:expr) => {
+        unsafe {
+            $crate::f32::F32x12Cast {
+                f32x4x3: [
+                    [$f32x9[0], $f32x9[1], $f32x9[2], 0.0],
+                    [$f32x9[3], $f32x9[4], $f32x9[5], 0.0],
+                    [$f32x9[6], $f32x9[7], $f32x9[8], 0.0],
+                ],
+            }
+            .mat3a
+        }
+    };
+    ($col0:expr, $col1:expr, $col2:expr) => {
+        unsafe {
+            $crate::f32::F32x12Cast {
+                f32x4x3: [
+                    [$col0[0], $col0[1], $col0[2], 0.0],
+                    [$col1[0], $col1[1], $col1[2], 0.0],
+                    [$col2[0], $col2[1], $col2[2], 0.0],
+                ],
+            }
+            .mat3a
+        }
+    };
+}
+
 /// Creates a `Mat4` from four column vectors that can be used to initialize a constant value.
 ///
 /// ```
@@ -186,3 +221,214 @@ macro_rules! const_vec4 {
         unsafe { $crate::f32::F32x4Cast { f32x4: $f32x4 }.vec4 }
     };
 }
+
+/// Creates a `DMat2` from two column vectors that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dmat2, DMat2};
+/// const ZERO: DMat2 = const_dmat2!([0.0; 4]);
+/// const IDENTITY: DMat2 = const_dmat2!([1.0, 0.0], [0.0, 1.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dmat2 {
+    ($f64x4:expr) => {
+        unsafe { $crate::f64::F64x4Cast { f64x4: $f64x4 }.dmat2 }
+    };
+    ($col0:expr, $col1:expr) => {
+        unsafe {
+            $crate::f64::F64x4Cast {
+                f64x2x2: [$col0, $col1],
+            }
+            .dmat2
+        }
+    };
+}
+
+/// Creates a `DMat3` from three column vectors that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dmat3, DMat3};
+/// const ZERO: DMat3 = const_dmat3!([0.0; 9]);
+/// const IDENTITY: DMat3 = const_dmat3!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dmat3 {
+    ($f64x9:expr) => {
+        unsafe { $crate::f64::F64x9Cast { f64x9: $f64x9 }.dmat3 }
+    };
+    ($col0:expr, $col1:expr, $col2:expr) => {
+        unsafe {
+            $crate::f64::F64x9Cast {
+                f64x3x3: [$col0, $col1, $col2],
+            }
+            .dmat3
+        }
+    };
+}
+
+/// Creates a `DMat4` from four column vectors that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dmat4, DMat4};
+/// const ZERO: DMat4 = const_dmat4!([0.0; 16]);
+/// const IDENTITY: DMat4 = const_dmat4!(
+///     [1.0, 0.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [0.0, 0.0, 1.0, 0.0],
+///     [0.0, 0.0, 0.0, 1.0]
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_dmat4 {
+    ($f64x16:expr) => {
+        unsafe { $crate::f64::F64x16Cast { f64x16: $f64x16 }.dmat4 }
+    };
+    ($col0:expr, $col1:expr, $col2:expr, $col3:expr) => {
+        unsafe {
+            $crate::f64::F64x16Cast {
+                f64x4x4: [$col0, $col1, $col2, $col3],
+            }
+            .dmat4
+        }
+    };
+}
+
+/// Creates a `DQuat` from `x`, `y`, `z` and `w` values that can be used to initialize a constant
+/// value.
+///
+/// ```
+/// use glam::{const_dquat, DQuat};
+/// const IDENTITY: DQuat = const_dquat!([0.0, 0.0, 0.0, 1.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dquat {
+    ($f64x4:expr) => {
+        unsafe { $crate::f64::F64x4Cast { f64x4: $f64x4 }.dquat }
+    };
+}
+
+/// Creates a `DVec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dvec2, DVec2};
+/// const ONE: DVec2 = const_dvec2!([1.0; 2]);
+/// const X_AXIS: DVec2 = const_dvec2!([1.0, 0.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dvec2 {
+    ($f64x2:expr) => {
+        unsafe { $crate::f64::F64x2Cast { f64x2: $f64x2 }.dvec2 }
+    };
+}
+
+/// Creates a `DVec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dvec3, DVec3};
+/// const ONE: DVec3 = const_dvec3!([1.0; 3]);
+/// const X_AXIS: DVec3 = const_dvec3!([1.0, 0.0, 0.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dvec3 {
+    ($f64x3:expr) => {
+        unsafe { $crate::f64::F64x3Cast { f64x3: $f64x3 }.dvec3 }
+    };
+}
+
+/// Creates a `DVec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_dvec4, DVec4};
+/// const ONE: DVec4 = const_dvec4!([1.0; 4]);
+/// const X_AXIS: DVec4 = const_dvec4!([1.0, 0.0, 0.0, 0.0]);
+/// ```
+#[macro_export]
+macro_rules! const_dvec4 {
+    ($f64x4:expr) => {
+        unsafe { $crate::f64::F64x4Cast { f64x4: $f64x4 }.dvec4 }
+    };
+}
+
+/// Creates an `IVec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_ivec2, IVec2};
+/// const ONE: IVec2 = const_ivec2!([1; 2]);
+/// const X_AXIS: IVec2 = const_ivec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_ivec2 {
+    ($i32x2:expr) => {
+        unsafe { $crate::i32::I32x2Cast { i32x2: $i32x2 }.ivec2 }
+    };
+}
+
+/// Creates an `IVec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_ivec3, IVec3};
+/// const ONE: IVec3 = const_ivec3!([1; 3]);
+/// const X_AXIS: IVec3 = const_ivec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_ivec3 {
+    ($i32x3:expr) => {
+        unsafe { $crate::i32::I32x3Cast { i32x3: $i32x3 }.ivec3 }
+    };
+}
+
+/// Creates an `IVec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_ivec4, IVec4};
+/// const ONE: IVec4 = const_ivec4!([1; 4]);
+/// const X_AXIS: IVec4 = const_ivec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_ivec4 {
+    ($i32x4:expr) => {
+        unsafe { $crate::i32::I32x4Cast { i32x4: $i32x4 }.ivec4 }
+    };
+}
+
+/// Creates a `UVec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_uvec2, UVec2};
+/// const ONE: UVec2 = const_uvec2!([1; 2]);
+/// const X_AXIS: UVec2 = const_uvec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_uvec2 {
+    ($u32x2:expr) => {
+        unsafe { $crate::u32::U32x2Cast { u32x2: $u32x2 }.uvec2 }
+    };
+}
+
+/// Creates a `UVec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_uvec3, UVec3};
+/// const ONE: UVec3 = const_uvec3!([1; 3]);
+/// const X_AXIS: UVec3 = const_uvec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_uvec3 {
+    ($u32x3:expr) => {
+        unsafe { $crate::u32::U32x3Cast { u32x3: $u32x3 }.uvec3 }
+    };
+}
+
+/// Creates a `UVec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_uvec4, UVec4};
+/// const ONE: UVec4 = const_uvec4!([1; 4]);
+/// const X_AXIS: UVec4 = const_uvec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_uvec4 {
+    ($u32x4:expr) => {
+        unsafe { $crate::u32::U32x4Cast { u32x4: $u32x4 }.uvec4 }
+    };
+}
@@ -0,0 +1,25 @@
+use super::{UVec2, UVec3, UVec4};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(C)]
+pub union U32x4Cast {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub m128i: __m128i,
+    pub u32x4: [u32; 4],
+    pub uvec4: UVec4,
+}
+
+#[repr(C)]
+pub union U32x3Cast {
+    pub u32x3: [u32; 3],
+    pub uvec3: UVec3,
+}
+
+#[repr(C)]
+pub union U32x2Cast {
+    pub u32x2: [u32; 2],
+    pub uvec2: UVec2,
+}
@@ -0,0 +1,32 @@
+use super::{UVec2, UVec3, UVec4};
+use bytemuck::{Pod, Zeroable};
+
+unsafe impl Pod for UVec2 {}
+unsafe impl Zeroable for UVec2 {}
+unsafe impl Pod for UVec3 {}
+unsafe impl Zeroable for UVec3 {}
+unsafe impl Pod for UVec4 {}
+unsafe impl Zeroable for UVec4 {}
+
+#[cfg(test)]
+mod test {
+    use super::{UVec2, UVec3, UVec4};
+    use bytemuck;
+    use core::mem;
+
+    macro_rules! test_t {
+        ($name:ident, $t:ty) => {
+            #[test]
+            fn $name() {
+                let t = <$t>::default();
+                let b = bytemuck::bytes_of(&t);
+                assert_eq!(t.as_ref().as_ptr() as usize, b.as_ptr() as usize);
+                assert_eq!(b.len(), mem::size_of_val(&t));
+            }
+        };
+    }
+
+    test_t!(uvec2, UVec2);
+    test_t!(uvec3, UVec3);
+    test_t!(uvec4, UVec4);
+}
@@ -0,0 +1,122 @@
+use super::{UVec2, UVec3, UVec4};
+use mint;
+
+impl From<mint::Point2<u32>> for UVec2 {
+    fn from(v: mint::Point2<u32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<UVec2> for mint::Point2<u32> {
+    fn from(v: UVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Point3<u32>> for UVec3 {
+    fn from(v: mint::Point3<u32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<UVec3> for mint::Point3<u32> {
+    fn from(v: UVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector2<u32>> for UVec2 {
+    fn from(v: mint::Vector2<u32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<UVec2> for mint::Vector2<u32> {
+    fn from(v: UVec2) -> Self {
+        let (x, y) = v.into();
+        Self { x, y }
+    }
+}
+
+impl From<mint::Vector3<u32>> for UVec3 {
+    fn from(v: mint::Vector3<u32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<UVec3> for mint::Vector3<u32> {
+    fn from(v: UVec3) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector4<u32>> for UVec4 {
+    fn from(v: mint::Vector4<u32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<UVec4> for mint::Vector4<u32> {
+    fn from(v: UVec4) -> Self {
+        let (x, y, z, w) = v.into();
+        Self { x, y, z, w }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mint;
+
+    #[test]
+    fn test_point2() {
+        use crate::UVec2;
+        let m = mint::Point2 { x: 1, y: 2 };
+        let g = UVec2::from(m);
+        assert_eq!(g, UVec2::new(1, 2));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_point3() {
+        use crate::UVec3;
+        let m = mint::Point3 { x: 1, y: 2, z: 3 };
+        let g = UVec3::from(m);
+        assert_eq!(g, UVec3::new(1, 2, 3));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector2() {
+        use crate::UVec2;
+        let m = mint::Vector2 { x: 1, y: 2 };
+        let g = UVec2::from(m);
+        assert_eq!(g, UVec2::new(1, 2));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector3() {
+        use crate::UVec3;
+        let m = mint::Vector3 { x: 1, y: 2, z: 3 };
+        let g = UVec3::from(m);
+        assert_eq!(g, UVec3::new(1, 2, 3));
+        assert_eq!(m, g.into());
+    }
+
+    #[test]
+    fn test_vector4() {
+        use crate::UVec4;
+        let m = mint::Vector4 {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4,
+        };
+        let g = UVec4::from(m);
+        assert_eq!(g, UVec4::new(1, 2, 3, 4));
+        assert_eq!(m, g.into());
+    }
+}
@@ -0,0 +1,27 @@
+use super::{UVec2, UVec3, UVec4};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<UVec2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UVec2 {
+        rng.gen::<[u32; 2]>().into()
+    }
+}
+
+impl Distribution<UVec3> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UVec3 {
+        rng.gen::<[u32; 3]>().into()
+    }
+}
+
+impl Distribution<UVec4> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UVec4 {
+        rng.gen::<[u32; 4]>().into()
+    }
+}
@@ -0,0 +1,155 @@
+use super::{UVec2, UVec3, UVec4};
+use core::fmt;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTupleStruct, Serializer},
+};
+
+impl Serialize for UVec2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y) = (*self).into();
+        let mut state = serializer.serialize_tuple_struct("UVec2", 2)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.end()
+    }
+}
+
+impl Serialize for UVec3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z) = (*self).into();
+        // 3 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("UVec3", 3)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.end()
+    }
+}
+
+impl Serialize for UVec4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (x, y, z, w) = (*self).into();
+        // 4 is the number of fields in the struct.
+        let mut state = serializer.serialize_tuple_struct("UVec4", 4)?;
+        state.serialize_field(&x)?;
+        state.serialize_field(&y)?;
+        state.serialize_field(&z)?;
+        state.serialize_field(&w)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for UVec2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec2Visitor;
+
+        impl<'de> Visitor<'de> for Vec2Visitor {
+            type Value = UVec2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UVec2")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<UVec2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(UVec2::new(x, y))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("UVec2", 2, Vec2Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for UVec3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec3Visitor;
+
+        impl<'de> Visitor<'de> for Vec3Visitor {
+            type Value = UVec3;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UVec3")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<UVec3, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(UVec3::new(x, y, z))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("UVec3", 3, Vec3Visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for UVec4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Vec4Visitor;
+
+        impl<'de> Visitor<'de> for Vec4Visitor {
+            type Value = UVec4;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UVec4")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<UVec4, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let w = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(UVec4::new(x, y, z, w))
+            }
+        }
+
+        deserializer.deserialize_tuple_struct("UVec4", 4, Vec4Visitor)
+    }
+}
@@ -0,0 +1,33 @@
+mod cast;
+mod uvec2;
+mod uvec2_swizzle;
+mod uvec3;
+mod uvec3_swizzle;
+mod uvec4;
+mod uvec4_swizzle;
+
+pub use cast::{U32x2Cast, U32x3Cast, U32x4Cast};
+pub use uvec2::*;
+pub use uvec2_swizzle::*;
+pub use uvec3::*;
+pub use uvec3_swizzle::*;
+pub use uvec4::*;
+pub use uvec4_swizzle::*;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct XYZW {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+    pub w: u32,
+}
+
+#[cfg(feature = "bytemuck")]
+mod glam_bytemuck;
+#[cfg(feature = "mint")]
+mod glam_mint;
+#[cfg(feature = "rand")]
+mod glam_rand;
+#[cfg(feature = "serde")]
+mod glam_serde;
@@ -0,0 +1,185 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{UVec2, UVec3, UVec4};
+
+pub trait UVec2Swizzles {
+    fn xxxx(self) -> UVec4;
+    fn xxxy(self) -> UVec4;
+    fn xxyx(self) -> UVec4;
+    fn xxyy(self) -> UVec4;
+    fn xyxx(self) -> UVec4;
+    fn xyxy(self) -> UVec4;
+    fn xyyx(self) -> UVec4;
+    fn xyyy(self) -> UVec4;
+    fn yxxx(self) -> UVec4;
+    fn yxxy(self) -> UVec4;
+    fn yxyx(self) -> UVec4;
+    fn yxyy(self) -> UVec4;
+    fn yyxx(self) -> UVec4;
+    fn yyxy(self) -> UVec4;
+    fn yyyx(self) -> UVec4;
+    fn yyyy(self) -> UVec4;
+    fn xxx(self) -> UVec3;
+    fn xxy(self) -> UVec3;
+    fn xyx(self) -> UVec3;
+    fn xyy(self) -> UVec3;
+    fn yxx(self) -> UVec3;
+    fn yxy(self) -> UVec3;
+    fn yyx(self) -> UVec3;
+    fn yyy(self) -> UVec3;
+    fn xx(self) -> UVec2;
+    fn yx(self) -> UVec2;
+    fn yy(self) -> UVec2;
+}
+
+impl UVec2Swizzles for UVec2 {
+    #[inline]
+    fn xxxx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxyx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xyxx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyyx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yxxx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxyx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yyxx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyyx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xxx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xx(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yx(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+}
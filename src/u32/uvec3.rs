@@ -0,0 +1,871 @@
+use super::{UVec2, UVec4};
+use crate::i32::IVec3;
+use crate::{DVec3, Vec3, Vec3Mask};
+use core::{fmt, ops::*};
+
+#[cfg(feature = "std")]
+use std::iter::{Product, Sum};
+
+const ZERO: UVec3 = const_uvec3!([0; 3]);
+const ONE: UVec3 = const_uvec3!([1; 3]);
+const X_AXIS: UVec3 = const_uvec3!([1, 0, 0]);
+const Y_AXIS: UVec3 = const_uvec3!([0, 1, 0]);
+const Z_AXIS: UVec3 = const_uvec3!([0, 0, 1]);
+
+/// A 3-dimensional vector of `u32` elements.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct UVec3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+/// Creates a `UVec3`.
+#[inline]
+pub fn uvec3(x: u32, y: u32, z: u32) -> UVec3 {
+    UVec3::new(x, y, z)
+}
+
+impl UVec3 {
+    /// Creates a new `UVec3`.
+    #[inline]
+    pub fn new(x: u32, y: u32, z: u32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a `UVec3` with all elements set to `0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        ZERO
+    }
+
+    /// Creates a `UVec3` with all elements set to `1`.
+    #[inline]
+    pub const fn one() -> Self {
+        ONE
+    }
+
+    /// Creates a `UVec3` with values `[x: 1, y: 0, z: 0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        X_AXIS
+    }
+
+    /// Creates a `UVec3` with values `[x: 0, y: 1, z: 0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Y_AXIS
+    }
+
+    /// Creates a `UVec3` with values `[x: 0, y: 0, z: 1]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Z_AXIS
+    }
+
+    /// Creates a `UVec3` with all elements set to `v`.
+    #[inline]
+    pub fn splat(v: u32) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    /// Creates a `UVec4` from `self` and the given `w` value.
+    #[inline]
+    pub fn extend(self, w: u32) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Creates a `UVec2` from the `x` and `y` elements of `self`, discarding `z`.
+    ///
+    /// Truncation may also be performed by using `self.xy()` or `UVec2::from()`.
+    #[inline]
+    pub fn truncate(self) -> UVec2 {
+        UVec2::new(self.x, self.y)
+    }
+
+    /// Computes the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> u32 {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Component-wise clamping of values, similar to [`u32::clamp`].
+    ///
+    /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+    ///
+    /// If the `glam-assert` feature is enabled, the function will panic if the contract is not
+    /// met, otherwise the behavior is undefined.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        glam_assert!(min.cmple(max).all());
+        self.max(min).min(max)
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z)`.
+    #[inline]
+    pub fn min_element(self) -> u32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z)`.
+    #[inline]
+    pub fn max_element(self) -> u32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.eq(&other.x),
+            self.y.eq(&other.y),
+            self.z.eq(&other.z),
+        )
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.ne(&other.x),
+            self.y.ne(&other.y),
+            self.z.ne(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.ge(&other.x),
+            self.y.ge(&other.y),
+            self.z.ge(&other.z),
+        )
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.gt(&other.x),
+            self.y.gt(&other.y),
+            self.z.gt(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.le(&other.x),
+            self.y.le(&other.y),
+            self.z.le(&other.z),
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `Vec3Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> Vec3Mask {
+        Vec3Mask::new(
+            self.x.lt(&other.x),
+            self.y.lt(&other.y),
+            self.z.lt(&other.z),
+        )
+    }
+
+    /// Creates a `UVec3` from the first three values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[u32]) -> Self {
+        Self {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+        }
+    }
+
+    /// Writes the elements of `self` to the first three elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than three elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [u32]) {
+        slice[0] = self.x;
+        slice[1] = self.y;
+        slice[2] = self.z;
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(other.x), self.y.wrapping_add(other.y), self.z.wrapping_add(other.z)]`.
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+            z: self.z.wrapping_add(other.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(other.x), self.y.wrapping_sub(other.y), self.z.wrapping_sub(other.z)]`.
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+            z: self.z.wrapping_sub(other.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.wrapping_mul(other.x), self.y.wrapping_mul(other.y), self.z.wrapping_mul(other.z)]`.
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_mul(other.x),
+            y: self.y.wrapping_mul(other.y),
+            z: self.z.wrapping_mul(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(other.x), self.y.saturating_add(other.y), self.z.saturating_add(other.z)]`.
+    #[inline]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+            z: self.z.saturating_add(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(other.x), self.y.saturating_sub(other.y), self.z.saturating_sub(other.z)]`.
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+            z: self.z.saturating_sub(other.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating multiplication of `self` and `other`.
+    ///
+    /// In other words this computes `[self.x.saturating_mul(other.x), self.y.saturating_mul(other.y), self.z.saturating_mul(other.z)]`.
+    #[inline]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_mul(other.x),
+            y: self.y.saturating_mul(other.y),
+            z: self.z.saturating_mul(other.z),
+        }
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec3(self) -> Vec3 {
+        Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    pub fn as_dvec3(self) -> DVec3 {
+        DVec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    #[inline]
+    pub fn as_ivec3(self) -> IVec3 {
+        IVec3::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+}
+
+impl AsRef<[u32; 3]> for UVec3 {
+    #[inline]
+    fn as_ref(&self) -> &[u32; 3] {
+        unsafe { &*(self as *const Self as *const [u32; 3]) }
+    }
+}
+
+impl AsMut<[u32; 3]> for UVec3 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u32; 3] {
+        unsafe { &mut *(self as *mut Self as *mut [u32; 3]) }
+    }
+}
+
+impl fmt::Debug for UVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        fmt.debug_tuple("UVec3")
+            .field(&a[0])
+            .field(&a[1])
+            .field(&a[2])
+            .finish()
+    }
+}
+
+impl fmt::Display for UVec3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let a = self.as_ref();
+        write!(fmt, "[{}, {}, {}]", a[0], a[1], a[2])
+    }
+}
+
+impl Div<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl DivAssign<UVec3> for UVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.x /= other.x;
+        self.y /= other.y;
+        self.z /= other.z;
+    }
+}
+
+impl Div<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: u32) -> Self {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl DivAssign<u32> for UVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: u32) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+    }
+}
+
+impl Div<UVec3> for u32 {
+    type Output = UVec3;
+    #[inline]
+    fn div(self, other: UVec3) -> UVec3 {
+        UVec3 {
+            x: self / other.x,
+            y: self / other.y,
+            z: self / other.z,
+        }
+    }
+}
+
+impl Mul<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+}
+
+impl MulAssign<UVec3> for UVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.x *= other.x;
+        self.y *= other.y;
+        self.z *= other.z;
+    }
+}
+
+impl Mul<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: u32) -> Self {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl MulAssign<u32> for UVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: u32) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+impl Mul<UVec3> for u32 {
+    type Output = UVec3;
+    #[inline]
+    fn mul(self, other: UVec3) -> UVec3 {
+        UVec3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+impl Add<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl AddAssign<UVec3> for UVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl Add<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: u32) -> Self {
+        Self {
+            x: self.x + other,
+            y: self.y + other,
+            z: self.z + other,
+        }
+    }
+}
+
+impl AddAssign<u32> for UVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: u32) {
+        self.x += other;
+        self.y += other;
+        self.z += other;
+    }
+}
+
+impl Add<UVec3> for u32 {
+    type Output = UVec3;
+    #[inline]
+    fn add(self, other: UVec3) -> UVec3 {
+        UVec3 {
+            x: self + other.x,
+            y: self + other.y,
+            z: self + other.z,
+        }
+    }
+}
+
+impl Sub<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl SubAssign<UVec3> for UVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl Sub<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: u32) -> Self {
+        Self {
+            x: self.x - other,
+            y: self.y - other,
+            z: self.z - other,
+        }
+    }
+}
+
+impl SubAssign<u32> for UVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: u32) {
+        self.x -= other;
+        self.y -= other;
+        self.z -= other;
+    }
+}
+
+impl Sub<UVec3> for u32 {
+    type Output = UVec3;
+    #[inline]
+    fn sub(self, other: UVec3) -> UVec3 {
+        UVec3 {
+            x: self - other.x,
+            y: self - other.y,
+            z: self - other.z,
+        }
+    }
+}
+
+impl Rem<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Self {
+            x: self.x % other.x,
+            y: self.y % other.y,
+            z: self.z % other.z,
+        }
+    }
+}
+
+impl RemAssign<UVec3> for UVec3 {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        self.x %= other.x;
+        self.y %= other.y;
+        self.z %= other.z;
+    }
+}
+
+impl Rem<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn rem(self, other: u32) -> Self {
+        Self {
+            x: self.x % other,
+            y: self.y % other,
+            z: self.z % other,
+        }
+    }
+}
+
+impl RemAssign<u32> for UVec3 {
+    #[inline]
+    fn rem_assign(&mut self, other: u32) {
+        self.x %= other;
+        self.y %= other;
+        self.z %= other;
+    }
+}
+
+impl Rem<UVec3> for u32 {
+    type Output = UVec3;
+    #[inline]
+    fn rem(self, other: UVec3) -> UVec3 {
+        UVec3 {
+            x: self % other.x,
+            y: self % other.y,
+            z: self % other.z,
+        }
+    }
+}
+
+impl Not for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self {
+            x: !self.x,
+            y: !self.y,
+            z: !self.z,
+        }
+    }
+}
+
+impl BitAnd for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            x: self.x & other.x,
+            y: self.y & other.y,
+            z: self.z & other.z,
+        }
+    }
+}
+
+impl BitAnd<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: u32) -> Self {
+        Self {
+            x: self.x & other,
+            y: self.y & other,
+            z: self.z & other,
+        }
+    }
+}
+
+impl BitOr for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            x: self.x | other.x,
+            y: self.y | other.y,
+            z: self.z | other.z,
+        }
+    }
+}
+
+impl BitOr<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: u32) -> Self {
+        Self {
+            x: self.x | other,
+            y: self.y | other,
+            z: self.z | other,
+        }
+    }
+}
+
+impl BitXor for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            x: self.x ^ other.x,
+            y: self.y ^ other.y,
+            z: self.z ^ other.z,
+        }
+    }
+}
+
+impl BitXor<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, other: u32) -> Self {
+        Self {
+            x: self.x ^ other,
+            y: self.y ^ other,
+            z: self.z ^ other,
+        }
+    }
+}
+
+impl Shl<i32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: i32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+            z: self.z << other,
+        }
+    }
+}
+
+impl Shr<i32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: i32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+            z: self.z >> other,
+        }
+    }
+}
+
+impl Shl<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: u32) -> Self {
+        Self {
+            x: self.x << other,
+            y: self.y << other,
+            z: self.z << other,
+        }
+    }
+}
+
+impl Shr<u32> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: u32) -> Self {
+        Self {
+            x: self.x >> other,
+            y: self.y >> other,
+            z: self.z >> other,
+        }
+    }
+}
+
+impl Shl<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shl(self, other: Self) -> Self {
+        Self {
+            x: self.x << other.x,
+            y: self.y << other.y,
+            z: self.z << other.z,
+        }
+    }
+}
+
+impl Shr<UVec3> for UVec3 {
+    type Output = Self;
+    #[inline]
+    fn shr(self, other: Self) -> Self {
+        Self {
+            x: self.x >> other.x,
+            y: self.y >> other.y,
+            z: self.z >> other.z,
+        }
+    }
+}
+
+impl Index<usize> for UVec3 {
+    type Output = u32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for UVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(u32, u32, u32)> for UVec3 {
+    #[inline]
+    fn from(t: (u32, u32, u32)) -> Self {
+        Self::new(t.0, t.1, t.2)
+    }
+}
+
+impl From<UVec3> for (u32, u32, u32) {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl From<[u32; 3]> for UVec3 {
+    #[inline]
+    fn from(a: [u32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<UVec3> for [u32; 3] {
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<UVec3> for UVec2 {
+    /// Creates a `UVec2` from the `x` and `y` elements of the `UVec3`, discarding `z`.
+    #[inline]
+    fn from(v: UVec3) -> Self {
+        UVec2::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Sum<&'a Self> for UVec3 {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ZERO, |a, &b| Self::add(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Product<&'a Self> for UVec3 {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(ONE, |a, &b| Self::mul(a, b))
+    }
+}
@@ -0,0 +1,720 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{UVec2, UVec3, UVec4};
+
+pub trait UVec3Swizzles {
+    fn xxxx(self) -> UVec4;
+    fn xxxy(self) -> UVec4;
+    fn xxxz(self) -> UVec4;
+    fn xxyx(self) -> UVec4;
+    fn xxyy(self) -> UVec4;
+    fn xxyz(self) -> UVec4;
+    fn xxzx(self) -> UVec4;
+    fn xxzy(self) -> UVec4;
+    fn xxzz(self) -> UVec4;
+    fn xyxx(self) -> UVec4;
+    fn xyxy(self) -> UVec4;
+    fn xyxz(self) -> UVec4;
+    fn xyyx(self) -> UVec4;
+    fn xyyy(self) -> UVec4;
+    fn xyyz(self) -> UVec4;
+    fn xyzx(self) -> UVec4;
+    fn xyzy(self) -> UVec4;
+    fn xyzz(self) -> UVec4;
+    fn xzxx(self) -> UVec4;
+    fn xzxy(self) -> UVec4;
+    fn xzxz(self) -> UVec4;
+    fn xzyx(self) -> UVec4;
+    fn xzyy(self) -> UVec4;
+    fn xzyz(self) -> UVec4;
+    fn xzzx(self) -> UVec4;
+    fn xzzy(self) -> UVec4;
+    fn xzzz(self) -> UVec4;
+    fn yxxx(self) -> UVec4;
+    fn yxxy(self) -> UVec4;
+    fn yxxz(self) -> UVec4;
+    fn yxyx(self) -> UVec4;
+    fn yxyy(self) -> UVec4;
+    fn yxyz(self) -> UVec4;
+    fn yxzx(self) -> UVec4;
+    fn yxzy(self) -> UVec4;
+    fn yxzz(self) -> UVec4;
+    fn yyxx(self) -> UVec4;
+    fn yyxy(self) -> UVec4;
+    fn yyxz(self) -> UVec4;
+    fn yyyx(self) -> UVec4;
+    fn yyyy(self) -> UVec4;
+    fn yyyz(self) -> UVec4;
+    fn yyzx(self) -> UVec4;
+    fn yyzy(self) -> UVec4;
+    fn yyzz(self) -> UVec4;
+    fn yzxx(self) -> UVec4;
+    fn yzxy(self) -> UVec4;
+    fn yzxz(self) -> UVec4;
+    fn yzyx(self) -> UVec4;
+    fn yzyy(self) -> UVec4;
+    fn yzyz(self) -> UVec4;
+    fn yzzx(self) -> UVec4;
+    fn yzzy(self) -> UVec4;
+    fn yzzz(self) -> UVec4;
+    fn zxxx(self) -> UVec4;
+    fn zxxy(self) -> UVec4;
+    fn zxxz(self) -> UVec4;
+    fn zxyx(self) -> UVec4;
+    fn zxyy(self) -> UVec4;
+    fn zxyz(self) -> UVec4;
+    fn zxzx(self) -> UVec4;
+    fn zxzy(self) -> UVec4;
+    fn zxzz(self) -> UVec4;
+    fn zyxx(self) -> UVec4;
+    fn zyxy(self) -> UVec4;
+    fn zyxz(self) -> UVec4;
+    fn zyyx(self) -> UVec4;
+    fn zyyy(self) -> UVec4;
+    fn zyyz(self) -> UVec4;
+    fn zyzx(self) -> UVec4;
+    fn zyzy(self) -> UVec4;
+    fn zyzz(self) -> UVec4;
+    fn zzxx(self) -> UVec4;
+    fn zzxy(self) -> UVec4;
+    fn zzxz(self) -> UVec4;
+    fn zzyx(self) -> UVec4;
+    fn zzyy(self) -> UVec4;
+    fn zzyz(self) -> UVec4;
+    fn zzzx(self) -> UVec4;
+    fn zzzy(self) -> UVec4;
+    fn zzzz(self) -> UVec4;
+    fn xxx(self) -> UVec3;
+    fn xxy(self) -> UVec3;
+    fn xxz(self) -> UVec3;
+    fn xyx(self) -> UVec3;
+    fn xyy(self) -> UVec3;
+    fn xzx(self) -> UVec3;
+    fn xzy(self) -> UVec3;
+    fn xzz(self) -> UVec3;
+    fn yxx(self) -> UVec3;
+    fn yxy(self) -> UVec3;
+    fn yxz(self) -> UVec3;
+    fn yyx(self) -> UVec3;
+    fn yyy(self) -> UVec3;
+    fn yyz(self) -> UVec3;
+    fn yzx(self) -> UVec3;
+    fn yzy(self) -> UVec3;
+    fn yzz(self) -> UVec3;
+    fn zxx(self) -> UVec3;
+    fn zxy(self) -> UVec3;
+    fn zxz(self) -> UVec3;
+    fn zyx(self) -> UVec3;
+    fn zyy(self) -> UVec3;
+    fn zyz(self) -> UVec3;
+    fn zzx(self) -> UVec3;
+    fn zzy(self) -> UVec3;
+    fn zzz(self) -> UVec3;
+    fn xx(self) -> UVec2;
+    fn xy(self) -> UVec2;
+    fn xz(self) -> UVec2;
+    fn yx(self) -> UVec2;
+    fn yy(self) -> UVec2;
+    fn yz(self) -> UVec2;
+    fn zx(self) -> UVec2;
+    fn zy(self) -> UVec2;
+    fn zz(self) -> UVec2;
+}
+
+impl UVec3Swizzles for UVec3 {
+    #[inline]
+    fn xxxx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxxz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn xxyx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xxyz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn xxzx(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn xxzy(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn xxzz(self) -> UVec4 {
+        UVec4::new(self.x, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn xyxx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyxz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn xyyx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xyyz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn xyzx(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn xyzy(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn xyzz(self) -> UVec4 {
+        UVec4::new(self.x, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn xzxx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn xzxy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn xzxz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn xzyx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn xzyy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn xzyz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn xzzx(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn xzzy(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn xzzz(self) -> UVec4 {
+        UVec4::new(self.x, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn yxxx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxxz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn yxyx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yxyz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn yxzx(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn yxzy(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn yxzz(self) -> UVec4 {
+        UVec4::new(self.y, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn yyxx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyxz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn yyyx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yyyz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn yyzx(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn yyzy(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn yyzz(self) -> UVec4 {
+        UVec4::new(self.y, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn yzxx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn yzxy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn yzxz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn yzyx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn yzyy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn yzyz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn yzzx(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn yzzy(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn yzzz(self) -> UVec4 {
+        UVec4::new(self.y, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn zxxx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn zxxy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn zxxz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn zxyx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn zxyy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn zxyz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn zxzx(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn zxzy(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn zxzz(self) -> UVec4 {
+        UVec4::new(self.z, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn zyxx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn zyxy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn zyxz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn zyyx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn zyyy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn zyyz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn zyzx(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn zyzy(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn zyzz(self) -> UVec4 {
+        UVec4::new(self.z, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn zzxx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn zzxy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn zzxz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn zzyx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn zzyy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn zzyz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn zzzx(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn zzzy(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn zzzz(self) -> UVec4 {
+        UVec4::new(self.z, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn xxx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xxz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xzy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yzx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yzy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yzz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zxx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zxy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zxz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zyx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zyy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zyz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zzx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zzy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zzz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xx(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn xy(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn xz(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn yx(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn yz(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn zx(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn zy(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn zz(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+}
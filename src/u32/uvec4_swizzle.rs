@@ -0,0 +1,5308 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::{UVec2, UVec3, UVec4};
+
+#[cfg(all(vec4_sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4_sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+
+pub trait UVec4Swizzles {
+    fn xxxx(self) -> UVec4;
+    fn xxxy(self) -> UVec4;
+    fn xxxz(self) -> UVec4;
+    fn xxxw(self) -> UVec4;
+    fn xxyx(self) -> UVec4;
+    fn xxyy(self) -> UVec4;
+    fn xxyz(self) -> UVec4;
+    fn xxyw(self) -> UVec4;
+    fn xxzx(self) -> UVec4;
+    fn xxzy(self) -> UVec4;
+    fn xxzz(self) -> UVec4;
+    fn xxzw(self) -> UVec4;
+    fn xxwx(self) -> UVec4;
+    fn xxwy(self) -> UVec4;
+    fn xxwz(self) -> UVec4;
+    fn xxww(self) -> UVec4;
+    fn xyxx(self) -> UVec4;
+    fn xyxy(self) -> UVec4;
+    fn xyxz(self) -> UVec4;
+    fn xyxw(self) -> UVec4;
+    fn xyyx(self) -> UVec4;
+    fn xyyy(self) -> UVec4;
+    fn xyyz(self) -> UVec4;
+    fn xyyw(self) -> UVec4;
+    fn xyzx(self) -> UVec4;
+    fn xyzy(self) -> UVec4;
+    fn xyzz(self) -> UVec4;
+    fn xywx(self) -> UVec4;
+    fn xywy(self) -> UVec4;
+    fn xywz(self) -> UVec4;
+    fn xyww(self) -> UVec4;
+    fn xzxx(self) -> UVec4;
+    fn xzxy(self) -> UVec4;
+    fn xzxz(self) -> UVec4;
+    fn xzxw(self) -> UVec4;
+    fn xzyx(self) -> UVec4;
+    fn xzyy(self) -> UVec4;
+    fn xzyz(self) -> UVec4;
+    fn xzyw(self) -> UVec4;
+    fn xzzx(self) -> UVec4;
+    fn xzzy(self) -> UVec4;
+    fn xzzz(self) -> UVec4;
+    fn xzzw(self) -> UVec4;
+    fn xzwx(self) -> UVec4;
+    fn xzwy(self) -> UVec4;
+    fn xzwz(self) -> UVec4;
+    fn xzww(self) -> UVec4;
+    fn xwxx(self) -> UVec4;
+    fn xwxy(self) -> UVec4;
+    fn xwxz(self) -> UVec4;
+    fn xwxw(self) -> UVec4;
+    fn xwyx(self) -> UVec4;
+    fn xwyy(self) -> UVec4;
+    fn xwyz(self) -> UVec4;
+    fn xwyw(self) -> UVec4;
+    fn xwzx(self) -> UVec4;
+    fn xwzy(self) -> UVec4;
+    fn xwzz(self) -> UVec4;
+    fn xwzw(self) -> UVec4;
+    fn xwwx(self) -> UVec4;
+    fn xwwy(self) -> UVec4;
+    fn xwwz(self) -> UVec4;
+    fn xwww(self) -> UVec4;
+    fn yxxx(self) -> UVec4;
+    fn yxxy(self) -> UVec4;
+    fn yxxz(self) -> UVec4;
+    fn yxxw(self) -> UVec4;
+    fn yxyx(self) -> UVec4;
+    fn yxyy(self) -> UVec4;
+    fn yxyz(self) -> UVec4;
+    fn yxyw(self) -> UVec4;
+    fn yxzx(self) -> UVec4;
+    fn yxzy(self) -> UVec4;
+    fn yxzz(self) -> UVec4;
+    fn yxzw(self) -> UVec4;
+    fn yxwx(self) -> UVec4;
+    fn yxwy(self) -> UVec4;
+    fn yxwz(self) -> UVec4;
+    fn yxww(self) -> UVec4;
+    fn yyxx(self) -> UVec4;
+    fn yyxy(self) -> UVec4;
+    fn yyxz(self) -> UVec4;
+    fn yyxw(self) -> UVec4;
+    fn yyyx(self) -> UVec4;
+    fn yyyy(self) -> UVec4;
+    fn yyyz(self) -> UVec4;
+    fn yyyw(self) -> UVec4;
+    fn yyzx(self) -> UVec4;
+    fn yyzy(self) -> UVec4;
+    fn yyzz(self) -> UVec4;
+    fn yyzw(self) -> UVec4;
+    fn yywx(self) -> UVec4;
+    fn yywy(self) -> UVec4;
+    fn yywz(self) -> UVec4;
+    fn yyww(self) -> UVec4;
+    fn yzxx(self) -> UVec4;
+    fn yzxy(self) -> UVec4;
+    fn yzxz(self) -> UVec4;
+    fn yzxw(self) -> UVec4;
+    fn yzyx(self) -> UVec4;
+    fn yzyy(self) -> UVec4;
+    fn yzyz(self) -> UVec4;
+    fn yzyw(self) -> UVec4;
+    fn yzzx(self) -> UVec4;
+    fn yzzy(self) -> UVec4;
+    fn yzzz(self) -> UVec4;
+    fn yzzw(self) -> UVec4;
+    fn yzwx(self) -> UVec4;
+    fn yzwy(self) -> UVec4;
+    fn yzwz(self) -> UVec4;
+    fn yzww(self) -> UVec4;
+    fn ywxx(self) -> UVec4;
+    fn ywxy(self) -> UVec4;
+    fn ywxz(self) -> UVec4;
+    fn ywxw(self) -> UVec4;
+    fn ywyx(self) -> UVec4;
+    fn ywyy(self) -> UVec4;
+    fn ywyz(self) -> UVec4;
+    fn ywyw(self) -> UVec4;
+    fn ywzx(self) -> UVec4;
+    fn ywzy(self) -> UVec4;
+    fn ywzz(self) -> UVec4;
+    fn ywzw(self) -> UVec4;
+    fn ywwx(self) -> UVec4;
+    fn ywwy(self) -> UVec4;
+    fn ywwz(self) -> UVec4;
+    fn ywww(self) -> UVec4;
+    fn zxxx(self) -> UVec4;
+    fn zxxy(self) -> UVec4;
+    fn zxxz(self) -> UVec4;
+    fn zxxw(self) -> UVec4;
+    fn zxyx(self) -> UVec4;
+    fn zxyy(self) -> UVec4;
+    fn zxyz(self) -> UVec4;
+    fn zxyw(self) -> UVec4;
+    fn zxzx(self) -> UVec4;
+    fn zxzy(self) -> UVec4;
+    fn zxzz(self) -> UVec4;
+    fn zxzw(self) -> UVec4;
+    fn zxwx(self) -> UVec4;
+    fn zxwy(self) -> UVec4;
+    fn zxwz(self) -> UVec4;
+    fn zxww(self) -> UVec4;
+    fn zyxx(self) -> UVec4;
+    fn zyxy(self) -> UVec4;
+    fn zyxz(self) -> UVec4;
+    fn zyxw(self) -> UVec4;
+    fn zyyx(self) -> UVec4;
+    fn zyyy(self) -> UVec4;
+    fn zyyz(self) -> UVec4;
+    fn zyyw(self) -> UVec4;
+    fn zyzx(self) -> UVec4;
+    fn zyzy(self) -> UVec4;
+    fn zyzz(self) -> UVec4;
+    fn zyzw(self) -> UVec4;
+    fn zywx(self) -> UVec4;
+    fn zywy(self) -> UVec4;
+    fn zywz(self) -> UVec4;
+    fn zyww(self) -> UVec4;
+    fn zzxx(self) -> UVec4;
+    fn zzxy(self) -> UVec4;
+    fn zzxz(self) -> UVec4;
+    fn zzxw(self) -> UVec4;
+    fn zzyx(self) -> UVec4;
+    fn zzyy(self) -> UVec4;
+    fn zzyz(self) -> UVec4;
+    fn zzyw(self) -> UVec4;
+    fn zzzx(self) -> UVec4;
+    fn zzzy(self) -> UVec4;
+    fn zzzz(self) -> UVec4;
+    fn zzzw(self) -> UVec4;
+    fn zzwx(self) -> UVec4;
+    fn zzwy(self) -> UVec4;
+    fn zzwz(self) -> UVec4;
+    fn zzww(self) -> UVec4;
+    fn zwxx(self) -> UVec4;
+    fn zwxy(self) -> UVec4;
+    fn zwxz(self) -> UVec4;
+    fn zwxw(self) -> UVec4;
+    fn zwyx(self) -> UVec4;
+    fn zwyy(self) -> UVec4;
+    fn zwyz(self) -> UVec4;
+    fn zwyw(self) -> UVec4;
+    fn zwzx(self) -> UVec4;
+    fn zwzy(self) -> UVec4;
+    fn zwzz(self) -> UVec4;
+    fn zwzw(self) -> UVec4;
+    fn zwwx(self) -> UVec4;
+    fn zwwy(self) -> UVec4;
+    fn zwwz(self) -> UVec4;
+    fn zwww(self) -> UVec4;
+    fn wxxx(self) -> UVec4;
+    fn wxxy(self) -> UVec4;
+    fn wxxz(self) -> UVec4;
+    fn wxxw(self) -> UVec4;
+    fn wxyx(self) -> UVec4;
+    fn wxyy(self) -> UVec4;
+    fn wxyz(self) -> UVec4;
+    fn wxyw(self) -> UVec4;
+    fn wxzx(self) -> UVec4;
+    fn wxzy(self) -> UVec4;
+    fn wxzz(self) -> UVec4;
+    fn wxzw(self) -> UVec4;
+    fn wxwx(self) -> UVec4;
+    fn wxwy(self) -> UVec4;
+    fn wxwz(self) -> UVec4;
+    fn wxww(self) -> UVec4;
+    fn wyxx(self) -> UVec4;
+    fn wyxy(self) -> UVec4;
+    fn wyxz(self) -> UVec4;
+    fn wyxw(self) -> UVec4;
+    fn wyyx(self) -> UVec4;
+    fn wyyy(self) -> UVec4;
+    fn wyyz(self) -> UVec4;
+    fn wyyw(self) -> UVec4;
+    fn wyzx(self) -> UVec4;
+    fn wyzy(self) -> UVec4;
+    fn wyzz(self) -> UVec4;
+    fn wyzw(self) -> UVec4;
+    fn wywx(self) -> UVec4;
+    fn wywy(self) -> UVec4;
+    fn wywz(self) -> UVec4;
+    fn wyww(self) -> UVec4;
+    fn wzxx(self) -> UVec4;
+    fn wzxy(self) -> UVec4;
+    fn wzxz(self) -> UVec4;
+    fn wzxw(self) -> UVec4;
+    fn wzyx(self) -> UVec4;
+    fn wzyy(self) -> UVec4;
+    fn wzyz(self) -> UVec4;
+    fn wzyw(self) -> UVec4;
+    fn wzzx(self) -> UVec4;
+    fn wzzy(self) -> UVec4;
+    fn wzzz(self) -> UVec4;
+    fn wzzw(self) -> UVec4;
+    fn wzwx(self) -> UVec4;
+    fn wzwy(self) -> UVec4;
+    fn wzwz(self) -> UVec4;
+    fn wzww(self) -> UVec4;
+    fn wwxx(self) -> UVec4;
+    fn wwxy(self) -> UVec4;
+    fn wwxz(self) -> UVec4;
+    fn wwxw(self) -> UVec4;
+    fn wwyx(self) -> UVec4;
+    fn wwyy(self) -> UVec4;
+    fn wwyz(self) -> UVec4;
+    fn wwyw(self) -> UVec4;
+    fn wwzx(self) -> UVec4;
+    fn wwzy(self) -> UVec4;
+    fn wwzz(self) -> UVec4;
+    fn wwzw(self) -> UVec4;
+    fn wwwx(self) -> UVec4;
+    fn wwwy(self) -> UVec4;
+    fn wwwz(self) -> UVec4;
+    fn wwww(self) -> UVec4;
+    fn xxx(self) -> UVec3;
+    fn xxy(self) -> UVec3;
+    fn xxz(self) -> UVec3;
+    fn xxw(self) -> UVec3;
+    fn xyx(self) -> UVec3;
+    fn xyy(self) -> UVec3;
+    fn xyz(self) -> UVec3;
+    fn xyw(self) -> UVec3;
+    fn xzx(self) -> UVec3;
+    fn xzy(self) -> UVec3;
+    fn xzz(self) -> UVec3;
+    fn xzw(self) -> UVec3;
+    fn xwx(self) -> UVec3;
+    fn xwy(self) -> UVec3;
+    fn xwz(self) -> UVec3;
+    fn xww(self) -> UVec3;
+    fn yxx(self) -> UVec3;
+    fn yxy(self) -> UVec3;
+    fn yxz(self) -> UVec3;
+    fn yxw(self) -> UVec3;
+    fn yyx(self) -> UVec3;
+    fn yyy(self) -> UVec3;
+    fn yyz(self) -> UVec3;
+    fn yyw(self) -> UVec3;
+    fn yzx(self) -> UVec3;
+    fn yzy(self) -> UVec3;
+    fn yzz(self) -> UVec3;
+    fn yzw(self) -> UVec3;
+    fn ywx(self) -> UVec3;
+    fn ywy(self) -> UVec3;
+    fn ywz(self) -> UVec3;
+    fn yww(self) -> UVec3;
+    fn zxx(self) -> UVec3;
+    fn zxy(self) -> UVec3;
+    fn zxz(self) -> UVec3;
+    fn zxw(self) -> UVec3;
+    fn zyx(self) -> UVec3;
+    fn zyy(self) -> UVec3;
+    fn zyz(self) -> UVec3;
+    fn zyw(self) -> UVec3;
+    fn zzx(self) -> UVec3;
+    fn zzy(self) -> UVec3;
+    fn zzz(self) -> UVec3;
+    fn zzw(self) -> UVec3;
+    fn zwx(self) -> UVec3;
+    fn zwy(self) -> UVec3;
+    fn zwz(self) -> UVec3;
+    fn zww(self) -> UVec3;
+    fn wxx(self) -> UVec3;
+    fn wxy(self) -> UVec3;
+    fn wxz(self) -> UVec3;
+    fn wxw(self) -> UVec3;
+    fn wyx(self) -> UVec3;
+    fn wyy(self) -> UVec3;
+    fn wyz(self) -> UVec3;
+    fn wyw(self) -> UVec3;
+    fn wzx(self) -> UVec3;
+    fn wzy(self) -> UVec3;
+    fn wzz(self) -> UVec3;
+    fn wzw(self) -> UVec3;
+    fn wwx(self) -> UVec3;
+    fn wwy(self) -> UVec3;
+    fn wwz(self) -> UVec3;
+    fn www(self) -> UVec3;
+    fn xx(self) -> UVec2;
+    fn xy(self) -> UVec2;
+    fn xz(self) -> UVec2;
+    fn xw(self) -> UVec2;
+    fn yx(self) -> UVec2;
+    fn yy(self) -> UVec2;
+    fn yz(self) -> UVec2;
+    fn yw(self) -> UVec2;
+    fn zx(self) -> UVec2;
+    fn zy(self) -> UVec2;
+    fn zz(self) -> UVec2;
+    fn zw(self) -> UVec2;
+    fn wx(self) -> UVec2;
+    fn wy(self) -> UVec2;
+    fn wz(self) -> UVec2;
+    fn ww(self) -> UVec2;
+}
+
+impl UVec4Swizzles for UVec4 {
+    #[inline]
+    fn xxxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xxxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xxxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xxxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xxyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xxyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xxyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xxyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xxzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xxzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xxzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xxzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xxwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xxwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xxwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xxww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_00_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.x,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xyxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xyxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xyxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xyxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xyyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xyyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xyyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xyyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xyzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xyzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xyzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xywx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xywy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xywz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xyww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_01_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.y,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xzxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xzxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xzxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xzxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xzyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xzyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xzyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xzyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xzzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xzzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xzzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xzzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xzwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xzwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xzwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xzww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_10_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.z,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xwxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xwxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xwxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xwxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xwyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xwyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xwyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xwyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xwzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xwzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xwzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xwzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xwwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn xwwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn xwwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn xwww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_11_00))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.x,
+                y: self.w,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yxxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yxxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yxxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yxxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yxyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yxyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yxyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yxyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yxzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yxzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yxzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yxzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yxwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yxwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yxwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yxww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_00_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.x,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yyxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yyxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yyxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yyxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yyyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yyyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yyyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yyyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yyzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yyzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yyzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yyzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yywx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yywy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yywz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yyww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_01_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.y,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yzxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yzxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yzxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yzxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yzyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yzyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yzyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yzyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yzzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yzzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yzzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yzzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn yzwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn yzwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn yzwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn yzww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_10_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.z,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn ywxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn ywxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn ywxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn ywxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn ywyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn ywyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn ywyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn ywyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn ywzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn ywzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn ywzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn ywzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn ywwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn ywwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn ywwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn ywww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_11_01))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.y,
+                y: self.w,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zxxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zxxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zxxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zxxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zxyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zxyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zxyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zxyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zxzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zxzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zxzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zxzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zxwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zxwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zxwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zxww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_00_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.x,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zyxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zyxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zyxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zyxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zyyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zyyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zyyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zyyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zyzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zyzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zyzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zyzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zywx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zywy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zywz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zyww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_01_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.y,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zzxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zzxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zzxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zzxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zzyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zzyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zzyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zzyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zzzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zzzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zzzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zzzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zzwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zzwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zzwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zzww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_10_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.z,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zwxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zwxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zwxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zwxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zwyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zwyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zwyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zwyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zwzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zwzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zwzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zwzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn zwwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn zwwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn zwwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn zwww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_11_10))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.z,
+                y: self.w,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wxxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wxxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wxxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wxxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wxyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wxyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wxyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wxyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wxzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wxzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wxzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wxzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wxwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wxwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wxwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wxww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_00_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.x,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wyxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wyxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wyxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wyxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wyyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wyyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wyyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wyyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wyzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wyzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wyzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wyzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wywx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wywy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wywz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wyww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_01_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.y,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wzxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wzxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wzxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wzxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wzyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wzyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wzyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wzyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wzzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wzzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wzzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wzzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wzwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wzwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wzwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wzww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_10_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.z,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wwxx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_00_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.x,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wwxy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_00_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.x,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wwxz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_00_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.x,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wwxw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_00_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.x,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wwyx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_01_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.y,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wwyy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_01_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.y,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wwyz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_01_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.y,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wwyw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_01_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.y,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wwzx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_10_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.z,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wwzy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_10_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.z,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wwzz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_10_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.z,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wwzw(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_10_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.z,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn wwwx(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b00_11_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.w,
+                w: self.x,
+            }
+        }
+    }
+    #[inline]
+    fn wwwy(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b01_11_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.w,
+                w: self.y,
+            }
+        }
+    }
+    #[inline]
+    fn wwwz(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b10_11_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.w,
+                w: self.z,
+            }
+        }
+    }
+    #[inline]
+    fn wwww(self) -> UVec4 {
+        #[cfg(vec4_sse2)]
+        unsafe {
+            UVec4(_mm_shuffle_epi32(self.0, 0b11_11_11_11))
+        }
+
+        #[cfg(vec4_f32)]
+        {
+            UVec4 {
+                x: self.w,
+                y: self.w,
+                z: self.w,
+                w: self.w,
+            }
+        }
+    }
+    #[inline]
+    fn xxx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xxy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xxz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xxw(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xyx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xyy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xyz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xyw(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xzx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xzy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xzz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xzw(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xwx(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn xwy(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn xwz(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn xww(self) -> UVec3 {
+        UVec3 {
+            x: self.x,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yxx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yxy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yxz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yxw(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yyx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yyy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yyz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yyw(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn yzx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn yzy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn yzz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yzw(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn ywx(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn ywy(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn ywz(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn yww(self) -> UVec3 {
+        UVec3 {
+            x: self.y,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zxx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zxy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zxz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zxw(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zyx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zyy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zyz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zyw(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zzx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zzy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zzz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zzw(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn zwx(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn zwy(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn zwz(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn zww(self) -> UVec3 {
+        UVec3 {
+            x: self.z,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wxx(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wxy(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wxz(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wxw(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.x,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wyx(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wyy(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wyz(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wyw(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.y,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wzx(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wzy(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wzz(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn wzw(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.z,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn wwx(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.x,
+        }
+    }
+    #[inline]
+    fn wwy(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.y,
+        }
+    }
+    #[inline]
+    fn wwz(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.z,
+        }
+    }
+    #[inline]
+    fn www(self) -> UVec3 {
+        UVec3 {
+            x: self.w,
+            y: self.w,
+            z: self.w,
+        }
+    }
+    #[inline]
+    fn xx(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn xy(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn xz(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn xw(self) -> UVec2 {
+        UVec2 {
+            x: self.x,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn yx(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn yy(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn yz(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn yw(self) -> UVec2 {
+        UVec2 {
+            x: self.y,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn zx(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn zy(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn zz(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn zw(self) -> UVec2 {
+        UVec2 {
+            x: self.z,
+            y: self.w,
+        }
+    }
+    #[inline]
+    fn wx(self) -> UVec2 {
+        UVec2 {
+            x: self.w,
+            y: self.x,
+        }
+    }
+    #[inline]
+    fn wy(self) -> UVec2 {
+        UVec2 {
+            x: self.w,
+            y: self.y,
+        }
+    }
+    #[inline]
+    fn wz(self) -> UVec2 {
+        UVec2 {
+            x: self.w,
+            y: self.z,
+        }
+    }
+    #[inline]
+    fn ww(self) -> UVec2 {
+        UVec2 {
+            x: self.w,
+            y: self.w,
+        }
+    }
+}
@@ -4,10 +4,20 @@ use std::io::{Result, Write};
 const E: [char; 4] = ['x', 'y', 'z', 'w']; // element name
 const B: [&str; 4] = ["00", "01", "10", "11"]; // shuffle bits
 const V: [&str; 4] = ["1.0", "2.0", "3.0", "4.0"]; //element value
+const VI: [&str; 4] = ["1", "2", "3", "4"]; // integer element value
 const VEC4: &str = "Vec4";
 const VEC3A: &str = "Vec3A";
 const VEC3: &str = "Vec3";
 const VEC2: &str = "Vec2";
+const DVEC4: &str = "DVec4";
+const DVEC3: &str = "DVec3";
+const DVEC2: &str = "DVec2";
+const IVEC4: &str = "IVec4";
+const IVEC3: &str = "IVec3";
+const IVEC2: &str = "IVec2";
+const UVEC4: &str = "UVec4";
+const UVEC3: &str = "UVec3";
+const UVEC2: &str = "UVec2";
 
 fn write_swizzle_head(out: &mut impl Write) -> Result<()> {
     writeln!(out, "// Generated by swizzlegen. Do not edit.")?;
@@ -91,8 +101,8 @@ pub trait {}Swizzles {{"#,
             write!(
                 out,
                 r#"
-    fn {}{}{}{}(self) -> Vec4;"#,
-                E[e0], E[e1], E[e2], E[e3]
+    fn {}{}{}{}(self) -> {};"#,
+                E[e0], E[e1], E[e2], E[e3], vec4t
             )
         },
         |out, e0, e1, e2| {
@@ -107,8 +117,8 @@ pub trait {}Swizzles {{"#,
             write!(
                 out,
                 r#"
-    fn {}{}(self) -> Vec2;"#,
-                E[e0], E[e1]
+    fn {}{}(self) -> {};"#,
+                E[e0], E[e1], vec2t
             )
         },
     )?;
@@ -318,22 +328,29 @@ impl Vec3ASwizzles for Vec3A {{"#
     Ok(())
 }
 
-fn write_swizzle_vec3(out: &mut impl Write) -> Result<()> {
-    const SIZE: usize = 3;
+fn write_swizzle_vec4i(out: &mut impl Write, vec4t: &str, vec3t: &str, vec2t: &str) -> Result<()> {
+    const SIZE: usize = 4;
 
     write!(
         out,
         r#"
-use super::{{Vec2, Vec3, Vec4}};
-"#
+use super::{{{}, {}, {}}};
+
+#[cfg(all(vec4_sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4_sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+"#,
+        vec2t, vec3t, vec4t
     )?;
 
-    write_swizzle_trait(out, SIZE, VEC4, VEC3, VEC2)?;
+    write_swizzle_trait(out, SIZE, vec4t, vec3t, vec2t)?;
 
     write!(
         out,
         r#"
-impl Vec3Swizzles for Vec3 {{"#
+impl {}Swizzles for {} {{"#,
+        vec4t, vec4t
     )?;
 
     write_loops(
@@ -344,10 +361,37 @@ impl Vec3Swizzles for Vec3 {{"#
                 out,
                 r#"
     #[inline]
-    fn {}{}{}{}(self) -> Vec4 {{
-        Vec4::new(self.{}, self.{}, self.{}, self.{})
+    fn {}{}{}{}(self) -> {} {{
+        #[cfg(vec4_sse2)]
+        unsafe {{
+            {}(_mm_shuffle_epi32(self.0, 0b{}_{}_{}_{}))
+        }}
+
+        #[cfg(vec4_f32)]
+        {{
+            {} {{
+                x: self.{},
+                y: self.{},
+                z: self.{},
+                w: self.{},
+            }}
+        }}
     }}"#,
-                E[e0], E[e1], E[e2], E[e3], E[e0], E[e1], E[e2], E[e3],
+                E[e0],
+                E[e1],
+                E[e2],
+                E[e3],
+                vec4t,
+                vec4t,
+                B[e3],
+                B[e2],
+                B[e1],
+                B[e0],
+                vec4t,
+                E[e0],
+                E[e1],
+                E[e2],
+                E[e3],
             )
         },
         |out, e0, e1, e2| {
@@ -355,14 +399,14 @@ impl Vec3Swizzles for Vec3 {{"#
                 out,
                 r#"
     #[inline]
-    fn {}{}{}(self) -> Vec3 {{
-        Vec3 {{
+    fn {}{}{}(self) -> {} {{
+        {} {{
             x: self.{},
             y: self.{},
             z: self.{},
         }}
     }}"#,
-                E[e0], E[e1], E[e2], E[e0], E[e1], E[e2]
+                E[e0], E[e1], E[e2], vec3t, vec3t, E[e0], E[e1], E[e2]
             )
         },
         |out, e0, e1| {
@@ -370,13 +414,13 @@ impl Vec3Swizzles for Vec3 {{"#
                 out,
                 r#"
     #[inline]
-    fn {}{}(self) -> Vec2 {{
-        Vec2 {{
+    fn {}{}(self) -> {} {{
+        {} {{
             x: self.{},
             y: self.{},
         }}
     }}"#,
-                E[e0], E[e1], E[e0], E[e1]
+                E[e0], E[e1], vec2t, vec2t, E[e0], E[e1]
             )
         },
     )?;
@@ -386,36 +430,49 @@ impl Vec3Swizzles for Vec3 {{"#
     Ok(())
 }
 
-fn write_swizzle_vec2(out: &mut impl Write) -> Result<()> {
-    const SIZE: usize = 2;
+fn write_swizzle_scalar(
+    out: &mut impl Write,
+    size: usize,
+    vec4t: &str,
+    vec3t: &str,
+    vec2t: &str,
+) -> Result<()> {
+    let t = match size {
+        4 => vec4t,
+        3 => vec3t,
+        2 => vec2t,
+        _ => unreachable!(),
+    };
 
     write!(
         out,
         r#"
-use super::{{Vec2, Vec3, Vec4}};
-"#
+use super::{{{}, {}, {}}};
+"#,
+        vec2t, vec3t, vec4t
     )?;
 
-    write_swizzle_trait(out, SIZE, VEC4, VEC3, VEC2)?;
+    write_swizzle_trait(out, size, vec4t, vec3t, vec2t)?;
 
     write!(
         out,
         r#"
-impl Vec2Swizzles for Vec2 {{"#
+impl {}Swizzles for {} {{"#,
+        t, t
     )?;
 
     write_loops(
         out,
-        SIZE,
+        size,
         |out, e0, e1, e2, e3| {
             write!(
                 out,
                 r#"
     #[inline]
-    fn {}{}{}{}(self) -> Vec4 {{
-        Vec4::new(self.{}, self.{}, self.{}, self.{})
+    fn {}{}{}{}(self) -> {} {{
+        {}::new(self.{}, self.{}, self.{}, self.{})
     }}"#,
-                E[e0], E[e1], E[e2], E[e3], E[e0], E[e1], E[e2], E[e3],
+                E[e0], E[e1], E[e2], E[e3], vec4t, vec4t, E[e0], E[e1], E[e2], E[e3],
             )
         },
         |out, e0, e1, e2| {
@@ -423,14 +480,14 @@ impl Vec2Swizzles for Vec2 {{"#
                 out,
                 r#"
     #[inline]
-    fn {}{}{}(self) -> Vec3 {{
-        Vec3 {{
+    fn {}{}{}(self) -> {} {{
+        {} {{
             x: self.{},
             y: self.{},
             z: self.{},
         }}
     }}"#,
-                E[e0], E[e1], E[e2], E[e0], E[e1], E[e2]
+                E[e0], E[e1], E[e2], vec3t, vec3t, E[e0], E[e1], E[e2]
             )
         },
         |out, e0, e1| {
@@ -438,13 +495,13 @@ impl Vec2Swizzles for Vec2 {{"#
                 out,
                 r#"
     #[inline]
-    fn {}{}(self) -> Vec2 {{
-        Vec2 {{
+    fn {}{}(self) -> {} {{
+        {} {{
             x: self.{},
             y: self.{},
         }}
     }}"#,
-                E[e0], E[e1], E[e0], E[e1]
+                E[e0], E[e1], vec2t, vec2t, E[e0], E[e1]
             )
         },
     )?;
@@ -465,11 +522,47 @@ fn write_src() -> Result<()> {
 
     let mut out = File::create("../src/f32/vec3_swizzle.rs")?;
     write_swizzle_head(&mut out)?;
-    write_swizzle_vec3(&mut out)?;
+    write_swizzle_scalar(&mut out, 3, VEC4, VEC3, VEC2)?;
 
     let mut out = File::create("../src/f32/vec2_swizzle.rs")?;
     write_swizzle_head(&mut out)?;
-    write_swizzle_vec2(&mut out)?;
+    write_swizzle_scalar(&mut out, 2, VEC4, VEC3, VEC2)?;
+
+    let mut out = File::create("../src/f64/dvec4_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 4, DVEC4, DVEC3, DVEC2)?;
+
+    let mut out = File::create("../src/f64/dvec3_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 3, DVEC4, DVEC3, DVEC2)?;
+
+    let mut out = File::create("../src/f64/dvec2_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 2, DVEC4, DVEC3, DVEC2)?;
+
+    let mut out = File::create("../src/i32/ivec4_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_vec4i(&mut out, IVEC4, IVEC3, IVEC2)?;
+
+    let mut out = File::create("../src/i32/ivec3_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 3, IVEC4, IVEC3, IVEC2)?;
+
+    let mut out = File::create("../src/i32/ivec2_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 2, IVEC4, IVEC3, IVEC2)?;
+
+    let mut out = File::create("../src/u32/uvec4_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_vec4i(&mut out, UVEC4, UVEC3, UVEC2)?;
+
+    let mut out = File::create("../src/u32/uvec3_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 3, UVEC4, UVEC3, UVEC2)?;
+
+    let mut out = File::create("../src/u32/uvec2_swizzle.rs")?;
+    write_swizzle_head(&mut out)?;
+    write_swizzle_scalar(&mut out, 2, UVEC4, UVEC3, UVEC2)?;
 
     Ok(())
 }
@@ -478,32 +571,50 @@ fn write_test_head(out: &mut impl Write) -> Result<()> {
     write!(
         out,
         r#"// Generated by swizzles. Do not edit.
-use glam::{{swizzles::*, vec2, vec3, vec3a, vec4}};
+use glam::{{
+    dvec2, dvec3, dvec4, ivec2, ivec3, ivec4, swizzles::*, uvec2, uvec3, uvec4, vec2, vec3, vec3a,
+    vec4,
+}};
 "#
     )?;
     Ok(())
 }
 
-fn write_test_vec4(out: &mut impl Write) -> Result<()> {
+fn write_test_vec4(
+    out: &mut impl Write,
+    vec4t: &str,
+    vec3t: &str,
+    vec2t: &str,
+    v: &[&str; 4],
+) -> Result<()> {
     const SIZE: usize = 4;
 
     write!(
         out,
         r#"
 #[test]
-fn test_vec4_swizzles() {{
-    let v = vec4(1.0, 2.0, 3.0, 4.0);
-"#
+fn test_{}_swizzles() {{
+    let v = {}({});
+"#,
+        vec4t,
+        vec4t,
+        v[..SIZE].join(", "),
     )?;
 
-    write_test_loops(out, SIZE, "vec3")?;
+    write_test_loops(out, SIZE, vec4t, vec3t, vec2t, v)?;
 
     writeln!(out, "}}")?;
 
     Ok(())
 }
 
-fn write_test_vec3(out: &mut impl Write, vec3t: &str) -> Result<()> {
+fn write_test_vec3(
+    out: &mut impl Write,
+    vec4t: &str,
+    vec3t: &str,
+    vec2t: &str,
+    v: &[&str; 4],
+) -> Result<()> {
     const SIZE: usize = 3;
 
     write!(
@@ -511,60 +622,78 @@ fn write_test_vec3(out: &mut impl Write, vec3t: &str) -> Result<()> {
         r#"
 #[test]
 fn test_{}_swizzles() {{
-    let v = {}(1.0, 2.0, 3.0);
+    let v = {}({});
 "#,
-        vec3t, vec3t,
+        vec3t,
+        vec3t,
+        v[..SIZE].join(", "),
     )?;
 
-    write_test_loops(out, SIZE, vec3t)?;
+    write_test_loops(out, SIZE, vec4t, vec3t, vec2t, v)?;
 
     writeln!(out, "}}")?;
 
     Ok(())
 }
 
-fn write_test_vec2(out: &mut impl Write) -> Result<()> {
+fn write_test_vec2(
+    out: &mut impl Write,
+    vec4t: &str,
+    vec3t: &str,
+    vec2t: &str,
+    v: &[&str; 4],
+) -> Result<()> {
     const SIZE: usize = 2;
 
     write!(
         out,
         r#"
 #[test]
-fn test_vec2_swizzles() {{
-    let v = vec2(1.0, 2.0);
-"#
+fn test_{}_swizzles() {{
+    let v = {}({});
+"#,
+        vec2t,
+        vec2t,
+        v[..SIZE].join(", "),
     )?;
 
-    write_test_loops(out, SIZE, "vec3")?;
+    write_test_loops(out, SIZE, vec4t, vec3t, vec2t, v)?;
 
     writeln!(out, "}}")?;
 
     Ok(())
 }
 
-fn write_test_loops(out: &mut impl Write, size: usize, vec3t: &str) -> Result<()> {
+fn write_test_loops(
+    out: &mut impl Write,
+    size: usize,
+    vec4t: &str,
+    vec3t: &str,
+    vec2t: &str,
+    v: &[&str; 4],
+) -> Result<()> {
     write_loops(
         out,
         size,
         |out, e0, e1, e2, e3| {
             writeln!(
                 out,
-                "    assert_eq!(v.{}{}{}{}(), vec4({}, {}, {}, {}));",
-                E[e0], E[e1], E[e2], E[e3], V[e0], V[e1], V[e2], V[e3]
+                "    assert_eq!(v.{}{}{}{}(), {}({}, {}, {}, {}));",
+                E[e0], E[e1], E[e2], E[e3], vec4t, v[e0], v[e1], v[e2], v[e3]
             )
         },
         |out, e0, e1, e2| {
             writeln!(
                 out,
                 "    assert_eq!(v.{}{}{}(), {}({}, {}, {}));",
-                E[e0], E[e1], E[e2], vec3t, V[e0], V[e1], V[e2]
+                E[e0], E[e1], E[e2], vec3t, v[e0], v[e1], v[e2]
             )
         },
         |out, e0, e1| {
             writeln!(
                 out,
-                "    assert_eq!(v.{}{}(), vec2({}, {}));",
-                E[e0], E[e1], V[e0], V[e1]
+                "    assert_eq!(v.{}{}(), {}({}, {}));",
+                E[e0], E[e1], vec2t, v[e0], v[e1]
             )
         },
     )
@@ -573,10 +702,19 @@ fn write_test_loops(out: &mut impl Write, size: usize, vec3t: &str) -> Result<()
 fn write_test() -> Result<()> {
     let mut out = File::create("../tests/swizzle.rs")?;
     write_test_head(&mut out)?;
-    write_test_vec4(&mut out)?;
-    write_test_vec3(&mut out, "vec3a")?;
-    write_test_vec3(&mut out, "vec3")?;
-    write_test_vec2(&mut out)?;
+    write_test_vec4(&mut out, "vec4", "vec3", "vec2", &V)?;
+    write_test_vec3(&mut out, "vec4", "vec3a", "vec2", &V)?;
+    write_test_vec3(&mut out, "vec4", "vec3", "vec2", &V)?;
+    write_test_vec2(&mut out, "vec4", "vec3", "vec2", &V)?;
+    write_test_vec4(&mut out, "dvec4", "dvec3", "dvec2", &V)?;
+    write_test_vec3(&mut out, "dvec4", "dvec3", "dvec2", &V)?;
+    write_test_vec2(&mut out, "dvec4", "dvec3", "dvec2", &V)?;
+    write_test_vec4(&mut out, "ivec4", "ivec3", "ivec2", &VI)?;
+    write_test_vec3(&mut out, "ivec4", "ivec3", "ivec2", &VI)?;
+    write_test_vec2(&mut out, "ivec4", "ivec3", "ivec2", &VI)?;
+    write_test_vec4(&mut out, "uvec4", "uvec3", "uvec2", &VI)?;
+    write_test_vec3(&mut out, "uvec4", "uvec3", "uvec2", &VI)?;
+    write_test_vec2(&mut out, "uvec4", "uvec3", "uvec2", &VI)?;
     Ok(())
 }
 
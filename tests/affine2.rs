@@ -0,0 +1,206 @@
+mod support;
+
+use glam::{vec2, Affine2, Mat2, Mat3, Vec2};
+use support::deg;
+
+const MATRIX: [[f32; 2]; 3] = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+#[test]
+fn test_affine2_align() {
+    use std::mem;
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(24, mem::size_of::<Affine2>());
+        assert_eq!(4, mem::align_of::<Affine2>());
+    } else {
+        assert_eq!(32, mem::size_of::<Affine2>());
+        assert_eq!(16, mem::align_of::<Affine2>());
+    }
+}
+
+#[test]
+fn test_affine2_identity() {
+    let identity = Affine2::identity();
+    assert_eq!(
+        [[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]],
+        identity.to_cols_array_2d()
+    );
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, Affine2::default());
+    assert_eq!(Mat3::identity(), Mat3::from(identity));
+}
+
+#[test]
+fn test_affine2_zero() {
+    assert_eq!([0.0; 6], Affine2::zero().to_cols_array());
+}
+
+#[test]
+fn test_affine2_from_cols() {
+    let a = Affine2::from_cols_array_2d(&MATRIX);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = Affine2::from_cols(vec2(1.0, 2.0), vec2(3.0, 4.0), vec2(5.0, 6.0));
+    assert_eq!(a, b);
+    let c = Affine2::from_cols_array(&b.to_cols_array());
+    assert_eq!(b, c);
+    assert_eq!(Vec2::new(5.0, 6.0), a.translation);
+    assert_eq!(Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), a.matrix2);
+}
+
+#[test]
+fn test_affine2_from_mat3() {
+    let m = Mat3::from_scale_angle_translation(vec2(0.5, 1.5), deg(30.0), vec2(1.0, 2.0));
+    let a = Affine2::from_mat3(m);
+    assert_approx_eq!(m, Mat3::from(a));
+}
+
+#[test]
+fn test_affine2_from_angle() {
+    assert_approx_eq!(
+        Affine2::from_angle(deg(90.0)),
+        Affine2::from_mat2(Mat2::from_angle(deg(90.0)))
+    );
+    assert_approx_eq!(
+        Affine2::from_angle_translation(deg(90.0), vec2(1.0, 2.0)),
+        Affine2::from_translation(vec2(1.0, 2.0)) * Affine2::from_angle(deg(90.0))
+    );
+}
+
+#[test]
+fn test_affine2_sat() {
+    let scale = vec2(0.5, 1.5);
+    let angle = deg(30.0);
+    let translation = vec2(1.0, 2.0);
+
+    let a = Affine2::from_scale_angle_translation(scale, angle, translation);
+    let m = Mat3::from_scale_angle_translation(scale, angle, translation);
+    assert_approx_eq!(m, Mat3::from(a));
+    assert_approx_eq!(
+        a,
+        Affine2::from_translation(translation)
+            * Affine2::from_angle(angle)
+            * Affine2::from_scale(scale)
+    );
+    assert_approx_eq!(
+        a,
+        Affine2::from_mat2_translation(Mat2::from_scale_angle(scale, angle), translation)
+    );
+
+    let (out_scale, out_angle, out_translation) = a.to_scale_angle_translation();
+    assert_approx_eq!(scale, out_scale, 1e-6);
+    assert_approx_eq!(angle, out_angle, 1e-6);
+    assert_approx_eq!(translation, out_translation);
+
+    // a negative scale may come back on a different axis but produces the same transform
+    let a = Affine2::from_scale_angle_translation(vec2(0.5, -1.5), angle, translation);
+    let (out_scale, out_angle, out_translation) = a.to_scale_angle_translation();
+    assert_approx_eq!(
+        a,
+        Affine2::from_scale_angle_translation(out_scale, out_angle, out_translation),
+        1e-6
+    );
+}
+
+#[test]
+fn test_affine2_transform() {
+    let a = Affine2::from_scale_angle_translation(vec2(0.5, 1.5), deg(90.0), vec2(1.0, 2.0));
+    assert_approx_eq!(vec2(-1.5, 0.0), a.transform_vector2(Vec2::unit_y()), 1.0e-6);
+    assert_approx_eq!(vec2(-0.5, 2.0), a.transform_point2(Vec2::unit_y()), 1.0e-6);
+    let m = Mat3::from(a);
+    assert_approx_eq!(
+        m.transform_point2(vec2(3.0, 4.0)),
+        a.transform_point2(vec2(3.0, 4.0)),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        m.transform_vector2(vec2(3.0, 4.0)),
+        a.transform_vector2(vec2(3.0, 4.0)),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_affine2_inverse() {
+    let inv = Affine2::identity().inverse();
+    assert_approx_eq!(Affine2::identity(), inv);
+
+    let rot = Affine2::from_angle(deg(90.0));
+    let rot_inv = rot.inverse();
+    assert_approx_eq!(Affine2::identity(), rot * rot_inv);
+    assert_approx_eq!(Affine2::identity(), rot_inv * rot);
+
+    let trans = Affine2::from_translation(vec2(1.0, 2.0));
+    let trans_inv = trans.inverse();
+    assert_approx_eq!(Affine2::identity(), trans * trans_inv);
+    assert_approx_eq!(Affine2::identity(), trans_inv * trans);
+
+    let scale = Affine2::from_scale(vec2(4.0, 5.0));
+    let scale_inv = scale.inverse();
+    assert_approx_eq!(Affine2::identity(), scale * scale_inv);
+    assert_approx_eq!(Affine2::identity(), scale_inv * scale);
+
+    let m = scale * rot * trans;
+    let m_inv = m.inverse();
+    assert_approx_eq!(Affine2::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(Affine2::identity(), m_inv * m, 1.0e-5);
+    assert_approx_eq!(m_inv, trans_inv * rot_inv * scale_inv, 1.0e-6);
+    assert_approx_eq!(Mat3::from(m).inverse(), Mat3::from(m_inv), 1.0e-6);
+}
+
+#[test]
+fn test_affine2_mul_mat3() {
+    let a = Affine2::from_scale_angle_translation(vec2(0.5, 1.5), deg(30.0), vec2(1.0, 2.0));
+    let m = Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_approx_eq!(Mat3::from(a) * m, a * m);
+    assert_approx_eq!(m * Mat3::from(a), m * a);
+}
+
+#[test]
+fn test_affine2_fmt() {
+    let a = Affine2::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2], [3, 4], [5, 6]]");
+}
+
+#[test]
+fn test_affine2_is_finite() {
+    use std::f32::INFINITY;
+    use std::f32::NAN;
+    assert!(Affine2::identity().is_finite());
+    assert!(!Affine2::from_scale(vec2(1.0, INFINITY)).is_finite());
+    assert!(!Affine2::from_translation(vec2(NAN, 1.0)).is_finite());
+    assert!(Affine2::from_translation(vec2(NAN, 1.0)).is_nan());
+    assert!(!Affine2::identity().is_nan());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_affine2_serde() {
+    let a = Affine2::from_cols_array_2d(&MATRIX);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Affine2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Affine2>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Affine2>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let a = Affine2::from_translation(vec2(1.0, 2.0));
+    assert_eq!([a, a].iter().product::<Affine2>(), a * a);
+}
+
+#[test]
+fn test_affine2_try_inverse() {
+    let a = Affine2::from_scale_angle_translation(vec2(2.0, 0.5), deg(30.0), vec2(1.0, -2.0));
+    assert_eq!(Some(a.inverse()), a.try_inverse());
+    assert_eq!(a.inverse(), a.inverse_or_zero());
+    let singular =
+        Affine2::from_scale_angle_translation(vec2(0.0, 0.5), deg(30.0), vec2(1.0, -2.0));
+    assert_eq!(None, singular.try_inverse());
+    assert_eq!(Affine2::zero(), singular.inverse_or_zero());
+}
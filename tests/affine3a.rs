@@ -0,0 +1,285 @@
+mod support;
+
+use glam::{vec3, vec3a, Affine3A, Mat3, Mat4, Quat, Vec3, Vec3A};
+use support::deg;
+
+const MATRIX: [[f32; 3]; 4] = [
+    [1.0, 2.0, 3.0],
+    [4.0, 5.0, 6.0],
+    [7.0, 8.0, 9.0],
+    [10.0, 11.0, 12.0],
+];
+
+#[test]
+fn test_affine3a_align() {
+    use std::mem;
+    assert_eq!(64, mem::size_of::<Affine3A>());
+    assert_eq!(16, mem::align_of::<Affine3A>());
+}
+
+#[test]
+fn test_affine3a_identity() {
+    let identity = Affine3A::identity();
+    assert_eq!(
+        [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0; 3]],
+        identity.to_cols_array_2d()
+    );
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, Affine3A::default());
+    assert_eq!(Mat4::identity(), Mat4::from(identity));
+}
+
+#[test]
+fn test_affine3a_zero() {
+    assert_eq!([0.0; 12], Affine3A::zero().to_cols_array());
+}
+
+#[test]
+fn test_affine3a_from_cols() {
+    let a = Affine3A::from_cols_array_2d(&MATRIX);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = Affine3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+        vec3a(10.0, 11.0, 12.0),
+    );
+    assert_eq!(a, b);
+    let c = Affine3A::from_cols_array(&b.to_cols_array());
+    assert_eq!(b, c);
+    assert_eq!(Vec3A::new(10.0, 11.0, 12.0), a.translation);
+    assert_eq!(
+        Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        Mat3::from(a.matrix3)
+    );
+}
+
+#[test]
+fn test_affine3a_from_mat4() {
+    let m = Mat4::from_scale_rotation_translation(
+        vec3(0.5, 1.5, 2.0),
+        Quat::from_rotation_x(deg(90.0)),
+        vec3(1.0, 2.0, 3.0),
+    );
+    let a = Affine3A::from_mat4(m);
+    assert_approx_eq!(m, Mat4::from(a));
+}
+
+#[test]
+fn test_affine3a_from_rotation() {
+    let rot_x1 = Affine3A::from_rotation_x(deg(180.0));
+    let rot_x2 = Affine3A::from_axis_angle(Vec3::unit_x(), deg(180.0));
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = Affine3A::from_rotation_y(deg(180.0));
+    let rot_y2 = Affine3A::from_axis_angle(Vec3::unit_y(), deg(180.0));
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = Affine3A::from_rotation_z(deg(180.0));
+    let rot_z2 = Affine3A::from_axis_angle(Vec3::unit_z(), deg(180.0));
+    assert_approx_eq!(rot_z1, rot_z2);
+    assert_approx_eq!(
+        Affine3A::from_quat(Quat::from_rotation_z(deg(180.0))),
+        rot_z1
+    );
+    assert_approx_eq!(
+        Affine3A::from_mat3(Mat3::from_rotation_z(deg(180.0))),
+        rot_z1
+    );
+}
+
+#[test]
+fn test_affine3a_srt() {
+    let scale = vec3(0.5, 1.5, 2.0);
+    let rotation = Quat::from_rotation_ypr(deg(30.0), deg(60.0), deg(90.0));
+    let translation = vec3(1.0, 2.0, 3.0);
+
+    let a = Affine3A::from_scale_rotation_translation(scale, rotation, translation);
+    let m = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    assert_approx_eq!(m, Mat4::from(a));
+    assert_approx_eq!(
+        a,
+        Affine3A::from_translation(translation)
+            * Affine3A::from_quat(rotation)
+            * Affine3A::from_scale(scale)
+    );
+
+    let (out_scale, out_rotation, out_translation) = a.to_scale_rotation_translation();
+    assert_approx_eq!(scale, out_scale, 1e-6);
+    assert_approx_eq!(translation, out_translation);
+    // out_rotation may differ but produces the same transform
+    assert_approx_eq!(
+        a,
+        Affine3A::from_scale_rotation_translation(out_scale, out_rotation, out_translation),
+        1e-6
+    );
+
+    let a = Affine3A::from_rotation_translation(rotation, translation);
+    assert_approx_eq!(
+        a,
+        Affine3A::from_mat3_translation(Mat3::from_quat(rotation), translation)
+    );
+}
+
+#[test]
+fn test_affine3a_decompose_shear() {
+    let scale = vec3(-2.0, 0.5, 3.0);
+    let shear = vec3(0.25, 1.0, -0.5);
+    let rotation = Quat::from_rotation_ypr(deg(20.0), deg(-70.0), deg(45.0));
+    let translation = vec3(1.0, 2.0, 3.0);
+    let a = Affine3A::from_scale_shear_rotation_translation(scale, shear, rotation, translation);
+    assert_approx_eq!(
+        Mat4::from_scale_shear_rotation_translation(scale, shear, rotation, translation),
+        Mat4::from(a)
+    );
+
+    let (out_scale, out_shear, out_rotation, out_translation) =
+        a.to_scale_shear_rotation_translation();
+    assert_approx_eq!(scale, out_scale, 1e-5);
+    assert_approx_eq!(shear, out_shear, 1e-5);
+    assert_approx_eq!(1.0, rotation.dot(out_rotation).abs(), 1e-5);
+    assert_approx_eq!(translation, out_translation);
+    assert_eq!(
+        Mat4::from(a).to_scale_shear_rotation_translation(),
+        (out_scale, out_shear, out_rotation, out_translation)
+    );
+}
+
+#[test]
+fn test_affine3a_transform() {
+    let a = Affine3A::from_scale_rotation_translation(
+        vec3(0.5, 1.5, 2.0),
+        Quat::from_rotation_x(deg(90.0)),
+        vec3(1.0, 2.0, 3.0),
+    );
+    assert_approx_eq!(
+        vec3(0.0, 0.0, 1.5),
+        a.transform_vector3(Vec3::unit_y()),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        vec3(1.0, 2.0, 4.5),
+        a.transform_point3(Vec3::unit_y()),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        vec3a(0.0, 0.0, 1.5),
+        a.transform_vector3a(Vec3A::unit_y()),
+        1.0e-6
+    );
+    assert_approx_eq!(
+        vec3a(1.0, 2.0, 4.5),
+        a.transform_point3a(Vec3A::unit_y()),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_affine3a_inverse() {
+    let inv = Affine3A::identity().inverse();
+    assert_approx_eq!(Affine3A::identity(), inv);
+
+    let rotz = Affine3A::from_rotation_z(deg(90.0));
+    let rotz_inv = rotz.inverse();
+    assert_approx_eq!(Affine3A::identity(), rotz * rotz_inv);
+    assert_approx_eq!(Affine3A::identity(), rotz_inv * rotz);
+
+    let trans = Affine3A::from_translation(vec3(1.0, 2.0, 3.0));
+    let trans_inv = trans.inverse();
+    assert_approx_eq!(Affine3A::identity(), trans * trans_inv);
+    assert_approx_eq!(Affine3A::identity(), trans_inv * trans);
+
+    let scale = Affine3A::from_scale(vec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    assert_approx_eq!(Affine3A::identity(), scale * scale_inv);
+    assert_approx_eq!(Affine3A::identity(), scale_inv * scale);
+
+    let m = scale * rotz * trans;
+    let m_inv = m.inverse();
+    assert_approx_eq!(Affine3A::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(Affine3A::identity(), m_inv * m, 1.0e-5);
+    assert_approx_eq!(m_inv, trans_inv * rotz_inv * scale_inv, 1.0e-6);
+    assert_approx_eq!(Mat4::from(m).inverse(), Mat4::from(m_inv), 1.0e-6);
+}
+
+#[test]
+fn test_affine3a_look_at() {
+    let eye = Vec3::new(0.0, 0.0, -5.0);
+    let center = Vec3::new(0.0, 0.0, 0.0);
+    let up = Vec3::new(1.0, 0.0, 0.0);
+    let lh = Affine3A::look_at_lh(eye, center, up);
+    let rh = Affine3A::look_at_rh(eye, center, up);
+    let point = Vec3::new(1.0, 0.0, 0.0);
+    assert_approx_eq!(lh.transform_point3(point), Vec3::new(0.0, 1.0, 5.0));
+    assert_approx_eq!(rh.transform_point3(point), Vec3::new(0.0, 1.0, -5.0));
+    assert_approx_eq!(Mat4::look_at_rh(eye, center, up), Mat4::from(rh));
+}
+
+#[test]
+fn test_affine3a_mul_mat4() {
+    let a = Affine3A::from_scale_rotation_translation(
+        vec3(0.5, 1.5, 2.0),
+        Quat::from_rotation_y(deg(30.0)),
+        vec3(1.0, 2.0, 3.0),
+    );
+    let m = Mat4::perspective_rh(deg(90.0), 2.0, 5.0, 15.0);
+    assert_approx_eq!(Mat4::from(a) * m, a * m);
+    assert_approx_eq!(m * Mat4::from(a), m * a);
+}
+
+#[test]
+fn test_affine3a_fmt() {
+    let a = Affine3A::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        format!("{}", a),
+        "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+    );
+}
+
+#[test]
+fn test_affine3a_is_finite() {
+    use std::f32::INFINITY;
+    use std::f32::NAN;
+    assert!(Affine3A::identity().is_finite());
+    assert!(!Affine3A::from_scale(vec3(1.0, INFINITY, 1.0)).is_finite());
+    assert!(!Affine3A::from_translation(vec3(NAN, 1.0, 1.0)).is_finite());
+    assert!(Affine3A::from_translation(vec3(NAN, 1.0, 1.0)).is_nan());
+    assert!(!Affine3A::identity().is_nan());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_affine3a_serde() {
+    let a = Affine3A::from_cols_array_2d(&MATRIX);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0]"
+    );
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Affine3A>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Affine3A>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized =
+        serde_json::from_str::<Affine3A>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let a = Affine3A::from_translation(vec3(1.0, 2.0, 3.0));
+    assert_eq!([a, a].iter().product::<Affine3A>(), a * a);
+}
+
+#[test]
+fn test_affine3a_try_inverse() {
+    let rotation = Quat::from_rotation_y(deg(30.0));
+    let a = Affine3A::from_scale_rotation_translation(vec3(2.0, 0.5, 1.0), rotation, Vec3::one());
+    assert_eq!(Some(a.inverse()), a.try_inverse());
+    assert_eq!(a.inverse(), a.inverse_or_zero());
+    let singular =
+        Affine3A::from_scale_rotation_translation(vec3(2.0, 0.0, 1.0), rotation, Vec3::one());
+    assert_eq!(None, singular.try_inverse());
+    assert_eq!(Affine3A::zero(), singular.inverse_or_zero());
+}
@@ -0,0 +1,236 @@
+mod support;
+
+use glam::{dmat2, dvec2, DMat2, DVec2};
+use support::ddeg;
+
+const IDENTITY: [[f64; 2]; 2] = [[1.0, 0.0], [0.0, 1.0]];
+
+const MATRIX: [[f64; 2]; 2] = [[1.0, 2.0], [3.0, 4.0]];
+
+const ZERO: [[f64; 2]; 2] = [[0.0; 2]; 2];
+
+#[test]
+fn test_mat2_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DMat2>());
+    assert_eq!(8, mem::align_of::<DMat2>());
+}
+
+#[test]
+fn test_mat2_identity() {
+    let identity = DMat2::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat2::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat2::default());
+}
+
+#[test]
+fn test_mat2_zero() {
+    assert_eq!(DMat2::from_cols_array_2d(&ZERO), DMat2::zero());
+}
+
+#[test]
+fn test_mat2_accessors() {
+    let mut m = DMat2::zero();
+    m.x_axis = DVec2::new(1.0, 2.0);
+    m.y_axis = DVec2::new(3.0, 4.0);
+    assert_eq!(DMat2::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec2::new(1.0, 2.0), m.x_axis);
+    assert_eq!(DVec2::new(3.0, 4.0), m.y_axis);
+}
+
+#[test]
+fn test_mat2_from_axes() {
+    let a = DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat2::from_cols(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    assert_eq!(a, b);
+    let c = dmat2(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat2::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_mat2_mul() {
+    let mat_a = DMat2::from_angle(ddeg(90.0));
+    let res_a = mat_a * DVec2::unit_y();
+    assert_approx_eq!(dvec2(-1.0, 0.0), res_a);
+    let res_b = mat_a * DVec2::unit_x();
+    assert_approx_eq!(dvec2(0.0, 1.0), res_b);
+}
+
+#[test]
+fn test_from_scale() {
+    let m = DMat2::from_scale(DVec2::new(2.0, 4.0));
+    assert_approx_eq!(m * DVec2::new(1.0, 1.0), DVec2::new(2.0, 4.0));
+    assert_approx_eq!(DVec2::unit_x() * 2.0, m.x_axis);
+    assert_approx_eq!(DVec2::unit_y() * 4.0, m.y_axis);
+
+    let rot = DMat2::from_scale_angle(DVec2::new(4.0, 2.0), ddeg(180.0));
+    assert_approx_eq!(DVec2::unit_x() * -4.0, rot * DVec2::unit_x(), 1.0e-6);
+    assert_approx_eq!(DVec2::unit_y() * -2.0, rot * DVec2::unit_y(), 1.0e-6);
+}
+
+#[test]
+fn test_mat2_transpose() {
+    let m = dmat2(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis, dvec2(1.0, 3.0));
+    assert_eq!(mt.y_axis, dvec2(2.0, 4.0));
+}
+
+#[test]
+fn test_mat2_det() {
+    assert_eq!(0.0, DMat2::zero().determinant());
+    assert_eq!(1.0, DMat2::identity().determinant());
+    assert_eq!(1.0, DMat2::from_angle(ddeg(90.0)).determinant());
+    assert_eq!(1.0, DMat2::from_angle(ddeg(180.0)).determinant());
+    assert_eq!(1.0, DMat2::from_angle(ddeg(270.0)).determinant());
+    assert_eq!(2.0 * 2.0, DMat2::from_scale(dvec2(2.0, 2.0)).determinant());
+    assert_eq!(
+        1.0 * 4.0 - 2.0 * 3.0,
+        DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]).determinant()
+    );
+}
+
+#[test]
+fn test_mat2_inverse() {
+    let inv = DMat2::identity().inverse();
+    assert_approx_eq!(DMat2::identity(), inv);
+
+    let rot = DMat2::from_angle(ddeg(90.0));
+    let rot_inv = rot.inverse();
+    assert_approx_eq!(DMat2::identity(), rot * rot_inv);
+    assert_approx_eq!(DMat2::identity(), rot_inv * rot);
+
+    let scale = DMat2::from_scale(dvec2(4.0, 5.0));
+    let scale_inv = scale.inverse();
+    assert_approx_eq!(DMat2::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat2::identity(), scale_inv * scale);
+
+    let m = scale * rot;
+    let m_inv = m.inverse();
+    assert_approx_eq!(DMat2::identity(), m * m_inv);
+    assert_approx_eq!(DMat2::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rot_inv * scale_inv);
+}
+
+#[test]
+fn test_mat2_ops() {
+    let m0 = DMat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        m0 * 2.0
+    );
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        2.0 * m0
+    );
+    assert_eq!(
+        DMat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]),
+        m0 + m0
+    );
+    assert_eq!(DMat2::zero(), m0 - m0);
+    assert_approx_eq!(
+        DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]),
+        m0 * DMat2::identity()
+    );
+    assert_approx_eq!(
+        DMat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]),
+        DMat2::identity() * m0
+    );
+}
+
+#[test]
+fn test_mat2_fmt() {
+    let a = DMat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2], [3, 4]]");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mat2_serde() {
+    let a = DMat2::from_cols(dvec2(1.0, 2.0), dvec2(3.0, 4.0));
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DMat2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat2>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat2>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat2>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat2>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat2>("[[1.0,2.0],[3.0,4.0]]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_mat2_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a = DMat2::from_cols_array(&rng1.gen::<[f64; 4]>());
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b = rng2.gen::<DMat2>();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let id = DMat2::identity();
+    assert_eq!([id, id].iter().sum::<DMat2>(), id + id);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = DMat2::identity() + DMat2::identity();
+    assert_eq!([two, two].iter().product::<DMat2>(), two * two);
+}
+
+#[test]
+fn test_mat2_is_finite() {
+    use std::f64::INFINITY;
+    use std::f64::NAN;
+    use std::f64::NEG_INFINITY;
+    assert!(DMat2::identity().is_finite());
+    assert!(!(DMat2::identity() * INFINITY).is_finite());
+    assert!(!(DMat2::identity() * NEG_INFINITY).is_finite());
+    assert!(!(DMat2::identity() * NAN).is_finite());
+}
+
+#[test]
+fn test_mat2_as_f32() {
+    let a = DMat2::from_cols_array_2d(&MATRIX);
+    let b = glam::Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(b, a.as_f32());
+    assert_eq!(a, b.as_f64());
+}
+
+#[test]
+fn test_dmat2_try_inverse() {
+    let m = DMat2::from_cols(dvec2(2.0, 1.0), dvec2(-1.0, 0.5));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-100).try_inverse().is_some());
+
+    assert_eq!(None, DMat2::zero().try_inverse());
+    assert_eq!(DMat2::zero(), DMat2::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = DMat2::from_cols(dvec2(1.0, 2.0), dvec2(2.0, 4.0));
+    for &scale in [1.0, 1.0e-100, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f64::NAN).try_inverse());
+}
@@ -0,0 +1,307 @@
+mod support;
+
+use glam::{dmat3, dvec2, dvec3, DMat3, DVec2, DVec3};
+use support::ddeg;
+
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+const MATRIX: [[f64; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+const ZERO: [[f64; 3]; 3] = [[0.0; 3]; 3];
+
+#[test]
+fn test_mat3_align() {
+    use std::mem;
+    assert_eq!(72, mem::size_of::<DMat3>());
+    assert_eq!(8, mem::align_of::<DMat3>());
+}
+
+#[test]
+fn test_mat3_identity() {
+    let identity = DMat3::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat3::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat3::default());
+}
+
+#[test]
+fn test_mat3_zero() {
+    assert_eq!(DMat3::from_cols_array_2d(&ZERO), DMat3::zero());
+}
+
+#[test]
+fn test_mat3_accessors() {
+    let mut m = DMat3::zero();
+    m.x_axis = DVec3::new(1.0, 2.0, 3.0);
+    m.y_axis = DVec3::new(4.0, 5.0, 6.0);
+    m.z_axis = DVec3::new(7.0, 8.0, 9.0);
+    assert_eq!(DMat3::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec3::new(1.0, 2.0, 3.0), m.x_axis);
+    assert_eq!(DVec3::new(4.0, 5.0, 6.0), m.y_axis);
+    assert_eq!(DVec3::new(7.0, 8.0, 9.0), m.z_axis);
+}
+
+#[test]
+fn test_mat3_from_axes() {
+    let a = DMat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat3::from_cols(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, b);
+    let c = dmat3(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat3::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_from_rotation() {
+    let rot_x1 = DMat3::from_rotation_x(ddeg(180.0));
+    let rot_x2 = DMat3::from_axis_angle(DVec3::unit_x(), ddeg(180.0));
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = DMat3::from_rotation_y(ddeg(180.0));
+    let rot_y2 = DMat3::from_axis_angle(DVec3::unit_y(), ddeg(180.0));
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = DMat3::from_rotation_z(ddeg(180.0));
+    let rot_z2 = DMat3::from_axis_angle(DVec3::unit_z(), ddeg(180.0));
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_mat3_mul() {
+    let mat_a = DMat3::from_axis_angle(DVec3::unit_z(), ddeg(90.0));
+    assert_approx_eq!(dvec3(-1.0, 0.0, 0.0), mat_a * DVec3::unit_y());
+    assert_approx_eq!(dvec3(-1.0, 0.0, 0.0), mat_a.mul_vec3(DVec3::unit_y()));
+}
+
+#[test]
+fn test_mat3_transform2d() {
+    let mat_b = DMat3::from_scale_angle_translation(
+        DVec2::new(0.5, 1.5),
+        f64::to_radians(90.0),
+        DVec2::new(1.0, 2.0),
+    );
+    let result2 = mat_b.transform_vector2(DVec2::unit_y());
+    assert_approx_eq!(dvec2(-1.5, 0.0), result2, 1.0e-6);
+    assert_approx_eq!(result2, (mat_b * DVec2::unit_y().extend(0.0)).truncate());
+
+    let result2 = mat_b.transform_point2(DVec2::unit_y());
+    assert_approx_eq!(dvec2(-0.5, 2.0), result2, 1.0e-6);
+    assert_approx_eq!(result2, (mat_b * DVec2::unit_y().extend(1.0)).truncate());
+}
+
+#[test]
+fn test_from_ypr() {
+    let zero = ddeg(0.0);
+    let yaw = ddeg(30.0);
+    let pitch = ddeg(60.0);
+    let roll = ddeg(90.0);
+    let y0 = DMat3::from_rotation_y(yaw);
+    let y1 = DMat3::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+
+    let x0 = DMat3::from_rotation_x(pitch);
+    let x1 = DMat3::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+
+    let z0 = DMat3::from_rotation_z(roll);
+    let z1 = DMat3::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+
+    let yx0 = y0 * x0;
+    let yx1 = DMat3::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    let yxz1 = DMat3::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1, 1e-6);
+}
+
+#[test]
+fn test_from_scale() {
+    let m = DMat3::from_scale(DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(m * DVec3::new(1.0, 1.0, 1.0), DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(DVec3::unit_x() * 2.0, m.x_axis);
+    assert_approx_eq!(DVec3::unit_y() * 4.0, m.y_axis);
+    assert_approx_eq!(DVec3::unit_z() * 8.0, m.z_axis);
+}
+
+#[test]
+fn test_mat3_transpose() {
+    let m = dmat3(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis, dvec3(1.0, 4.0, 7.0));
+    assert_eq!(mt.y_axis, dvec3(2.0, 5.0, 8.0));
+    assert_eq!(mt.z_axis, dvec3(3.0, 6.0, 9.0));
+}
+
+#[test]
+fn test_mat3_det() {
+    assert_eq!(0.0, DMat3::zero().determinant());
+    assert_eq!(1.0, DMat3::identity().determinant());
+    assert_eq!(1.0, DMat3::from_rotation_x(ddeg(90.0)).determinant());
+    assert_eq!(1.0, DMat3::from_rotation_y(ddeg(180.0)).determinant());
+    assert_eq!(1.0, DMat3::from_rotation_z(ddeg(270.0)).determinant());
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        DMat3::from_scale(dvec3(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_mat3_inverse() {
+    // assert_eq!(None, DMat3::zero().inverse());
+    let inv = DMat3::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(DMat3::identity(), inv);
+
+    let rotz = DMat3::from_rotation_z(ddeg(90.0));
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), rotz * rotz_inv);
+    assert_approx_eq!(DMat3::identity(), rotz_inv * rotz);
+
+    let scale = DMat3::from_scale(dvec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat3::identity(), scale_inv * scale);
+
+    let m = scale * rotz;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(DMat3::identity(), m * m_inv);
+    assert_approx_eq!(DMat3::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rotz_inv * scale_inv);
+}
+
+#[test]
+fn test_mat3_ops() {
+    let m0 = DMat3::from_cols_array_2d(&MATRIX);
+    let m0x2 = DMat3::from_cols_array_2d(&[[2.0, 4.0, 6.0], [8.0, 10.0, 12.0], [14.0, 16.0, 18.0]]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(DMat3::zero(), m0 - m0);
+    assert_approx_eq!(m0, m0 * DMat3::identity());
+    assert_approx_eq!(m0, DMat3::identity() * m0);
+}
+
+#[test]
+fn test_mat3_fmt() {
+    let a = DMat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mat3_serde() {
+    let a = DMat3::from_cols(
+        dvec3(1.0, 2.0, 3.0),
+        dvec3(4.0, 5.0, 6.0),
+        dvec3(7.0, 8.0, 9.0),
+    );
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DMat3>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat3>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat3>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat3>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat3>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat3>("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_mat3_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a = DMat3::from_cols_array(&rng1.gen::<[f64; 9]>());
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b = rng2.gen::<DMat3>();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let id = DMat3::identity();
+    assert_eq!([id, id].iter().sum::<DMat3>(), id + id);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = DMat3::identity() + DMat3::identity();
+    assert_eq!([two, two].iter().product::<DMat3>(), two * two);
+}
+
+#[test]
+fn test_mat3_is_finite() {
+    use std::f64::INFINITY;
+    use std::f64::NAN;
+    use std::f64::NEG_INFINITY;
+    assert!(DMat3::identity().is_finite());
+    assert!(!(DMat3::identity() * INFINITY).is_finite());
+    assert!(!(DMat3::identity() * NEG_INFINITY).is_finite());
+    assert!(!(DMat3::identity() * NAN).is_finite());
+}
+
+#[test]
+fn test_mat3_as_f32() {
+    let a = DMat3::from_cols_array_2d(&MATRIX);
+    let b = glam::Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(b, a.as_f32());
+    assert_eq!(a, b.as_f64());
+}
+
+#[test]
+fn test_dmat3_try_inverse() {
+    let m = DMat3::from_cols(
+        dvec3(2.0, 1.0, 0.0),
+        dvec3(-1.0, 0.5, 0.0),
+        dvec3(0.5, 0.0, 4.0),
+    );
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-100).try_inverse().is_some());
+
+    assert_eq!(None, DMat3::zero().try_inverse());
+    assert_eq!(DMat3::zero(), DMat3::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = DMat3::from_cols(
+        dvec3(1.0, 2.0, -3.0),
+        dvec3(2.0, 4.0, -6.0),
+        dvec3(0.0, 1.0, 0.0),
+    );
+    for &scale in [1.0, 1.0e-100, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f64::NAN).try_inverse());
+}
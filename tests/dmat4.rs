@@ -0,0 +1,588 @@
+mod support;
+
+use glam::{dmat4, dvec3, dvec4, DMat4, DQuat, DVec3, DVec4};
+use support::ddeg;
+
+const IDENTITY: [[f64; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+const MATRIX: [[f64; 4]; 4] = [
+    [1.0, 2.0, 3.0, 4.0],
+    [5.0, 6.0, 7.0, 8.0],
+    [9.0, 10.0, 11.0, 12.0],
+    [13.0, 14.0, 15.0, 16.0],
+];
+
+const ZERO: [[f64; 4]; 4] = [[0.0; 4]; 4];
+
+#[test]
+fn test_mat4_align() {
+    use std::mem;
+    assert_eq!(128, mem::size_of::<DMat4>());
+    assert_eq!(8, mem::align_of::<DMat4>());
+}
+
+#[test]
+fn test_mat4_identity() {
+    let identity = DMat4::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(DMat4::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, DMat4::default());
+}
+
+#[test]
+fn test_mat4_zero() {
+    assert_eq!(DMat4::from_cols_array_2d(&ZERO), DMat4::zero());
+}
+
+#[test]
+fn test_mat4_accessors() {
+    let mut m = DMat4::zero();
+    m.x_axis = DVec4::new(1.0, 2.0, 3.0, 4.0);
+    m.y_axis = DVec4::new(5.0, 6.0, 7.0, 8.0);
+    m.z_axis = DVec4::new(9.0, 10.0, 11.0, 12.0);
+    m.w_axis = DVec4::new(13.0, 14.0, 15.0, 16.0);
+    assert_eq!(DMat4::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(DVec4::new(1.0, 2.0, 3.0, 4.0), m.x_axis);
+    assert_eq!(DVec4::new(5.0, 6.0, 7.0, 8.0), m.y_axis);
+    assert_eq!(DVec4::new(9.0, 10.0, 11.0, 12.0), m.z_axis);
+    assert_eq!(DVec4::new(13.0, 14.0, 15.0, 16.0), m.w_axis);
+}
+
+#[test]
+fn test_mat4_from_axes() {
+    let a = DMat4::from_cols_array_2d(&[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = DMat4::from_cols(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    assert_eq!(a, b);
+    let c = dmat4(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = DMat4::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_mat4_translation() {
+    let translate = DMat4::from_translation(dvec3(1.0, 2.0, 3.0));
+    assert_eq!(
+        DMat4::from_cols(
+            dvec4(1.0, 0.0, 0.0, 0.0),
+            dvec4(0.0, 1.0, 0.0, 0.0),
+            dvec4(0.0, 0.0, 1.0, 0.0),
+            dvec4(1.0, 2.0, 3.0, 1.0)
+        ),
+        translate
+    );
+}
+
+#[test]
+fn test_from_rotation() {
+    let rot_x1 = DMat4::from_rotation_x(ddeg(180.0));
+    let rot_x2 = DMat4::from_axis_angle(DVec3::unit_x(), ddeg(180.0));
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = DMat4::from_rotation_y(ddeg(180.0));
+    let rot_y2 = DMat4::from_axis_angle(DVec3::unit_y(), ddeg(180.0));
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = DMat4::from_rotation_z(ddeg(180.0));
+    let rot_z2 = DMat4::from_axis_angle(DVec3::unit_z(), ddeg(180.0));
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_mat4_mul() {
+    let mat_a = DMat4::from_axis_angle(DVec3::unit_z(), ddeg(90.0));
+    let result3 = mat_a.transform_vector3(DVec3::unit_y());
+    assert_approx_eq!(dvec3(-1.0, 0.0, 0.0), result3);
+    assert_approx_eq!(result3, (mat_a * DVec3::unit_y().extend(0.0)).truncate());
+    let result4 = mat_a * DVec4::unit_y();
+    assert_approx_eq!(dvec4(-1.0, 0.0, 0.0, 0.0), result4);
+    assert_approx_eq!(result4, mat_a * DVec4::unit_y());
+
+    let mat_b = DMat4::from_scale_rotation_translation(
+        DVec3::new(0.5, 1.5, 2.0),
+        DQuat::from_rotation_x(ddeg(90.0)),
+        DVec3::new(1.0, 2.0, 3.0),
+    );
+    let result3 = mat_b.transform_vector3(DVec3::unit_y());
+    assert_approx_eq!(dvec3(0.0, 0.0, 1.5), result3, 1.0e-6);
+    assert_approx_eq!(result3, (mat_b * DVec3::unit_y().extend(0.0)).truncate());
+
+    let result3 = mat_b.transform_point3(DVec3::unit_y());
+    assert_approx_eq!(dvec3(1.0, 2.0, 4.5), result3, 1.0e-6);
+    assert_approx_eq!(result3, (mat_b * DVec3::unit_y().extend(1.0)).truncate());
+}
+
+#[test]
+fn test_from_ypr() {
+    let zero = ddeg(0.0);
+    let yaw = ddeg(30.0);
+    let pitch = ddeg(60.0);
+    let roll = ddeg(90.0);
+    let y0 = DMat4::from_rotation_y(yaw);
+    let y1 = DMat4::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+
+    let x0 = DMat4::from_rotation_x(pitch);
+    let x1 = DMat4::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+
+    let z0 = DMat4::from_rotation_z(roll);
+    let z1 = DMat4::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+
+    let yx0 = y0 * x0;
+    let yx1 = DMat4::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    let yxz1 = DMat4::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1, 1e-6);
+}
+
+#[test]
+fn test_from_scale() {
+    let m = DMat4::from_scale(DVec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(
+        m.transform_point3(DVec3::new(1.0, 1.0, 1.0)),
+        DVec3::new(2.0, 4.0, 8.0)
+    );
+    assert_approx_eq!(DVec4::unit_x() * 2.0, m.x_axis);
+    assert_approx_eq!(DVec4::unit_y() * 4.0, m.y_axis);
+    assert_approx_eq!(DVec4::unit_z() * 8.0, m.z_axis);
+    assert_approx_eq!(DVec4::unit_w(), m.w_axis);
+}
+
+#[test]
+fn test_mat4_transpose() {
+    let m = dmat4(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis, dvec4(1.0, 5.0, 9.0, 13.0));
+    assert_eq!(mt.y_axis, dvec4(2.0, 6.0, 10.0, 14.0));
+    assert_eq!(mt.z_axis, dvec4(3.0, 7.0, 11.0, 15.0));
+    assert_eq!(mt.w_axis, dvec4(4.0, 8.0, 12.0, 16.0));
+}
+
+#[test]
+fn test_mat4_det() {
+    assert_eq!(0.0, DMat4::zero().determinant());
+    assert_eq!(1.0, DMat4::identity().determinant());
+    assert_eq!(1.0, DMat4::from_rotation_x(ddeg(90.0)).determinant());
+    assert_eq!(1.0, DMat4::from_rotation_y(ddeg(180.0)).determinant());
+    assert_eq!(1.0, DMat4::from_rotation_z(ddeg(270.0)).determinant());
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        DMat4::from_scale(dvec3(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_mat4_inverse() {
+    // assert_eq!(None, DMat4::zero().inverse());
+    let inv = DMat4::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(DMat4::identity(), inv);
+
+    let rotz = DMat4::from_rotation_z(ddeg(90.0));
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), rotz * rotz_inv);
+    assert_approx_eq!(DMat4::identity(), rotz_inv * rotz);
+
+    let trans = DMat4::from_translation(dvec3(1.0, 2.0, 3.0));
+    let trans_inv = trans.inverse();
+    // assert_ne!(None, trans_inv);
+    // let trans_inv = trans_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), trans * trans_inv);
+    assert_approx_eq!(DMat4::identity(), trans_inv * trans);
+
+    let scale = DMat4::from_scale(dvec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), scale * scale_inv);
+    assert_approx_eq!(DMat4::identity(), scale_inv * scale);
+
+    let m = scale * rotz * trans;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(DMat4::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(DMat4::identity(), m_inv * m, 1.0e-5);
+    assert_approx_eq!(m_inv, trans_inv * rotz_inv * scale_inv, 1.0e-6);
+}
+
+#[test]
+fn test_mat4_decompose() {
+    // identity
+    let (out_scale, out_rotation, out_translation) =
+        DMat4::identity().to_scale_rotation_translation();
+    assert_approx_eq!(DVec3::one(), out_scale);
+    assert!(out_rotation.is_near_identity());
+    assert_approx_eq!(DVec3::zero(), out_translation);
+
+    // no scale
+    let in_scale = DVec3::one();
+    let in_translation = DVec3::new(-2.0, 4.0, -0.125);
+    let in_rotation = DQuat::from_rotation_ypr(
+        f64::to_radians(-45.0),
+        f64::to_radians(180.0),
+        f64::to_radians(270.0),
+    );
+    let in_mat = DMat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+    let (out_scale, out_rotation, out_translation) = in_mat.to_scale_rotation_translation();
+    assert_approx_eq!(in_scale, out_scale, 1e-6);
+    // out_rotation is different but produces the same matrix
+    // assert_approx_eq!(in_rotation, out_rotation);
+    assert_approx_eq!(in_translation, out_translation);
+    assert_approx_eq!(
+        in_mat,
+        DMat4::from_scale_rotation_translation(out_scale, out_rotation, out_translation),
+        1e-6
+    );
+
+    // positive scale
+    let in_scale = DVec3::new(1.0, 2.0, 4.0);
+    let in_mat = DMat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+    let (out_scale, out_rotation, out_translation) = in_mat.to_scale_rotation_translation();
+    assert_approx_eq!(in_scale, out_scale, 1e-6);
+    // out_rotation is different but produces the same matrix
+    // assert_approx_eq!(in_rotation, out_rotation);
+    assert_approx_eq!(in_translation, out_translation);
+    assert_approx_eq!(
+        in_mat,
+        DMat4::from_scale_rotation_translation(out_scale, out_rotation, out_translation),
+        1e-6
+    );
+
+    // negative scale
+    let in_scale = DVec3::new(-4.0, 1.0, 2.0);
+    let in_mat = DMat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+    let (out_scale, out_rotation, out_translation) = in_mat.to_scale_rotation_translation();
+    assert_approx_eq!(in_scale, out_scale, 1e-6);
+    // out_rotation is different but produces the same matrix
+    // assert_approx_eq!(in_rotation, out_rotation);
+    assert_approx_eq!(in_translation, out_translation);
+    assert_approx_eq!(
+        in_mat,
+        DMat4::from_scale_rotation_translation(out_scale, out_rotation, out_translation),
+        1e-5
+    );
+
+    // negative scale
+    let in_scale = DVec3::new(4.0, -1.0, -2.0);
+    let in_mat = DMat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+    let (out_scale, out_rotation, out_translation) = in_mat.to_scale_rotation_translation();
+    // out_scale and out_rotation are different but they produce the same matrix
+    // assert_approx_eq!(in_scale, out_scale, 1e-6);
+    // assert_approx_eq!(in_rotation, out_rotation);
+    assert_approx_eq!(in_translation, out_translation);
+    assert_approx_eq!(
+        in_mat,
+        DMat4::from_scale_rotation_translation(out_scale, out_rotation, out_translation),
+        1e-6
+    );
+}
+
+#[test]
+fn test_mat4_look_at() {
+    let eye = DVec3::new(0.0, 0.0, -5.0);
+    let center = DVec3::new(0.0, 0.0, 0.0);
+    let up = DVec3::new(1.0, 0.0, 0.0);
+    let lh = DMat4::look_at_lh(eye, center, up);
+    let rh = DMat4::look_at_rh(eye, center, up);
+    let point = DVec3::new(1.0, 0.0, 0.0);
+    assert_approx_eq!(lh.transform_point3(point), DVec3::new(0.0, 1.0, 5.0));
+    assert_approx_eq!(rh.transform_point3(point), DVec3::new(0.0, 1.0, -5.0));
+}
+
+#[test]
+fn test_mat4_perspective_gl_rh() {
+    let projection = DMat4::perspective_rh_gl(f64::to_radians(90.0), 2.0, 5.0, 15.0);
+
+    let original = DVec3::new(5.0, 5.0, -15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 15.0, 15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, -5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, -5.0, 5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_lh() {
+    let projection = DMat4::perspective_lh(f64::to_radians(90.0), 2.0, 5.0, 15.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 15.0, 15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 0.0, 5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_infinite_lh() {
+    let projection = DMat4::perspective_infinite_lh(f64::to_radians(90.0), 2.0, 5.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 10.0, 15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 0.0, 5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_infinite_reverse_lh() {
+    let projection = DMat4::perspective_infinite_reverse_lh(f64::to_radians(90.0), 2.0, 5.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 5.0, 15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 5.0, 5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_rh() {
+    let projection = DMat4::perspective_rh(f64::to_radians(90.0), 2.0, 5.0, 15.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, -30.0, -15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, -15.0, -5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_infinite_rh() {
+    let projection = DMat4::perspective_infinite_rh(f64::to_radians(90.0), 2.0, 5.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, -20.0, -15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, -10.0, -5.0), projected);
+}
+
+#[test]
+fn test_mat4_perspective_infinite_reverse_rh() {
+    let projection = DMat4::perspective_infinite_reverse_rh(f64::to_radians(90.0), 2.0, 5.0);
+
+    let original = DVec3::new(5.0, 5.0, 15.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 5.0, -15.0), projected);
+
+    let original = DVec3::new(5.0, 5.0, 5.0);
+    let projected = projection * original.extend(1.0);
+    assert_approx_eq!(DVec4::new(2.5, 5.0, 5.0, -5.0), projected);
+}
+
+#[test]
+fn test_mat4_orthographic_gl_rh() {
+    let projection = DMat4::orthographic_rh_gl(-10.0, 10.0, -5.0, 5.0, 0.0, -10.0);
+    let original = DVec4::new(5.0, 5.0, -5.0, 1.0);
+    let projected = projection.mul_vec4(original);
+    assert_approx_eq!(projected, DVec4::new(0.5, 1.0, -2.0, 1.0));
+}
+
+#[test]
+fn test_mat4_orthographic_rh() {
+    let projection = DMat4::orthographic_rh(-10.0, 10.0, -5.0, 5.0, -10.0, 10.0);
+    let original = DVec4::new(5.0, 5.0, -5.0, 1.0);
+    let projected = projection.mul_vec4(original);
+    assert_approx_eq!(projected, DVec4::new(0.5, 1.0, 0.75, 1.0));
+
+    let original = DVec4::new(5.0, 5.0, 5.0, 1.0);
+    let projected = projection.mul_vec4(original);
+    assert_approx_eq!(projected, DVec4::new(0.5, 1.0, 0.25, 1.0));
+}
+
+#[test]
+fn test_mat4_orthographic_lh() {
+    let projection = DMat4::orthographic_lh(-10.0, 10.0, -5.0, 5.0, -10.0, 10.0);
+    let original = DVec4::new(5.0, 5.0, -5.0, 1.0);
+    let projected = projection.mul_vec4(original);
+    assert_approx_eq!(projected, DVec4::new(0.5, 1.0, 0.25, 1.0));
+
+    let original = DVec4::new(5.0, 5.0, 5.0, 1.0);
+    let projected = projection.mul_vec4(original);
+    assert_approx_eq!(projected, DVec4::new(0.5, 1.0, 0.75, 1.0));
+}
+
+#[test]
+fn test_mat4_ops() {
+    let m0 = DMat4::from_cols_array_2d(&MATRIX);
+    let m0x2 = DMat4::from_cols_array_2d(&[
+        [2.0, 4.0, 6.0, 8.0],
+        [10.0, 12.0, 14.0, 16.0],
+        [18.0, 20.0, 22.0, 24.0],
+        [26.0, 28.0, 30.0, 32.0],
+    ]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(DMat4::zero(), m0 - m0);
+    assert_approx_eq!(m0, m0 * DMat4::identity());
+    assert_approx_eq!(m0, DMat4::identity() * m0);
+}
+
+#[test]
+fn test_mat4_fmt() {
+    let a = DMat4::from_cols_array_2d(&MATRIX);
+    assert_eq!(
+        format!("{}", a),
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mat4_serde() {
+    let a = DMat4::from_cols(
+        dvec4(1.0, 2.0, 3.0, 4.0),
+        dvec4(5.0, 6.0, 7.0, 8.0),
+        dvec4(9.0, 10.0, 11.0, 12.0),
+        dvec4(13.0, 14.0, 15.0, 16.0),
+    );
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]"
+    );
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DMat4>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DMat4>(
+        "[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0][13.0,14.0,15.0,16.0]]",
+    );
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_mat4_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a = DMat4::from_cols_array(&rng1.gen::<[f64; 16]>());
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b = rng2.gen::<DMat4>();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let id = DMat4::identity();
+    assert_eq!([id, id].iter().sum::<DMat4>(), id + id);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = DMat4::identity() + DMat4::identity();
+    assert_eq!([two, two].iter().product::<DMat4>(), two * two);
+}
+
+#[test]
+fn test_mat4_is_finite() {
+    use std::f64::INFINITY;
+    use std::f64::NAN;
+    use std::f64::NEG_INFINITY;
+    assert!(DMat4::identity().is_finite());
+    assert!(!(DMat4::identity() * INFINITY).is_finite());
+    assert!(!(DMat4::identity() * NEG_INFINITY).is_finite());
+    assert!(!(DMat4::identity() * NAN).is_finite());
+}
+
+#[test]
+fn test_mat4_as_f32() {
+    let a = DMat4::from_cols_array_2d(&MATRIX);
+    let b = glam::Mat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ]);
+    assert_eq!(b, a.as_f32());
+    assert_eq!(a, b.as_f64());
+}
+
+#[test]
+fn test_dmat4_try_inverse() {
+    let m = DMat4::from_cols(
+        dvec4(2.0, 1.0, 0.0, 0.0),
+        dvec4(-1.0, 0.5, 0.0, 0.0),
+        dvec4(0.5, 0.0, 4.0, 0.0),
+        dvec4(1.0, 2.0, 3.0, 1.0),
+    );
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-50).try_inverse().is_some());
+
+    assert_eq!(None, DMat4::zero().try_inverse());
+    assert_eq!(DMat4::zero(), DMat4::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = DMat4::from_cols(
+        dvec4(1.0, 2.0, -3.0, 0.5),
+        dvec4(2.0, 4.0, -6.0, 1.0),
+        dvec4(0.0, 1.0, 0.0, 0.0),
+        dvec4(0.0, 0.0, 0.0, 1.0),
+    );
+    for &scale in [1.0, 1.0e-50, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f64::NAN).try_inverse());
+
+    // the translation doesn't affect the tolerance
+    let m = DMat4::from_translation(DVec3::new(1.0e7, 0.0, 0.0));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    let m = DMat4::from_scale(DVec3::new(1.0e-3, 2.0, 1.0))
+        * DMat4::from_translation(DVec3::new(-3.0e6, 1.0e7, 5.0e5));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+}
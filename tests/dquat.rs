@@ -0,0 +1,680 @@
+mod support;
+
+use core::ops::Neg;
+use glam::{dquat, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use support::ddeg;
+
+#[test]
+fn test_quat_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DQuat>());
+    assert_eq!(8, mem::align_of::<DQuat>());
+}
+
+#[test]
+fn test_quat_rotation() {
+    let zero = ddeg(0.0);
+    let yaw = ddeg(30.0);
+    let pitch = ddeg(60.0);
+    let roll = ddeg(90.0);
+    let y0 = DQuat::from_rotation_y(yaw);
+    assert!(y0.is_normalized());
+    let (axis, angle) = y0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_y(), 1.0e-6);
+    assert_approx_eq!(angle, yaw);
+    let y1 = DQuat::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+    let y2 = DQuat::from_axis_angle(DVec3::unit_y(), yaw);
+    assert_approx_eq!(y0, y2);
+    let y3 = DQuat::from_rotation_mat3(&DMat3::from_rotation_y(yaw));
+    assert_approx_eq!(y0, y3);
+    let y4 = DQuat::from_rotation_mat3(&DMat3::from_quat(y0));
+    assert_approx_eq!(y0, y4);
+
+    let x0 = DQuat::from_rotation_x(pitch);
+    assert!(x0.is_normalized());
+    let (axis, angle) = x0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_x());
+    assert_approx_eq!(angle, pitch);
+    let x1 = DQuat::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+    let x2 = DQuat::from_axis_angle(DVec3::unit_x(), pitch);
+    assert_approx_eq!(x0, x2);
+    let x3 = DQuat::from_rotation_mat4(&DMat4::from_rotation_x(ddeg(180.0)));
+    assert!(x3.is_normalized());
+    assert_approx_eq!(DQuat::from_rotation_x(ddeg(180.0)), x3);
+
+    let z0 = DQuat::from_rotation_z(roll);
+    assert!(z0.is_normalized());
+    let (axis, angle) = z0.to_axis_angle();
+    assert_approx_eq!(axis, DVec3::unit_z());
+    assert_approx_eq!(angle, roll);
+    let z1 = DQuat::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+    let z2 = DQuat::from_axis_angle(DVec3::unit_z(), roll);
+    assert_approx_eq!(z0, z2);
+    let z3 = DQuat::from_rotation_mat4(&DMat4::from_rotation_z(roll));
+    assert_approx_eq!(z0, z3);
+
+    let yx0 = y0 * x0;
+    assert!(yx0.is_normalized());
+    let yx1 = DQuat::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    assert!(yxz0.is_normalized());
+    let yxz1 = DQuat::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1);
+
+    // use the conjugate of z0 to remove the rotation from yxz0
+    let yx2 = yxz0 * z0.conjugate();
+    assert_approx_eq!(yx0, yx2);
+
+    let yxz2 = DQuat::from_rotation_mat4(&DMat4::from_quat(yxz0));
+    assert_approx_eq!(yxz0, yxz2);
+
+    // if near identity, just returns x axis and 0 rotation
+    let (axis, angle) = DQuat::identity().to_axis_angle();
+    assert_eq!(axis, DVec3::unit_x());
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn test_quat_new() {
+    let ytheta = ddeg(45.0);
+    let q0 = DQuat::from_rotation_y(ytheta);
+
+    let t1 = (0.0, (ytheta * 0.5).sin(), 0.0, (ytheta * 0.5).cos());
+    assert_eq!(q0, t1.into());
+    let q1 = DQuat::from(t1);
+    assert_eq!(t1, q1.into());
+
+    assert_eq!(q0, dquat(t1.0, t1.1, t1.2, t1.3));
+
+    let a1 = [0.0, (ytheta * 0.5).sin(), 0.0, (ytheta * 0.5).cos()];
+    assert_eq!(q0, a1.into());
+    let q1 = DQuat::from(a1);
+    let a2: [f64; 4] = q1.into();
+    assert_eq!(a1, a2);
+}
+
+#[test]
+fn test_quat_mul_vec3() {
+    let qrz = DQuat::from_rotation_z(ddeg(90.0));
+    assert_approx_eq!(DVec3::unit_y(), qrz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), qrz.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_y(), -qrz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), qrz.neg().mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(-DVec3::unit_x(), qrz * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), qrz.mul_vec3(DVec3::unit_y()));
+    assert_approx_eq!(-DVec3::unit_x(), -qrz * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), qrz.neg().mul_vec3(DVec3::unit_y()));
+
+    // check dvec3 * dmat3 is the same
+    let mrz = DMat3::from_quat(qrz);
+    assert_approx_eq!(DVec3::unit_y(), mrz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), mrz.mul_vec3(DVec3::unit_x()));
+    // assert_approx_eq!(DVec3::unit_y(), -mrz * DVec3::unit_x());
+    assert_approx_eq!(-DVec3::unit_x(), mrz * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), mrz.mul_vec3(DVec3::unit_y()));
+
+    let qrx = DQuat::from_rotation_x(ddeg(90.0));
+    assert_approx_eq!(DVec3::unit_x(), qrx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_x(), qrx.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_x(), -qrx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_x(), qrx.neg().mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_z(), qrx * DVec3::unit_y());
+    assert_approx_eq!(DVec3::unit_z(), qrx.mul_vec3(DVec3::unit_y()));
+    assert_approx_eq!(DVec3::unit_z(), -qrx * DVec3::unit_y());
+    assert_approx_eq!(DVec3::unit_z(), qrx.neg().mul_vec3(DVec3::unit_y()));
+
+    // check dvec3 * dmat3 is the same
+    let mrx = DMat3::from_quat(qrx);
+    assert_approx_eq!(DVec3::unit_x(), mrx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_x(), mrx.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_z(), mrx * DVec3::unit_y());
+    assert_approx_eq!(DVec3::unit_z(), mrx.mul_vec3(DVec3::unit_y()));
+
+    let qrxz = qrz * qrx;
+    assert_approx_eq!(DVec3::unit_y(), qrxz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), qrxz.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_z(), qrxz * DVec3::unit_y());
+    assert_approx_eq!(DVec3::unit_z(), qrxz.mul_vec3(DVec3::unit_y()));
+
+    let mrxz = mrz * mrx;
+    assert_approx_eq!(DVec3::unit_y(), mrxz * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_y(), mrxz.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(DVec3::unit_z(), mrxz * DVec3::unit_y());
+    assert_approx_eq!(DVec3::unit_z(), mrxz.mul_vec3(DVec3::unit_y()));
+
+    let qrzx = qrx * qrz;
+    assert_approx_eq!(DVec3::unit_z(), qrzx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_z(), qrzx.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(-DVec3::unit_x(), qrzx * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), qrzx.mul_vec3(DVec3::unit_y()));
+
+    let mrzx = qrx * qrz;
+    assert_approx_eq!(DVec3::unit_z(), mrzx * DVec3::unit_x());
+    assert_approx_eq!(DVec3::unit_z(), mrzx.mul_vec3(DVec3::unit_x()));
+    assert_approx_eq!(-DVec3::unit_x(), mrzx * DVec3::unit_y());
+    assert_approx_eq!(-DVec3::unit_x(), mrzx.mul_vec3(DVec3::unit_y()));
+}
+
+#[test]
+fn test_quat_funcs() {
+    let q0 = DQuat::from_rotation_ypr(ddeg(45.0), ddeg(180.0), ddeg(90.0));
+    assert!(q0.is_normalized());
+    assert_approx_eq!(q0.length_squared(), 1.0);
+    assert_approx_eq!(q0.length(), 1.0);
+    assert_approx_eq!(q0.length_recip(), 1.0);
+    assert_approx_eq!(q0, q0.normalize());
+
+    assert_approx_eq!(q0.dot(q0), 1.0);
+    assert_approx_eq!(q0.dot(q0), 1.0);
+
+    let q1 = DQuat::from(DVec4::from(q0) * 2.0);
+    assert!(!q1.is_normalized());
+    assert_approx_eq!(q1.length_squared(), 4.0, 1.0e-6);
+    assert_approx_eq!(q1.length(), 2.0);
+    assert_approx_eq!(q1.length_recip(), 0.5);
+    assert_approx_eq!(q0, q1.normalize());
+    assert_approx_eq!(q0.dot(q1), 2.0, 1.0e-6);
+}
+
+#[test]
+fn test_quat_lerp() {
+    let q0 = DQuat::from_rotation_y(ddeg(0.0));
+    let q1 = DQuat::from_rotation_y(ddeg(90.0));
+    assert_approx_eq!(q0, q0.lerp(q1, 0.0));
+    assert_approx_eq!(q1, q0.lerp(q1, 1.0));
+    assert_approx_eq!(DQuat::from_rotation_y(ddeg(45.0)), q0.lerp(q1, 0.5));
+}
+
+#[test]
+fn test_quat_slerp() {
+    let q0 = DQuat::from_rotation_y(ddeg(0.0));
+    let q1 = DQuat::from_rotation_y(ddeg(90.0));
+    assert_approx_eq!(q0, q0.slerp(q1, 0.0), 1.0e-3);
+    assert_approx_eq!(q1, q0.slerp(q1, 1.0), 1.0e-3);
+    assert_approx_eq!(
+        DQuat::from_rotation_y(ddeg(45.0)),
+        q0.slerp(q1, 0.5),
+        1.0e-3
+    );
+}
+
+#[test]
+fn test_quat_slerp_constant_speed() {
+    let step = 0.01;
+    let mut s = 0.0;
+    while s <= 1.0 {
+        let q0 = DQuat::from_rotation_y(ddeg(0.0));
+        let q1 = DQuat::from_rotation_y(ddeg(90.0));
+        assert_approx_eq!(
+            DQuat::from_rotation_y(ddeg(s * 90.0)),
+            q0.slerp(q1, s),
+            1.0e-3
+        );
+        s += step;
+    }
+}
+
+#[test]
+fn test_quat_fmt() {
+    let a = DQuat::identity();
+    assert_eq!(format!("{:?}", a), "DQuat(0.0, 0.0, 0.0, 1.0)");
+    // assert_eq!(
+    //     format!("{:#?}", a),
+    //     "DQuat(\n    1.0,\n    2.0,\n    3.0,\n    4.0\n)"
+    // );
+    assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
+}
+
+#[test]
+fn test_quat_identity() {
+    let identity = DQuat::identity();
+    assert!(identity.is_near_identity());
+    assert!(identity.is_normalized());
+    assert_eq!(identity, DQuat::from_xyzw(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(identity, identity * identity);
+    let q = DQuat::from_rotation_ypr(ddeg(10.0), ddeg(-10.0), ddeg(45.0));
+    assert_eq!(q, q * identity);
+    assert_eq!(q, identity * q);
+    assert_eq!(identity, DQuat::default());
+}
+
+#[test]
+fn test_quat_slice() {
+    let a: [f64; 4] = DQuat::from_rotation_ypr(ddeg(30.0), ddeg(60.0), ddeg(90.0)).into();
+    let b = DQuat::from_slice_unaligned(&a);
+    let c: [f64; 4] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0.0, 0.0, 0.0, 0.0];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_quat_elements() {
+    let x = 1.0;
+    let y = 2.0;
+    let z = 3.0;
+    let w = 4.0;
+
+    let a = DQuat::from_xyzw(x, y, z, w);
+    assert!(a.x == x);
+    assert!(a.y == y);
+    assert!(a.z == z);
+    assert!(a.w == w);
+}
+
+#[test]
+fn test_quat_addition() {
+    let a = DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    let b = DQuat::from_xyzw(5.0, 6.0, 7.0, -9.0);
+    assert_eq!(a + b, DQuat::from_xyzw(6.0, 8.0, 10.0, -5.0));
+}
+
+#[test]
+fn test_quat_subtraction() {
+    let a = DQuat::from_xyzw(6.0, 8.0, 10.0, -5.0);
+    let b = DQuat::from_xyzw(5.0, 6.0, 7.0, -9.0);
+    assert_eq!(a - b, DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn test_quat_f64_multiplication() {
+    let a = DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(a * 2.0, DQuat::from_xyzw(2.0, 4.0, 6.0, 8.0));
+}
+
+#[test]
+fn test_quat_f64_division() {
+    let a = DQuat::from_xyzw(2.0, 4.0, 6.0, 8.0);
+    assert_eq!(a / 2.0, DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_quat_serde() {
+    let a = DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DQuat>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DQuat>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DQuat>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DQuat>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DQuat>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_quat_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: DQuat = rng1.gen();
+    assert!(a.is_normalized());
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: DQuat = rng2.gen();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let two = dquat(2.0, 2.0, 2.0, 2.0);
+    assert_eq!([two, two].iter().sum::<DQuat>(), two + two);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = dquat(2.0, 2.0, 2.0, 2.0).normalize();
+    assert_eq!([two, two].iter().product::<DQuat>(), two * two);
+}
+
+#[test]
+fn test_quat_is_finite() {
+    use std::f64::INFINITY;
+    use std::f64::NAN;
+    use std::f64::NEG_INFINITY;
+    assert!(DQuat::from_xyzw(0.0, 0.0, 0.0, 0.0).is_finite());
+    assert!(DQuat::from_xyzw(-1e-10, 1.0, 1e10, 42.0).is_finite());
+    assert!(!DQuat::from_xyzw(INFINITY, 0.0, 0.0, 0.0).is_finite());
+    assert!(!DQuat::from_xyzw(0.0, NAN, 0.0, 0.0).is_finite());
+    assert!(!DQuat::from_xyzw(0.0, 0.0, NEG_INFINITY, 0.0).is_finite());
+    assert!(!DQuat::from_xyzw(0.0, 0.0, 0.0, NAN).is_finite());
+}
+
+#[test]
+fn test_quat_as_f32() {
+    let a = DQuat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    let b = glam::Quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(b, a.as_f32());
+    assert_eq!(a, b.as_f64());
+}
+
+#[test]
+fn test_dquat_ln_exp() {
+    let q = DQuat::from_axis_angle(DVec3::new(1.0, 2.0, 3.0).normalize(), ddeg(120.0));
+    let ln = q.ln();
+    assert_approx_eq!(0.0, ln.w, 1.0e-12);
+    assert_approx_eq!(ddeg(60.0), DVec3::new(ln.x, ln.y, ln.z).length(), 1.0e-12);
+    assert_approx_eq!(q, ln.exp(), 1.0e-12);
+    assert_approx_eq!(q * 2.0, (q * 2.0).ln().exp(), 1.0e-12);
+    assert_eq!(DQuat::identity(), DQuat::identity().ln().exp());
+    assert_eq!(DQuat::from_xyzw(0.0, 0.0, 0.0, 0.0), DQuat::identity().ln());
+}
+
+#[test]
+fn test_dquat_powf() {
+    let axis = DVec3::new(1.0, -1.0, 2.0).normalize();
+    let q = DQuat::from_axis_angle(axis, ddeg(90.0));
+    assert_approx_eq!(
+        DQuat::from_axis_angle(axis, ddeg(45.0)),
+        q.powf(0.5),
+        1.0e-12
+    );
+    assert_approx_eq!(
+        DQuat::from_axis_angle(axis, ddeg(270.0)),
+        q.powf(3.0),
+        1.0e-12
+    );
+    assert_approx_eq!(q.conjugate(), q.powf(-1.0), 1.0e-12);
+    assert_approx_eq!(DQuat::identity(), q.powf(0.0), 1.0e-12);
+}
+
+#[test]
+fn test_dquat_scaled_axis() {
+    let v = DVec3::new(0.5, -1.0, 0.25);
+    let q = DQuat::from_scaled_axis(v);
+    assert_approx_eq!(
+        DQuat::from_axis_angle(v.normalize(), v.length()),
+        q,
+        1.0e-12
+    );
+    assert_approx_eq!(v, q.to_scaled_axis(), 1.0e-12);
+    assert_eq!(DQuat::identity(), DQuat::from_scaled_axis(DVec3::zero()));
+    assert_eq!(DVec3::zero(), DQuat::identity().to_scaled_axis());
+}
+
+#[test]
+fn test_dquat_squad() {
+    let keys = [
+        DQuat::identity(),
+        DQuat::from_rotation_y(ddeg(90.0)),
+        DQuat::from_rotation_y(ddeg(90.0)) * DQuat::from_rotation_x(ddeg(60.0)),
+        DQuat::from_rotation_z(ddeg(45.0)),
+    ];
+    let tangent = |i: usize| {
+        let prev = keys[if i > 0 { i - 1 } else { i }];
+        let next = keys[if i + 1 < keys.len() { i + 1 } else { i }];
+        keys[i].squad_tangent(prev, next)
+    };
+    let segment = |i: usize, s: f64| keys[i].squad(keys[i + 1], tangent(i), tangent(i + 1), s);
+
+    // stays normalized along the path
+    for i in 0..keys.len() - 1 {
+        for j in 0..=10 {
+            assert!(segment(i, j as f64 * 0.1).is_normalized());
+        }
+    }
+
+    // passes through the keyframes
+    for i in 0..keys.len() - 1 {
+        assert_approx_eq!(keys[i], segment(i, 0.0), 1.0e-12);
+        assert_approx_eq!(keys[i + 1], segment(i, 1.0), 1.0e-12);
+    }
+
+    // the angular velocity is continuous at the inner keyframes
+    let h = 0.0001;
+    for i in 1..keys.len() - 1 {
+        let before = (segment(i - 1, 1.0 - h).conjugate() * segment(i - 1, 1.0)).to_scaled_axis();
+        let after = (segment(i, 0.0).conjugate() * segment(i, h)).to_scaled_axis();
+        assert_approx_eq!(before, after, 1.0e-6);
+    }
+}
+
+#[test]
+fn test_dquat_from_rotation_arc() {
+    let from = DVec3::new(1.0, 2.0, -0.5).normalize();
+    let to = DVec3::new(-0.5, 1.0, 2.0).normalize();
+    let q = DQuat::from_rotation_arc(from, to);
+    assert!(q.is_normalized());
+    assert_approx_eq!(to, q * from, 1.0e-12);
+    // the rotation axis is perpendicular to both vectors
+    let (axis, _) = q.to_axis_angle();
+    assert_approx_eq!(0.0, axis.dot(from), 1.0e-9);
+    assert_approx_eq!(0.0, axis.dot(to), 1.0e-9);
+
+    assert_eq!(DQuat::identity(), DQuat::from_rotation_arc(from, from));
+    for &v in [DVec3::unit_x(), DVec3::unit_y(), DVec3::unit_z(), from].iter() {
+        let q = DQuat::from_rotation_arc(v, -v);
+        assert!(q.is_normalized());
+        assert_approx_eq!(-v, q * v, 1.0e-12);
+    }
+
+    let q = DQuat::from_rotation_arc_colinear(from, -to);
+    assert_approx_eq!(to, q * from, 1.0e-12);
+    assert_approx_eq!(DQuat::from_rotation_arc(from, to), q, 1.0e-12);
+    assert_eq!(
+        DQuat::identity(),
+        DQuat::from_rotation_arc_colinear(from, -from)
+    );
+
+    let q = DQuat::from_rotation_arc_2d(DVec2::unit_x(), DVec2::new(-1.0, 1.0).normalize());
+    assert_approx_eq!(DQuat::from_rotation_z(ddeg(135.0)), q, 1.0e-12);
+    let q = DQuat::from_rotation_arc_2d(DVec2::unit_x(), -DVec2::unit_x());
+    assert_approx_eq!(-DVec3::unit_x(), q * DVec3::unit_x(), 1.0e-12);
+}
+
+#[test]
+fn test_dquat_look_rotation() {
+    let eye = DVec3::new(1.0, 2.0, 3.0);
+    let forward = DVec3::new(-2.0, 0.5, 1.0);
+    let up = DVec3::unit_y();
+
+    let q = DQuat::look_rotation(forward, up);
+    assert!(q.is_normalized());
+    assert_approx_eq!(forward.normalize(), q * DVec3::unit_z(), 1.0e-12);
+    assert_approx_eq!(0.0, (q * DVec3::unit_x()).dot(up), 1.0e-12);
+    assert!((q * DVec3::unit_y()).dot(up) > 0.0);
+    let view = DMat4::look_at_lh(eye, eye + forward, up);
+    let rotation = DQuat::from_rotation_mat4(&view);
+    assert_approx_eq!(1.0, rotation.dot(q.conjugate()).abs(), 1.0e-12);
+
+    let q = DQuat::look_rotation_rh(forward, up);
+    assert_approx_eq!(forward.normalize(), q * -DVec3::unit_z(), 1.0e-12);
+    let view = DMat4::look_at_rh(eye, eye + forward, up);
+    let rotation = DQuat::from_rotation_mat4(&view);
+    assert_approx_eq!(1.0, rotation.dot(q.conjugate()).abs(), 1.0e-12);
+
+    // degenerate inputs
+    assert_eq!(DQuat::identity(), DQuat::look_rotation(DVec3::zero(), up));
+    let q = DQuat::look_rotation(up * 2.0, up);
+    assert!(q.is_normalized());
+    assert_approx_eq!(up, q * DVec3::unit_z(), 1.0e-12);
+    let q = DQuat::look_rotation(-DVec3::unit_z(), DVec3::zero());
+    assert!(q.is_normalized());
+    assert_approx_eq!(-DVec3::unit_z(), q * DVec3::unit_z(), 1.0e-12);
+}
+
+#[test]
+fn test_dquat_swing_twist() {
+    let axis = DVec3::new(1.0, 1.0, 0.0).normalize();
+    let swing_axis = DVec3::new(1.0, -1.0, 2.0).normalize();
+    assert_approx_eq!(0.0, axis.dot(swing_axis), 1.0e-12);
+    let expected_swing = DQuat::from_axis_angle(swing_axis, ddeg(50.0));
+    let expected_twist = DQuat::from_axis_angle(axis, ddeg(-120.0));
+    let q = expected_swing * expected_twist;
+
+    let (swing, twist) = q.swing_twist(axis);
+    assert_approx_eq!(expected_swing, swing, 1.0e-12);
+    assert_approx_eq!(expected_twist, twist, 1.0e-12);
+    assert_approx_eq!(q, swing * twist, 1.0e-12);
+    assert!(twist.w >= 0.0);
+
+    // pure twist and pure swing
+    let (swing, twist) = expected_twist.swing_twist(axis);
+    assert_approx_eq!(DQuat::identity(), swing, 1.0e-12);
+    assert_approx_eq!(expected_twist, twist, 1.0e-12);
+    let (swing, twist) = expected_swing.swing_twist(axis);
+    assert_approx_eq!(expected_swing, swing, 1.0e-12);
+    assert_approx_eq!(DQuat::identity(), twist, 1.0e-12);
+
+    // a half turn swing leaves the twist undefined
+    let half_turn = DQuat::from_axis_angle(swing_axis, ddeg(180.0));
+    assert_eq!((half_turn, DQuat::identity()), half_turn.swing_twist(axis));
+}
+
+#[test]
+fn test_dquat_clamp_twist() {
+    let swing = DQuat::from_rotation_x(ddeg(30.0));
+    let q = swing * DQuat::from_rotation_y(ddeg(100.0));
+    assert_approx_eq!(
+        swing * DQuat::from_rotation_y(ddeg(45.0)),
+        q.clamp_twist(DVec3::unit_y(), ddeg(-10.0), ddeg(45.0)),
+        1.0e-12
+    );
+    let q = swing * DQuat::from_rotation_y(ddeg(-100.0));
+    assert_approx_eq!(
+        swing * DQuat::from_rotation_y(ddeg(-10.0)),
+        q.clamp_twist(DVec3::unit_y(), ddeg(-10.0), ddeg(45.0)),
+        1.0e-12
+    );
+    let q = swing * DQuat::from_rotation_y(ddeg(20.0));
+    assert_eq!(q, q.clamp_twist(DVec3::unit_y(), ddeg(-10.0), ddeg(45.0)));
+}
+
+#[test]
+fn test_dquat_clamp_swing() {
+    let twist = DQuat::from_rotation_z(ddeg(70.0));
+    let (twist_axis, swing_axis) = (DVec3::unit_z(), DVec3::unit_x());
+
+    // inside the cone
+    let q = DQuat::from_rotation_x(ddeg(20.0)) * twist;
+    assert_eq!(
+        q,
+        q.clamp_swing(twist_axis, swing_axis, ddeg(30.0), ddeg(10.0))
+    );
+
+    // outside along each of the axes of the ellipse
+    let q = DQuat::from_rotation_x(ddeg(50.0)) * twist;
+    assert_approx_eq!(
+        DQuat::from_rotation_x(ddeg(30.0)) * twist,
+        q.clamp_swing(twist_axis, swing_axis, ddeg(30.0), ddeg(10.0)),
+        1.0e-12
+    );
+    let q = DQuat::from_rotation_y(ddeg(-50.0)) * twist;
+    assert_approx_eq!(
+        DQuat::from_rotation_y(ddeg(-10.0)) * twist,
+        q.clamp_swing(twist_axis, swing_axis, ddeg(30.0), ddeg(10.0)),
+        1.0e-12
+    );
+
+    // outside diagonally ends up on the boundary of the ellipse
+    let q = DQuat::from_axis_angle(DVec3::new(1.0, 1.0, 0.0).normalize(), ddeg(60.0)) * twist;
+    let clamped = q.clamp_swing(twist_axis, swing_axis, ddeg(30.0), ddeg(10.0));
+    let (swing, clamped_twist) = clamped.swing_twist(twist_axis);
+    assert_approx_eq!(twist, clamped_twist, 1.0e-12);
+    let v = swing.to_scaled_axis();
+    assert_approx_eq!(v.x, v.y, 1.0e-12);
+    assert_approx_eq!(
+        1.0,
+        (v.x / ddeg(30.0)).powi(2) + (v.y / ddeg(10.0)).powi(2),
+        1.0e-10
+    );
+
+    // a zero limit removes the swing entirely
+    let q = DQuat::from_rotation_x(ddeg(50.0)) * twist;
+    assert_approx_eq!(
+        twist,
+        q.clamp_swing(twist_axis, swing_axis, 0.0, 0.0),
+        1.0e-12
+    );
+}
+
+#[test]
+fn test_dquat_average() {
+    assert_eq!(DQuat::identity(), DQuat::average(&[], None));
+    assert_eq!(DQuat::identity(), DQuat::average_approx(&[], None));
+
+    // rotations spread symmetrically around `base` average to `base`
+    let base = DQuat::from_rotation_ypr(ddeg(30.0), ddeg(-20.0), ddeg(10.0));
+    let mut quats = Vec::new();
+    for &axis in [
+        DVec3::unit_x(),
+        DVec3::unit_y(),
+        DVec3::new(1.0, 1.0, 1.0).normalize(),
+    ]
+    .iter()
+    {
+        quats.push(base * DQuat::from_axis_angle(axis, ddeg(80.0)));
+        quats.push(base * DQuat::from_axis_angle(axis, ddeg(-80.0)));
+    }
+    // flipping the sign of a quaternion doesn't change the rotation it represents
+    quats[1] = -quats[1];
+    quats[4] = -quats[4];
+    assert_approx_eq!(base, DQuat::average(&quats, None), 1.0e-12);
+    // the result is independent of the order and in the hemisphere of the first quaternion
+    quats.reverse();
+    quats[0] = -quats[0];
+    assert_approx_eq!(-base, DQuat::average(&quats, None), 1.0e-12);
+    quats[0] = -quats[0];
+    quats.reverse();
+
+    // weights
+    let weights = [1.0, 1.0, 2.0, 2.0, 0.5, 0.5];
+    let a = DQuat::average(&quats, Some(&weights));
+    assert_approx_eq!(base, a, 1.0e-12);
+    let weights = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    // `quats[1]` was flipped so it is in the opposite hemisphere to `quats[0]`
+    assert_approx_eq!(-quats[1], DQuat::average(&quats, Some(&weights)), 1.0e-12);
+    assert_eq!(
+        DQuat::identity(),
+        DQuat::average(&quats, Some(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0]))
+    );
+
+    // the approximation is close for nearby rotations
+    let quats = [
+        base,
+        base * DQuat::from_rotation_x(ddeg(5.0)),
+        -(base * DQuat::from_rotation_y(ddeg(-4.0))),
+        base * DQuat::from_rotation_z(ddeg(3.0)),
+    ];
+    let weights = [1.0, 2.0, 0.5, 1.0];
+    let exact = DQuat::average(&quats, Some(&weights));
+    let approx = DQuat::average_approx(&quats, Some(&weights));
+    assert!(approx.is_normalized());
+    assert_approx_eq!(exact, approx, 1.0e-4);
+}
+
+#[test]
+fn test_dquat_try_normalize() {
+    let q = dquat(1.0, -2.0, 3.0, 4.0);
+    assert_approx_eq!(q.normalize(), q.try_normalize().unwrap());
+    assert_eq!(None, dquat(0.0, 0.0, 0.0, 0.0).try_normalize());
+    assert_eq!(None, dquat(1.0e-200, 0.0, 0.0, 0.0).try_normalize());
+    assert_eq!(None, dquat(std::f64::NAN, 0.0, 0.0, 1.0).try_normalize());
+}
+
+#[test]
+fn test_dquat_normalize_or_zero() {
+    let q = dquat(1.0, -2.0, 3.0, 4.0);
+    assert_approx_eq!(q.normalize(), q.normalize_or_zero());
+    let zero = dquat(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(zero, zero.normalize_or_zero());
+    assert_eq!(zero, dquat(1.0e-200, 0.0, 0.0, 0.0).normalize_or_zero());
+    assert_eq!(
+        zero,
+        dquat(std::f64::NAN, 0.0, 0.0, 1.0).normalize_or_zero()
+    );
+}
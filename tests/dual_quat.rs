@@ -0,0 +1,195 @@
+mod support;
+
+use glam::{vec3, DualQuat, Mat4, Quat, Vec3};
+use support::deg;
+
+fn rotation() -> Quat {
+    Quat::from_axis_angle(vec3(1.0, 2.0, 3.0).normalize(), deg(70.0))
+}
+
+fn translation() -> Vec3 {
+    vec3(1.0, -2.0, 3.0)
+}
+
+#[test]
+fn test_dual_quat_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DualQuat>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<DualQuat>());
+    } else {
+        assert_eq!(16, mem::align_of::<DualQuat>());
+    }
+}
+
+#[test]
+fn test_dual_quat_identity() {
+    let identity = DualQuat::identity();
+    assert_eq!(identity, DualQuat::default());
+    assert!(identity.is_normalized());
+    let p = vec3(1.0, 2.0, 3.0);
+    assert_eq!(p, identity.transform_point3(p));
+    assert_eq!(identity, identity * identity);
+    assert_eq!(
+        (Quat::identity(), Vec3::zero()),
+        identity.to_rotation_translation()
+    );
+    assert_eq!(
+        DualQuat::from_real_dual(
+            Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+            Quat::from_xyzw(0.0, 0.0, 0.0, 0.0)
+        ),
+        DualQuat::zero()
+    );
+}
+
+#[test]
+fn test_dual_quat_rotation_translation() {
+    let dq = DualQuat::from_rotation_translation(rotation(), translation());
+    assert!(dq.is_normalized());
+    let (r, t) = dq.to_rotation_translation();
+    assert_approx_eq!(rotation(), r);
+    assert_approx_eq!(translation(), t, 1.0e-6);
+
+    let p = vec3(-4.0, 0.5, 2.0);
+    assert_approx_eq!(
+        rotation() * p + translation(),
+        dq.transform_point3(p),
+        1.0e-5
+    );
+    assert_approx_eq!(rotation() * p, dq.transform_vector3(p), 1.0e-5);
+
+    assert_approx_eq!(
+        DualQuat::from_translation(translation()) * DualQuat::from_rotation(rotation()),
+        dq,
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_dual_quat_mul_inverse() {
+    let a = DualQuat::from_rotation_translation(rotation(), translation());
+    let b =
+        DualQuat::from_rotation_translation(Quat::from_rotation_y(deg(-40.0)), vec3(0.5, 0.0, 1.0));
+    let p = vec3(2.0, 1.0, -1.0);
+    assert_approx_eq!(
+        a.transform_point3(b.transform_point3(p)),
+        (a * b).transform_point3(p),
+        1.0e-5
+    );
+    let mut c = a;
+    c *= b;
+    assert_eq!(a * b, c);
+
+    assert_approx_eq!(DualQuat::identity(), a * a.inverse(), 1.0e-6);
+    assert_approx_eq!(DualQuat::identity(), a.conjugate() * a, 1.0e-6);
+}
+
+#[test]
+fn test_dual_quat_normalize() {
+    let dq = DualQuat::from_rotation_translation(rotation(), translation());
+    let scaled = DualQuat::from_real_dual(dq.real * 2.5, dq.dual * 2.5 + dq.real * 0.1);
+    assert!(!scaled.is_normalized());
+    let normalized = scaled.normalize();
+    assert!(normalized.is_normalized());
+    assert_approx_eq!(dq.real, normalized.real, 1.0e-6);
+    assert_approx_eq!(dq.dual, normalized.dual, 1.0e-6);
+}
+
+#[test]
+fn test_dual_quat_mat4() {
+    let dq = DualQuat::from_rotation_translation(rotation(), translation());
+    let m = Mat4::from(dq);
+    assert_approx_eq!(
+        Mat4::from_rotation_translation(rotation(), translation()),
+        m
+    );
+    assert_approx_eq!(dq, DualQuat::from_mat4(&m), 1.0e-6);
+}
+
+#[test]
+fn test_dual_quat_sclerp() {
+    let a =
+        DualQuat::from_rotation_translation(Quat::from_rotation_z(deg(10.0)), vec3(1.0, 0.0, 0.0));
+    let b =
+        DualQuat::from_rotation_translation(Quat::from_rotation_z(deg(100.0)), vec3(0.0, 2.0, 1.0));
+    assert_approx_eq!(a, a.sclerp(&b, 0.0), 1.0e-6);
+    assert_approx_eq!(b, a.sclerp(&b, 1.0), 1.0e-5);
+
+    // the rotation is interpolated at constant speed
+    let mid = a.sclerp(&b, 0.5);
+    assert!(mid.is_normalized());
+    assert_approx_eq!(Quat::from_rotation_z(deg(55.0)), mid.real, 1.0e-5);
+    // the translation along the screw axis is interpolated linearly
+    assert_approx_eq!(0.5, mid.translation().z, 1.0e-5);
+
+    // both signs of `b` give the same result
+    let neg_b = DualQuat::from_real_dual(-b.real, -b.dual);
+    assert_approx_eq!(mid, a.sclerp(&neg_b, 0.5), 1.0e-5);
+
+    // pure translations
+    let a = DualQuat::from_translation(vec3(1.0, 2.0, 3.0));
+    let b = DualQuat::from_translation(vec3(3.0, 2.0, -1.0));
+    assert_approx_eq!(
+        DualQuat::from_translation(vec3(1.5, 2.0, 2.0)),
+        a.sclerp(&b, 0.25),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_dual_quat_blend() {
+    let a =
+        DualQuat::from_rotation_translation(Quat::from_rotation_x(deg(20.0)), vec3(1.0, 0.0, 0.0));
+    let b =
+        DualQuat::from_rotation_translation(Quat::from_rotation_x(deg(80.0)), vec3(1.0, 2.0, 0.0));
+    assert_eq!(DualQuat::identity(), DualQuat::blend(&[], &[]));
+    assert_approx_eq!(a, DualQuat::blend(&[a], &[1.0]), 1.0e-6);
+    assert_approx_eq!(a, DualQuat::blend(&[a, b], &[2.0, 0.0]), 1.0e-6);
+
+    let blended = DualQuat::blend(&[a, b], &[0.5, 0.5]);
+    assert!(blended.is_normalized());
+    assert_approx_eq!(Quat::from_rotation_x(deg(50.0)), blended.real, 1.0e-5);
+    // both signs of `b` give the same result
+    let neg_b = DualQuat::from_real_dual(-b.real, -b.dual);
+    assert_approx_eq!(blended, DualQuat::blend(&[a, neg_b], &[0.5, 0.5]), 1.0e-6);
+}
+
+#[cfg(feature = "transform-types")]
+#[test]
+fn test_dual_quat_transform_rt() {
+    use glam::TransformRT;
+    let rt = TransformRT::from_rotation_translation(rotation(), translation());
+    let dq = DualQuat::from(rt);
+    assert_approx_eq!(
+        DualQuat::from_rotation_translation(rotation(), translation()),
+        dq
+    );
+    let back = TransformRT::from(dq);
+    assert_approx_eq!(rt.rotation, back.rotation);
+    assert_approx_eq!(rt.translation, back.translation, 1.0e-6);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_dual_quat_serde() {
+    let a = DualQuat::from_real_dual(
+        Quat::from_xyzw(1.0, 2.0, 3.0, 4.0),
+        Quat::from_xyzw(5.0, 6.0, 7.0, 8.0),
+    );
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DualQuat>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DualQuat>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let a = DualQuat::from_rotation_translation(rotation(), translation());
+    assert_eq!([a, a].iter().product::<DualQuat>(), a * a);
+}
@@ -0,0 +1,587 @@
+mod support;
+
+use glam::{dvec2, dvec3, DMat2, DVec2, DVec2Mask, DVec3};
+use std::f64;
+
+#[test]
+fn test_vec2_align() {
+    use core::mem;
+    assert_eq!(16, mem::size_of::<DVec2>());
+    assert_eq!(8, mem::align_of::<DVec2>());
+    assert_eq!(8, mem::size_of::<DVec2Mask>());
+    assert_eq!(4, mem::align_of::<DVec2Mask>());
+}
+
+#[test]
+fn test_vec2_new() {
+    let v = dvec2(1.0, 2.0);
+
+    assert_eq!(v.x, 1.0);
+    assert_eq!(v.y, 2.0);
+
+    let t = (1.0, 2.0);
+    let v = DVec2::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1.0, 2.0];
+    let v = DVec2::from(a);
+    let a1: [f64; 2] = v.into();
+    assert_eq!(a, a1);
+
+    let v = DVec2::new(t.0, t.1);
+    assert_eq!(t, v.into());
+
+    assert_eq!(DVec2::new(1.0, 0.0), DVec2::unit_x());
+    assert_eq!(DVec2::new(0.0, 1.0), DVec2::unit_y());
+}
+
+#[test]
+fn test_vec2_fmt() {
+    let a = DVec2::new(1.0, 2.0);
+    assert_eq!(format!("{:?}", a), "DVec2(1.0, 2.0)");
+    // assert_eq!(format!("{:#?}", a), "DVec2(\n    1.0,\n    2.0\n)");
+    assert_eq!(format!("{}", a), "[1, 2]");
+}
+
+#[test]
+fn test_vec2_zero() {
+    let v = DVec2::zero();
+    assert_eq!(dvec2(0.0, 0.0), v);
+    assert_eq!(v, DVec2::default());
+}
+
+#[test]
+fn test_vec2_splat() {
+    let v = DVec2::splat(1.0);
+    assert_eq!(dvec2(1.0, 1.0), v);
+}
+
+#[test]
+fn test_vec2_accessors() {
+    let mut a = DVec2::zero();
+    a.x = 1.0;
+    a.y = 2.0;
+    assert_eq!(1.0, a.x);
+    assert_eq!(2.0, a.y);
+    assert_eq!(DVec2::new(1.0, 2.0), a);
+
+    let mut a = DVec2::zero();
+    a[0] = 1.0;
+    a[1] = 2.0;
+    assert_eq!(1.0, a[0]);
+    assert_eq!(2.0, a[1]);
+    assert_eq!(DVec2::new(1.0, 2.0), a);
+}
+
+#[test]
+fn test_vec2_funcs() {
+    let x = dvec2(1.0, 0.0);
+    let y = dvec2(0.0, 1.0);
+    assert_eq!(1.0, x.dot(x));
+    assert_eq!(0.0, x.dot(y));
+    assert_eq!(-1.0, x.dot(-x));
+    assert_eq!(4.0, (2.0 * x).length_squared());
+    assert_eq!(9.0, (-3.0 * y).length_squared());
+    assert_eq!(2.0, (-2.0 * x).length());
+    assert_eq!(3.0, (3.0 * y).length());
+    assert_eq!(2.0, x.distance_squared(y));
+    assert_eq!(13.0, (2.0 * x).distance_squared(-3.0 * y));
+    assert_eq!(2.0_f64.sqrt(), x.distance(y));
+    assert_eq!(5.0, (3.0 * x).distance(-4.0 * y));
+    assert_eq!(13.0, (-5.0 * x).distance(12.0 * y));
+    assert_eq!(x, (2.0 * x).normalize());
+    assert_eq!(1.0 * 3.0 + 2.0 * 4.0, dvec2(1.0, 2.0).dot(dvec2(3.0, 4.0)));
+    assert_eq!(2.0 * 2.0 + 3.0 * 3.0, dvec2(2.0, 3.0).length_squared());
+    assert_eq!((2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(), dvec2(2.0, 3.0).length());
+    assert_eq!(
+        1.0 / (2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(),
+        dvec2(2.0, 3.0).length_recip()
+    );
+    assert!(dvec2(2.0, 3.0).normalize().is_normalized());
+    assert_eq!(
+        dvec2(2.0, 3.0) / (2.0_f64 * 2.0 + 3.0 * 3.0).sqrt(),
+        dvec2(2.0, 3.0).normalize()
+    );
+    assert_eq!(dvec2(0.5, 0.25), dvec2(2.0, 4.0).recip());
+}
+
+#[test]
+fn test_vec2_ops() {
+    let a = dvec2(1.0, 2.0);
+    assert_eq!(dvec2(2.0, 4.0), (a + a));
+    assert_eq!(dvec2(0.0, 0.0), (a - a));
+    assert_eq!(dvec2(1.0, 4.0), (a * a));
+    assert_eq!(dvec2(2.0, 4.0), (a * 2.0));
+    assert_eq!(dvec2(2.0, 4.0), (2.0 * a));
+    assert_eq!(dvec2(1.0, 1.0), (a / a));
+    assert_eq!(dvec2(0.5, 1.0), (a / 2.0));
+    assert_eq!(dvec2(2.0, 1.0), (2.0 / a));
+    assert_eq!(dvec2(-1.0, -2.0), (-a));
+}
+
+#[test]
+fn test_vec2_assign_ops() {
+    let a = dvec2(1.0, 2.0);
+    let mut b = a;
+    b += a;
+    assert_eq!(dvec2(2.0, 4.0), b);
+    b -= a;
+    assert_eq!(dvec2(1.0, 2.0), b);
+    b *= a;
+    assert_eq!(dvec2(1.0, 4.0), b);
+    b /= a;
+    assert_eq!(dvec2(1.0, 2.0), b);
+    b *= 2.0;
+    assert_eq!(dvec2(2.0, 4.0), b);
+    b /= 2.0;
+    assert_eq!(dvec2(1.0, 2.0), b);
+}
+
+#[test]
+fn test_vec2_min_max() {
+    let a = dvec2(-1.0, 2.0);
+    let b = dvec2(1.0, -2.0);
+    assert_eq!(dvec2(-1.0, -2.0), a.min(b));
+    assert_eq!(dvec2(-1.0, -2.0), b.min(a));
+    assert_eq!(dvec2(1.0, 2.0), a.max(b));
+    assert_eq!(dvec2(1.0, 2.0), b.max(a));
+}
+
+#[test]
+fn test_vec2_hmin_hmax() {
+    let a = dvec2(-1.0, 2.0);
+    assert_eq!(-1.0, a.min_element());
+    assert_eq!(2.0, a.max_element());
+}
+
+#[test]
+fn test_vec2_eq() {
+    let a = dvec2(1.0, 1.0);
+    let b = dvec2(1.0, 2.0);
+    assert!(a.cmpeq(a).all());
+    assert!(b.cmpeq(b).all());
+    assert!(a.cmpne(b).any());
+    assert!(b.cmpne(a).any());
+    assert!(b.cmpeq(a).any());
+}
+
+#[test]
+fn test_vec2_cmp() {
+    assert!(!DVec2Mask::default().any());
+    assert!(!DVec2Mask::default().all());
+    assert_eq!(DVec2Mask::default().bitmask(), 0x0);
+    let a = dvec2(-1.0, -1.0);
+    let b = dvec2(1.0, 1.0);
+    let c = dvec2(-1.0, -1.0);
+    let d = dvec2(1.0, -1.0);
+    assert_eq!(a.cmplt(a).bitmask(), 0x0);
+    assert_eq!(a.cmplt(b).bitmask(), 0x3);
+    assert_eq!(a.cmplt(d).bitmask(), 0x1);
+    assert_eq!(c.cmple(a).bitmask(), 0x3);
+    assert!(a.cmplt(b).all());
+    assert!(a.cmplt(d).any());
+    assert!(a.cmple(b).all());
+    assert!(a.cmple(a).all());
+    assert!(b.cmpgt(a).all());
+    assert!(b.cmpge(a).all());
+    assert!(b.cmpge(b).all());
+    assert!(!(a.cmpge(d).all()));
+    assert!(c.cmple(c).all());
+    assert!(c.cmpge(c).all());
+    assert!(a == a);
+    assert!(a < b);
+    assert!(b > a);
+}
+
+#[test]
+fn test_extend_truncate() {
+    let a = dvec2(1.0, 2.0);
+    let b = a.extend(3.0);
+    assert_eq!(dvec3(1.0, 2.0, 3.0), b);
+}
+
+#[test]
+fn test_vec2mask() {
+    // make sure the unused 'w' value doesn't break Vec2b behaviour
+    let a = DVec3::zero();
+    let mut b = a.truncate();
+    b.x = 1.0;
+    b.y = 1.0;
+    assert!(!b.cmpeq(DVec2::zero()).any());
+    assert!(b.cmpeq(DVec2::splat(1.0)).all());
+}
+
+#[test]
+fn test_vec2mask_as_ref() {
+    assert_eq!(DVec2Mask::new(false, false).as_ref(), &[0, 0]);
+    assert_eq!(DVec2Mask::new(true, false).as_ref(), &[!0, 0]);
+    assert_eq!(DVec2Mask::new(false, true).as_ref(), &[0, !0]);
+    assert_eq!(DVec2Mask::new(true, true).as_ref(), &[!0, !0]);
+}
+
+#[test]
+fn test_vec2mask_from() {
+    assert_eq!(Into::<[u32; 2]>::into(DVec2Mask::new(false, false)), [0, 0]);
+    assert_eq!(Into::<[u32; 2]>::into(DVec2Mask::new(true, false)), [!0, 0]);
+    assert_eq!(Into::<[u32; 2]>::into(DVec2Mask::new(false, true)), [0, !0]);
+    assert_eq!(Into::<[u32; 2]>::into(DVec2Mask::new(true, true)), [!0, !0]);
+}
+
+#[test]
+fn test_vec2mask_bitmask() {
+    assert_eq!(DVec2Mask::new(false, false).bitmask(), 0b00);
+    assert_eq!(DVec2Mask::new(true, false).bitmask(), 0b01);
+    assert_eq!(DVec2Mask::new(false, true).bitmask(), 0b10);
+    assert_eq!(DVec2Mask::new(true, true).bitmask(), 0b11);
+}
+
+#[test]
+fn test_vec2mask_any() {
+    assert!(!DVec2Mask::new(false, false).any());
+    assert!(DVec2Mask::new(true, false).any());
+    assert!(DVec2Mask::new(false, true).any());
+    assert!(DVec2Mask::new(true, true).any());
+}
+
+#[test]
+fn test_vec2mask_all() {
+    assert!(!DVec2Mask::new(false, false).all());
+    assert!(!DVec2Mask::new(true, false).all());
+    assert!(!DVec2Mask::new(false, true).all());
+    assert!(DVec2Mask::new(true, true).all());
+}
+
+#[test]
+fn test_vec2mask_select() {
+    let a = DVec2::new(1.0, 2.0);
+    let b = DVec2::new(3.0, 4.0);
+    assert_eq!(
+        DVec2Mask::new(true, true).select(a, b),
+        DVec2::new(1.0, 2.0),
+    );
+    assert_eq!(
+        DVec2Mask::new(true, false).select(a, b),
+        DVec2::new(1.0, 4.0),
+    );
+    assert_eq!(
+        DVec2Mask::new(false, true).select(a, b),
+        DVec2::new(3.0, 2.0),
+    );
+    assert_eq!(
+        DVec2Mask::new(false, false).select(a, b),
+        DVec2::new(3.0, 4.0),
+    );
+}
+
+#[test]
+fn test_vec2mask_and() {
+    assert_eq!(
+        (DVec2Mask::new(false, false) & DVec2Mask::new(false, false)).bitmask(),
+        0b00,
+    );
+    assert_eq!(
+        (DVec2Mask::new(true, true) & DVec2Mask::new(true, false)).bitmask(),
+        0b01,
+    );
+    assert_eq!(
+        (DVec2Mask::new(true, false) & DVec2Mask::new(false, true)).bitmask(),
+        0b00,
+    );
+    assert_eq!(
+        (DVec2Mask::new(true, true) & DVec2Mask::new(true, true)).bitmask(),
+        0b11,
+    );
+
+    let mut mask = DVec2Mask::new(true, true);
+    mask &= DVec2Mask::new(true, false);
+    assert_eq!(mask.bitmask(), 0b01);
+}
+
+#[test]
+fn test_vec2mask_or() {
+    assert_eq!(
+        (DVec2Mask::new(false, false) | DVec2Mask::new(false, false)).bitmask(),
+        0b00,
+    );
+    assert_eq!(
+        (DVec2Mask::new(false, false) | DVec2Mask::new(false, true)).bitmask(),
+        0b10,
+    );
+    assert_eq!(
+        (DVec2Mask::new(true, false) | DVec2Mask::new(false, true)).bitmask(),
+        0b11,
+    );
+    assert_eq!(
+        (DVec2Mask::new(true, true) | DVec2Mask::new(true, true)).bitmask(),
+        0b11,
+    );
+
+    let mut mask = DVec2Mask::new(true, true);
+    mask |= DVec2Mask::new(true, false);
+    assert_eq!(mask.bitmask(), 0b11);
+}
+
+#[test]
+fn test_vec2mask_not() {
+    assert_eq!((!DVec2Mask::new(false, false)).bitmask(), 0b11);
+    assert_eq!((!DVec2Mask::new(true, false)).bitmask(), 0b10);
+    assert_eq!((!DVec2Mask::new(false, true)).bitmask(), 0b01);
+    assert_eq!((!DVec2Mask::new(true, true)).bitmask(), 0b00);
+}
+
+#[test]
+fn test_vec2mask_fmt() {
+    let a = DVec2Mask::new(true, false);
+
+    assert_eq!(format!("{:?}", a), "DVec2Mask(0xffffffff, 0x0)");
+    assert_eq!(format!("{}", a), "[true, false]");
+}
+
+#[test]
+fn test_vec2mask_eq() {
+    let a = DVec2Mask::new(true, false);
+    let b = DVec2Mask::new(true, false);
+    let c = DVec2Mask::new(false, true);
+
+    assert_eq!(a, b);
+    assert_eq!(b, a);
+    assert_ne!(a, c);
+    assert_ne!(b, c);
+
+    assert!(a > c);
+    assert!(c < a);
+}
+
+#[test]
+fn test_vec2mask_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+
+    let a = DVec2Mask::new(true, false);
+    let b = DVec2Mask::new(true, false);
+    let c = DVec2Mask::new(false, true);
+
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    let a_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    b.hash(&mut hasher);
+    let b_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    let c_hashed = hasher.finish();
+
+    assert_eq!(a, b);
+    assert_eq!(a_hashed, b_hashed);
+    assert_ne!(a, c);
+    assert_ne!(a_hashed, c_hashed);
+}
+
+#[test]
+fn test_vec2_sign() {
+    assert_eq!(DVec2::zero().signum(), DVec2::one());
+    assert_eq!(-DVec2::zero().signum(), -DVec2::one());
+    assert_eq!(DVec2::one().signum(), DVec2::one());
+    assert_eq!((-DVec2::one()).signum(), -DVec2::one());
+    assert_eq!(DVec2::splat(f64::INFINITY).signum(), DVec2::one());
+    assert_eq!(DVec2::splat(f64::NEG_INFINITY).signum(), -DVec2::one());
+    assert!(DVec2::splat(f64::NAN).signum().is_nan_mask().all());
+}
+
+#[test]
+fn test_vec2_abs() {
+    assert_eq!(DVec2::zero().abs(), DVec2::zero());
+    assert_eq!(DVec2::one().abs(), DVec2::one());
+    assert_eq!((-DVec2::one()).abs(), DVec2::one());
+}
+
+#[test]
+fn test_vec2_round() {
+    assert_eq!(DVec2::new(1.35, 0.0).round().x, 1.0);
+    assert_eq!(DVec2::new(0.0, 1.5).round().y, 2.0);
+    assert_eq!(DVec2::new(0.0, -15.5).round().y, -16.0);
+    assert_eq!(DVec2::new(0.0, 0.0).round().y, 0.0);
+    assert_eq!(DVec2::new(0.0, 21.1).round().y, 21.0);
+    assert_eq!(DVec2::new(0.0, 11.123).round().y, 11.0);
+    assert_eq!(DVec2::new(0.0, 11.499).round().y, 11.0);
+    assert_eq!(
+        DVec2::new(f64::NEG_INFINITY, f64::INFINITY).round(),
+        DVec2::new(f64::NEG_INFINITY, f64::INFINITY)
+    );
+    assert!(DVec2::new(f64::NAN, 0.0).round().x.is_nan());
+}
+
+#[test]
+fn test_vec2_floor() {
+    assert_eq!(DVec2::new(1.35, -1.5).floor(), DVec2::new(1.0, -2.0));
+    assert_eq!(
+        DVec2::new(f64::INFINITY, f64::NEG_INFINITY).floor(),
+        DVec2::new(f64::INFINITY, f64::NEG_INFINITY)
+    );
+    assert!(DVec2::new(f64::NAN, 0.0).floor().x.is_nan());
+    assert_eq!(
+        DVec2::new(-2_000_000.1, 10000000.123).floor(),
+        DVec2::new(-2000001.0, 10000000.0)
+    );
+}
+
+#[test]
+fn test_vec2_ceil() {
+    assert_eq!(DVec2::new(1.35, -1.5).ceil(), DVec2::new(2.0, -1.0));
+    assert_eq!(
+        DVec2::new(f64::INFINITY, f64::NEG_INFINITY).ceil(),
+        DVec2::new(f64::INFINITY, f64::NEG_INFINITY)
+    );
+    assert!(DVec2::new(f64::NAN, 0.0).ceil().x.is_nan());
+    assert_eq!(
+        DVec2::new(-2_000_000.1, 1_000_000.1).ceil(),
+        DVec2::new(-2000000.0, 1000001.0)
+    );
+}
+
+#[test]
+fn test_vec2_lerp() {
+    let v0 = DVec2::new(-1.0, -1.0);
+    let v1 = DVec2::new(1.0, 1.0);
+    assert_approx_eq!(v0, v0.lerp(v1, 0.0));
+    assert_approx_eq!(v1, v0.lerp(v1, 1.0));
+    assert_approx_eq!(DVec2::zero(), v0.lerp(v1, 0.5));
+}
+
+#[test]
+fn test_vec2_to_from_slice() {
+    let v = DVec2::new(1.0, 2.0);
+    let mut a = [0.0, 0.0];
+    v.write_to_slice_unaligned(&mut a);
+    assert_eq!(v, DVec2::from_slice_unaligned(&a));
+}
+
+#[test]
+fn test_vec2_angle_between() {
+    let angle = DVec2::new(1.0, 0.0).angle_between(DVec2::new(0.0, 1.0));
+    assert_approx_eq!(f64::consts::FRAC_PI_2, angle, 1e-6);
+
+    let angle = DVec2::new(10.0, 0.0).angle_between(DVec2::new(0.0, 5.0));
+    assert_approx_eq!(f64::consts::FRAC_PI_2, angle, 1e-6);
+
+    let angle = DVec2::new(-1.0, 0.0).angle_between(DVec2::new(0.0, 1.0));
+    assert_approx_eq!(-f64::consts::FRAC_PI_2, angle, 1e-6);
+}
+
+#[test]
+fn test_vec2_perp() {
+    let v1 = DVec2::new(1.0, 2.0);
+    let v2 = DVec2::new(1.0, 1.0);
+    let v1_perp = DVec2::new(-2.0, 1.0);
+    let rot90 = DMat2::from_angle(90.0_f64.to_radians());
+
+    assert_eq!(v1_perp, v1.perp());
+    assert_eq!(v1.perp().dot(v1), 0.0);
+    assert_eq!(v2.perp().dot(v2), 0.0);
+    assert_eq!(v1.perp().dot(v2), v1.perp_dot(v2));
+
+    assert_approx_eq!(v1.perp(), rot90 * v1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec2_serde() {
+    let a = DVec2::new(1.0, 2.0);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DVec2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DVec2>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DVec2>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_vec2_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (f64, f64) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: DVec2 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let one = DVec2::one();
+    assert_eq!([one, one].iter().sum::<DVec2>(), one + one);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = DVec2::new(2.0, 2.0);
+    assert_eq!([two, two].iter().product::<DVec2>(), two * two);
+}
+
+#[test]
+fn test_vec2_is_finite() {
+    use std::f64::INFINITY;
+    use std::f64::NAN;
+    use std::f64::NEG_INFINITY;
+    assert!(DVec2::new(0.0, 0.0).is_finite());
+    assert!(DVec2::new(-1e-10, 1e10).is_finite());
+    assert!(!DVec2::new(INFINITY, 0.0).is_finite());
+    assert!(!DVec2::new(0.0, NAN).is_finite());
+    assert!(!DVec2::new(0.0, NEG_INFINITY).is_finite());
+    assert!(!DVec2::new(INFINITY, NEG_INFINITY).is_finite());
+}
+
+#[test]
+fn test_powf() {
+    assert_eq!(DVec2::new(2.0, 4.0).powf(2.0), DVec2::new(4.0, 16.0));
+}
+
+#[test]
+fn test_exp() {
+    assert_eq!(
+        DVec2::new(1.0, 2.0).exp(),
+        DVec2::new(1.0_f64.exp(), 2.0_f64.exp())
+    );
+}
+
+#[test]
+fn test_vec2_as_f32() {
+    let a = DVec2::new(1.0, 2.0);
+    let b = glam::Vec2::new(1.0, 2.0);
+    assert_eq!(b, a.as_f32());
+    assert_eq!(a, b.as_f64());
+}
+
+#[test]
+fn test_dvec2_try_normalize() {
+    assert_approx_eq!(
+        DVec2::unit_x(),
+        (DVec2::unit_x() * 3.0).try_normalize().unwrap()
+    );
+    assert_approx_eq!(
+        DVec2::splat(-1.0).normalize(),
+        DVec2::splat(-1.0).normalize_or_zero()
+    );
+    for &v in [
+        DVec2::zero(),
+        DVec2::splat(1.0e-200),
+        DVec2::splat(std::f64::MAX),
+        DVec2::splat(std::f64::NAN),
+        DVec2::unit_x() * std::f64::INFINITY,
+    ]
+    .iter()
+    {
+        assert_eq!(None, v.try_normalize());
+        assert_eq!(DVec2::zero(), v.normalize_or_zero());
+    }
+}
@@ -0,0 +1,128 @@
+mod support;
+
+use glam::{DQuat, EulerRot, Mat3, Mat3A, Mat4, Quat};
+use support::{ddeg, deg};
+
+const ALL: [EulerRot; 12] = [
+    EulerRot::ZYX,
+    EulerRot::ZXY,
+    EulerRot::YXZ,
+    EulerRot::YZX,
+    EulerRot::XYZ,
+    EulerRot::XZY,
+    EulerRot::ZYZ,
+    EulerRot::ZXZ,
+    EulerRot::YXY,
+    EulerRot::YZY,
+    EulerRot::XYX,
+    EulerRot::XZX,
+];
+
+fn is_proper(euler: EulerRot) -> bool {
+    match euler {
+        EulerRot::ZYZ
+        | EulerRot::ZXZ
+        | EulerRot::YXY
+        | EulerRot::YZY
+        | EulerRot::XYX
+        | EulerRot::XZX => true,
+        _ => false,
+    }
+}
+
+fn axis_quat(axis: char, angle: f32) -> Quat {
+    match axis {
+        'X' => Quat::from_rotation_x(angle),
+        'Y' => Quat::from_rotation_y(angle),
+        _ => Quat::from_rotation_z(angle),
+    }
+}
+
+#[test]
+fn test_euler_default() {
+    assert_eq!(EulerRot::YXZ, EulerRot::default());
+}
+
+#[test]
+fn test_from_euler() {
+    let (a, b, c) = (deg(10.0), deg(20.0), deg(30.0));
+    for &euler in ALL.iter() {
+        let axes: Vec<char> = format!("{:?}", euler).chars().collect();
+        let expected = axis_quat(axes[0], a) * axis_quat(axes[1], b) * axis_quat(axes[2], c);
+        let q = Quat::from_euler(euler, a, b, c);
+        assert_approx_eq!(expected, q, 1e-6);
+        assert_approx_eq!(Mat3::from_quat(q), Mat3::from_euler(euler, a, b, c), 1e-6);
+        assert_approx_eq!(Mat3A::from_quat(q), Mat3A::from_euler(euler, a, b, c), 1e-6);
+        assert_approx_eq!(Mat4::from_quat(q), Mat4::from_euler(euler, a, b, c), 1e-6);
+    }
+    assert_approx_eq!(
+        Quat::from_rotation_ypr(a, b, c),
+        Quat::from_euler(EulerRot::YXZ, a, b, c),
+        1e-6
+    );
+}
+
+#[test]
+fn test_to_euler() {
+    let angles = [-170.0, -90.0, -45.0, 0.0, 30.0, 120.0, 170.0];
+    for &euler in ALL.iter() {
+        let middle: &[f32] = if is_proper(euler) {
+            &[10.0, 45.0, 90.0, 170.0]
+        } else {
+            &[-80.0, -45.0, 0.0, 30.0, 80.0]
+        };
+        for &a in angles.iter() {
+            for &b in middle.iter() {
+                for &c in angles.iter() {
+                    let (a, b, c) = (deg(a), deg(b), deg(c));
+                    let q = Quat::from_euler(euler, a, b, c);
+                    let (a1, b1, c1) = q.to_euler(euler);
+                    // within the canonical ranges the angles are recovered exactly
+                    assert_approx_eq!(a, a1, 1e-4);
+                    assert_approx_eq!(b, b1, 1e-4);
+                    assert_approx_eq!(c, c1, 1e-4);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_to_euler_gimbal_lock() {
+    for &euler in ALL.iter() {
+        let middle: &[f32] = if is_proper(euler) {
+            &[0.0, 180.0]
+        } else {
+            &[-90.0, 90.0]
+        };
+        for &b in middle.iter() {
+            let q = Quat::from_euler(euler, deg(20.0), deg(b), deg(50.0));
+            let (a1, b1, c1) = q.to_euler(euler);
+            assert_eq!(0.0, c1);
+            assert_approx_eq!(deg(b), b1, 1e-3);
+            assert_approx_eq!(
+                Mat3::from_quat(q),
+                Mat3::from_euler(euler, a1, b1, c1),
+                1e-5
+            );
+        }
+    }
+}
+
+#[test]
+fn test_to_euler_f64() {
+    for &euler in ALL.iter() {
+        let b = if is_proper(euler) { 60.0 } else { -60.0 };
+        let q = DQuat::from_euler(euler, ddeg(-120.0), ddeg(b), ddeg(30.0));
+        let (a1, b1, c1) = q.to_euler(euler);
+        assert!((ddeg(-120.0) - a1).abs() < 1e-10);
+        assert!((ddeg(b) - b1).abs() < 1e-10);
+        assert!((ddeg(30.0) - c1).abs() < 1e-10);
+
+        let b = if is_proper(euler) { 180.0 } else { -90.0 };
+        let q = DQuat::from_euler(euler, ddeg(-120.0), ddeg(b), ddeg(30.0));
+        let (a1, b1, c1) = q.to_euler(euler);
+        assert_eq!(0.0, c1);
+        assert!(DQuat::from_euler(euler, a1, b1, c1).dot(q).abs() > 1.0 - 1e-10);
+    }
+}
@@ -0,0 +1,419 @@
+mod support;
+
+use glam::geom::{Aabb3, Capsule, Obb, Plane, Ray3, Sphere, Triangle3};
+use glam::{vec3, Affine3A, Mat3, Mat4, Quat, Vec3};
+use support::deg;
+
+#[test]
+fn test_aabb3() {
+    let points = [
+        vec3(1.0, -2.0, 3.0),
+        vec3(-1.0, 2.0, 0.0),
+        vec3(0.0, 0.0, -3.0),
+    ];
+    let aabb = Aabb3::from_points(&points);
+    assert_eq!(vec3(-1.0, -2.0, -3.0), aabb.min);
+    assert_eq!(vec3(1.0, 2.0, 3.0), aabb.max);
+    assert_eq!(Vec3::zero(), aabb.center());
+    assert_eq!(vec3(1.0, 2.0, 3.0), aabb.half_extents());
+    assert_eq!(vec3(2.0, 4.0, 6.0), aabb.size());
+    assert_eq!(
+        aabb,
+        Aabb3::from_center_half_extents(Vec3::zero(), vec3(1.0, 2.0, 3.0))
+    );
+    assert!(!aabb.is_empty());
+    assert!(Aabb3::empty().is_empty());
+    assert!(Aabb3::from_points(&[]).is_empty());
+
+    assert!(aabb.contains_point(vec3(1.0, 0.0, -3.0)));
+    assert!(!aabb.contains_point(vec3(1.1, 0.0, 0.0)));
+    assert_eq!(vec3(0.5, 0.5, 0.5), aabb.closest_point(vec3(0.5, 0.5, 0.5)));
+    assert_eq!(vec3(1.0, 2.0, 0.0), aabb.closest_point(vec3(4.0, 6.0, 0.0)));
+    assert_eq!(5.0, aabb.distance_to_point(vec3(4.0, 6.0, 0.0)));
+    assert_eq!(0.0, aabb.distance_to_point(Vec3::zero()));
+
+    let other = Aabb3::new(vec3(0.0, 0.0, 0.0), vec3(2.0, 1.0, 5.0));
+    let union = aabb.union(&other);
+    assert_eq!(vec3(-1.0, -2.0, -3.0), union.min);
+    assert_eq!(vec3(2.0, 2.0, 5.0), union.max);
+    assert_eq!(union, union.union(&Aabb3::empty()));
+
+    let grown = aabb.grow(vec3(0.0, -5.0, 0.0));
+    assert_eq!(vec3(-1.0, -5.0, -3.0), grown.min);
+    assert_eq!(aabb.max, grown.max);
+    assert_eq!(
+        Aabb3::new(Vec3::one(), Vec3::one()),
+        Aabb3::empty().grow(Vec3::one())
+    );
+}
+
+#[test]
+fn test_aabb3_transform() {
+    let aabb = Aabb3::new(vec3(-1.0, -2.0, -3.0), vec3(1.0, 2.0, 3.0));
+    let transform =
+        Affine3A::from_rotation_translation(Quat::from_rotation_z(deg(90.0)), vec3(10.0, 0.0, 0.0));
+    let transformed = aabb.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(8.0, -1.0, -3.0), transformed.min, 1e-6);
+    assert_approx_eq!(vec3(12.0, 1.0, 3.0), transformed.max, 1e-6);
+
+    // the result must contain every transformed corner
+    let transform = Affine3A::from_scale_rotation_translation(
+        vec3(2.0, 0.5, 1.0),
+        Quat::from_rotation_ypr(deg(30.0), deg(20.0), deg(10.0)),
+        vec3(1.0, 2.0, 3.0),
+    );
+    let transformed = aabb.transform_mat4(&Mat4::from(transform));
+    for i in 0..8 {
+        let corner = vec3(
+            if i & 1 == 0 { aabb.min.x } else { aabb.max.x },
+            if i & 2 == 0 { aabb.min.y } else { aabb.max.y },
+            if i & 4 == 0 { aabb.min.z } else { aabb.max.z },
+        );
+        let p = transform.transform_point3(corner);
+        assert!(transformed.distance_to_point(p) < 1e-5);
+    }
+}
+
+#[test]
+fn test_ray3() {
+    let ray = Ray3::from_points(vec3(1.0, 0.0, 0.0), vec3(1.0, 0.0, 5.0));
+    assert_eq!(Vec3::unit_z(), ray.direction);
+    assert_eq!(vec3(1.0, 0.0, 2.0), ray.at(2.0));
+    assert_eq!(vec3(1.0, 0.0, 3.0), ray.closest_point(vec3(1.0, 4.0, 3.0)));
+    assert_eq!(vec3(1.0, 0.0, 0.0), ray.closest_point(vec3(1.0, 0.0, -3.0)));
+    assert_eq!(4.0, ray.distance_to_point(vec3(1.0, 4.0, 3.0)));
+
+    let transform = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::from_rotation_y(deg(90.0)),
+        vec3(0.0, 1.0, 0.0),
+    );
+    let transformed = ray.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(0.0, 1.0, -2.0), transformed.origin, 1e-6);
+    assert_approx_eq!(Vec3::unit_x(), transformed.direction, 1e-6);
+    let transformed = ray.transform_mat4(&Mat4::from(transform));
+    assert_approx_eq!(vec3(0.0, 1.0, -2.0), transformed.origin, 1e-6);
+    assert_approx_eq!(Vec3::unit_x(), transformed.direction, 1e-6);
+}
+
+#[test]
+fn test_plane() {
+    let plane = Plane::from_points(
+        vec3(0.0, 2.0, 0.0),
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, 2.0, 0.0),
+    );
+    assert_eq!(Vec3::unit_y(), plane.normal);
+    assert_eq!(-2.0, plane.d);
+    assert_eq!(
+        plane,
+        Plane::from_point_normal(vec3(5.0, 2.0, 5.0), Vec3::unit_y())
+    );
+    assert_eq!(3.0, plane.signed_distance(vec3(1.0, 5.0, 1.0)));
+    assert_eq!(-3.0, plane.signed_distance(vec3(1.0, -1.0, 1.0)));
+    assert_eq!(3.0, plane.distance_to_point(vec3(1.0, -1.0, 1.0)));
+    assert_eq!(
+        vec3(1.0, 2.0, 1.0),
+        plane.closest_point(vec3(1.0, -1.0, 1.0))
+    );
+
+    // non-uniform scale must keep the normal perpendicular to the plane
+    let plane = Plane::from_point_normal(Vec3::zero(), vec3(1.0, 1.0, 0.0).normalize());
+    let transform = Affine3A::from_scale_rotation_translation(
+        vec3(2.0, 1.0, 1.0),
+        Quat::identity(),
+        vec3(0.0, 0.0, 3.0),
+    );
+    let transformed = plane.transform_affine3a(&transform);
+    let on_plane = transform.transform_point3(vec3(1.0, -1.0, 4.0));
+    assert_approx_eq!(0.0, transformed.signed_distance(on_plane), 1e-6);
+    assert_approx_eq!(vec3(1.0, 2.0, 0.0).normalize(), transformed.normal, 1e-6);
+    let transformed = plane.transform_mat4(&Mat4::from(transform));
+    assert_approx_eq!(0.0, transformed.signed_distance(on_plane), 1e-6);
+}
+
+#[test]
+fn test_sphere() {
+    let sphere = Sphere::new(vec3(1.0, 0.0, 0.0), 2.0);
+    assert!(sphere.contains_point(vec3(3.0, 0.0, 0.0)));
+    assert!(!sphere.contains_point(vec3(3.1, 0.0, 0.0)));
+    assert_eq!(
+        vec3(1.0, 1.0, 0.0),
+        sphere.closest_point(vec3(1.0, 1.0, 0.0))
+    );
+    assert_eq!(
+        vec3(1.0, 0.0, 2.0),
+        sphere.closest_point(vec3(1.0, 0.0, 5.0))
+    );
+    assert_eq!(3.0, sphere.distance_to_point(vec3(1.0, 0.0, 5.0)));
+    assert_eq!(0.0, sphere.distance_to_point(sphere.center));
+
+    let aabb = sphere.aabb();
+    assert_eq!(vec3(-1.0, -2.0, -2.0), aabb.min);
+    assert_eq!(vec3(3.0, 2.0, 2.0), aabb.max);
+
+    let points = [
+        vec3(-1.0, 0.0, 0.0),
+        vec3(3.0, 0.0, 0.0),
+        vec3(1.0, 1.0, 0.0),
+        vec3(1.0, -1.0, 0.0),
+    ];
+    let from_points = Sphere::from_points(&points);
+    assert_eq!(sphere, from_points);
+
+    let other = Sphere::new(vec3(7.0, 0.0, 0.0), 1.0);
+    let union = sphere.union(&other);
+    assert_eq!(vec3(3.5, 0.0, 0.0), union.center);
+    assert_eq!(4.5, union.radius);
+    assert_eq!(sphere, sphere.union(&Sphere::new(vec3(1.5, 0.0, 0.0), 1.0)));
+    assert_eq!(union, sphere.union(&union));
+
+    let grown = sphere.grow(vec3(-5.0, 0.0, 0.0));
+    assert_eq!(vec3(-1.0, 0.0, 0.0), grown.center);
+    assert_eq!(4.0, grown.radius);
+    assert_eq!(sphere, sphere.grow(Vec3::zero()));
+
+    let transform = Affine3A::from_scale_rotation_translation(
+        vec3(1.0, 3.0, 2.0),
+        Quat::from_rotation_z(deg(90.0)),
+        vec3(0.0, 0.0, 1.0),
+    );
+    let transformed = sphere.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(0.0, 1.0, 1.0), transformed.center, 1e-6);
+    assert_approx_eq!(6.0, transformed.radius, 1e-6);
+    assert_eq!(transformed, sphere.transform_mat4(&Mat4::from(transform)));
+}
+
+#[test]
+fn test_obb() {
+    let axes = Mat3::from_rotation_z(deg(45.0));
+    let obb = Obb::new(vec3(1.0, 1.0, 0.0), axes, vec3(2.0, 1.0, 1.0));
+    let corner = obb.center + axes.mul_vec3(vec3(2.0, 1.0, 1.0));
+    assert!(obb.contains_point(obb.center));
+    assert!(obb.contains_point(corner * 0.999 + obb.center * 0.001));
+    assert!(!obb.contains_point(vec3(3.0, 1.0, 0.0)));
+    assert_approx_eq!(corner, obb.closest_point(corner + axes.x_axis), 1e-6);
+    assert_approx_eq!(1.0, obb.distance_to_point(corner + axes.x_axis), 1e-6);
+    assert_eq!(0.0, obb.distance_to_point(obb.center));
+
+    let aabb = obb.aabb();
+    let extent = 3.0 * deg(45.0).cos();
+    assert_approx_eq!(vec3(1.0 - extent, 1.0 - extent, -1.0), aabb.min, 1e-6);
+    assert_approx_eq!(vec3(1.0 + extent, 1.0 + extent, 1.0), aabb.max, 1e-6);
+
+    let aabb = Aabb3::new(vec3(-1.0, 0.0, 1.0), vec3(1.0, 4.0, 2.0));
+    let from_aabb = Obb::from_aabb(&aabb);
+    assert_eq!(aabb, from_aabb.aabb());
+
+    let points = [
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.0, 1.0, 0.0),
+        vec3(-1.0, 0.0, 0.0),
+        vec3(0.0, -1.0, 0.5),
+    ];
+    let from_points = Obb::from_points_with_axes(&points, axes);
+    assert_approx_eq!(vec3(0.0, 0.0, 0.25), from_points.center, 1e-6);
+    let half = deg(45.0).cos();
+    assert_approx_eq!(vec3(half, half, 0.25), from_points.half_extents, 1e-6);
+    for &point in points.iter() {
+        assert!(from_points.distance_to_point(point) < 1e-6);
+    }
+
+    let transform = Affine3A::from_scale_rotation_translation(
+        vec3(2.0, 3.0, 4.0),
+        Quat::from_rotation_x(deg(90.0)),
+        vec3(1.0, 0.0, 0.0),
+    );
+    let transformed = from_aabb.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(1.0, -6.0, 6.0), transformed.center, 1e-5);
+    assert_approx_eq!(vec3(2.0, 6.0, 2.0), transformed.half_extents, 1e-5);
+    assert_approx_eq!(Mat3::from_rotation_x(deg(90.0)), transformed.axes, 1e-6);
+    assert_approx_eq!(
+        aabb.transform_affine3a(&transform).max,
+        transformed.aabb().max,
+        1e-5
+    );
+    let transformed = from_aabb.transform_mat4(&Mat4::from(transform));
+    assert_approx_eq!(vec3(1.0, -6.0, 6.0), transformed.center, 1e-5);
+}
+
+#[test]
+fn test_triangle3() {
+    let triangle = Triangle3::new(
+        vec3(0.0, 0.0, 0.0),
+        vec3(2.0, 0.0, 0.0),
+        vec3(0.0, 2.0, 0.0),
+    );
+    assert_eq!(
+        triangle,
+        Triangle3::from_points(&[triangle.a, triangle.b, triangle.c])
+    );
+    assert_eq!(Vec3::unit_z(), triangle.normal());
+    assert_eq!(2.0, triangle.area());
+    assert_approx_eq!(vec3(2.0 / 3.0, 2.0 / 3.0, 0.0), triangle.centroid());
+
+    // face region
+    assert_eq!(
+        vec3(0.5, 0.5, 0.0),
+        triangle.closest_point(vec3(0.5, 0.5, 3.0))
+    );
+    assert_eq!(3.0, triangle.distance_to_point(vec3(0.5, 0.5, 3.0)));
+    // vertex regions
+    assert_eq!(triangle.a, triangle.closest_point(vec3(-1.0, -1.0, 1.0)));
+    assert_eq!(triangle.b, triangle.closest_point(vec3(3.0, -1.0, 0.0)));
+    assert_eq!(triangle.c, triangle.closest_point(vec3(-1.0, 3.0, 0.0)));
+    // edge regions
+    assert_eq!(
+        vec3(1.0, 0.0, 0.0),
+        triangle.closest_point(vec3(1.0, -1.0, 0.0))
+    );
+    assert_eq!(
+        vec3(0.0, 1.0, 0.0),
+        triangle.closest_point(vec3(-1.0, 1.0, 0.0))
+    );
+    assert_eq!(
+        vec3(1.0, 1.0, 0.0),
+        triangle.closest_point(vec3(2.0, 2.0, 0.0))
+    );
+
+    let aabb = triangle.aabb();
+    assert_eq!(Vec3::zero(), aabb.min);
+    assert_eq!(vec3(2.0, 2.0, 0.0), aabb.max);
+
+    let transform =
+        Affine3A::from_rotation_translation(Quat::from_rotation_x(deg(90.0)), vec3(0.0, 0.0, 1.0));
+    let transformed = triangle.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(0.0, 0.0, 1.0), transformed.a, 1e-6);
+    assert_approx_eq!(vec3(2.0, 0.0, 1.0), transformed.b, 1e-6);
+    assert_approx_eq!(vec3(0.0, 0.0, 3.0), transformed.c, 1e-6);
+    assert_approx_eq!(vec3(0.0, -1.0, 0.0), transformed.normal(), 1e-6);
+    let transformed = triangle.transform_mat4(&Mat4::from(transform));
+    assert_approx_eq!(vec3(0.0, 0.0, 3.0), transformed.c, 1e-6);
+}
+
+#[test]
+fn test_capsule() {
+    let capsule = Capsule::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), 1.0);
+    assert!(capsule.contains_point(vec3(1.0, 2.0, 0.0)));
+    assert!(capsule.contains_point(vec3(0.0, 5.0, 0.0)));
+    assert!(!capsule.contains_point(vec3(0.0, 5.1, 0.0)));
+    assert!(!capsule.contains_point(vec3(0.8, -0.8, 0.0)));
+    assert_eq!(
+        vec3(0.5, 1.0, 0.0),
+        capsule.closest_point(vec3(0.5, 1.0, 0.0))
+    );
+    assert_eq!(
+        vec3(1.0, 2.0, 0.0),
+        capsule.closest_point(vec3(3.0, 2.0, 0.0))
+    );
+    assert_eq!(
+        vec3(0.0, -1.0, 0.0),
+        capsule.closest_point(vec3(0.0, -4.0, 0.0))
+    );
+    assert_eq!(2.0, capsule.distance_to_point(vec3(3.0, 2.0, 0.0)));
+    assert_eq!(0.0, capsule.distance_to_point(vec3(0.0, 2.0, 0.0)));
+
+    let aabb = capsule.aabb();
+    assert_eq!(vec3(-1.0, -1.0, -1.0), aabb.min);
+    assert_eq!(vec3(1.0, 5.0, 1.0), aabb.max);
+
+    let transform = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::from_rotation_z(deg(-90.0)),
+        vec3(0.0, 0.0, 1.0),
+    );
+    let transformed = capsule.transform_affine3a(&transform);
+    assert_approx_eq!(vec3(0.0, 0.0, 1.0), transformed.a, 1e-6);
+    assert_approx_eq!(vec3(8.0, 0.0, 1.0), transformed.b, 1e-5);
+    assert_approx_eq!(2.0, transformed.radius, 1e-6);
+    let transformed = capsule.transform_mat4(&Mat4::from(transform));
+    assert_approx_eq!(vec3(8.0, 0.0, 1.0), transformed.b, 1e-5);
+}
+
+/// Returns points on the unit sphere spread over all octants.
+fn unit_sphere_points() -> Vec<Vec3> {
+    let mut points = Vec::new();
+    for i in 0..16 {
+        for j in 0..=8 {
+            let theta = deg(22.5 * i as f32);
+            let phi = deg(22.5 * j as f32);
+            points.push(vec3(
+                phi.sin() * theta.cos(),
+                phi.sin() * theta.sin(),
+                phi.cos(),
+            ));
+        }
+    }
+    points
+}
+
+#[test]
+fn test_round_primitives_sheared_transform() {
+    // the longest column of this shear is sqrt(2) but it stretches by the golden ratio
+    let transform =
+        Affine3A::from_cols_array(&[1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 3.0]);
+    let golden_ratio = (1.0 + 5.0f32.sqrt()) * 0.5;
+
+    let sphere = Sphere::new(vec3(1.0, -1.0, 2.0), 2.0);
+    let transformed = sphere.transform_affine3a(&transform);
+    assert_approx_eq!(2.0 * golden_ratio, transformed.radius, 1e-5);
+    for &n in unit_sphere_points().iter() {
+        let p = transform.transform_point3(sphere.center + n * sphere.radius);
+        assert!(transformed.distance_to_point(p) <= 1e-5);
+    }
+
+    let capsule = Capsule::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 2.0, 0.0), 0.5);
+    let transformed = capsule.transform_affine3a(&transform);
+    for &n in unit_sphere_points().iter() {
+        for &end in [capsule.a, capsule.b].iter() {
+            let p = transform.transform_point3(end + n * capsule.radius);
+            assert!(transformed.distance_to_point(p) <= 1e-5);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_geom_serde() {
+    let aabb = Aabb3::new(vec3(-1.0, -2.0, -3.0), vec3(1.0, 2.0, 3.0));
+    let serialized = serde_json::to_string(&aabb).unwrap();
+    assert_eq!(serialized, "[[-1.0,-2.0,-3.0],[1.0,2.0,3.0]]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(aabb, deserialized);
+    assert!(serde_json::from_str::<Aabb3>("[[-1.0,-2.0,-3.0]]").is_err());
+
+    let sphere = Sphere::new(vec3(1.0, 2.0, 3.0), 4.0);
+    let serialized = serde_json::to_string(&sphere).unwrap();
+    assert_eq!(serialized, "[[1.0,2.0,3.0],4.0]");
+    assert_eq!(sphere, serde_json::from_str(&serialized).unwrap());
+    assert!(serde_json::from_str::<Sphere>("[[1.0,2.0,3.0]]").is_err());
+
+    let obb = Obb::new(Vec3::one(), Mat3::identity(), vec3(1.0, 2.0, 3.0));
+    let serialized = serde_json::to_string(&obb).unwrap();
+    assert_eq!(
+        serialized,
+        "[[1.0,1.0,1.0],[1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0],[1.0,2.0,3.0]]"
+    );
+    assert_eq!(obb, serde_json::from_str(&serialized).unwrap());
+
+    let ray = Ray3::new(Vec3::zero(), Vec3::unit_x());
+    assert_eq!(
+        ray,
+        serde_json::from_str(&serde_json::to_string(&ray).unwrap()).unwrap()
+    );
+    let plane = Plane::new(Vec3::unit_y(), 2.0);
+    assert_eq!(
+        plane,
+        serde_json::from_str(&serde_json::to_string(&plane).unwrap()).unwrap()
+    );
+    let triangle = Triangle3::new(Vec3::zero(), Vec3::unit_x(), Vec3::unit_y());
+    assert_eq!(
+        triangle,
+        serde_json::from_str(&serde_json::to_string(&triangle).unwrap()).unwrap()
+    );
+    let capsule = Capsule::new(Vec3::zero(), Vec3::unit_y(), 0.5);
+    assert_eq!(
+        capsule,
+        serde_json::from_str(&serde_json::to_string(&capsule).unwrap()).unwrap()
+    );
+}
@@ -0,0 +1,150 @@
+mod support;
+
+use glam::geom::{Aabb3, ClipDepth, Frustum, Intersection, Sphere};
+use glam::{vec3, Mat4, Vec3};
+use support::deg;
+
+fn view() -> Mat4 {
+    // looking down the negative z axis from (0, 0, 5)
+    Mat4::look_at_rh(vec3(0.0, 0.0, 5.0), Vec3::zero(), Vec3::unit_y())
+}
+
+fn check_perspective(frustum: &Frustum) {
+    // the camera is at z = 5 looking towards -z, near = 1, far = 100, 90 degree fov, aspect 2
+    assert!(frustum.contains_point(Vec3::zero()));
+    assert!(frustum.contains_point(vec3(0.0, 0.0, 3.9)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, 4.1)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, 6.0)));
+    assert!(frustum.contains_point(vec3(9.9, 0.0, 0.0)));
+    assert!(!frustum.contains_point(vec3(10.1, 0.0, 0.0)));
+    assert!(frustum.contains_point(vec3(0.0, -4.9, 0.0)));
+    assert!(!frustum.contains_point(vec3(0.0, -5.1, 0.0)));
+
+    assert!(frustum.intersects_sphere(&Sphere::new(vec3(11.0, 0.0, 0.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(vec3(13.0, 0.0, 0.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(vec3(0.0, 0.0, 7.0), 1.0)));
+
+    let inside = Aabb3::from_center_half_extents(Vec3::zero(), Vec3::one());
+    assert_eq!(Intersection::Inside, frustum.intersects_aabb(&inside));
+    let straddling = Aabb3::from_center_half_extents(vec3(10.0, 0.0, 0.0), Vec3::one());
+    assert_eq!(
+        Intersection::Intersecting,
+        frustum.intersects_aabb(&straddling)
+    );
+    let outside = Aabb3::from_center_half_extents(vec3(20.0, 0.0, 0.0), Vec3::one());
+    assert_eq!(Intersection::Outside, frustum.intersects_aabb(&outside));
+    let behind = Aabb3::from_center_half_extents(vec3(0.0, 0.0, 10.0), Vec3::one());
+    assert_eq!(Intersection::Outside, frustum.intersects_aabb(&behind));
+}
+
+#[test]
+fn test_frustum_perspective() {
+    let proj = Mat4::perspective_rh(deg(90.0), 2.0, 1.0, 100.0);
+    let frustum = Frustum::from_mat4(&(proj * view()), ClipDepth::ZeroToOne);
+    check_perspective(&frustum);
+    assert!(!frustum.is_infinite());
+    assert!(frustum.contains_point(vec3(0.0, 0.0, -94.9)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, -95.1)));
+
+    let proj = Mat4::perspective_rh_gl(deg(90.0), 2.0, 1.0, 100.0);
+    let frustum = Frustum::from_mat4(&(proj * view()), ClipDepth::NegativeOneToOne);
+    check_perspective(&frustum);
+    assert!(frustum.contains_point(vec3(0.0, 0.0, -94.9)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, -95.1)));
+
+    let corners = frustum.corners();
+    assert_approx_eq!(vec3(-2.0, -1.0, 4.0), corners[0], 1e-4);
+    assert_approx_eq!(vec3(2.0, -1.0, 4.0), corners[1], 1e-4);
+    assert_approx_eq!(vec3(2.0, 1.0, 4.0), corners[2], 1e-4);
+    assert_approx_eq!(vec3(-2.0, 1.0, 4.0), corners[3], 1e-4);
+    assert_approx_eq!(vec3(-200.0, -100.0, -95.0), corners[4], 1e-2);
+    assert_approx_eq!(vec3(200.0, 100.0, -95.0), corners[6], 1e-2);
+}
+
+#[test]
+fn test_frustum_infinite() {
+    let proj = Mat4::perspective_infinite_rh(deg(90.0), 2.0, 1.0);
+    let frustum = Frustum::from_mat4(&(proj * view()), ClipDepth::ZeroToOne);
+    check_perspective(&frustum);
+    assert!(frustum.is_infinite());
+    assert!(frustum.contains_point(vec3(0.0, 0.0, -1.0e6)));
+
+    let proj = Mat4::perspective_infinite_reverse_rh(deg(90.0), 2.0, 1.0);
+    let frustum = Frustum::from_mat4(&(proj * view()), ClipDepth::ZeroToOneReversed);
+    check_perspective(&frustum);
+    assert!(frustum.is_infinite());
+    assert!(frustum.contains_point(vec3(0.0, 0.0, -1.0e6)));
+    assert_approx_eq!(vec3(-2.0, -1.0, 4.0), frustum.corners()[0], 1e-4);
+}
+
+#[test]
+fn test_frustum_far_from_origin() {
+    // the planes of a camera far from the origin have a large `d` but are still normalized
+    let eye = vec3(2.0e6, 0.0, 5.0);
+    let view = Mat4::look_at_rh(eye, eye - Vec3::unit_z(), Vec3::unit_y());
+    let projections = [
+        (
+            Mat4::perspective_rh(deg(60.0), 1.0, 1.0, 100.0),
+            ClipDepth::ZeroToOne,
+        ),
+        (
+            Mat4::perspective_infinite_rh(deg(60.0), 1.0, 1.0),
+            ClipDepth::ZeroToOne,
+        ),
+        (
+            Mat4::perspective_infinite_reverse_rh(deg(60.0), 1.0, 1.0),
+            ClipDepth::ZeroToOneReversed,
+        ),
+    ];
+    for (i, &(proj, depth)) in projections.iter().enumerate() {
+        let frustum = Frustum::from_mat4(&(proj * view), depth);
+        let infinite = i > 0;
+        assert_eq!(infinite, frustum.is_infinite());
+        for (j, plane) in frustum.planes.iter().enumerate() {
+            if j != Frustum::FAR || !infinite {
+                assert!(plane.normal.is_normalized());
+            }
+        }
+        assert!(frustum.contains_point(eye - vec3(0.0, 0.0, 5.0)));
+        assert!(!frustum.contains_point(eye + vec3(0.0, 0.0, 1.0)));
+        assert!(!frustum.contains_point(eye + vec3(20.0, 0.0, -5.0)));
+        assert!(!frustum.contains_point(eye + vec3(-20.0, 0.0, -5.0)));
+        assert!(!frustum.contains_point(eye + vec3(0.0, -10.0, -5.0)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(eye + vec3(30.0, 0.0, -5.0), 1.0)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(eye - vec3(30.0, 0.0, 5.0), 1.0)));
+    }
+}
+
+#[test]
+fn test_frustum_orthographic() {
+    let proj = Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    let frustum = Frustum::from_mat4(&proj, ClipDepth::ZeroToOne);
+    assert!(frustum.contains_point(vec3(1.9, 0.9, -9.9)));
+    assert!(!frustum.contains_point(vec3(2.1, 0.0, -5.0)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, -0.4)));
+    assert!(!frustum.contains_point(vec3(0.0, 0.0, -10.1)));
+
+    let proj_gl = Mat4::orthographic_rh_gl(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    let frustum_gl = Frustum::from_mat4(&proj_gl, ClipDepth::NegativeOneToOne);
+    for (a, b) in frustum.planes.iter().zip(frustum_gl.planes.iter()) {
+        assert_approx_eq!(a.normal, b.normal, 1e-6);
+        assert_approx_eq!(a.d, b.d, 1e-5);
+    }
+
+    let corners = frustum.corners();
+    assert_approx_eq!(vec3(-2.0, -1.0, -0.5), corners[0], 1e-5);
+    assert_approx_eq!(vec3(2.0, 1.0, -10.0), corners[6], 1e-5);
+    assert_eq!(frustum.planes[Frustum::NEAR].normal, -Vec3::unit_z(),);
+    assert_eq!(Frustum::from_planes(frustum.planes), frustum);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_frustum_serde() {
+    let proj = Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    let frustum = Frustum::from_mat4(&proj, ClipDepth::ZeroToOne);
+    let serialized = serde_json::to_string(&frustum).unwrap();
+    assert!(serialized.starts_with("[[[1.0,0.0,0.0],2.0],[[-1.0,"));
+    assert_eq!(frustum, serde_json::from_str(&serialized).unwrap());
+    assert!(serde_json::from_str::<Frustum>("[[[1.0,0.0,0.0],2.0]]").is_err());
+}
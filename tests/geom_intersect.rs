@@ -0,0 +1,315 @@
+mod support;
+
+use glam::geom::intersect::*;
+use glam::geom::{Aabb3, Obb, Plane, Ray3, Sphere, Triangle3};
+use glam::{vec3, Mat3, Vec3};
+use support::deg;
+
+#[test]
+fn test_ray_aabb3() {
+    let aabb = Aabb3::new(vec3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+
+    let ray = Ray3::new(vec3(-5.0, 0.5, 0.0), Vec3::unit_x());
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_eq!(4.0, hit.distance);
+    assert_eq!(vec3(-1.0, 0.5, 0.0), hit.point);
+    assert_eq!(-Vec3::unit_x(), hit.normal);
+
+    let ray = Ray3::new(vec3(0.0, 5.0, 0.0), -Vec3::unit_y());
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_eq!(4.0, hit.distance);
+    assert_eq!(Vec3::unit_y(), hit.normal);
+
+    let ray = Ray3::from_points(vec3(-3.0, -3.0, 0.5), vec3(0.0, 0.0, 0.5));
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_approx_eq!(vec3(-1.0, -1.0, 0.5), hit.point, 1e-6);
+    assert_approx_eq!(8.0f32.sqrt(), hit.distance, 1e-6);
+
+    // starting inside hits the exit face
+    let ray = Ray3::new(Vec3::zero(), Vec3::unit_z());
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_eq!(1.0, hit.distance);
+    assert_eq!(Vec3::unit_z(), hit.normal);
+
+    // misses
+    let ray = Ray3::new(vec3(-5.0, 1.5, 0.0), Vec3::unit_x());
+    assert_eq!(None, ray_aabb3(&ray, &aabb));
+    let ray = Ray3::new(vec3(-5.0, 0.0, 0.0), -Vec3::unit_x());
+    assert_eq!(None, ray_aabb3(&ray, &aabb));
+    let ray = Ray3::from_points(vec3(-5.0, 0.0, 0.0), vec3(0.0, 5.0, 0.0));
+    assert_eq!(None, ray_aabb3(&ray, &aabb));
+}
+
+#[test]
+fn test_ray_aabb3_parallel() {
+    let aabb = Aabb3::new(vec3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+
+    // grazing rays along the faces and edges of the box hit it
+    for &origin in [
+        vec3(-5.0, 1.0, 0.0),
+        vec3(-5.0, -1.0, 0.0),
+        vec3(-5.0, 0.0, 1.0),
+        vec3(-5.0, 1.0, 1.0),
+        vec3(-5.0, -1.0, -1.0),
+    ]
+    .iter()
+    {
+        let hit = ray_aabb3(&Ray3::new(origin, Vec3::unit_x()), &aabb).unwrap();
+        assert_eq!(4.0, hit.distance);
+        assert_eq!(-Vec3::unit_x(), hit.normal);
+    }
+    let ray = Ray3::new(vec3(1.0, 5.0, -1.0), -Vec3::unit_y());
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_eq!(4.0, hit.distance);
+    assert_eq!(Vec3::unit_y(), hit.normal);
+
+    // and rays starting just outside of a slab they are parallel to miss it
+    let ray = Ray3::new(vec3(-5.0, 1.0 + 1e-6, 0.0), Vec3::unit_x());
+    assert_eq!(None, ray_aabb3(&ray, &aabb));
+    let ray = Ray3::new(vec3(1.0, 5.0, -1.0 - 1e-6), -Vec3::unit_y());
+    assert_eq!(None, ray_aabb3(&ray, &aabb));
+
+    // a diagonal ray in the plane of a face
+    let ray = Ray3::from_points(vec3(-3.0, -3.0, 1.0), vec3(0.0, 0.0, 1.0));
+    let hit = ray_aabb3(&ray, &aabb).unwrap();
+    assert_approx_eq!(vec3(-1.0, -1.0, 1.0), hit.point, 1e-6);
+}
+
+#[test]
+fn test_ray_sphere() {
+    let sphere = Sphere::new(vec3(0.0, 0.0, 5.0), 2.0);
+
+    let ray = Ray3::new(Vec3::zero(), Vec3::unit_z());
+    let hit = ray_sphere(&ray, &sphere).unwrap();
+    assert_eq!(3.0, hit.distance);
+    assert_eq!(vec3(0.0, 0.0, 3.0), hit.point);
+    assert_eq!(-Vec3::unit_z(), hit.normal);
+
+    // starting inside hits the exit point
+    let ray = Ray3::new(vec3(0.0, 0.0, 5.0), Vec3::unit_x());
+    let hit = ray_sphere(&ray, &sphere).unwrap();
+    assert_eq!(2.0, hit.distance);
+    assert_eq!(Vec3::unit_x(), hit.normal);
+
+    let ray = Ray3::new(vec3(0.0, 2.5, 0.0), Vec3::unit_z());
+    assert_eq!(None, ray_sphere(&ray, &sphere));
+    let ray = Ray3::new(Vec3::zero(), -Vec3::unit_z());
+    assert_eq!(None, ray_sphere(&ray, &sphere));
+
+    // a point has no normal
+    let ray = Ray3::new(Vec3::zero(), Vec3::unit_z());
+    assert_eq!(
+        None,
+        ray_sphere(&ray, &Sphere::new(vec3(0.0, 0.0, 5.0), 0.0))
+    );
+    // `Sphere::new` asserts the radius is not negative when `debug-glam-assert` is enabled
+    let sphere = Sphere {
+        center: vec3(0.0, 0.0, 5.0),
+        radius: -2.0,
+    };
+    assert_eq!(None, ray_sphere(&ray, &sphere));
+}
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::from_point_normal(vec3(0.0, 2.0, 0.0), Vec3::unit_y());
+
+    let ray = Ray3::new(vec3(1.0, 5.0, 1.0), -Vec3::unit_y());
+    let hit = ray_plane(&ray, &plane).unwrap();
+    assert_eq!(3.0, hit.distance);
+    assert_eq!(vec3(1.0, 2.0, 1.0), hit.point);
+    assert_eq!(Vec3::unit_y(), hit.normal);
+
+    // from below the normal faces the ray
+    let ray = Ray3::new(vec3(1.0, 0.0, 1.0), Vec3::unit_y());
+    let hit = ray_plane(&ray, &plane).unwrap();
+    assert_eq!(2.0, hit.distance);
+    assert_eq!(-Vec3::unit_y(), hit.normal);
+
+    let ray = Ray3::new(vec3(1.0, 5.0, 1.0), Vec3::unit_y());
+    assert_eq!(None, ray_plane(&ray, &plane));
+    let ray = Ray3::new(vec3(1.0, 5.0, 1.0), Vec3::unit_x());
+    assert_eq!(None, ray_plane(&ray, &plane));
+}
+
+#[test]
+fn test_ray_triangle3() {
+    let triangle = Triangle3::new(
+        vec3(0.0, 0.0, 0.0),
+        vec3(2.0, 0.0, 0.0),
+        vec3(0.0, 2.0, 0.0),
+    );
+
+    let ray = Ray3::new(vec3(0.5, 1.0, 3.0), -Vec3::unit_z());
+    let hit = ray_triangle3(&ray, &triangle).unwrap();
+    assert_eq!(3.0, hit.distance);
+    assert_eq!(vec3(0.5, 1.0, 0.0), hit.point);
+    assert_eq!(Vec3::unit_z(), hit.normal);
+    assert_approx_eq!(vec3(0.25, 0.25, 0.5), hit.barycentric, 1e-6);
+    let point = triangle.a * hit.barycentric.x
+        + triangle.b * hit.barycentric.y
+        + triangle.c * hit.barycentric.z;
+    assert_approx_eq!(hit.point, point, 1e-6);
+
+    // back face
+    let ray = Ray3::new(vec3(0.5, 1.0, -3.0), Vec3::unit_z());
+    let hit = ray_triangle3(&ray, &triangle).unwrap();
+    assert_eq!(3.0, hit.distance);
+    assert_eq!(-Vec3::unit_z(), hit.normal);
+
+    // misses
+    let ray = Ray3::new(vec3(1.5, 1.5, 3.0), -Vec3::unit_z());
+    assert_eq!(None, ray_triangle3(&ray, &triangle));
+    let ray = Ray3::new(vec3(0.5, 1.0, 3.0), Vec3::unit_z());
+    assert_eq!(None, ray_triangle3(&ray, &triangle));
+    let ray = Ray3::new(vec3(0.5, 1.0, 3.0), Vec3::unit_x());
+    assert_eq!(None, ray_triangle3(&ray, &triangle));
+
+    // small triangles are hit at any scale
+    for &scale in [1.0e-2, 1.0e-4, 1.0e-6].iter() {
+        let small = Triangle3::new(triangle.a * scale, triangle.b * scale, triangle.c * scale);
+        let ray = Ray3::new(vec3(0.5, 0.5, 3.0) * scale, -Vec3::unit_z());
+        let hit = ray_triangle3(&ray, &small).unwrap();
+        assert_approx_eq!(vec3(0.5, 0.5, 0.0) * scale, hit.point, scale * 1e-6);
+        assert_eq!(Vec3::unit_z(), hit.normal);
+    }
+}
+
+#[test]
+fn test_sphere_overlap() {
+    let sphere = Sphere::new(vec3(0.0, 0.0, 0.0), 1.0);
+    assert!(sphere_sphere(
+        &sphere,
+        &Sphere::new(vec3(1.5, 0.0, 0.0), 0.5)
+    ));
+    assert!(!sphere_sphere(
+        &sphere,
+        &Sphere::new(vec3(1.5, 0.0, 0.0), 0.4)
+    ));
+
+    let aabb = Aabb3::new(vec3(1.0, 1.0, -1.0), vec3(2.0, 2.0, 1.0));
+    assert!(!sphere_aabb3(&sphere, &aabb));
+    assert!(sphere_aabb3(&Sphere::new(Vec3::zero(), 1.5), &aabb));
+    assert!(sphere_aabb3(&Sphere::new(vec3(1.5, 1.5, 0.0), 0.1), &aabb));
+}
+
+#[test]
+fn test_aabb3_aabb3() {
+    let a = Aabb3::new(vec3(0.0, 0.0, 0.0), vec3(2.0, 2.0, 2.0));
+    assert!(aabb3_aabb3(&a, &a));
+    assert!(aabb3_aabb3(
+        &a,
+        &Aabb3::new(vec3(1.0, 1.0, 1.0), vec3(3.0, 3.0, 3.0))
+    ));
+    assert!(aabb3_aabb3(
+        &a,
+        &Aabb3::new(vec3(2.0, 0.0, 0.0), vec3(3.0, 1.0, 1.0))
+    ));
+    assert!(!aabb3_aabb3(
+        &a,
+        &Aabb3::new(vec3(2.5, 0.0, 0.0), vec3(3.0, 1.0, 1.0))
+    ));
+    assert!(!aabb3_aabb3(
+        &a,
+        &Aabb3::new(vec3(0.0, 0.0, -3.0), vec3(1.0, 1.0, -0.5))
+    ));
+}
+
+#[test]
+fn test_obb_obb() {
+    let a = Obb::new(Vec3::zero(), Mat3::identity(), Vec3::one());
+
+    // separated along an axis of `a`
+    let b = Obb::new(vec3(2.5, 0.0, 0.0), Mat3::identity(), Vec3::one());
+    assert!(!obb_obb(&a, &b));
+    let b = Obb::new(vec3(1.5, 0.0, 0.0), Mat3::identity(), Vec3::one());
+    assert!(obb_obb(&a, &b));
+
+    // rotated 45 degrees its corner reaches sqrt(2) along x
+    let rotated = Mat3::from_rotation_z(deg(45.0));
+    let b = Obb::new(vec3(2.3, 0.0, 0.0), rotated, Vec3::one());
+    assert!(obb_obb(&a, &b));
+    let b = Obb::new(vec3(2.5, 0.0, 0.0), rotated, Vec3::one());
+    assert!(!obb_obb(&a, &b));
+
+    // separated along an axis of `b`
+    let b = Obb::new(vec3(2.2, 2.2, 0.0), rotated, vec3(1.0, 0.1, 1.0));
+    assert!(!obb_obb(&a, &b));
+    assert!(!obb_obb(&b, &a));
+
+    // separated only by an edge cross product axis
+    let edge_a = Obb::new(
+        Vec3::zero(),
+        Mat3::from_rotation_x(deg(45.0)),
+        vec3(1.0, 1.0, 1.0),
+    );
+    let edge_b = Obb::new(
+        vec3(0.0, 0.0, 2.9),
+        Mat3::from_rotation_y(deg(45.0)),
+        vec3(1.0, 1.0, 1.0),
+    );
+    assert!(!obb_obb(&edge_a, &edge_b));
+    let edge_b = Obb::new(
+        vec3(0.0, 0.0, 2.7),
+        Mat3::from_rotation_y(deg(45.0)),
+        vec3(1.0, 1.0, 1.0),
+    );
+    assert!(obb_obb(&edge_a, &edge_b));
+}
+
+#[test]
+fn test_triangle3_aabb3() {
+    let aabb = Aabb3::new(vec3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+
+    // a vertex inside the box
+    let triangle = Triangle3::new(
+        vec3(0.5, 0.5, 0.5),
+        vec3(3.0, 0.0, 0.0),
+        vec3(0.0, 3.0, 0.0),
+    );
+    assert!(triangle3_aabb3(&triangle, &aabb));
+
+    // the box pokes through the middle of a large triangle
+    let triangle = Triangle3::new(
+        vec3(-10.0, -10.0, 0.0),
+        vec3(10.0, -10.0, 0.0),
+        vec3(0.0, 10.0, 0.0),
+    );
+    assert!(triangle3_aabb3(&triangle, &aabb));
+
+    // separated by a box face
+    let triangle = Triangle3::new(
+        vec3(2.0, 0.0, 0.0),
+        vec3(3.0, 0.0, 0.0),
+        vec3(2.0, 1.0, 0.0),
+    );
+    assert!(!triangle3_aabb3(&triangle, &aabb));
+
+    // separated by the triangle plane
+    let triangle = Triangle3::new(
+        vec3(3.0, 0.0, 0.0),
+        vec3(0.0, 3.0, 0.0),
+        vec3(0.0, 0.0, 3.5),
+    );
+    assert!(!triangle3_aabb3(&triangle, &aabb));
+    let triangle = Triangle3::new(
+        vec3(2.5, 0.0, 0.0),
+        vec3(0.0, 2.5, 0.0),
+        vec3(0.0, 0.0, 2.5),
+    );
+    assert!(triangle3_aabb3(&triangle, &aabb));
+
+    // separated only by an edge cross product axis
+    let triangle = Triangle3::new(
+        vec3(2.2, 0.0, 0.0),
+        vec3(0.0, 2.2, 0.0),
+        vec3(3.0, 3.0, -5.0),
+    );
+    assert!(!triangle3_aabb3(&triangle, &aabb));
+    let triangle = Triangle3::new(
+        vec3(1.8, 0.0, 0.0),
+        vec3(0.0, 1.8, 0.0),
+        vec3(3.0, 3.0, -5.0),
+    );
+    assert!(triangle3_aabb3(&triangle, &aabb));
+}
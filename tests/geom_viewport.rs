@@ -0,0 +1,142 @@
+mod support;
+
+use glam::geom::{
+    project_point3, screen_ray, unproject_point3, ClipDepth, Viewport, ViewportOrigin,
+};
+use glam::{vec2, vec3, Mat4, Vec3};
+use support::deg;
+
+fn view() -> Mat4 {
+    // looking down the negative z axis from (0, 0, 5)
+    Mat4::look_at_rh(vec3(0.0, 0.0, 5.0), Vec3::zero(), Vec3::unit_y())
+}
+
+fn projections() -> [(Mat4, ClipDepth); 5] {
+    [
+        (
+            Mat4::perspective_rh_gl(deg(90.0), 2.0, 1.0, 100.0),
+            ClipDepth::NegativeOneToOne,
+        ),
+        (
+            Mat4::perspective_rh(deg(90.0), 2.0, 1.0, 100.0),
+            ClipDepth::ZeroToOne,
+        ),
+        (
+            Mat4::perspective_infinite_rh(deg(90.0), 2.0, 1.0),
+            ClipDepth::ZeroToOne,
+        ),
+        (
+            Mat4::perspective_infinite_reverse_rh(deg(90.0), 2.0, 1.0),
+            ClipDepth::ZeroToOneReversed,
+        ),
+        (
+            Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0),
+            ClipDepth::ZeroToOne,
+        ),
+    ]
+}
+
+#[test]
+fn test_project_unproject() {
+    for &(proj, clip_depth) in projections().iter() {
+        for &origin in [ViewportOrigin::TopLeft, ViewportOrigin::BottomLeft].iter() {
+            let viewport = Viewport::new(vec2(10.0, 20.0), vec2(800.0, 400.0), origin, clip_depth);
+            let view_proj = proj * view();
+            let view_proj_inverse = view_proj.inverse();
+
+            // the point the camera looks at lands in the center of the viewport
+            let center = project_point3(&view_proj, &viewport, Vec3::zero()).unwrap();
+            assert_approx_eq!(410.0, center.x, 1e-3);
+            assert_approx_eq!(220.0, center.y, 1e-3);
+            assert!(center.z > 0.0 && center.z < 1.0);
+
+            for &point in [vec3(1.0, 0.5, 0.0), vec3(-1.5, -0.5, 3.0)].iter() {
+                let window = project_point3(&view_proj, &viewport, point).unwrap();
+                let unprojected = unproject_point3(&view_proj_inverse, &viewport, window);
+                assert_approx_eq!(point, unprojected, 1e-3);
+            }
+
+            // positive y in world space is up on the screen
+            let up = project_point3(&view_proj, &viewport, vec3(0.0, 0.5, 0.0)).unwrap();
+            match origin {
+                ViewportOrigin::TopLeft => assert!(up.y < center.y),
+                ViewportOrigin::BottomLeft => assert!(up.y > center.y),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_project_depth() {
+    let viewport = |clip_depth| {
+        Viewport::new(
+            vec2(0.0, 0.0),
+            vec2(100.0, 100.0),
+            ViewportOrigin::BottomLeft,
+            clip_depth,
+        )
+    };
+    let near = vec3(0.0, 0.0, 4.0);
+    let far = vec3(0.0, 0.0, -95.0);
+
+    let proj = Mat4::perspective_rh_gl(deg(90.0), 1.0, 1.0, 100.0) * view();
+    let gl = viewport(ClipDepth::NegativeOneToOne);
+    assert_approx_eq!(0.0, project_point3(&proj, &gl, near).unwrap().z, 1e-5);
+    assert_approx_eq!(1.0, project_point3(&proj, &gl, far).unwrap().z, 1e-5);
+
+    let proj = Mat4::perspective_rh(deg(90.0), 1.0, 1.0, 100.0) * view();
+    let d3d = viewport(ClipDepth::ZeroToOne);
+    assert_approx_eq!(0.0, project_point3(&proj, &d3d, near).unwrap().z, 1e-5);
+    assert_approx_eq!(1.0, project_point3(&proj, &d3d, far).unwrap().z, 1e-5);
+
+    let proj = Mat4::perspective_infinite_reverse_rh(deg(90.0), 1.0, 1.0) * view();
+    let reversed = viewport(ClipDepth::ZeroToOneReversed);
+    assert_approx_eq!(1.0, project_point3(&proj, &reversed, near).unwrap().z, 1e-5);
+    assert!(project_point3(&proj, &reversed, far).unwrap().z < 0.02);
+
+    // points behind the camera can't be projected
+    assert_eq!(None, project_point3(&proj, &reversed, vec3(0.0, 0.0, 6.0)));
+}
+
+#[test]
+fn test_screen_ray() {
+    for &(proj, clip_depth) in projections().iter() {
+        for &origin in [ViewportOrigin::TopLeft, ViewportOrigin::BottomLeft].iter() {
+            let viewport = Viewport::new(vec2(0.0, 0.0), vec2(800.0, 400.0), origin, clip_depth);
+            let view_proj = proj * view();
+            let view_proj_inverse = view_proj.inverse();
+
+            let ray = screen_ray(&view_proj_inverse, &viewport, vec2(400.0, 200.0));
+            assert_approx_eq!(-Vec3::unit_z(), ray.direction, 1e-5);
+            assert!(ray.origin.z > 4.0 - 1e-4);
+
+            // a ray through the projection of a point passes through that point
+            let point = vec3(1.5, -0.5, -2.0);
+            let window = project_point3(&view_proj, &viewport, point).unwrap();
+            let ray = screen_ray(&view_proj_inverse, &viewport, vec2(window.x, window.y));
+            assert!(ray.distance_to_point(point) < 1e-3);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_viewport_serde() {
+    let viewport = Viewport::new(
+        vec2(10.0, 20.0),
+        vec2(640.0, 480.0),
+        ViewportOrigin::BottomLeft,
+        ClipDepth::ZeroToOneReversed,
+    );
+    let serialized = serde_json::to_string(&viewport).unwrap();
+    assert_eq!(
+        serialized,
+        "[[10.0,20.0],[640.0,480.0],\"BottomLeft\",\"ZeroToOneReversed\"]"
+    );
+    assert_eq!(viewport, serde_json::from_str(&serialized).unwrap());
+    assert!(serde_json::from_str::<Viewport>(
+        "[[10.0,20.0],[640.0,480.0],\"Middle\",\"ZeroToOne\"]"
+    )
+    .is_err());
+    assert!(serde_json::from_str::<Viewport>("[[10.0,20.0],[640.0,480.0]]").is_err());
+}
@@ -0,0 +1,285 @@
+use glam::{ivec2, ivec3, IVec2, Vec2Mask};
+
+#[test]
+fn test_ivec2_align() {
+    use std::mem;
+    assert_eq!(8, mem::size_of::<IVec2>());
+    assert_eq!(4, mem::align_of::<IVec2>());
+}
+
+#[test]
+fn test_ivec2_new() {
+    let v = ivec2(1, 2);
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, 2);
+
+    let t = (1, 2);
+    let v = IVec2::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1, 2];
+    let v = IVec2::from(a);
+    let a1: [i32; 2] = v.into();
+    assert_eq!(a, a1);
+
+    assert_eq!(IVec2::new(1, 0), IVec2::unit_x());
+    assert_eq!(IVec2::new(0, 1), IVec2::unit_y());
+}
+
+#[test]
+fn test_ivec2_fmt() {
+    let a = ivec2(1, 2);
+    assert_eq!(format!("{:?}", a), "IVec2(1, 2)");
+    assert_eq!(format!("{}", a), "[1, 2]");
+}
+
+#[test]
+fn test_ivec2_zero() {
+    let v = IVec2::zero();
+    assert_eq!((0, 0), v.into());
+    assert_eq!(v, IVec2::default());
+}
+
+#[test]
+fn test_ivec2_splat() {
+    let v = IVec2::splat(1);
+    assert_eq!((1, 1), v.into());
+}
+
+#[test]
+fn test_ivec2_accessors() {
+    let mut a = IVec2::zero();
+    a.x = 1;
+    a.y = 2;
+    assert_eq!(1, a.x);
+    assert_eq!(2, a.y);
+    assert_eq!(ivec2(1, 2), a);
+
+    let mut a = IVec2::zero();
+    a[0] = 1;
+    a[1] = 2;
+    assert_eq!(1, a[0]);
+    assert_eq!(2, a[1]);
+    assert_eq!(ivec2(1, 2), a);
+}
+
+#[test]
+fn test_ivec2_funcs() {
+    let x = ivec2(1, 2);
+    let y = ivec2(4, 3);
+    assert_eq!(10, x.dot(y));
+    assert_eq!(x.extend(3), ivec3(1, 2, 3));
+}
+
+#[test]
+fn test_ivec2_ops() {
+    let a = ivec2(2, 4);
+    assert_eq!(ivec2(4, 8), a + a);
+    assert_eq!(ivec2(0, 0), a - a);
+    assert_eq!(ivec2(4, 16), a * a);
+    assert_eq!(ivec2(1, 1), a / a);
+    assert_eq!(ivec2(2, 1), a % ivec2(3, 3));
+    assert_eq!(ivec2(4, 8), a * 2);
+    assert_eq!(ivec2(4, 8), 2 * a);
+    assert_eq!(ivec2(1, 2), a / 2);
+    assert_eq!(ivec2(2, 1), 4 / a);
+    assert_eq!(ivec2(0, 0), a % 2);
+    assert_eq!(ivec2(-2, -4), -a);
+}
+
+#[test]
+fn test_ivec2_assign_ops() {
+    let a = ivec2(1, 2);
+    let mut b = a;
+    b += a;
+    assert_eq!(ivec2(2, 4), b);
+    b -= a;
+    assert_eq!(ivec2(1, 2), b);
+    b *= a;
+    assert_eq!(ivec2(1, 4), b);
+    b /= a;
+    assert_eq!(ivec2(1, 2), b);
+    b *= 2;
+    assert_eq!(ivec2(2, 4), b);
+    b /= 2;
+    assert_eq!(ivec2(1, 2), b);
+    b %= 2;
+    assert_eq!(ivec2(1, 0), b);
+    b += 3;
+    assert_eq!(ivec2(4, 3), b);
+    b -= 1;
+    assert_eq!(ivec2(3, 2), b);
+}
+
+#[test]
+fn test_ivec2_bit_ops() {
+    let a = ivec2(0b0101, 0b0110);
+    let b = ivec2(0b0011, 0b0011);
+    assert_eq!(ivec2(0b0001, 0b0010), a & b);
+    assert_eq!(ivec2(0b0111, 0b0111), a | b);
+    assert_eq!(ivec2(0b0110, 0b0101), a ^ b);
+    assert_eq!(ivec2(0b0100, 0b0100), a & 0b0100);
+    assert_eq!(ivec2(0b0101, 0b0111), a | 0b0001);
+    assert_eq!(ivec2(0b1010, 0b1001), a ^ 0b1111);
+    assert_eq!(ivec2(-1, -1), !IVec2::zero());
+    assert_eq!(ivec2(0b1010, 0b1100), a << 1);
+    assert_eq!(ivec2(0b0010, 0b0011), a >> 1);
+    assert_eq!(ivec2(0b1010, 0b1100), a << 1u32);
+    assert_eq!(ivec2(0b0010, 0b0011), a >> 1u32);
+    assert_eq!(ivec2(0b10100, 0b1100), a << ivec2(2, 1));
+    assert_eq!(ivec2(0b0001, 0b0011), a >> ivec2(2, 1));
+}
+
+#[test]
+fn test_ivec2_wrapping_saturating() {
+    let max = IVec2::splat(i32::max_value());
+    let min = IVec2::splat(i32::min_value());
+    let one = IVec2::one();
+    assert_eq!(min, max.wrapping_add(one));
+    assert_eq!(max, min.wrapping_sub(one));
+    assert_eq!(
+        IVec2::splat(i32::max_value().wrapping_mul(2)),
+        max.wrapping_mul(IVec2::splat(2))
+    );
+    assert_eq!(max, max.saturating_add(one));
+    assert_eq!(min, min.saturating_sub(one));
+    assert_eq!(max, max.saturating_mul(IVec2::splat(2)));
+    assert_eq!(ivec2(6, 12), ivec2(2, 3).wrapping_mul(ivec2(3, 4)));
+}
+
+#[test]
+fn test_ivec2_min_max() {
+    let a = ivec2(1, 5);
+    let b = ivec2(4, 2);
+    assert_eq!(ivec2(1, 2), a.min(b));
+    assert_eq!(ivec2(1, 2), b.min(a));
+    assert_eq!(ivec2(4, 5), a.max(b));
+    assert_eq!(ivec2(4, 5), b.max(a));
+    assert_eq!(ivec2(2, 3), a.clamp(IVec2::splat(2), IVec2::splat(3)));
+}
+
+#[test]
+fn test_ivec2_hmin_hmax() {
+    let a = ivec2(3, 1);
+    assert_eq!(1, a.min_element());
+    assert_eq!(3, a.max_element());
+}
+
+#[test]
+fn test_ivec2_cmp() {
+    let a = ivec2(1, 2);
+    let b = ivec2(1, 3);
+    assert_eq!(Vec2Mask::new(true, false), a.cmpeq(b));
+    assert_eq!(Vec2Mask::new(false, true), a.cmpne(b));
+    assert_eq!(Vec2Mask::new(false, true), a.cmplt(b));
+    assert_eq!(Vec2Mask::new(true, true), a.cmple(b));
+    assert_eq!(Vec2Mask::new(false, false), a.cmpgt(b));
+    assert_eq!(Vec2Mask::new(true, false), a.cmpge(b));
+    assert!(a.cmpeq(a).all());
+    assert!(!a.cmpne(a).any());
+
+    let a = ivec2(-1, 1);
+    let b = ivec2(0, -1);
+    assert_eq!(Vec2Mask::new(true, false), a.cmplt(b));
+    assert_eq!(Vec2Mask::new(false, true), a.cmpgt(b));
+}
+
+#[test]
+fn test_ivec2_slice() {
+    let a = [1, 2];
+    let b = IVec2::from_slice_unaligned(&a);
+    let c: [i32; 2] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0; 2];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_ivec2_abs_signum() {
+    let a = ivec2(-1, 0);
+    assert_eq!(ivec2(1, 0), a.abs());
+    assert_eq!(ivec2(-1, 0), a.signum());
+    assert_eq!(IVec2::splat(-1), IVec2::splat(i32::min_value()).signum());
+}
+
+#[test]
+fn test_ivec2_as() {
+    let a = ivec2(1, 2);
+    assert_eq!(glam::vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(glam::dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(glam::UVec2::new(1, 2), a.as_uvec2());
+    assert_eq!(a, glam::vec2(1.0, 2.0).as_ivec2());
+    assert_eq!(a, glam::dvec2(1.0, 2.0).as_ivec2());
+    assert_eq!(a, glam::uvec2(1, 2).as_ivec2());
+    assert_eq!(ivec2(-1, 2), glam::vec2(-1.5, 2.5).as_ivec2());
+}
+
+#[test]
+fn test_ivec2_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let a = ivec2(1, 2);
+    let b = ivec2(1, 2);
+    let c = ivec2(4, 3);
+
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    let a_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    b.hash(&mut hasher);
+    let b_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    let c_hashed = hasher.finish();
+
+    assert_eq!(a, b);
+    assert_eq!(a_hashed, b_hashed);
+    assert_ne!(a, c);
+    assert_ne!(a_hashed, c_hashed);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_ivec2_serde() {
+    let a = ivec2(1, 2);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1,2]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<IVec2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec2>("[1]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec2>("[1,2,3]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_ivec2_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (i32, i32) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: IVec2 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let one = IVec2::one();
+    assert_eq!([one, one].iter().sum::<IVec2>(), one + one);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = IVec2::splat(2);
+    assert_eq!([two, two].iter().product::<IVec2>(), two * two);
+}
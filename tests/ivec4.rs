@@ -0,0 +1,337 @@
+use glam::{ivec3, ivec4, IVec3, IVec4, Vec4Mask};
+
+#[test]
+fn test_ivec4_align() {
+    use std::mem;
+    assert_eq!(16, mem::size_of::<IVec4>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<IVec4>());
+    } else {
+        assert_eq!(16, mem::align_of::<IVec4>());
+    }
+}
+
+#[test]
+fn test_ivec4_new() {
+    let v = ivec4(1, 2, 3, 4);
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, 2);
+    assert_eq!(v.z, 3);
+    assert_eq!(v.w, 4);
+
+    let t = (1, 2, 3, 4);
+    let v = IVec4::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1, 2, 3, 4];
+    let v = IVec4::from(a);
+    let a1: [i32; 4] = v.into();
+    assert_eq!(a, a1);
+
+    assert_eq!(IVec4::new(1, 0, 0, 0), IVec4::unit_x());
+    assert_eq!(IVec4::new(0, 1, 0, 0), IVec4::unit_y());
+    assert_eq!(IVec4::new(0, 0, 1, 0), IVec4::unit_z());
+    assert_eq!(IVec4::new(0, 0, 0, 1), IVec4::unit_w());
+}
+
+#[test]
+fn test_ivec4_fmt() {
+    let a = ivec4(1, 2, 3, 4);
+    assert_eq!(format!("{:?}", a), "IVec4(1, 2, 3, 4)");
+    assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+}
+
+#[test]
+fn test_ivec4_zero() {
+    let v = IVec4::zero();
+    assert_eq!((0, 0, 0, 0), v.into());
+    assert_eq!(v, IVec4::default());
+}
+
+#[test]
+fn test_ivec4_splat() {
+    let v = IVec4::splat(1);
+    assert_eq!((1, 1, 1, 1), v.into());
+}
+
+#[test]
+fn test_ivec4_accessors() {
+    let mut a = IVec4::zero();
+    a.x = 1;
+    a.y = 2;
+    a.z = 3;
+    a.w = 4;
+    assert_eq!(1, a.x);
+    assert_eq!(2, a.y);
+    assert_eq!(3, a.z);
+    assert_eq!(4, a.w);
+    assert_eq!(ivec4(1, 2, 3, 4), a);
+
+    let mut a = IVec4::zero();
+    a[0] = 1;
+    a[1] = 2;
+    a[2] = 3;
+    a[3] = 4;
+    assert_eq!(1, a[0]);
+    assert_eq!(2, a[1]);
+    assert_eq!(3, a[2]);
+    assert_eq!(4, a[3]);
+    assert_eq!(ivec4(1, 2, 3, 4), a);
+}
+
+#[test]
+fn test_ivec4_funcs() {
+    let x = ivec4(1, 2, 3, 4);
+    let y = ivec4(4, 3, 2, 1);
+    assert_eq!(20, x.dot(y));
+    assert_eq!(x.truncate(), ivec3(1, 2, 3));
+    assert_eq!(IVec3::from(x), x.truncate());
+}
+
+#[test]
+fn test_ivec4_ops() {
+    let a = ivec4(2, 4, 6, 8);
+    assert_eq!(ivec4(4, 8, 12, 16), a + a);
+    assert_eq!(ivec4(0, 0, 0, 0), a - a);
+    assert_eq!(ivec4(4, 16, 36, 64), a * a);
+    assert_eq!(ivec4(1, 1, 1, 1), a / a);
+    assert_eq!(ivec4(2, 1, 0, 3), a % ivec4(3, 3, 3, 5));
+    assert_eq!(ivec4(4, 8, 12, 16), a * 2);
+    assert_eq!(ivec4(4, 8, 12, 16), 2 * a);
+    assert_eq!(ivec4(1, 2, 3, 4), a / 2);
+    assert_eq!(ivec4(2, 1, 0, 0), 4 / a);
+    assert_eq!(ivec4(0, 0, 0, 0), a % 2);
+    assert_eq!(ivec4(-2, -4, -6, -8), -a);
+}
+
+#[test]
+fn test_ivec4_assign_ops() {
+    let a = ivec4(1, 2, 3, 4);
+    let mut b = a;
+    b += a;
+    assert_eq!(ivec4(2, 4, 6, 8), b);
+    b -= a;
+    assert_eq!(ivec4(1, 2, 3, 4), b);
+    b *= a;
+    assert_eq!(ivec4(1, 4, 9, 16), b);
+    b /= a;
+    assert_eq!(ivec4(1, 2, 3, 4), b);
+    b *= 2;
+    assert_eq!(ivec4(2, 4, 6, 8), b);
+    b /= 2;
+    assert_eq!(ivec4(1, 2, 3, 4), b);
+    b %= 2;
+    assert_eq!(ivec4(1, 0, 1, 0), b);
+    b += 3;
+    assert_eq!(ivec4(4, 3, 4, 3), b);
+    b -= 1;
+    assert_eq!(ivec4(3, 2, 3, 2), b);
+}
+
+#[test]
+fn test_ivec4_bit_ops() {
+    let a = ivec4(0b0101, 0b0110, 0b1100, 0b1111);
+    let b = ivec4(0b0011, 0b0011, 0b0011, 0b0011);
+    assert_eq!(ivec4(0b0001, 0b0010, 0b0000, 0b0011), a & b);
+    assert_eq!(ivec4(0b0111, 0b0111, 0b1111, 0b1111), a | b);
+    assert_eq!(ivec4(0b0110, 0b0101, 0b1111, 0b1100), a ^ b);
+    assert_eq!(ivec4(0b0100, 0b0100, 0b0100, 0b0100), a & 0b0100);
+    assert_eq!(ivec4(0b0101, 0b0111, 0b1101, 0b1111), a | 0b0001);
+    assert_eq!(ivec4(0b1010, 0b1001, 0b0011, 0b0000), a ^ 0b1111);
+    assert_eq!(ivec4(-1, -1, -1, -1), !IVec4::zero());
+    assert_eq!(ivec4(0b1010, 0b1100, 0b11000, 0b11110), a << 1);
+    assert_eq!(ivec4(0b0010, 0b0011, 0b0110, 0b0111), a >> 1);
+    assert_eq!(ivec4(0b1010, 0b1100, 0b11000, 0b11110), a << 1u32);
+    assert_eq!(ivec4(0b0010, 0b0011, 0b0110, 0b0111), a >> 1u32);
+    assert_eq!(
+        ivec4(0b10100, 0b1100, 0b1100, 0b1111),
+        a << ivec4(2, 1, 0, 0)
+    );
+    assert_eq!(
+        ivec4(0b0001, 0b0011, 0b0110, 0b1111),
+        a >> ivec4(2, 1, 1, 0)
+    );
+}
+
+#[test]
+fn test_ivec4_wrapping_saturating() {
+    let max = IVec4::splat(i32::max_value());
+    let min = IVec4::splat(i32::min_value());
+    let one = IVec4::one();
+    assert_eq!(min, max.wrapping_add(one));
+    assert_eq!(max, min.wrapping_sub(one));
+    assert_eq!(
+        IVec4::splat(i32::max_value().wrapping_mul(2)),
+        max.wrapping_mul(IVec4::splat(2))
+    );
+    assert_eq!(max, max.saturating_add(one));
+    assert_eq!(min, min.saturating_sub(one));
+    assert_eq!(max, max.saturating_mul(IVec4::splat(2)));
+    assert_eq!(
+        ivec4(6, 12, 20, 30),
+        ivec4(2, 3, 4, 5).wrapping_mul(ivec4(3, 4, 5, 6))
+    );
+}
+
+#[test]
+fn test_ivec4_min_max() {
+    let a = ivec4(1, 5, 3, 7);
+    let b = ivec4(4, 2, 6, 0);
+    assert_eq!(ivec4(1, 2, 3, 0), a.min(b));
+    assert_eq!(ivec4(1, 2, 3, 0), b.min(a));
+    assert_eq!(ivec4(4, 5, 6, 7), a.max(b));
+    assert_eq!(ivec4(4, 5, 6, 7), b.max(a));
+    assert_eq!(ivec4(2, 3, 3, 3), a.clamp(IVec4::splat(2), IVec4::splat(3)));
+}
+
+#[test]
+fn test_ivec4_hmin_hmax() {
+    let a = ivec4(3, 1, 4, 2);
+    assert_eq!(1, a.min_element());
+    assert_eq!(4, a.max_element());
+}
+
+#[test]
+fn test_ivec4_cmp() {
+    let a = ivec4(1, 2, 3, 4);
+    let b = ivec4(1, 3, 2, 4);
+    assert_eq!(Vec4Mask::new(true, false, false, true), a.cmpeq(b));
+    assert_eq!(Vec4Mask::new(false, true, true, false), a.cmpne(b));
+    assert_eq!(Vec4Mask::new(false, true, false, false), a.cmplt(b));
+    assert_eq!(Vec4Mask::new(true, true, false, true), a.cmple(b));
+    assert_eq!(Vec4Mask::new(false, false, true, false), a.cmpgt(b));
+    assert_eq!(Vec4Mask::new(true, false, true, true), a.cmpge(b));
+    assert!(a.cmpeq(a).all());
+    assert!(!a.cmpne(a).any());
+
+    let a = ivec4(-1, 1, -2, 0);
+    let b = ivec4(0, -1, -2, 1);
+    assert_eq!(Vec4Mask::new(true, false, false, true), a.cmplt(b));
+    assert_eq!(Vec4Mask::new(false, true, false, false), a.cmpgt(b));
+}
+
+#[test]
+fn test_ivec4_slice() {
+    let a = [1, 2, 3, 4];
+    let b = IVec4::from_slice_unaligned(&a);
+    let c: [i32; 4] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0; 4];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_ivec4_abs_signum() {
+    let a = ivec4(-1, 0, 5, -7);
+    assert_eq!(ivec4(1, 0, 5, 7), a.abs());
+    assert_eq!(ivec4(-1, 0, 1, -1), a.signum());
+    assert_eq!(IVec4::splat(-1), IVec4::splat(i32::min_value()).signum());
+}
+
+#[test]
+fn test_ivec4_as() {
+    let a = ivec4(1, 2, 3, 4);
+    assert_eq!(glam::vec4(1.0, 2.0, 3.0, 4.0), a.as_vec4());
+    assert_eq!(glam::dvec4(1.0, 2.0, 3.0, 4.0), a.as_dvec4());
+    assert_eq!(glam::UVec4::new(1, 2, 3, 4), a.as_uvec4());
+    assert_eq!(a, glam::vec4(1.0, 2.0, 3.0, 4.0).as_ivec4());
+    assert_eq!(a, glam::dvec4(1.0, 2.0, 3.0, 4.0).as_ivec4());
+    assert_eq!(a, glam::uvec4(1, 2, 3, 4).as_ivec4());
+    assert_eq!(
+        ivec4(-1, 2, -3, 4),
+        glam::vec4(-1.5, 2.5, -3.9, 4.1).as_ivec4()
+    );
+}
+
+#[test]
+fn test_ivec4_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let a = ivec4(1, 2, 3, 4);
+    let b = ivec4(1, 2, 3, 4);
+    let c = ivec4(4, 3, 2, 1);
+
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    let a_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    b.hash(&mut hasher);
+    let b_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    let c_hashed = hasher.finish();
+
+    assert_eq!(a, b);
+    assert_eq!(a_hashed, b_hashed);
+    assert_ne!(a, c);
+    assert_ne!(a_hashed, c_hashed);
+}
+
+#[cfg(vec4_sse2)]
+#[test]
+fn test_ivec4_m128i() {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let v0 = ivec4(1, 2, 3, 4);
+    let m0: __m128i = v0.into();
+    let mut a0 = [0; 4];
+    unsafe {
+        _mm_storeu_si128(a0.as_mut_ptr() as *mut __m128i, m0);
+    }
+    assert_eq!([1, 2, 3, 4], a0);
+    let v1 = IVec4::from(m0);
+    assert_eq!(v0, v1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_ivec4_serde() {
+    let a = ivec4(1, 2, 3, 4);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1,2,3,4]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<IVec4>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec4>("[1]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec4>("[1,2]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec4>("[1,2,3]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<IVec4>("[1,2,3,4,5]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_ivec4_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (i32, i32, i32, i32) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: IVec4 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let one = IVec4::one();
+    assert_eq!([one, one].iter().sum::<IVec4>(), one + one);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = IVec4::splat(2);
+    assert_eq!([two, two].iter().product::<IVec4>(), two * two);
+}
@@ -209,3 +209,94 @@ fn test_mat2_is_finite() {
     assert!(!(Mat2::identity() * NEG_INFINITY).is_finite());
     assert!(!(Mat2::identity() * NAN).is_finite());
 }
+
+#[test]
+fn test_mat2_try_inverse() {
+    let m = Mat2::from_cols(vec2(2.0, 1.0), vec2(-1.0, 0.5));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-10).try_inverse().is_some());
+
+    assert_eq!(None, Mat2::zero().try_inverse());
+    assert_eq!(Mat2::zero(), Mat2::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = Mat2::from_cols(vec2(1.0, 2.0), vec2(2.0, 4.0));
+    for &scale in [1.0, 1.0e-10, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f32::NAN).try_inverse());
+}
+
+#[test]
+fn test_mat2_solve_lu() {
+    let m = Mat2::from_cols(vec2(0.0, 2.0), vec2(1.0, 3.0));
+    let b = vec2(1.0, -1.0);
+    let x = m.solve(b).unwrap();
+    assert_approx_eq!(b, m * x, 1.0e-5);
+    assert_approx_eq!(m.inverse() * b, x, 1.0e-5);
+
+    let (p, l, u) = m.lu().unwrap();
+    assert_approx_eq!(p * m, l * u, 1.0e-5);
+    let (p, l, u) = (
+        p.to_cols_array_2d(),
+        l.to_cols_array_2d(),
+        u.to_cols_array_2d(),
+    );
+    for (col, ((p, l), u)) in p.iter().zip(l.iter()).zip(u.iter()).enumerate() {
+        assert_eq!(1.0, p.iter().sum::<f32>());
+        assert!(p.iter().all(|&e| e == 0.0 || e == 1.0));
+        assert_eq!(1.0, l[col]);
+        assert!(l[..col].iter().all(|&e| e == 0.0));
+        assert!(u[col + 1..].iter().all(|&e| e == 0.0));
+    }
+
+    let singular = Mat2::from_cols(vec2(0.0, 2.0), vec2(0.0, 4.0));
+    assert_eq!(None, singular.solve(b));
+    assert_eq!(None, singular.lu());
+    assert_eq!(None, (singular * 1.0e-6).solve(b));
+    assert_eq!(None, Mat2::zero().solve(b));
+    assert_eq!(None, (m * std::f32::NAN).solve(b));
+    assert_eq!(Some(b), Mat2::identity().solve(b));
+}
+
+#[test]
+fn test_mat2_cholesky() {
+    let m = Mat2::from_cols(vec2(0.0, 2.0), vec2(1.0, 3.0));
+    let spd = m.transpose() * m + Mat2::identity();
+    let l = spd.cholesky().unwrap();
+    assert_approx_eq!(spd, l * l.transpose(), 1.0e-4);
+    let l = l.to_cols_array_2d();
+    for (col, column) in l.iter().enumerate() {
+        assert!(column[col] > 0.0);
+        assert!(column[..col].iter().all(|&e| e == 0.0));
+    }
+
+    assert_eq!(Some(Mat2::identity()), Mat2::identity().cholesky());
+    // symmetric but not positive definite
+    assert_eq!(
+        None,
+        (m.transpose() * m - Mat2::identity() * 100.0).cholesky()
+    );
+    assert_eq!(None, Mat2::zero().cholesky());
+}
+
+#[test]
+fn test_mat2_qr() {
+    let check = |m: Mat2| {
+        let (q, r) = m.qr();
+        assert_approx_eq!(m, q * r, 1.0e-5);
+        assert_approx_eq!(Mat2::identity(), q.transpose() * q, 1.0e-5);
+        let r = r.to_cols_array_2d();
+        for (col, column) in r.iter().enumerate() {
+            assert!(column[col] >= 0.0);
+            assert!(column[col + 1..].iter().all(|&e| e == 0.0));
+        }
+        r
+    };
+    check(Mat2::from_cols(vec2(0.0, 2.0), vec2(1.0, 3.0)));
+    check(Mat2::identity());
+    let r = check(Mat2::from_cols(vec2(0.0, 2.0), vec2(0.0, 4.0)));
+    assert_approx_eq!(0.0, r[1][1], 1.0e-5);
+    check(Mat2::zero());
+}
@@ -83,6 +83,24 @@ fn test_mat3_mul() {
     assert_approx_eq!(vec3(-1.0, 0.0, 0.0), mat_a.mul_vec3(Vec3::unit_y()));
     assert_approx_eq!(vec3a(-1.0, 0.0, 0.0), mat_a * Vec3A::unit_y());
     assert_approx_eq!(vec3a(-1.0, 0.0, 0.0), mat_a.mul_vec3a(Vec3A::unit_y()));
+
+    let mat_b = Mat3::from_cols(
+        vec3(1.0, 2.0, 3.0),
+        vec3(4.0, 5.0, 6.0),
+        vec3(7.0, 8.0, 10.0),
+    );
+    let mat_c = Mat3::from_cols(
+        vec3(2.0, 0.0, 1.0),
+        vec3(1.0, 3.0, 0.0),
+        vec3(0.0, 1.0, 4.0),
+    );
+    let expected = Mat3::from_cols(
+        vec3(9.0, 12.0, 16.0),
+        vec3(13.0, 17.0, 21.0),
+        vec3(32.0, 37.0, 46.0),
+    );
+    assert_eq!(expected, mat_b * mat_c);
+    assert_eq!(expected, mat_b.mul_mat3(&mat_c));
 }
 
 #[test]
@@ -101,6 +119,54 @@ fn test_mat3_transform2d() {
     assert_approx_eq!(result2, (mat_b * Vec2::unit_y().extend(1.0)).truncate());
 }
 
+#[test]
+fn test_mat3_transform2d_slice() {
+    let points = [
+        vec2(1.0, 2.0),
+        vec2(-3.0, 0.5),
+        vec2(0.25, -4.0),
+        vec2(0.0, 0.0),
+        vec2(7.0, -1.5),
+    ];
+    let mat_b = Mat3::from_scale_angle_translation(
+        Vec2::new(0.5, 1.5),
+        f32::to_radians(30.0),
+        Vec2::new(1.0, 2.0),
+    );
+    // a projective matrix as well, so the homogeneous coordinate isn't 1.0
+    let mat_c = Mat3::from_cols(
+        vec3(1.0, 0.5, 0.25),
+        vec3(-0.5, 2.0, -0.125),
+        vec3(3.0, 1.0, 2.0),
+    );
+    for m in [mat_b, mat_c].iter() {
+        // odd lengths leave one element to be transformed on its own
+        for len in 0..=points.len() {
+            let mut result = points;
+            m.transform_points2(&mut result[..len]);
+            for i in 0..points.len() {
+                let expected = if i < len {
+                    m.transform_point2(points[i])
+                } else {
+                    points[i]
+                };
+                assert_eq!(expected, result[i]);
+            }
+
+            let mut result = points;
+            m.transform_vectors2(&mut result[..len]);
+            for i in 0..points.len() {
+                let expected = if i < len {
+                    m.transform_vector2(points[i])
+                } else {
+                    points[i]
+                };
+                assert_eq!(expected, result[i]);
+            }
+        }
+    }
+}
+
 #[test]
 fn test_from_ypr() {
     let zero = deg(0.0);
@@ -191,6 +257,15 @@ fn test_mat3_inverse() {
     assert_approx_eq!(Mat3::identity(), m * m_inv);
     assert_approx_eq!(Mat3::identity(), m_inv * m);
     assert_approx_eq!(m_inv, rotz_inv * scale_inv);
+
+    let m = Mat3::from_cols(
+        vec3(1.0, 2.0, 3.0),
+        vec3(4.0, 5.0, 6.0),
+        vec3(7.0, 8.0, 10.0),
+    );
+    let m_inv = m.inverse();
+    assert_approx_eq!(Mat3::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(Mat3::identity(), m_inv * m, 1.0e-5);
 }
 
 #[test]
@@ -273,3 +348,237 @@ fn test_mat3_is_finite() {
     assert!(!(Mat3::identity() * NEG_INFINITY).is_finite());
     assert!(!(Mat3::identity() * NAN).is_finite());
 }
+
+#[test]
+fn test_mat3_symmetric_eigen() {
+    let rotation = Mat3::from_axis_angle(vec3(1.0, -2.0, 0.5).normalize(), deg(37.0));
+    let m = rotation * Mat3::from_scale(vec3(2.0, 7.0, -3.0)) * rotation.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_approx_eq!(vec3(7.0, 2.0, -3.0), values, 1.0e-5);
+    assert_approx_eq!(1.0, vectors.determinant(), 1.0e-5);
+    assert_approx_eq!(Mat3::identity(), vectors.transpose() * vectors, 1.0e-5);
+    assert_approx_eq!(
+        m,
+        vectors * Mat3::from_scale(values) * vectors.transpose(),
+        1.0e-5
+    );
+    // the eigenvectors match the columns of the rotation up to sign
+    assert_approx_eq!(1.0, vectors.x_axis.dot(rotation.y_axis).abs(), 1.0e-5);
+    assert_approx_eq!(1.0, vectors.y_axis.dot(rotation.x_axis).abs(), 1.0e-5);
+    assert_approx_eq!(1.0, vectors.z_axis.dot(rotation.z_axis).abs(), 1.0e-5);
+    // the first two have a positive largest component
+    for v in [vectors.x_axis, vectors.y_axis].iter() {
+        let abs = v.abs();
+        let max = abs.max_element();
+        assert!(abs.x == max && v.x > 0.0 || abs.y == max && v.y > 0.0 || v.z > 0.0);
+    }
+
+    // already diagonal and repeated eigenvalues
+    let (values, vectors) = Mat3::from_scale(vec3(1.0, 3.0, 2.0)).symmetric_eigen();
+    assert_eq!(vec3(3.0, 2.0, 1.0), values);
+    assert_eq!(
+        Mat3::from_cols(Vec3::unit_y(), Vec3::unit_z(), Vec3::unit_x()),
+        vectors
+    );
+    let (values, vectors) = (Mat3::identity() * 2.0).symmetric_eigen();
+    assert_eq!(Vec3::splat(2.0), values);
+    assert_eq!(Mat3::identity(), vectors);
+    let (values, vectors) = Mat3::zero().symmetric_eigen();
+    assert_eq!(Vec3::zero(), values);
+    assert_eq!(Mat3::identity(), vectors);
+}
+
+#[test]
+fn test_mat3_svd() {
+    let check = |m: Mat3| {
+        let (u, sigma, v) = m.svd();
+        let u_sigma = Mat3::from_cols(u.x_axis * sigma.x, u.y_axis * sigma.y, u.z_axis * sigma.z);
+        assert_approx_eq!(m, u_sigma * v.transpose(), 1.0e-4);
+        assert_approx_eq!(1.0, u.determinant(), 1.0e-5);
+        assert_approx_eq!(1.0, v.determinant(), 1.0e-5);
+        assert_approx_eq!(Mat3::identity(), u.transpose() * u, 1.0e-5);
+        assert_approx_eq!(Mat3::identity(), v.transpose() * v, 1.0e-5);
+        assert!(sigma.x >= sigma.y && sigma.y >= sigma.z.abs());
+        assert!(sigma.y >= 0.0);
+        assert_eq!(m.determinant() < -1.0e-6, sigma.z < 0.0);
+        sigma
+    };
+
+    let u = Mat3::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let v = Mat3::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), deg(-50.0));
+    let sigma = check(u * Mat3::from_scale(vec3(4.0, 0.5, 2.0)) * v.transpose());
+    assert_approx_eq!(vec3(4.0, 2.0, 0.5), sigma, 1.0e-5);
+
+    // a reflection
+    let sigma = check(u * Mat3::from_scale(vec3(-4.0, 0.5, 2.0)) * v.transpose());
+    assert_approx_eq!(vec3(4.0, 2.0, -0.5), sigma, 1.0e-5);
+
+    // general, singular and zero matrices
+    check(Mat3::from_cols_array_2d(&MATRIX));
+    check(Mat3::from_cols(
+        vec3(1.0, -2.0, 0.5),
+        vec3(3.0, 0.25, -1.0),
+        vec3(-0.5, 2.0, 4.0),
+    ));
+    let sigma = check(Mat3::from_cols(
+        vec3(1.0, 2.0, 3.0),
+        vec3(2.0, 4.0, 6.0),
+        vec3(-1.0, -2.0, -3.0),
+    ));
+    assert_approx_eq!(0.0, sigma.y, 1.0e-5);
+    assert_eq!(Vec3::zero(), check(Mat3::zero()));
+}
+
+#[test]
+fn test_mat3_polar_decompose() {
+    let check = |m: Mat3| {
+        let (rotation, stretch) = m.polar_decompose();
+        assert_approx_eq!(m, rotation * stretch, 1.0e-5);
+        assert_approx_eq!(1.0, rotation.determinant(), 1.0e-5);
+        assert_approx_eq!(Mat3::identity(), rotation.transpose() * rotation, 1.0e-5);
+        assert_approx_eq!(stretch, stretch.transpose(), 1.0e-5);
+        // a reflection is carried by the stretch
+        assert_eq!(m.determinant() < 0.0, stretch.determinant() < 0.0);
+        (rotation, stretch)
+    };
+
+    let rotation = Mat3::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let axes = Mat3::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), deg(-50.0));
+    let stretch = axes * Mat3::from_scale(vec3(4.0, 0.5, 2.0)) * axes.transpose();
+    let (out_rotation, out_stretch) = check(rotation * stretch);
+    assert_approx_eq!(rotation, out_rotation, 1.0e-5);
+    assert_approx_eq!(stretch, out_stretch, 1.0e-5);
+
+    // a rotation is its own rotation part
+    let (out_rotation, out_stretch) = check(rotation);
+    assert_approx_eq!(rotation, out_rotation, 1.0e-5);
+    assert_approx_eq!(Mat3::identity(), out_stretch, 1.0e-5);
+
+    // shear and reflection
+    check(Mat3::from_cols(
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.5, 1.0, 0.0),
+        vec3(0.25, -0.75, 2.0),
+    ));
+    let (_, out_stretch) = check(rotation * Mat3::from_scale(vec3(2.0, -3.0, 1.0)));
+    assert_approx_eq!(-6.0, out_stretch.determinant(), 1.0e-4);
+}
+
+#[test]
+fn test_mat3_try_inverse() {
+    let m = Mat3::from_cols(
+        vec3(2.0, 1.0, 0.0),
+        vec3(-1.0, 0.5, 0.0),
+        vec3(0.5, 0.0, 4.0),
+    );
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-10).try_inverse().is_some());
+
+    assert_eq!(None, Mat3::zero().try_inverse());
+    assert_eq!(Mat3::zero(), Mat3::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = Mat3::from_cols(
+        vec3(1.0, 2.0, -3.0),
+        vec3(2.0, 4.0, -6.0),
+        vec3(0.0, 1.0, 0.0),
+    );
+    for &scale in [1.0, 1.0e-10, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f32::NAN).try_inverse());
+}
+
+#[test]
+fn test_mat3_solve_lu() {
+    let m = Mat3::from_cols(
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, -1.0, 3.0),
+        vec3(4.0, 0.5, -2.0),
+    );
+    let b = vec3(1.0, -1.0, 2.0);
+    let x = m.solve(b).unwrap();
+    assert_approx_eq!(b, m * x, 1.0e-5);
+    assert_approx_eq!(m.inverse() * b, x, 1.0e-5);
+
+    let (p, l, u) = m.lu().unwrap();
+    assert_approx_eq!(p * m, l * u, 1.0e-5);
+    let (p, l, u) = (
+        p.to_cols_array_2d(),
+        l.to_cols_array_2d(),
+        u.to_cols_array_2d(),
+    );
+    for (col, ((p, l), u)) in p.iter().zip(l.iter()).zip(u.iter()).enumerate() {
+        assert_eq!(1.0, p.iter().sum::<f32>());
+        assert!(p.iter().all(|&e| e == 0.0 || e == 1.0));
+        assert_eq!(1.0, l[col]);
+        assert!(l[..col].iter().all(|&e| e == 0.0));
+        assert!(u[col + 1..].iter().all(|&e| e == 0.0));
+    }
+
+    let singular = Mat3::from_cols(
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, -1.0, 3.0),
+        vec3(1.0, 1.0, 4.0),
+    );
+    assert_eq!(None, singular.solve(b));
+    assert_eq!(None, singular.lu());
+    assert_eq!(None, (singular * 1.0e-6).solve(b));
+    assert_eq!(None, Mat3::zero().solve(b));
+    assert_eq!(None, (m * std::f32::NAN).solve(b));
+    assert_eq!(Some(b), Mat3::identity().solve(b));
+}
+
+#[test]
+fn test_mat3_cholesky() {
+    let m = Mat3::from_cols(
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, -1.0, 3.0),
+        vec3(4.0, 0.5, -2.0),
+    );
+    let spd = m.transpose() * m + Mat3::identity();
+    let l = spd.cholesky().unwrap();
+    assert_approx_eq!(spd, l * l.transpose(), 1.0e-4);
+    let l = l.to_cols_array_2d();
+    for (col, column) in l.iter().enumerate() {
+        assert!(column[col] > 0.0);
+        assert!(column[..col].iter().all(|&e| e == 0.0));
+    }
+
+    assert_eq!(Some(Mat3::identity()), Mat3::identity().cholesky());
+    // symmetric but not positive definite
+    assert_eq!(
+        None,
+        (m.transpose() * m - Mat3::identity() * 100.0).cholesky()
+    );
+    assert_eq!(None, Mat3::zero().cholesky());
+}
+
+#[test]
+fn test_mat3_qr() {
+    let check = |m: Mat3| {
+        let (q, r) = m.qr();
+        assert_approx_eq!(m, q * r, 1.0e-5);
+        assert_approx_eq!(Mat3::identity(), q.transpose() * q, 1.0e-5);
+        let r = r.to_cols_array_2d();
+        for (col, column) in r.iter().enumerate() {
+            assert!(column[col] >= 0.0);
+            assert!(column[col + 1..].iter().all(|&e| e == 0.0));
+        }
+        r
+    };
+    check(Mat3::from_cols(
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, -1.0, 3.0),
+        vec3(4.0, 0.5, -2.0),
+    ));
+    check(Mat3::identity());
+    let r = check(Mat3::from_cols(
+        vec3(0.0, 2.0, 1.0),
+        vec3(1.0, -1.0, 3.0),
+        vec3(1.0, 1.0, 4.0),
+    ));
+    assert_approx_eq!(0.0, r[2][2], 1.0e-5);
+    check(Mat3::zero());
+}
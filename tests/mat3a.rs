@@ -0,0 +1,495 @@
+mod support;
+
+use glam::{mat3a, vec2, vec3, vec3a, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use support::deg;
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+const MATRIX: [[f32; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+const ZERO: [[f32; 3]; 3] = [[0.0; 3]; 3];
+
+#[test]
+fn test_mat3a_align() {
+    use std::mem;
+    assert_eq!(48, mem::size_of::<Mat3A>());
+    assert_eq!(16, mem::align_of::<Mat3A>());
+}
+
+#[test]
+fn test_mat3a_identity() {
+    let identity = Mat3A::identity();
+    assert_eq!(IDENTITY, identity.to_cols_array_2d());
+    assert_eq!(Mat3A::from_cols_array_2d(&IDENTITY), identity);
+    assert_eq!(identity, identity * identity);
+    assert_eq!(identity, Mat3A::default());
+}
+
+#[test]
+fn test_mat3a_zero() {
+    assert_eq!(Mat3A::from_cols_array_2d(&ZERO), Mat3A::zero());
+}
+
+#[test]
+fn test_mat3a_accessors() {
+    let mut m = Mat3A::zero();
+    m.x_axis = Vec3A::new(1.0, 2.0, 3.0);
+    m.y_axis = Vec3A::new(4.0, 5.0, 6.0);
+    m.z_axis = Vec3A::new(7.0, 8.0, 9.0);
+    assert_eq!(Mat3A::from_cols_array_2d(&MATRIX), m);
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), m.x_axis);
+    assert_eq!(Vec3A::new(4.0, 5.0, 6.0), m.y_axis);
+    assert_eq!(Vec3A::new(7.0, 8.0, 9.0), m.z_axis);
+}
+
+#[test]
+fn test_mat3a_from_axes() {
+    let a = Mat3A::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(MATRIX, a.to_cols_array_2d());
+    let b = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, b);
+    let c = mat3a(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a, c);
+    let d = b.to_cols_array();
+    let f = Mat3A::from_cols_array(&d);
+    assert_eq!(b, f);
+}
+
+#[test]
+fn test_from_rotation() {
+    let rot_x1 = Mat3A::from_rotation_x(deg(180.0));
+    let rot_x2 = Mat3A::from_axis_angle(Vec3::unit_x(), deg(180.0));
+    assert_approx_eq!(rot_x1, rot_x2);
+    let rot_y1 = Mat3A::from_rotation_y(deg(180.0));
+    let rot_y2 = Mat3A::from_axis_angle(Vec3::unit_y(), deg(180.0));
+    assert_approx_eq!(rot_y1, rot_y2);
+    let rot_z1 = Mat3A::from_rotation_z(deg(180.0));
+    let rot_z2 = Mat3A::from_axis_angle(Vec3::unit_z(), deg(180.0));
+    assert_approx_eq!(rot_z1, rot_z2);
+}
+
+#[test]
+fn test_mat3a_mul() {
+    let mat_a = Mat3A::from_axis_angle(Vec3::unit_z(), deg(90.0));
+    assert_approx_eq!(vec3(-1.0, 0.0, 0.0), mat_a * Vec3::unit_y());
+    assert_approx_eq!(vec3(-1.0, 0.0, 0.0), mat_a.mul_vec3(Vec3::unit_y()));
+    assert_approx_eq!(vec3a(-1.0, 0.0, 0.0), mat_a * Vec3A::unit_y());
+    assert_approx_eq!(vec3a(-1.0, 0.0, 0.0), mat_a.mul_vec3a(Vec3A::unit_y()));
+
+    let mat_b = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 10.0),
+    );
+    let mat_c = Mat3A::from_cols(
+        vec3a(2.0, 0.0, 1.0),
+        vec3a(1.0, 3.0, 0.0),
+        vec3a(0.0, 1.0, 4.0),
+    );
+    let expected = Mat3A::from_cols(
+        vec3a(9.0, 12.0, 16.0),
+        vec3a(13.0, 17.0, 21.0),
+        vec3a(32.0, 37.0, 46.0),
+    );
+    assert_eq!(expected, mat_b * mat_c);
+    assert_eq!(expected, mat_b.mul_mat3a(&mat_c));
+}
+
+#[test]
+fn test_mat3a_mul_mat3() {
+    let a = Mat3::from_rotation_ypr(deg(30.0), deg(60.0), deg(90.0));
+    let b = Mat3::from_scale(vec3(2.0, 3.0, 4.0)) * Mat3::from_rotation_x(deg(45.0));
+    assert_approx_eq!(Mat3A::from(a * b), Mat3A::from(a) * Mat3A::from(b));
+    let v = vec3(1.0, 2.0, 3.0);
+    assert_approx_eq!(a * v, Mat3A::from(a) * v);
+}
+
+#[test]
+fn test_mat3a_transform2d() {
+    let mat_b = Mat3A::from_scale_angle_translation(
+        Vec2::new(0.5, 1.5),
+        f32::to_radians(90.0),
+        Vec2::new(1.0, 2.0),
+    );
+    let result2 = mat_b.transform_vector2(Vec2::unit_y());
+    assert_approx_eq!(vec2(-1.5, 0.0), result2, 1.0e-6);
+    assert_approx_eq!(result2, (mat_b * Vec2::unit_y().extend(0.0)).truncate());
+
+    let result2 = mat_b.transform_point2(Vec2::unit_y());
+    assert_approx_eq!(vec2(-0.5, 2.0), result2, 1.0e-6);
+    assert_approx_eq!(result2, (mat_b * Vec2::unit_y().extend(1.0)).truncate());
+}
+
+#[test]
+fn test_from_ypr() {
+    let zero = deg(0.0);
+    let yaw = deg(30.0);
+    let pitch = deg(60.0);
+    let roll = deg(90.0);
+    let y0 = Mat3A::from_rotation_y(yaw);
+    let y1 = Mat3A::from_rotation_ypr(yaw, zero, zero);
+    assert_approx_eq!(y0, y1);
+
+    let x0 = Mat3A::from_rotation_x(pitch);
+    let x1 = Mat3A::from_rotation_ypr(zero, pitch, zero);
+    assert_approx_eq!(x0, x1);
+
+    let z0 = Mat3A::from_rotation_z(roll);
+    let z1 = Mat3A::from_rotation_ypr(zero, zero, roll);
+    assert_approx_eq!(z0, z1);
+
+    let yx0 = y0 * x0;
+    let yx1 = Mat3A::from_rotation_ypr(yaw, pitch, zero);
+    assert_approx_eq!(yx0, yx1);
+
+    let yxz0 = y0 * x0 * z0;
+    let yxz1 = Mat3A::from_rotation_ypr(yaw, pitch, roll);
+    assert_approx_eq!(yxz0, yxz1, 1e-6);
+}
+
+#[test]
+fn test_from_scale() {
+    let m = Mat3A::from_scale(Vec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(m * Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 4.0, 8.0));
+    assert_approx_eq!(Vec3A::unit_x() * 2.0, m.x_axis);
+    assert_approx_eq!(Vec3A::unit_y() * 4.0, m.y_axis);
+    assert_approx_eq!(Vec3A::unit_z() * 8.0, m.z_axis);
+}
+
+#[test]
+fn test_mat3a_transpose() {
+    let m = mat3a(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    let mt = m.transpose();
+    assert_eq!(mt.x_axis, vec3a(1.0, 4.0, 7.0));
+    assert_eq!(mt.y_axis, vec3a(2.0, 5.0, 8.0));
+    assert_eq!(mt.z_axis, vec3a(3.0, 6.0, 9.0));
+}
+
+#[test]
+fn test_mat3a_det() {
+    assert_eq!(0.0, Mat3A::zero().determinant());
+    assert_eq!(1.0, Mat3A::identity().determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_x(deg(90.0)).determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_y(deg(180.0)).determinant());
+    assert_eq!(1.0, Mat3A::from_rotation_z(deg(270.0)).determinant());
+    assert_eq!(
+        2.0 * 2.0 * 2.0,
+        Mat3A::from_scale(vec3(2.0, 2.0, 2.0)).determinant()
+    );
+}
+
+#[test]
+fn test_mat3a_inverse() {
+    // assert_eq!(None, Mat3A::zero().inverse());
+    let inv = Mat3A::identity().inverse();
+    // assert_ne!(None, inv);
+    assert_approx_eq!(Mat3A::identity(), inv);
+
+    let rotz = Mat3A::from_rotation_z(deg(90.0));
+    let rotz_inv = rotz.inverse();
+    // assert_ne!(None, rotz_inv);
+    // let rotz_inv = rotz_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), rotz * rotz_inv);
+    assert_approx_eq!(Mat3A::identity(), rotz_inv * rotz);
+
+    let scale = Mat3A::from_scale(vec3(4.0, 5.0, 6.0));
+    let scale_inv = scale.inverse();
+    // assert_ne!(None, scale_inv);
+    // let scale_inv = scale_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), scale * scale_inv);
+    assert_approx_eq!(Mat3A::identity(), scale_inv * scale);
+
+    let m = scale * rotz;
+    let m_inv = m.inverse();
+    // assert_ne!(None, m_inv);
+    // let m_inv = m_inv.unwrap();
+    assert_approx_eq!(Mat3A::identity(), m * m_inv);
+    assert_approx_eq!(Mat3A::identity(), m_inv * m);
+    assert_approx_eq!(m_inv, rotz_inv * scale_inv);
+
+    let m = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 10.0),
+    );
+    let m_inv = m.inverse();
+    assert_approx_eq!(Mat3A::identity(), m * m_inv, 1.0e-5);
+    assert_approx_eq!(Mat3A::identity(), m_inv * m, 1.0e-5);
+}
+
+#[test]
+fn test_mat3a_conversions() {
+    let m = Mat3::from_cols_array_2d(&MATRIX);
+    let ma = Mat3A::from(m);
+    assert_eq!(MATRIX, ma.to_cols_array_2d());
+    assert_eq!(m, Mat3::from(ma));
+
+    let m4 = Mat4::from(ma);
+    assert_eq!(m4.x_axis, Vec4::new(1.0, 2.0, 3.0, 0.0));
+    assert_eq!(m4.y_axis, Vec4::new(4.0, 5.0, 6.0, 0.0));
+    assert_eq!(m4.z_axis, Vec4::new(7.0, 8.0, 9.0, 0.0));
+    assert_eq!(m4.w_axis, Vec4::unit_w());
+    assert_eq!(ma, Mat3A::from_mat4(m4));
+
+    let q = Quat::from_rotation_ypr(deg(30.0), deg(60.0), deg(90.0));
+    let mq = Mat3A::from_quat(q);
+    assert_approx_eq!(Mat3A::from(Mat3::from_quat(q)), mq);
+    assert_approx_eq!(Mat3A::from_mat4(Mat4::from_quat(q)), mq);
+    assert_approx_eq!(q, Quat::from_rotation_mat3a(&mq), 1e-6);
+}
+
+#[test]
+fn test_mat3a_ops() {
+    let m0 = Mat3A::from_cols_array_2d(&MATRIX);
+    let m0x2 = Mat3A::from_cols_array_2d(&[[2.0, 4.0, 6.0], [8.0, 10.0, 12.0], [14.0, 16.0, 18.0]]);
+    assert_eq!(m0x2, m0 * 2.0);
+    assert_eq!(m0x2, 2.0 * m0);
+    assert_eq!(m0x2, m0 + m0);
+    assert_eq!(Mat3A::zero(), m0 - m0);
+    assert_approx_eq!(m0, m0 * Mat3A::identity());
+    assert_approx_eq!(m0, Mat3A::identity() * m0);
+}
+
+#[test]
+fn test_mat3a_fmt() {
+    let a = Mat3A::from_cols_array_2d(&MATRIX);
+    assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mat3a_serde() {
+    let a = Mat3A::from_cols(
+        vec3a(1.0, 2.0, 3.0),
+        vec3a(4.0, 5.0, 6.0),
+        vec3a(7.0, 8.0, 9.0),
+    );
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Mat3A>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[1.0,2.0,3.0,4.0,5.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Mat3A>("[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_mat3a_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a = Mat3A::from_cols_array(&rng1.gen::<[f32; 9]>());
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b = rng2.gen::<Mat3A>();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let id = Mat3A::identity();
+    assert_eq!([id, id].iter().sum::<Mat3A>(), id + id);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = Mat3A::identity() + Mat3A::identity();
+    assert_eq!([two, two].iter().product::<Mat3A>(), two * two);
+}
+
+#[test]
+fn test_mat3a_is_finite() {
+    use std::f32::INFINITY;
+    use std::f32::NAN;
+    use std::f32::NEG_INFINITY;
+    assert!(Mat3A::identity().is_finite());
+    assert!(!(Mat3A::identity() * INFINITY).is_finite());
+    assert!(!(Mat3A::identity() * NEG_INFINITY).is_finite());
+    assert!(!(Mat3A::identity() * NAN).is_finite());
+}
+
+#[test]
+fn test_mat3a_symmetric_eigen_svd() {
+    let rotation = Mat3A::from_rotation_ypr(deg(10.0), deg(20.0), deg(30.0));
+    let m = rotation * Mat3A::from_scale(vec3(1.0, 5.0, 3.0)) * rotation.transpose();
+    let (values, vectors) = m.symmetric_eigen();
+    assert_approx_eq!(vec3a(5.0, 3.0, 1.0), values, 1.0e-5);
+    assert_approx_eq!(
+        m,
+        vectors * Mat3A::from_scale(values.into()) * vectors.transpose(),
+        1.0e-5
+    );
+    assert_eq!(
+        (values.into(), vectors.into()),
+        Mat3::from(m).symmetric_eigen()
+    );
+
+    let (u, sigma, v) = m.svd();
+    assert_approx_eq!(values, sigma, 1.0e-5);
+    assert_approx_eq!(
+        m,
+        u * Mat3A::from_scale(sigma.into()) * v.transpose(),
+        1.0e-5
+    );
+    assert_eq!((u.into(), sigma.into(), v.into()), Mat3::from(m).svd());
+}
+
+#[test]
+fn test_mat3a_polar_decompose() {
+    let m = Mat3A::from_cols(
+        vec3a(1.0, 0.0, -1.0),
+        vec3a(0.5, 2.0, 0.0),
+        vec3a(0.25, -0.75, -2.0),
+    );
+    let (rotation, stretch) = m.polar_decompose();
+    assert!(m.determinant() < 0.0);
+    assert!(stretch.determinant() < 0.0);
+    assert_approx_eq!(m, rotation * stretch, 1.0e-5);
+    assert_approx_eq!(1.0, rotation.determinant(), 1.0e-5);
+    assert_eq!(
+        (rotation.into(), stretch.into()),
+        Mat3::from(m).polar_decompose()
+    );
+}
+
+#[test]
+fn test_mat3a_try_inverse() {
+    let m = Mat3A::from_cols(
+        vec3a(2.0, 1.0, 0.0),
+        vec3a(-1.0, 0.5, 0.0),
+        vec3a(0.5, 0.0, 4.0),
+    );
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-10).try_inverse().is_some());
+
+    assert_eq!(None, Mat3A::zero().try_inverse());
+    assert_eq!(Mat3A::zero(), Mat3A::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = Mat3A::from_cols(
+        vec3a(1.0, 2.0, -3.0),
+        vec3a(2.0, 4.0, -6.0),
+        vec3a(0.0, 1.0, 0.0),
+    );
+    for &scale in [1.0, 1.0e-10, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f32::NAN).try_inverse());
+}
+
+#[test]
+fn test_mat3a_solve_lu() {
+    let m = Mat3A::from_cols(
+        vec3a(0.0, 2.0, 1.0),
+        vec3a(1.0, -1.0, 3.0),
+        vec3a(4.0, 0.5, -2.0),
+    );
+    let b = vec3a(1.0, -1.0, 2.0);
+    let x = m.solve(b).unwrap();
+    assert_approx_eq!(b, m * x, 1.0e-5);
+    assert_approx_eq!(m.inverse() * b, x, 1.0e-5);
+
+    let (p, l, u) = m.lu().unwrap();
+    assert_approx_eq!(p * m, l * u, 1.0e-5);
+    let (p, l, u) = (
+        p.to_cols_array_2d(),
+        l.to_cols_array_2d(),
+        u.to_cols_array_2d(),
+    );
+    for (col, ((p, l), u)) in p.iter().zip(l.iter()).zip(u.iter()).enumerate() {
+        assert_eq!(1.0, p.iter().sum::<f32>());
+        assert!(p.iter().all(|&e| e == 0.0 || e == 1.0));
+        assert_eq!(1.0, l[col]);
+        assert!(l[..col].iter().all(|&e| e == 0.0));
+        assert!(u[col + 1..].iter().all(|&e| e == 0.0));
+    }
+
+    let singular = Mat3A::from_cols(
+        vec3a(0.0, 2.0, 1.0),
+        vec3a(1.0, -1.0, 3.0),
+        vec3a(1.0, 1.0, 4.0),
+    );
+    assert_eq!(None, singular.solve(b));
+    assert_eq!(None, singular.lu());
+    assert_eq!(None, (singular * 1.0e-6).solve(b));
+    assert_eq!(None, Mat3A::zero().solve(b));
+    assert_eq!(None, (m * std::f32::NAN).solve(b));
+    assert_eq!(Some(b), Mat3A::identity().solve(b));
+}
+
+#[test]
+fn test_mat3a_cholesky() {
+    let m = Mat3A::from_cols(
+        vec3a(0.0, 2.0, 1.0),
+        vec3a(1.0, -1.0, 3.0),
+        vec3a(4.0, 0.5, -2.0),
+    );
+    let spd = m.transpose() * m + Mat3A::identity();
+    let l = spd.cholesky().unwrap();
+    assert_approx_eq!(spd, l * l.transpose(), 1.0e-4);
+    let l = l.to_cols_array_2d();
+    for (col, column) in l.iter().enumerate() {
+        assert!(column[col] > 0.0);
+        assert!(column[..col].iter().all(|&e| e == 0.0));
+    }
+
+    assert_eq!(Some(Mat3A::identity()), Mat3A::identity().cholesky());
+    // symmetric but not positive definite
+    assert_eq!(
+        None,
+        (m.transpose() * m - Mat3A::identity() * 100.0).cholesky()
+    );
+    assert_eq!(None, Mat3A::zero().cholesky());
+}
+
+#[test]
+fn test_mat3a_qr() {
+    let check = |m: Mat3A| {
+        let (q, r) = m.qr();
+        assert_approx_eq!(m, q * r, 1.0e-5);
+        assert_approx_eq!(Mat3A::identity(), q.transpose() * q, 1.0e-5);
+        let r = r.to_cols_array_2d();
+        for (col, column) in r.iter().enumerate() {
+            assert!(column[col] >= 0.0);
+            assert!(column[col + 1..].iter().all(|&e| e == 0.0));
+        }
+        r
+    };
+    check(Mat3A::from_cols(
+        vec3a(0.0, 2.0, 1.0),
+        vec3a(1.0, -1.0, 3.0),
+        vec3a(4.0, 0.5, -2.0),
+    ));
+    check(Mat3A::identity());
+    let r = check(Mat3A::from_cols(
+        vec3a(0.0, 2.0, 1.0),
+        vec3a(1.0, -1.0, 3.0),
+        vec3a(1.0, 1.0, 4.0),
+    ));
+    assert_approx_eq!(0.0, r[2][2], 1.0e-5);
+    check(Mat3A::zero());
+}
@@ -323,6 +323,63 @@ fn test_mat4_decompose() {
     );
 }
 
+#[test]
+fn test_mat4_decompose_shear() {
+    let in_scale = Vec3::new(1.0, 2.0, 4.0);
+    let in_shear = Vec3::new(0.5, -0.25, 1.5);
+    let in_rotation = Quat::from_rotation_ypr(deg(-45.0), deg(30.0), deg(60.0));
+    let in_translation = Vec3::new(-2.0, 4.0, -0.125);
+    let in_mat = Mat4::from_scale_shear_rotation_translation(
+        in_scale,
+        in_shear,
+        in_rotation,
+        in_translation,
+    );
+    let p = vec3(1.0, -2.0, 3.0);
+    let sheared = vec3(p.x + 0.5 * p.y - 0.25 * p.z, p.y + 1.5 * p.z, p.z);
+    assert_approx_eq!(
+        in_rotation * (in_scale * sheared) + in_translation,
+        in_mat.transform_point3(p),
+        1e-5
+    );
+
+    let (out_scale, out_shear, out_rotation, out_translation) =
+        in_mat.to_scale_shear_rotation_translation();
+    assert_approx_eq!(in_scale, out_scale, 1e-5);
+    assert_approx_eq!(in_shear, out_shear, 1e-5);
+    assert_approx_eq!(1.0, in_rotation.dot(out_rotation).abs(), 1e-5);
+    assert_approx_eq!(in_translation, out_translation);
+
+    // without shear this matches to_scale_rotation_translation
+    let in_mat = Mat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+    let (out_scale, out_shear, out_rotation, _) = in_mat.to_scale_shear_rotation_translation();
+    assert_approx_eq!(in_scale, out_scale, 1e-5);
+    assert_approx_eq!(Vec3::zero(), out_shear, 1e-5);
+    assert_approx_eq!(1.0, in_rotation.dot(out_rotation).abs(), 1e-5);
+
+    // a reflection gives a negative x scale
+    let in_mat = Mat4::from_scale_shear_rotation_translation(
+        Vec3::new(1.0, -2.0, 4.0),
+        in_shear,
+        in_rotation,
+        in_translation,
+    );
+    let (out_scale, out_shear, out_rotation, out_translation) =
+        in_mat.to_scale_shear_rotation_translation();
+    assert!(out_scale.x < 0.0 && out_scale.y > 0.0 && out_scale.z > 0.0);
+    assert!(out_rotation.is_normalized());
+    assert_approx_eq!(
+        in_mat,
+        Mat4::from_scale_shear_rotation_translation(
+            out_scale,
+            out_shear,
+            out_rotation,
+            out_translation
+        ),
+        1e-5
+    );
+}
+
 #[test]
 fn test_mat4_look_at() {
     let eye = Vec3::new(0.0, 0.0, -5.0);
@@ -554,3 +611,176 @@ fn test_mat4_is_finite() {
     assert!(!(Mat4::identity() * NEG_INFINITY).is_finite());
     assert!(!(Mat4::identity() * NAN).is_finite());
 }
+
+#[test]
+fn test_mat4_try_inverse() {
+    let m = Mat4::from_cols(
+        vec4(2.0, 1.0, 0.0, 0.0),
+        vec4(-1.0, 0.5, 0.0, 0.0),
+        vec4(0.5, 0.0, 4.0, 0.0),
+        vec4(1.0, 2.0, 3.0, 1.0),
+    );
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    assert_eq!(m.inverse(), m.inverse_or_zero());
+    // invertible at any scale
+    assert!((m * 1.0e-5).try_inverse().is_some());
+
+    assert_eq!(None, Mat4::zero().try_inverse());
+    assert_eq!(Mat4::zero(), Mat4::zero().inverse_or_zero());
+    // columns that are linearly dependent up to rounding are detected at any scale
+    let singular = Mat4::from_cols(
+        vec4(1.0, 2.0, -3.0, 0.5),
+        vec4(2.0, 4.0, -6.0, 1.0),
+        vec4(0.0, 1.0, 0.0, 0.0),
+        vec4(0.0, 0.0, 0.0, 1.0),
+    );
+    for &scale in [1.0, 1.0e-5, 1.0e5].iter() {
+        assert_eq!(None, (singular * scale).try_inverse());
+    }
+    assert_eq!(None, (m * std::f32::NAN).try_inverse());
+
+    // the translation doesn't affect the tolerance
+    let m = Mat4::from_translation(Vec3::new(1.0e7, 0.0, 0.0));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    let m = Mat4::perspective_rh(deg(60.0), 1.5, 0.1, 1000.0);
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+    let m = Mat4::from_scale(Vec3::new(1.0e-3, 2.0, 1.0))
+        * Mat4::from_translation(Vec3::new(-3.0e6, 1.0e7, 5.0e5));
+    assert_eq!(Some(m.inverse()), m.try_inverse());
+}
+
+#[test]
+fn test_mat4_solve_lu() {
+    let m = Mat4::from_cols(
+        vec4(0.0, 2.0, 1.0, -1.0),
+        vec4(1.0, -1.0, 3.0, 0.5),
+        vec4(4.0, 0.5, -2.0, 1.0),
+        vec4(2.0, 1.0, 0.0, 3.0),
+    );
+    let b = vec4(1.0, -1.0, 2.0, 0.5);
+    let x = m.solve(b).unwrap();
+    assert_approx_eq!(b, m * x, 1.0e-5);
+    assert_approx_eq!(m.inverse() * b, x, 1.0e-5);
+
+    let (p, l, u) = m.lu().unwrap();
+    assert_approx_eq!(p * m, l * u, 1.0e-5);
+    let (p, l, u) = (
+        p.to_cols_array_2d(),
+        l.to_cols_array_2d(),
+        u.to_cols_array_2d(),
+    );
+    for (col, ((p, l), u)) in p.iter().zip(l.iter()).zip(u.iter()).enumerate() {
+        assert_eq!(1.0, p.iter().sum::<f32>());
+        assert!(p.iter().all(|&e| e == 0.0 || e == 1.0));
+        assert_eq!(1.0, l[col]);
+        assert!(l[..col].iter().all(|&e| e == 0.0));
+        assert!(u[col + 1..].iter().all(|&e| e == 0.0));
+    }
+
+    let singular = Mat4::from_cols(
+        vec4(0.0, 2.0, 1.0, -1.0),
+        vec4(1.0, -1.0, 3.0, 0.5),
+        vec4(4.0, 0.5, -2.0, 1.0),
+        vec4(1.0, 1.0, 4.0, -0.5),
+    );
+    assert_eq!(None, singular.solve(b));
+    assert_eq!(None, singular.lu());
+    assert_eq!(None, (singular * 1.0e-6).solve(b));
+    assert_eq!(None, Mat4::zero().solve(b));
+    assert_eq!(None, (m * std::f32::NAN).solve(b));
+    assert_eq!(Some(b), Mat4::identity().solve(b));
+}
+
+#[test]
+fn test_mat4_cholesky() {
+    let m = Mat4::from_cols(
+        vec4(0.0, 2.0, 1.0, -1.0),
+        vec4(1.0, -1.0, 3.0, 0.5),
+        vec4(4.0, 0.5, -2.0, 1.0),
+        vec4(2.0, 1.0, 0.0, 3.0),
+    );
+    let spd = m.transpose() * m + Mat4::identity();
+    let l = spd.cholesky().unwrap();
+    assert_approx_eq!(spd, l * l.transpose(), 1.0e-4);
+    let l = l.to_cols_array_2d();
+    for (col, column) in l.iter().enumerate() {
+        assert!(column[col] > 0.0);
+        assert!(column[..col].iter().all(|&e| e == 0.0));
+    }
+
+    assert_eq!(Some(Mat4::identity()), Mat4::identity().cholesky());
+    // symmetric but not positive definite
+    assert_eq!(
+        None,
+        (m.transpose() * m - Mat4::identity() * 100.0).cholesky()
+    );
+    assert_eq!(None, Mat4::zero().cholesky());
+}
+
+#[test]
+fn test_mat4_qr() {
+    let check = |m: Mat4| {
+        let (q, r) = m.qr();
+        assert_approx_eq!(m, q * r, 1.0e-5);
+        assert_approx_eq!(Mat4::identity(), q.transpose() * q, 1.0e-5);
+        let r = r.to_cols_array_2d();
+        for (col, column) in r.iter().enumerate() {
+            assert!(column[col] >= 0.0);
+            assert!(column[col + 1..].iter().all(|&e| e == 0.0));
+        }
+        r
+    };
+    check(Mat4::from_cols(
+        vec4(0.0, 2.0, 1.0, -1.0),
+        vec4(1.0, -1.0, 3.0, 0.5),
+        vec4(4.0, 0.5, -2.0, 1.0),
+        vec4(2.0, 1.0, 0.0, 3.0),
+    ));
+    check(Mat4::identity());
+    let r = check(Mat4::from_cols(
+        vec4(0.0, 2.0, 1.0, -1.0),
+        vec4(1.0, -1.0, 3.0, 0.5),
+        vec4(4.0, 0.5, -2.0, 1.0),
+        vec4(1.0, 1.0, 4.0, -0.5),
+    ));
+    assert_approx_eq!(0.0, r[3][3], 1.0e-5);
+    check(Mat4::zero());
+}
+
+#[test]
+fn test_mat4_mul_matches_scalar() {
+    // Checks the optional FMA and AVX code paths selected by `build.rs` against a scalar
+    // reference computed in `f64`.
+    let q = Quat::from_axis_angle(vec3(0.3, -1.2, 2.1).normalize(), 1.7);
+    let mut mats = vec![
+        Mat4::from_scale_rotation_translation(vec3(1.5, -0.75, 2.25), q, vec3(-3.7, 12.9, 0.31)),
+        Mat4::perspective_rh(deg(67.3), 1.37, 0.13, 517.0),
+        Mat4::look_at_rh(vec3(1.3, -7.1, 2.9), vec3(-0.4, 0.2, 0.7), Vec3::unit_y()),
+    ];
+    let values = support::random_f32s(16 * 8, 10.0);
+    mats.extend(values.chunks(16).map(|c| {
+        let mut m = [0.0; 16];
+        m.copy_from_slice(c);
+        Mat4::from_cols_array(&m)
+    }));
+    for a in mats.iter() {
+        for b in mats.iter() {
+            let a_cols = a.to_cols_array_2d();
+            let b_cols = b.to_cols_array_2d();
+            let mut expected = [[0.0; 4]; 4];
+            let mut max_sum = 0.0_f64;
+            for (j, col) in expected.iter_mut().enumerate() {
+                for (i, e) in col.iter_mut().enumerate() {
+                    let terms = (0..4).map(|k| f64::from(a_cols[k][i]) * f64::from(b_cols[j][k]));
+                    *e = terms.clone().sum::<f64>() as f32;
+                    max_sum = max_sum.max(terms.map(f64::abs).sum());
+                }
+            }
+            // the rounding error of each element is relative to the sum of its absolute terms
+            let max_abs_diff = max_sum as f32 * 1.0e-6;
+            let expected = Mat4::from_cols_array_2d(&expected);
+            assert_approx_eq!(expected, *a * *b, max_abs_diff);
+            assert_approx_eq!(expected, a.mul_mat4(b), max_abs_diff);
+        }
+    }
+}
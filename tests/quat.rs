@@ -1,7 +1,7 @@
 mod support;
 
 use core::ops::Neg;
-use glam::{quat, Mat3, Mat4, Quat, Vec3, Vec3A, Vec4};
+use glam::{quat, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use support::{deg, rad};
 
 #[test]
@@ -415,3 +415,305 @@ fn test_quat_is_finite() {
     assert!(!Quat::from_xyzw(0.0, 0.0, NEG_INFINITY, 0.0).is_finite());
     assert!(!Quat::from_xyzw(0.0, 0.0, 0.0, NAN).is_finite());
 }
+
+#[test]
+fn test_quat_ln_exp() {
+    let q = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), deg(120.0));
+    let ln = q.ln();
+    assert_approx_eq!(0.0, ln.w, 1.0e-6);
+    assert_approx_eq!(deg(60.0), Vec3::new(ln.x, ln.y, ln.z).length(), 1.0e-6);
+    assert_approx_eq!(q, ln.exp(), 1.0e-6);
+    assert_approx_eq!(q * 2.0, (q * 2.0).ln().exp(), 1.0e-5);
+    assert_eq!(Quat::identity(), Quat::identity().ln().exp());
+    assert_eq!(Quat::from_xyzw(0.0, 0.0, 0.0, 0.0), Quat::identity().ln());
+}
+
+#[test]
+fn test_quat_powf() {
+    let axis = Vec3::new(1.0, -1.0, 2.0).normalize();
+    let q = Quat::from_axis_angle(axis, deg(90.0));
+    assert_approx_eq!(Quat::from_axis_angle(axis, deg(45.0)), q.powf(0.5), 1.0e-6);
+    assert_approx_eq!(Quat::from_axis_angle(axis, deg(270.0)), q.powf(3.0), 1.0e-6);
+    assert_approx_eq!(q.conjugate(), q.powf(-1.0), 1.0e-6);
+    assert_approx_eq!(Quat::identity(), q.powf(0.0), 1.0e-6);
+}
+
+#[test]
+fn test_quat_scaled_axis() {
+    let v = Vec3::new(0.5, -1.0, 0.25);
+    let q = Quat::from_scaled_axis(v);
+    assert_approx_eq!(Quat::from_axis_angle(v.normalize(), v.length()), q, 1.0e-6);
+    assert_approx_eq!(v, q.to_scaled_axis(), 1.0e-6);
+    assert_eq!(Quat::identity(), Quat::from_scaled_axis(Vec3::zero()));
+    assert_eq!(Vec3::zero(), Quat::identity().to_scaled_axis());
+}
+
+#[test]
+fn test_quat_squad() {
+    let keys = [
+        Quat::identity(),
+        Quat::from_rotation_y(deg(90.0)),
+        Quat::from_rotation_y(deg(90.0)) * Quat::from_rotation_x(deg(60.0)),
+        Quat::from_rotation_z(deg(45.0)),
+    ];
+    let tangent = |i: usize| {
+        let prev = keys[if i > 0 { i - 1 } else { i }];
+        let next = keys[if i + 1 < keys.len() { i + 1 } else { i }];
+        keys[i].squad_tangent(prev, next)
+    };
+    let segment = |i: usize, s: f32| keys[i].squad(keys[i + 1], tangent(i), tangent(i + 1), s);
+
+    // stays normalized along the path
+    for i in 0..keys.len() - 1 {
+        for j in 0..=10 {
+            assert!(segment(i, j as f32 * 0.1).is_normalized());
+        }
+    }
+
+    // passes through the keyframes
+    for i in 0..keys.len() - 1 {
+        assert_approx_eq!(keys[i], segment(i, 0.0), 1.0e-3);
+        assert_approx_eq!(keys[i + 1], segment(i, 1.0), 1.0e-3);
+    }
+
+    // the angular velocity is continuous at the inner keyframes
+    let h = 0.01;
+    for i in 1..keys.len() - 1 {
+        let before = (segment(i - 1, 1.0 - h).conjugate() * segment(i - 1, 1.0)).to_scaled_axis();
+        let after = (segment(i, 0.0).conjugate() * segment(i, h)).to_scaled_axis();
+        assert_approx_eq!(before, after, 2.0e-3);
+    }
+}
+
+#[test]
+fn test_quat_from_rotation_arc() {
+    let from = Vec3::new(1.0, 2.0, -0.5).normalize();
+    let to = Vec3::new(-0.5, 1.0, 2.0).normalize();
+    let q = Quat::from_rotation_arc(from, to);
+    assert!(q.is_normalized());
+    assert_approx_eq!(to, q * from, 1.0e-6);
+    // the rotation axis is perpendicular to both vectors
+    let (axis, _) = q.to_axis_angle();
+    assert_approx_eq!(0.0, axis.dot(from), 1.0e-3);
+    assert_approx_eq!(0.0, axis.dot(to), 1.0e-3);
+
+    assert_eq!(Quat::identity(), Quat::from_rotation_arc(from, from));
+    for &v in [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z(), from].iter() {
+        let q = Quat::from_rotation_arc(v, -v);
+        assert!(q.is_normalized());
+        assert_approx_eq!(-v, q * v, 1.0e-6);
+    }
+
+    let q = Quat::from_rotation_arc_colinear(from, -to);
+    assert_approx_eq!(to, q * from, 1.0e-6);
+    assert_approx_eq!(Quat::from_rotation_arc(from, to), q, 1.0e-6);
+    assert_eq!(
+        Quat::identity(),
+        Quat::from_rotation_arc_colinear(from, -from)
+    );
+
+    let q = Quat::from_rotation_arc_2d(Vec2::unit_x(), Vec2::new(-1.0, 1.0).normalize());
+    assert_approx_eq!(Quat::from_rotation_z(deg(135.0)), q, 1.0e-6);
+    let q = Quat::from_rotation_arc_2d(Vec2::unit_x(), -Vec2::unit_x());
+    assert_approx_eq!(-Vec3::unit_x(), q * Vec3::unit_x(), 1.0e-6);
+}
+
+#[test]
+fn test_quat_look_rotation() {
+    let eye = Vec3::new(1.0, 2.0, 3.0);
+    let forward = Vec3::new(-2.0, 0.5, 1.0);
+    let up = Vec3::unit_y();
+
+    let q = Quat::look_rotation(forward, up);
+    assert!(q.is_normalized());
+    assert_approx_eq!(forward.normalize(), q * Vec3::unit_z(), 1.0e-6);
+    assert_approx_eq!(0.0, (q * Vec3::unit_x()).dot(up), 1.0e-6);
+    assert!((q * Vec3::unit_y()).dot(up) > 0.0);
+    let view = Mat4::look_at_lh(eye, eye + forward, up);
+    let rotation = Quat::from_rotation_mat4(&view);
+    assert_approx_eq!(1.0, rotation.dot(q.conjugate()).abs(), 1.0e-6);
+
+    let q = Quat::look_rotation_rh(forward, up);
+    assert_approx_eq!(forward.normalize(), q * -Vec3::unit_z(), 1.0e-6);
+    let view = Mat4::look_at_rh(eye, eye + forward, up);
+    let rotation = Quat::from_rotation_mat4(&view);
+    assert_approx_eq!(1.0, rotation.dot(q.conjugate()).abs(), 1.0e-6);
+
+    // degenerate inputs
+    assert_eq!(Quat::identity(), Quat::look_rotation(Vec3::zero(), up));
+    let q = Quat::look_rotation(up * 2.0, up);
+    assert!(q.is_normalized());
+    assert_approx_eq!(up, q * Vec3::unit_z(), 1.0e-6);
+    let q = Quat::look_rotation(-Vec3::unit_z(), Vec3::zero());
+    assert!(q.is_normalized());
+    assert_approx_eq!(-Vec3::unit_z(), q * Vec3::unit_z(), 1.0e-6);
+}
+
+#[test]
+fn test_quat_swing_twist() {
+    let axis = Vec3::new(1.0, 1.0, 0.0).normalize();
+    let swing_axis = Vec3::new(1.0, -1.0, 2.0).normalize();
+    assert_approx_eq!(0.0, axis.dot(swing_axis));
+    let expected_swing = Quat::from_axis_angle(swing_axis, deg(50.0));
+    let expected_twist = Quat::from_axis_angle(axis, deg(-120.0));
+    let q = expected_swing * expected_twist;
+
+    let (swing, twist) = q.swing_twist(axis);
+    assert_approx_eq!(expected_swing, swing, 1.0e-6);
+    assert_approx_eq!(expected_twist, twist, 1.0e-6);
+    assert_approx_eq!(q, swing * twist, 1.0e-6);
+    assert!(twist.w >= 0.0);
+
+    // pure twist and pure swing
+    let (swing, twist) = expected_twist.swing_twist(axis);
+    assert_approx_eq!(Quat::identity(), swing, 1.0e-6);
+    assert_approx_eq!(expected_twist, twist, 1.0e-6);
+    let (swing, twist) = expected_swing.swing_twist(axis);
+    assert_approx_eq!(expected_swing, swing, 1.0e-6);
+    assert_approx_eq!(Quat::identity(), twist, 1.0e-6);
+
+    // a half turn swing leaves the twist undefined
+    let half_turn = Quat::from_axis_angle(swing_axis, deg(180.0));
+    assert_eq!((half_turn, Quat::identity()), half_turn.swing_twist(axis));
+}
+
+#[test]
+fn test_quat_clamp_twist() {
+    let swing = Quat::from_rotation_x(deg(30.0));
+    let q = swing * Quat::from_rotation_y(deg(100.0));
+    assert_approx_eq!(
+        swing * Quat::from_rotation_y(deg(45.0)),
+        q.clamp_twist(Vec3::unit_y(), deg(-10.0), deg(45.0)),
+        1.0e-6
+    );
+    let q = swing * Quat::from_rotation_y(deg(-100.0));
+    assert_approx_eq!(
+        swing * Quat::from_rotation_y(deg(-10.0)),
+        q.clamp_twist(Vec3::unit_y(), deg(-10.0), deg(45.0)),
+        1.0e-6
+    );
+    let q = swing * Quat::from_rotation_y(deg(20.0));
+    assert_eq!(q, q.clamp_twist(Vec3::unit_y(), deg(-10.0), deg(45.0)));
+}
+
+#[test]
+fn test_quat_clamp_swing() {
+    let twist = Quat::from_rotation_z(deg(70.0));
+    let (twist_axis, swing_axis) = (Vec3::unit_z(), Vec3::unit_x());
+
+    // inside the cone
+    let q = Quat::from_rotation_x(deg(20.0)) * twist;
+    assert_eq!(
+        q,
+        q.clamp_swing(twist_axis, swing_axis, deg(30.0), deg(10.0))
+    );
+
+    // outside along each of the axes of the ellipse
+    let q = Quat::from_rotation_x(deg(50.0)) * twist;
+    assert_approx_eq!(
+        Quat::from_rotation_x(deg(30.0)) * twist,
+        q.clamp_swing(twist_axis, swing_axis, deg(30.0), deg(10.0)),
+        1.0e-6
+    );
+    let q = Quat::from_rotation_y(deg(-50.0)) * twist;
+    assert_approx_eq!(
+        Quat::from_rotation_y(deg(-10.0)) * twist,
+        q.clamp_swing(twist_axis, swing_axis, deg(30.0), deg(10.0)),
+        1.0e-6
+    );
+
+    // outside diagonally ends up on the boundary of the ellipse
+    let q = Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0).normalize(), deg(60.0)) * twist;
+    let clamped = q.clamp_swing(twist_axis, swing_axis, deg(30.0), deg(10.0));
+    let (swing, clamped_twist) = clamped.swing_twist(twist_axis);
+    assert_approx_eq!(twist, clamped_twist, 1.0e-6);
+    let v = swing.to_scaled_axis();
+    assert_approx_eq!(v.x, v.y, 1.0e-6);
+    assert_approx_eq!(
+        1.0,
+        (v.x / deg(30.0)).powi(2) + (v.y / deg(10.0)).powi(2),
+        1.0e-4
+    );
+
+    // a zero limit removes the swing entirely
+    let q = Quat::from_rotation_x(deg(50.0)) * twist;
+    assert_approx_eq!(
+        twist,
+        q.clamp_swing(twist_axis, swing_axis, 0.0, 0.0),
+        1.0e-6
+    );
+}
+
+#[test]
+fn test_quat_average() {
+    assert_eq!(Quat::identity(), Quat::average(&[], None));
+    assert_eq!(Quat::identity(), Quat::average_approx(&[], None));
+
+    // rotations spread symmetrically around `base` average to `base`
+    let base = Quat::from_rotation_ypr(deg(30.0), deg(-20.0), deg(10.0));
+    let mut quats = Vec::new();
+    for &axis in [
+        Vec3::unit_x(),
+        Vec3::unit_y(),
+        Vec3::new(1.0, 1.0, 1.0).normalize(),
+    ]
+    .iter()
+    {
+        quats.push(base * Quat::from_axis_angle(axis, deg(80.0)));
+        quats.push(base * Quat::from_axis_angle(axis, deg(-80.0)));
+    }
+    // flipping the sign of a quaternion doesn't change the rotation it represents
+    quats[1] = -quats[1];
+    quats[4] = -quats[4];
+    assert_approx_eq!(base, Quat::average(&quats, None), 1.0e-5);
+    // the result is independent of the order and in the hemisphere of the first quaternion
+    quats.reverse();
+    quats[0] = -quats[0];
+    assert_approx_eq!(-base, Quat::average(&quats, None), 1.0e-5);
+    quats[0] = -quats[0];
+    quats.reverse();
+
+    // weights
+    let weights = [1.0, 1.0, 2.0, 2.0, 0.5, 0.5];
+    let a = Quat::average(&quats, Some(&weights));
+    assert_approx_eq!(base, a, 1.0e-5);
+    let weights = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    // `quats[1]` was flipped so it is in the opposite hemisphere to `quats[0]`
+    assert_approx_eq!(-quats[1], Quat::average(&quats, Some(&weights)), 1.0e-5);
+    assert_eq!(
+        Quat::identity(),
+        Quat::average(&quats, Some(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0]))
+    );
+
+    // the approximation is close for nearby rotations
+    let quats = [
+        base,
+        base * Quat::from_rotation_x(deg(5.0)),
+        -(base * Quat::from_rotation_y(deg(-4.0))),
+        base * Quat::from_rotation_z(deg(3.0)),
+    ];
+    let weights = [1.0, 2.0, 0.5, 1.0];
+    let exact = Quat::average(&quats, Some(&weights));
+    let approx = Quat::average_approx(&quats, Some(&weights));
+    assert!(approx.is_normalized());
+    assert_approx_eq!(exact, approx, 1.0e-4);
+}
+
+#[test]
+fn test_quat_try_normalize() {
+    let q = quat(1.0, -2.0, 3.0, 4.0);
+    assert_approx_eq!(q.normalize(), q.try_normalize().unwrap());
+    assert_eq!(None, quat(0.0, 0.0, 0.0, 0.0).try_normalize());
+    assert_eq!(None, quat(1.0e-30, 0.0, 0.0, 0.0).try_normalize());
+    assert_eq!(None, quat(std::f32::NAN, 0.0, 0.0, 1.0).try_normalize());
+}
+
+#[test]
+fn test_quat_normalize_or_zero() {
+    let q = quat(1.0, -2.0, 3.0, 4.0);
+    assert_approx_eq!(q.normalize(), q.normalize_or_zero());
+    let zero = quat(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(zero, zero.normalize_or_zero());
+    assert_eq!(zero, quat(1.0e-30, 0.0, 0.0, 0.0).normalize_or_zero());
+    assert_eq!(zero, quat(std::f32::NAN, 0.0, 0.0, 1.0).normalize_or_zero());
+}
@@ -1,7 +1,10 @@
 #[macro_use]
 mod macros;
 
-use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::{
+    Affine2, Affine3A, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, DualQuat, Mat2, Mat3,
+    Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec3x4, Vec3x8, Vec4, Vec4x4,
+};
 
 #[cfg(feature = "transform-types")]
 use glam::{TransformRT, TransformSRT};
@@ -20,6 +23,31 @@ pub fn rad(angle: f32) -> f32 {
     angle
 }
 
+/// `f64` version of `deg`.
+#[allow(dead_code)]
+#[inline]
+pub fn ddeg(angle: f64) -> f64 {
+    angle.to_radians()
+}
+
+/// Returns `count` pseudo random values in `[-range, range)`, the same values on every call.
+#[allow(dead_code)]
+pub fn random_f32s(count: usize, range: f32) -> Vec<f32> {
+    use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0x9e37_79b9);
+    (0..count)
+        .map(|_| ((rng.next_u32() >> 8) as f32 / (1 << 23) as f32 - 1.0) * range)
+        .collect()
+}
+
+/// Returns the distance between `actual` and the exact result `expected` in units in the last
+/// place of an `f32` with the magnitude of `expected`.
+#[allow(dead_code)]
+pub fn ulp_error(expected: f64, actual: f32) -> f64 {
+    let exponent = ((expected.abs().to_bits() >> 52) as i32 - 1023).max(-126);
+    (f64::from(actual) - expected).abs() / 2.0_f64.powi(exponent - 23)
+}
+
 /// Trait used by the `assert_approx_eq` macro for floating point comparisons.
 pub trait FloatCompare<Rhs: ?Sized = Self> {
     /// Return true if the absolute difference between `self` and `other` is
@@ -55,6 +83,37 @@ impl FloatCompare for Mat2 {
     }
 }
 
+impl FloatCompare for Affine2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Affine2::from_cols(
+            (self.matrix2.x_axis - other.matrix2.x_axis).abs(),
+            (self.matrix2.y_axis - other.matrix2.y_axis).abs(),
+            (self.translation - other.translation).abs(),
+        )
+    }
+}
+
+impl FloatCompare for Affine3A {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Affine3A::from_cols(
+            (self.matrix3.x_axis - other.matrix3.x_axis).abs(),
+            (self.matrix3.y_axis - other.matrix3.y_axis).abs(),
+            (self.matrix3.z_axis - other.matrix3.z_axis).abs(),
+            (self.translation - other.translation).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Mat3 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -70,6 +129,21 @@ impl FloatCompare for Mat3 {
     }
 }
 
+impl FloatCompare for Mat3A {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Mat3A::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Mat4 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -99,6 +173,20 @@ impl FloatCompare for Quat {
     }
 }
 
+impl FloatCompare for DualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        DualQuat::from_real_dual(
+            self.real.abs_diff(&other.real),
+            self.dual.abs_diff(&other.dual),
+        )
+    }
+}
+
 impl FloatCompare for Vec2 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -143,6 +231,141 @@ impl FloatCompare for Vec4 {
     }
 }
 
+impl FloatCompare for Vec3x4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for Vec3x8 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for Vec4x4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for f64 {
+    #[inline]
+    fn approx_eq(&self, other: &f64, max_abs_diff: f32) -> bool {
+        (self - other).abs() <= max_abs_diff as f64
+    }
+    #[inline]
+    fn abs_diff(&self, other: &f64) -> f64 {
+        (self - other).abs()
+    }
+}
+
+impl FloatCompare for DMat2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        DMat2::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        DMat3::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        DMat4::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+            (self.w_axis - other.w_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DQuat {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a: DVec4 = (*self).into();
+        let b: DVec4 = (*other).into();
+        (a - b).abs().into()
+    }
+}
+
+impl FloatCompare for DVec2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DVec3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for DVec4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
 #[cfg(feature = "transform-types")]
 impl FloatCompare for TransformSRT {
     #[inline]
@@ -85,4 +85,18 @@ mod transform {
         let inv_srt = srt.inverse();
         assert_eq!(srt * inv_srt, TransformSRT::identity());
     }
+
+    #[test]
+    fn test_srt_try_inverse() {
+        let r = Quat::from_rotation_y(30.0_f32.to_radians());
+        let t = -Vec3::unit_y();
+        let srt = TransformSRT::from_scale_rotation_translation(Vec3::splat(2.0), r, t);
+        assert_eq!(Some(srt.inverse()), srt.try_inverse());
+        assert_eq!(srt.inverse(), srt.inverse_or_zero());
+
+        let srt = TransformSRT::from_scale_rotation_translation(Vec3::new(2.0, 0.0, 1.0), r, t);
+        assert_eq!(None, srt.try_inverse());
+        let zero = srt.inverse_or_zero();
+        assert_eq!(Vec3::zero(), zero * Vec3::one());
+    }
 }
@@ -0,0 +1,275 @@
+use glam::{uvec2, uvec3, UVec2, Vec2Mask};
+
+#[test]
+fn test_uvec2_align() {
+    use std::mem;
+    assert_eq!(8, mem::size_of::<UVec2>());
+    assert_eq!(4, mem::align_of::<UVec2>());
+}
+
+#[test]
+fn test_uvec2_new() {
+    let v = uvec2(1, 2);
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, 2);
+
+    let t = (1, 2);
+    let v = UVec2::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1, 2];
+    let v = UVec2::from(a);
+    let a1: [u32; 2] = v.into();
+    assert_eq!(a, a1);
+
+    assert_eq!(UVec2::new(1, 0), UVec2::unit_x());
+    assert_eq!(UVec2::new(0, 1), UVec2::unit_y());
+}
+
+#[test]
+fn test_uvec2_fmt() {
+    let a = uvec2(1, 2);
+    assert_eq!(format!("{:?}", a), "UVec2(1, 2)");
+    assert_eq!(format!("{}", a), "[1, 2]");
+}
+
+#[test]
+fn test_uvec2_zero() {
+    let v = UVec2::zero();
+    assert_eq!((0, 0), v.into());
+    assert_eq!(v, UVec2::default());
+}
+
+#[test]
+fn test_uvec2_splat() {
+    let v = UVec2::splat(1);
+    assert_eq!((1, 1), v.into());
+}
+
+#[test]
+fn test_uvec2_accessors() {
+    let mut a = UVec2::zero();
+    a.x = 1;
+    a.y = 2;
+    assert_eq!(1, a.x);
+    assert_eq!(2, a.y);
+    assert_eq!(uvec2(1, 2), a);
+
+    let mut a = UVec2::zero();
+    a[0] = 1;
+    a[1] = 2;
+    assert_eq!(1, a[0]);
+    assert_eq!(2, a[1]);
+    assert_eq!(uvec2(1, 2), a);
+}
+
+#[test]
+fn test_uvec2_funcs() {
+    let x = uvec2(1, 2);
+    let y = uvec2(4, 3);
+    assert_eq!(10, x.dot(y));
+    assert_eq!(x.extend(3), uvec3(1, 2, 3));
+}
+
+#[test]
+fn test_uvec2_ops() {
+    let a = uvec2(2, 4);
+    assert_eq!(uvec2(4, 8), a + a);
+    assert_eq!(uvec2(0, 0), a - a);
+    assert_eq!(uvec2(4, 16), a * a);
+    assert_eq!(uvec2(1, 1), a / a);
+    assert_eq!(uvec2(2, 1), a % uvec2(3, 3));
+    assert_eq!(uvec2(4, 8), a * 2);
+    assert_eq!(uvec2(4, 8), 2 * a);
+    assert_eq!(uvec2(1, 2), a / 2);
+    assert_eq!(uvec2(2, 1), 4 / a);
+    assert_eq!(uvec2(0, 0), a % 2);
+}
+
+#[test]
+fn test_uvec2_assign_ops() {
+    let a = uvec2(1, 2);
+    let mut b = a;
+    b += a;
+    assert_eq!(uvec2(2, 4), b);
+    b -= a;
+    assert_eq!(uvec2(1, 2), b);
+    b *= a;
+    assert_eq!(uvec2(1, 4), b);
+    b /= a;
+    assert_eq!(uvec2(1, 2), b);
+    b *= 2;
+    assert_eq!(uvec2(2, 4), b);
+    b /= 2;
+    assert_eq!(uvec2(1, 2), b);
+    b %= 2;
+    assert_eq!(uvec2(1, 0), b);
+    b += 3;
+    assert_eq!(uvec2(4, 3), b);
+    b -= 1;
+    assert_eq!(uvec2(3, 2), b);
+}
+
+#[test]
+fn test_uvec2_bit_ops() {
+    let a = uvec2(0b0101, 0b0110);
+    let b = uvec2(0b0011, 0b0011);
+    assert_eq!(uvec2(0b0001, 0b0010), a & b);
+    assert_eq!(uvec2(0b0111, 0b0111), a | b);
+    assert_eq!(uvec2(0b0110, 0b0101), a ^ b);
+    assert_eq!(uvec2(0b0100, 0b0100), a & 0b0100);
+    assert_eq!(uvec2(0b0101, 0b0111), a | 0b0001);
+    assert_eq!(uvec2(0b1010, 0b1001), a ^ 0b1111);
+    assert_eq!(uvec2(0xffff_ffff, 0xffff_ffff), !UVec2::zero());
+    assert_eq!(uvec2(0b1010, 0b1100), a << 1);
+    assert_eq!(uvec2(0b0010, 0b0011), a >> 1);
+    assert_eq!(uvec2(0b1010, 0b1100), a << 1u32);
+    assert_eq!(uvec2(0b0010, 0b0011), a >> 1u32);
+    assert_eq!(uvec2(0b10100, 0b1100), a << uvec2(2, 1));
+    assert_eq!(uvec2(0b0001, 0b0011), a >> uvec2(2, 1));
+}
+
+#[test]
+fn test_uvec2_wrapping_saturating() {
+    let max = UVec2::splat(u32::max_value());
+    let min = UVec2::splat(u32::min_value());
+    let one = UVec2::one();
+    assert_eq!(min, max.wrapping_add(one));
+    assert_eq!(max, min.wrapping_sub(one));
+    assert_eq!(
+        UVec2::splat(u32::max_value().wrapping_mul(2)),
+        max.wrapping_mul(UVec2::splat(2))
+    );
+    assert_eq!(max, max.saturating_add(one));
+    assert_eq!(min, min.saturating_sub(one));
+    assert_eq!(max, max.saturating_mul(UVec2::splat(2)));
+    assert_eq!(uvec2(6, 12), uvec2(2, 3).wrapping_mul(uvec2(3, 4)));
+}
+
+#[test]
+fn test_uvec2_min_max() {
+    let a = uvec2(1, 5);
+    let b = uvec2(4, 2);
+    assert_eq!(uvec2(1, 2), a.min(b));
+    assert_eq!(uvec2(1, 2), b.min(a));
+    assert_eq!(uvec2(4, 5), a.max(b));
+    assert_eq!(uvec2(4, 5), b.max(a));
+    assert_eq!(uvec2(2, 3), a.clamp(UVec2::splat(2), UVec2::splat(3)));
+}
+
+#[test]
+fn test_uvec2_hmin_hmax() {
+    let a = uvec2(3, 1);
+    assert_eq!(1, a.min_element());
+    assert_eq!(3, a.max_element());
+}
+
+#[test]
+fn test_uvec2_cmp() {
+    let a = uvec2(1, 2);
+    let b = uvec2(1, 3);
+    assert_eq!(Vec2Mask::new(true, false), a.cmpeq(b));
+    assert_eq!(Vec2Mask::new(false, true), a.cmpne(b));
+    assert_eq!(Vec2Mask::new(false, true), a.cmplt(b));
+    assert_eq!(Vec2Mask::new(true, true), a.cmple(b));
+    assert_eq!(Vec2Mask::new(false, false), a.cmpgt(b));
+    assert_eq!(Vec2Mask::new(true, false), a.cmpge(b));
+    assert!(a.cmpeq(a).all());
+    assert!(!a.cmpne(a).any());
+
+    let a = uvec2(0xffff_ffff, 1);
+    let b = uvec2(0, 0xffff_ffff);
+    assert_eq!(Vec2Mask::new(false, true), a.cmplt(b));
+    assert_eq!(Vec2Mask::new(true, false), a.cmpgt(b));
+}
+
+#[test]
+fn test_uvec2_slice() {
+    let a = [1, 2];
+    let b = UVec2::from_slice_unaligned(&a);
+    let c: [u32; 2] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0; 2];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_uvec2_as() {
+    let a = uvec2(1, 2);
+    assert_eq!(glam::vec2(1.0, 2.0), a.as_vec2());
+    assert_eq!(glam::dvec2(1.0, 2.0), a.as_dvec2());
+    assert_eq!(glam::IVec2::new(1, 2), a.as_ivec2());
+    assert_eq!(a, glam::vec2(1.0, 2.0).as_uvec2());
+    assert_eq!(a, glam::dvec2(1.0, 2.0).as_uvec2());
+    assert_eq!(a, glam::ivec2(1, 2).as_uvec2());
+}
+
+#[test]
+fn test_uvec2_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let a = uvec2(1, 2);
+    let b = uvec2(1, 2);
+    let c = uvec2(4, 3);
+
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    let a_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    b.hash(&mut hasher);
+    let b_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    let c_hashed = hasher.finish();
+
+    assert_eq!(a, b);
+    assert_eq!(a_hashed, b_hashed);
+    assert_ne!(a, c);
+    assert_ne!(a_hashed, c_hashed);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_uvec2_serde() {
+    let a = uvec2(1, 2);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1,2]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<UVec2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<UVec2>("[1]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<UVec2>("[1,2,3]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_uvec2_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (u32, u32) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: UVec2 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let one = UVec2::one();
+    assert_eq!([one, one].iter().sum::<UVec2>(), one + one);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = UVec2::splat(2);
+    assert_eq!([two, two].iter().product::<UVec2>(), two * two);
+}
@@ -0,0 +1,288 @@
+use glam::{uvec2, uvec3, uvec4, UVec2, UVec3, Vec3Mask};
+
+#[test]
+fn test_uvec3_align() {
+    use std::mem;
+    assert_eq!(12, mem::size_of::<UVec3>());
+    assert_eq!(4, mem::align_of::<UVec3>());
+}
+
+#[test]
+fn test_uvec3_new() {
+    let v = uvec3(1, 2, 3);
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, 2);
+    assert_eq!(v.z, 3);
+
+    let t = (1, 2, 3);
+    let v = UVec3::from(t);
+    assert_eq!(t, v.into());
+
+    let a = [1, 2, 3];
+    let v = UVec3::from(a);
+    let a1: [u32; 3] = v.into();
+    assert_eq!(a, a1);
+
+    assert_eq!(UVec3::new(1, 0, 0), UVec3::unit_x());
+    assert_eq!(UVec3::new(0, 1, 0), UVec3::unit_y());
+    assert_eq!(UVec3::new(0, 0, 1), UVec3::unit_z());
+}
+
+#[test]
+fn test_uvec3_fmt() {
+    let a = uvec3(1, 2, 3);
+    assert_eq!(format!("{:?}", a), "UVec3(1, 2, 3)");
+    assert_eq!(format!("{}", a), "[1, 2, 3]");
+}
+
+#[test]
+fn test_uvec3_zero() {
+    let v = UVec3::zero();
+    assert_eq!((0, 0, 0), v.into());
+    assert_eq!(v, UVec3::default());
+}
+
+#[test]
+fn test_uvec3_splat() {
+    let v = UVec3::splat(1);
+    assert_eq!((1, 1, 1), v.into());
+}
+
+#[test]
+fn test_uvec3_accessors() {
+    let mut a = UVec3::zero();
+    a.x = 1;
+    a.y = 2;
+    a.z = 3;
+    assert_eq!(1, a.x);
+    assert_eq!(2, a.y);
+    assert_eq!(3, a.z);
+    assert_eq!(uvec3(1, 2, 3), a);
+
+    let mut a = UVec3::zero();
+    a[0] = 1;
+    a[1] = 2;
+    a[2] = 3;
+    assert_eq!(1, a[0]);
+    assert_eq!(2, a[1]);
+    assert_eq!(3, a[2]);
+    assert_eq!(uvec3(1, 2, 3), a);
+}
+
+#[test]
+fn test_uvec3_funcs() {
+    let x = uvec3(1, 2, 3);
+    let y = uvec3(4, 3, 2);
+    assert_eq!(16, x.dot(y));
+    assert_eq!(x.extend(4), uvec4(1, 2, 3, 4));
+    assert_eq!(x.truncate(), uvec2(1, 2));
+    assert_eq!(UVec2::from(x), x.truncate());
+}
+
+#[test]
+fn test_uvec3_ops() {
+    let a = uvec3(2, 4, 6);
+    assert_eq!(uvec3(4, 8, 12), a + a);
+    assert_eq!(uvec3(0, 0, 0), a - a);
+    assert_eq!(uvec3(4, 16, 36), a * a);
+    assert_eq!(uvec3(1, 1, 1), a / a);
+    assert_eq!(uvec3(2, 1, 0), a % uvec3(3, 3, 3));
+    assert_eq!(uvec3(4, 8, 12), a * 2);
+    assert_eq!(uvec3(4, 8, 12), 2 * a);
+    assert_eq!(uvec3(1, 2, 3), a / 2);
+    assert_eq!(uvec3(2, 1, 0), 4 / a);
+    assert_eq!(uvec3(0, 0, 0), a % 2);
+}
+
+#[test]
+fn test_uvec3_assign_ops() {
+    let a = uvec3(1, 2, 3);
+    let mut b = a;
+    b += a;
+    assert_eq!(uvec3(2, 4, 6), b);
+    b -= a;
+    assert_eq!(uvec3(1, 2, 3), b);
+    b *= a;
+    assert_eq!(uvec3(1, 4, 9), b);
+    b /= a;
+    assert_eq!(uvec3(1, 2, 3), b);
+    b *= 2;
+    assert_eq!(uvec3(2, 4, 6), b);
+    b /= 2;
+    assert_eq!(uvec3(1, 2, 3), b);
+    b %= 2;
+    assert_eq!(uvec3(1, 0, 1), b);
+    b += 3;
+    assert_eq!(uvec3(4, 3, 4), b);
+    b -= 1;
+    assert_eq!(uvec3(3, 2, 3), b);
+}
+
+#[test]
+fn test_uvec3_bit_ops() {
+    let a = uvec3(0b0101, 0b0110, 0b1100);
+    let b = uvec3(0b0011, 0b0011, 0b0011);
+    assert_eq!(uvec3(0b0001, 0b0010, 0b0000), a & b);
+    assert_eq!(uvec3(0b0111, 0b0111, 0b1111), a | b);
+    assert_eq!(uvec3(0b0110, 0b0101, 0b1111), a ^ b);
+    assert_eq!(uvec3(0b0100, 0b0100, 0b0100), a & 0b0100);
+    assert_eq!(uvec3(0b0101, 0b0111, 0b1101), a | 0b0001);
+    assert_eq!(uvec3(0b1010, 0b1001, 0b0011), a ^ 0b1111);
+    assert_eq!(uvec3(0xffff_ffff, 0xffff_ffff, 0xffff_ffff), !UVec3::zero());
+    assert_eq!(uvec3(0b1010, 0b1100, 0b11000), a << 1);
+    assert_eq!(uvec3(0b0010, 0b0011, 0b0110), a >> 1);
+    assert_eq!(uvec3(0b1010, 0b1100, 0b11000), a << 1u32);
+    assert_eq!(uvec3(0b0010, 0b0011, 0b0110), a >> 1u32);
+    assert_eq!(uvec3(0b10100, 0b1100, 0b1100), a << uvec3(2, 1, 0));
+    assert_eq!(uvec3(0b0001, 0b0011, 0b0110), a >> uvec3(2, 1, 1));
+}
+
+#[test]
+fn test_uvec3_wrapping_saturating() {
+    let max = UVec3::splat(u32::max_value());
+    let min = UVec3::splat(u32::min_value());
+    let one = UVec3::one();
+    assert_eq!(min, max.wrapping_add(one));
+    assert_eq!(max, min.wrapping_sub(one));
+    assert_eq!(
+        UVec3::splat(u32::max_value().wrapping_mul(2)),
+        max.wrapping_mul(UVec3::splat(2))
+    );
+    assert_eq!(max, max.saturating_add(one));
+    assert_eq!(min, min.saturating_sub(one));
+    assert_eq!(max, max.saturating_mul(UVec3::splat(2)));
+    assert_eq!(
+        uvec3(6, 12, 20),
+        uvec3(2, 3, 4).wrapping_mul(uvec3(3, 4, 5))
+    );
+}
+
+#[test]
+fn test_uvec3_min_max() {
+    let a = uvec3(1, 5, 3);
+    let b = uvec3(4, 2, 6);
+    assert_eq!(uvec3(1, 2, 3), a.min(b));
+    assert_eq!(uvec3(1, 2, 3), b.min(a));
+    assert_eq!(uvec3(4, 5, 6), a.max(b));
+    assert_eq!(uvec3(4, 5, 6), b.max(a));
+    assert_eq!(uvec3(2, 3, 3), a.clamp(UVec3::splat(2), UVec3::splat(3)));
+}
+
+#[test]
+fn test_uvec3_hmin_hmax() {
+    let a = uvec3(3, 1, 4);
+    assert_eq!(1, a.min_element());
+    assert_eq!(4, a.max_element());
+}
+
+#[test]
+fn test_uvec3_cmp() {
+    let a = uvec3(1, 2, 3);
+    let b = uvec3(1, 3, 2);
+    assert_eq!(Vec3Mask::new(true, false, false), a.cmpeq(b));
+    assert_eq!(Vec3Mask::new(false, true, true), a.cmpne(b));
+    assert_eq!(Vec3Mask::new(false, true, false), a.cmplt(b));
+    assert_eq!(Vec3Mask::new(true, true, false), a.cmple(b));
+    assert_eq!(Vec3Mask::new(false, false, true), a.cmpgt(b));
+    assert_eq!(Vec3Mask::new(true, false, true), a.cmpge(b));
+    assert!(a.cmpeq(a).all());
+    assert!(!a.cmpne(a).any());
+
+    let a = uvec3(0xffff_ffff, 1, 0x8000_0000);
+    let b = uvec3(0, 0xffff_ffff, 0x7fff_ffff);
+    assert_eq!(Vec3Mask::new(false, true, false), a.cmplt(b));
+    assert_eq!(Vec3Mask::new(true, false, true), a.cmpgt(b));
+}
+
+#[test]
+fn test_uvec3_slice() {
+    let a = [1, 2, 3];
+    let b = UVec3::from_slice_unaligned(&a);
+    let c: [u32; 3] = b.into();
+    assert_eq!(a, c);
+    let mut d = [0; 3];
+    b.write_to_slice_unaligned(&mut d[..]);
+    assert_eq!(a, d);
+}
+
+#[test]
+fn test_uvec3_as() {
+    let a = uvec3(1, 2, 3);
+    assert_eq!(glam::vec3(1.0, 2.0, 3.0), a.as_vec3());
+    assert_eq!(glam::dvec3(1.0, 2.0, 3.0), a.as_dvec3());
+    assert_eq!(glam::IVec3::new(1, 2, 3), a.as_ivec3());
+    assert_eq!(a, glam::vec3(1.0, 2.0, 3.0).as_uvec3());
+    assert_eq!(a, glam::dvec3(1.0, 2.0, 3.0).as_uvec3());
+    assert_eq!(a, glam::ivec3(1, 2, 3).as_uvec3());
+}
+
+#[test]
+fn test_uvec3_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let a = uvec3(1, 2, 3);
+    let b = uvec3(1, 2, 3);
+    let c = uvec3(4, 3, 2);
+
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    let a_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    b.hash(&mut hasher);
+    let b_hashed = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    let c_hashed = hasher.finish();
+
+    assert_eq!(a, b);
+    assert_eq!(a_hashed, b_hashed);
+    assert_ne!(a, c);
+    assert_ne!(a_hashed, c_hashed);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_uvec3_serde() {
+    let a = uvec3(1, 2, 3);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1,2,3]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<UVec3>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<UVec3>("[1]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<UVec3>("[1,2]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<UVec3>("[1,2,3,4]");
+    assert!(deserialized.is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_uvec3_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let a: (u32, u32, u32) = rng1.gen();
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    let b: UVec3 = rng2.gen();
+    assert_eq!(a, b.into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sum() {
+    let one = UVec3::one();
+    assert_eq!([one, one].iter().sum::<UVec3>(), one + one);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_product() {
+    let two = UVec3::splat(2);
+    assert_eq!([two, two].iter().product::<UVec3>(), two * two);
+}
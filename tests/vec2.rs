@@ -544,3 +544,44 @@ fn test_exp() {
         Vec2::new(1.0_f32.exp(), 2.0_f32.exp())
     );
 }
+
+#[test]
+fn test_vec2_transcendentals() {
+    let a = Vec2::new(0.5, 2.0);
+    assert_eq!(Vec2::new(a.x.sqrt(), a.y.sqrt()), a.sqrt());
+    assert_eq!(Vec2::new(a.x.sin(), a.y.sin()), a.sin());
+    assert_eq!(Vec2::new(a.x.cos(), a.y.cos()), a.cos());
+    assert_eq!(Vec2::new(a.x.tan(), a.y.tan()), a.tan());
+    assert_eq!(Vec2::new(a.x.exp(), a.y.exp()), a.exp());
+    assert_eq!(Vec2::new(a.x.ln(), a.y.ln()), a.ln());
+    assert_eq!(Vec2::new(a.x.log2(), a.y.log2()), a.log2());
+    assert_eq!((a.sin(), a.cos()), a.sin_cos());
+    assert_eq!(Vec2::new(a.x.atan2(-a.x), a.y.atan2(-a.y)), a.atan2(-a));
+    let b = a * 0.3;
+    assert_eq!(Vec2::new(b.x.asin(), b.y.asin()), b.asin());
+    assert_eq!(Vec2::new(b.x.acos(), b.y.acos()), b.acos());
+}
+
+#[test]
+fn test_vec2_try_normalize() {
+    assert_approx_eq!(
+        Vec2::unit_x(),
+        (Vec2::unit_x() * 3.0).try_normalize().unwrap()
+    );
+    assert_approx_eq!(
+        Vec2::splat(-1.0).normalize(),
+        Vec2::splat(-1.0).normalize_or_zero()
+    );
+    for &v in [
+        Vec2::zero(),
+        Vec2::splat(1.0e-30),
+        Vec2::splat(std::f32::MAX),
+        Vec2::splat(std::f32::NAN),
+        Vec2::unit_x() * std::f32::INFINITY,
+    ]
+    .iter()
+    {
+        assert_eq!(None, v.try_normalize());
+        assert_eq!(Vec2::zero(), v.normalize_or_zero());
+    }
+}
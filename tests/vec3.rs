@@ -595,3 +595,47 @@ fn test_exp() {
         Vec3::new(1.0_f32.exp(), 2.0_f32.exp(), 3.0_f32.exp())
     );
 }
+
+#[test]
+fn test_vec3_transcendentals() {
+    let a = Vec3::new(0.5, 2.0, 3.0);
+    assert_eq!(Vec3::new(a.x.sqrt(), a.y.sqrt(), a.z.sqrt()), a.sqrt());
+    assert_eq!(Vec3::new(a.x.sin(), a.y.sin(), a.z.sin()), a.sin());
+    assert_eq!(Vec3::new(a.x.cos(), a.y.cos(), a.z.cos()), a.cos());
+    assert_eq!(Vec3::new(a.x.tan(), a.y.tan(), a.z.tan()), a.tan());
+    assert_eq!(Vec3::new(a.x.exp(), a.y.exp(), a.z.exp()), a.exp());
+    assert_eq!(Vec3::new(a.x.ln(), a.y.ln(), a.z.ln()), a.ln());
+    assert_eq!(Vec3::new(a.x.log2(), a.y.log2(), a.z.log2()), a.log2());
+    assert_eq!((a.sin(), a.cos()), a.sin_cos());
+    assert_eq!(
+        Vec3::new(a.x.atan2(-a.x), a.y.atan2(-a.y), a.z.atan2(-a.z)),
+        a.atan2(-a)
+    );
+    let b = a * 0.3;
+    assert_eq!(Vec3::new(b.x.asin(), b.y.asin(), b.z.asin()), b.asin());
+    assert_eq!(Vec3::new(b.x.acos(), b.y.acos(), b.z.acos()), b.acos());
+}
+
+#[test]
+fn test_vec3_try_normalize() {
+    assert_approx_eq!(
+        Vec3::unit_x(),
+        (Vec3::unit_x() * 3.0).try_normalize().unwrap()
+    );
+    assert_approx_eq!(
+        Vec3::splat(-1.0).normalize(),
+        Vec3::splat(-1.0).normalize_or_zero()
+    );
+    for &v in [
+        Vec3::zero(),
+        Vec3::splat(1.0e-30),
+        Vec3::splat(std::f32::MAX),
+        Vec3::splat(std::f32::NAN),
+        Vec3::unit_x() * std::f32::INFINITY,
+    ]
+    .iter()
+    {
+        assert_eq!(None, v.try_normalize());
+        assert_eq!(Vec3::zero(), v.normalize_or_zero());
+    }
+}
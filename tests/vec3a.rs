@@ -561,6 +561,40 @@ fn test_vec3a_m128() {
     assert_eq!([0xffffffff, 0, 0xffffffff], a0.0);
 }
 
+#[cfg(vec3a_neon)]
+#[test]
+fn test_vec3a_neon() {
+    use core::arch::aarch64::*;
+
+    let v0 = Vec3A::new(1.0, 2.0, 3.0);
+    let m0: float32x4_t = v0.into();
+    let mut a0 = [1.0, 1.0, 1.0, 1.0];
+    unsafe {
+        vst1q_f32(a0.as_mut_ptr(), m0);
+    }
+    assert_eq!([1.0, 2.0, 3.0, 0.0], a0);
+    let v1 = Vec3A::from(m0);
+    assert_eq!(v0, v1);
+
+    let m0 = unsafe { vceqq_f32(m0, vdupq_n_f32(2.0)) };
+    assert_eq!(Vec3AMask::new(false, true, false), Vec3AMask::from(m0));
+}
+
+#[cfg(vec3a_simd128)]
+#[test]
+fn test_vec3a_v128() {
+    use core::arch::wasm32::*;
+
+    let v0 = Vec3A::new(1.0, 2.0, 3.0);
+    let m0: v128 = v0.into();
+    assert_eq!(0.0, f32x4_extract_lane::<3>(m0));
+    let v1 = Vec3A::from(m0);
+    assert_eq!(v0, v1);
+
+    let m0 = f32x4_eq(m0, f32x4_splat(2.0));
+    assert_eq!(Vec3AMask::new(false, true, false), Vec3AMask::from(m0));
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_vec3a_serde() {
@@ -638,8 +672,151 @@ fn test_powf() {
 
 #[test]
 fn test_exp() {
-    assert_eq!(
+    assert_approx_eq!(
         Vec3A::new(1.0, 2.0, 3.0).exp(),
-        Vec3A::new(1.0_f32.exp(), 2.0_f32.exp(), 3.0_f32.exp())
+        Vec3A::new(1.0_f32.exp(), 2.0_f32.exp(), 3.0_f32.exp()),
+        1.0e-5
+    );
+}
+
+#[test]
+fn test_vec3a_transcendentals() {
+    // The SSE2 versions use polynomial approximations, they are compared against `f64` results
+    // using the maximum error in ULP given in their documentation. Special values must match.
+    fn check(expected: f64, actual: f32, max_ulps: f64) {
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "expected NaN, got {}", actual);
+        } else if (expected as f32).is_infinite() {
+            assert_eq!(expected as f32, actual);
+        } else if expected.abs() < f64::from(std::f32::MIN_POSITIVE) {
+            // results that underflow may be flushed to zero
+            assert!(
+                actual.abs() <= std::f32::MIN_POSITIVE,
+                "expected {}, got {}",
+                expected,
+                actual
+            );
+        } else {
+            let ulps = support::ulp_error(expected, actual);
+            assert!(
+                ulps <= max_ulps,
+                "expected {}, got {} ({} ULP)",
+                expected,
+                actual,
+                ulps
+            );
+        }
+    }
+    let specials = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        std::f32::INFINITY,
+        std::f32::NEG_INFINITY,
+        std::f32::NAN,
+        std::f32::MIN_POSITIVE,
+        std::f32::MAX,
+        -std::f32::MAX,
+    ];
+    let mut values = specials.to_vec();
+    for i in -200..200 {
+        values.push(i as f32 * 0.123);
+        values.push(i as f32 * 41.7);
+    }
+    for i in -37..38 {
+        values.push(1.37 * 10.0_f32.powi(i));
+    }
+    values.extend(support::random_f32s(4096, 100.0));
+    values.extend(support::random_f32s(4096, 16384.0));
+    for v in values.chunks(3).filter(|v| v.len() == 3) {
+        let a = Vec3A::new(v[0], v[1], v[2]);
+        let b = Vec3A::new(v[1], v[2], v[0]);
+        let (sin, cos) = a.sin_cos();
+        for i in 0..3 {
+            let x = f64::from(a[i]);
+            check(x.sqrt(), a.sqrt()[i], 0.5);
+            check(x.sin(), a.sin()[i], 2.0);
+            check(x.cos(), a.cos()[i], 2.0);
+            check(x.sin(), sin[i], 2.0);
+            check(x.cos(), cos[i], 2.0);
+            check(x.exp(), a.exp()[i], 2.0);
+            check(x.ln(), a.ln()[i], 1.0);
+            check(x.log2(), a.log2()[i], 2.0);
+            check(x.atan2(f64::from(b[i])), a.atan2(b)[i], 4.0);
+            let t = x.tan();
+            // tan is ill-conditioned next to its poles
+            if t.abs() < 1.0e4 {
+                check(t, a.tan()[i], 4.0);
+            }
+        }
+        let c = a * 0.01;
+        for i in 0..3 {
+            let x = f64::from(c[i]);
+            check(x.asin(), c.asin()[i], 3.0);
+            check(x.acos(), c.acos()[i], 2.0);
+        }
+    }
+}
+
+#[test]
+fn test_vec3a_try_normalize() {
+    assert_approx_eq!(
+        Vec3A::unit_x(),
+        (Vec3A::unit_x() * 3.0).try_normalize().unwrap()
     );
+    assert_approx_eq!(
+        Vec3A::splat(-1.0).normalize(),
+        Vec3A::splat(-1.0).normalize_or_zero()
+    );
+    for &v in [
+        Vec3A::zero(),
+        Vec3A::splat(1.0e-30),
+        Vec3A::splat(std::f32::MAX),
+        Vec3A::splat(std::f32::NAN),
+        Vec3A::unit_x() * std::f32::INFINITY,
+    ]
+    .iter()
+    {
+        assert_eq!(None, v.try_normalize());
+        assert_eq!(Vec3A::zero(), v.normalize_or_zero());
+    }
+}
+
+#[test]
+fn test_vec3a_matches_scalar() {
+    // Checks the optional SSE4.1 code paths selected by `build.rs` against scalar results.
+    // Half-way cases are avoided as SSE2 and SSE4.1 round them to even.
+    let mut values = vec![
+        -8388609.0, -3.7, -2.25, -1.3, -0.6, 0.0, 0.4, 1.75, 2.6, 8.2, 8388609.0,
+    ];
+    values.extend(
+        support::random_f32s(64, 1000.0)
+            .into_iter()
+            .filter(|v| v.abs().fract() != 0.5),
+    );
+    for v in values.windows(3) {
+        let a = Vec3A::new(v[0], v[1], v[2]);
+        assert_eq!(Vec3A::new(a.x.round(), a.y.round(), a.z.round()), a.round());
+        assert_eq!(Vec3A::new(a.x.floor(), a.y.floor(), a.z.floor()), a.floor());
+        assert_eq!(Vec3A::new(a.x.ceil(), a.y.ceil(), a.z.ceil()), a.ceil());
+    }
+    // the reference results are computed in `f64`, the tolerances are relative to the
+    // magnitude of the terms
+    for v in values[1..].windows(6) {
+        let a = Vec3A::new(v[0], v[1], v[2]);
+        let b = Vec3A::new(v[3], v[4], v[5]);
+        let (av, bv) = (a.as_ref(), b.as_ref());
+        let terms = (0..3).map(|i| f64::from(av[i]) * f64::from(bv[i]));
+        let dot = terms.clone().sum::<f64>() as f32;
+        let max_abs_diff = terms.map(f64::abs).sum::<f64>() as f32 * 1.0e-6;
+        assert_approx_eq!(dot, a.dot(b), max_abs_diff);
+        let length = (0..3).map(|i| f64::from(av[i]).powi(2)).sum::<f64>().sqrt();
+        assert_approx_eq!(length as f32, a.length(), length as f32 * 1.0e-6);
+        let normalized = (0..3)
+            .map(|i| (f64::from(av[i]) / length) as f32)
+            .collect::<Vec<_>>();
+        let normalized = Vec3A::from_slice_unaligned(&normalized);
+        assert!(normalized.abs_diff_eq(a.normalize(), 1.0e-6));
+    }
 }
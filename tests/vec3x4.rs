@@ -0,0 +1,235 @@
+mod support;
+
+use glam::{vec3, vec3a, vec4, Mat4, Quat, Vec3, Vec3A, Vec3x4, Vec4, Vec4Mask};
+use support::deg;
+
+fn vectors() -> [Vec3; 4] {
+    [
+        vec3(1.0, 2.0, 3.0),
+        vec3(-4.0, 5.0, -6.0),
+        vec3(0.5, -0.25, 8.0),
+        vec3(0.0, 0.0, -2.0),
+    ]
+}
+
+#[test]
+fn test_vec3x4_align() {
+    use std::mem;
+    assert_eq!(48, mem::size_of::<Vec3x4>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<Vec3x4>());
+    } else {
+        assert_eq!(16, mem::align_of::<Vec3x4>());
+    }
+}
+
+#[test]
+fn test_vec3x4_new() {
+    let v = Vec3x4::from(vectors());
+    assert_eq!(vec4(1.0, -4.0, 0.5, 0.0), v.x);
+    assert_eq!(vec4(2.0, 5.0, -0.25, 0.0), v.y);
+    assert_eq!(vec4(3.0, -6.0, 8.0, -2.0), v.z);
+    assert_eq!(v, Vec3x4::new(v.x, v.y, v.z));
+    assert_eq!(vectors(), v.to_array());
+
+    let s = Vec3x4::splat(vec3(1.0, 2.0, 3.0));
+    assert_eq!(Vec4::splat(1.0), s.x);
+    assert_eq!(Vec4::splat(2.0), s.y);
+    assert_eq!(Vec4::splat(3.0), s.z);
+
+    assert_eq!(Vec3x4::splat(Vec3::zero()), Vec3x4::zero());
+    assert_eq!(Vec3x4::splat(Vec3::one()), Vec3x4::one());
+    assert_eq!(Vec3x4::zero(), Vec3x4::default());
+
+    let a: [Vec3A; 4] = v.into();
+    assert_eq!(vec3a(-4.0, 5.0, -6.0), a[1]);
+    assert_eq!(v, Vec3x4::from(a));
+
+    let w = v.extend(Vec4::one());
+    assert_eq!(vec4(3.0, -6.0, 8.0, -2.0), w.z);
+    assert_eq!(Vec4::one(), w.w);
+    assert_eq!(v, w.truncate());
+}
+
+#[test]
+fn test_vec3x4_gather_scatter() {
+    let src = [
+        vec3(1.0, 1.0, 1.0),
+        vec3(2.0, 2.0, 2.0),
+        vec3(3.0, 3.0, 3.0),
+        vec3(4.0, 4.0, 4.0),
+        vec3(5.0, 5.0, 5.0),
+    ];
+    let v = Vec3x4::gather(&src, [4, 0, 2, 2]);
+    assert_eq!(vec4(5.0, 1.0, 3.0, 3.0), v.x);
+
+    let mut dst = [Vec3::zero(); 5];
+    v.scatter(&mut dst, [0, 1, 2, 3]);
+    assert_eq!(&[src[4], src[0], src[2], src[2]], &dst[..4]);
+    assert_eq!(Vec3::zero(), dst[4]);
+
+    let v = Vec3x4::from_slice(&src[1..]);
+    assert_eq!(vec4(2.0, 3.0, 4.0, 5.0), v.y);
+    let mut dst = [Vec3::zero(); 4];
+    v.write_to_slice(&mut dst);
+    assert_eq!(&src[1..], &dst[..]);
+
+    let src_a: Vec<Vec3A> = src.iter().map(|&v| Vec3A::from(v)).collect();
+    let v = Vec3x4::gather_vec3a(&src_a, [3, 2, 1, 0]);
+    assert_eq!(vec4(4.0, 3.0, 2.0, 1.0), v.z);
+    let mut dst_a = [Vec3A::zero(); 5];
+    v.scatter_vec3a(&mut dst_a, [4, 3, 2, 1]);
+    assert_eq!(&src_a[..4], &dst_a[1..]);
+    assert_eq!(Vec3A::zero(), dst_a[0]);
+
+    let v = Vec3x4::from_slice_vec3a(&src_a);
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0), v.x);
+    let mut dst_a = [Vec3A::zero(); 4];
+    v.write_to_slice_vec3a(&mut dst_a);
+    assert_eq!(&src_a[..4], &dst_a[..]);
+}
+
+#[test]
+#[should_panic]
+fn test_vec3x4_from_slice_panics() {
+    Vec3x4::from_slice(&[Vec3::zero(); 3]);
+}
+
+#[test]
+fn test_vec3x4_ops() {
+    let a = vectors();
+    let b = [
+        vec3(2.0, 1.0, -1.0),
+        vec3(0.5, 2.0, 4.0),
+        vec3(-3.0, 1.0, 1.0),
+        vec3(1.0, 2.0, 4.0),
+    ];
+    let s = vec4(2.0, -1.0, 0.5, 4.0);
+    let va = Vec3x4::from(a);
+    let vb = Vec3x4::from(b);
+    let per_lane = |f: &dyn Fn(usize) -> Vec3| Vec3x4::from([f(0), f(1), f(2), f(3)]);
+
+    assert_eq!(per_lane(&|i| a[i] + b[i]), va + vb);
+    assert_eq!(per_lane(&|i| a[i] - b[i]), va - vb);
+    assert_eq!(per_lane(&|i| a[i] * b[i]), va * vb);
+    assert_eq!(per_lane(&|i| a[i] / b[i]), va / vb);
+    assert_eq!(per_lane(&|i| a[i] * s[i]), va * s);
+    assert_eq!(per_lane(&|i| a[i] / s[i]), va / s);
+    assert_eq!(per_lane(&|i| a[i] * 2.0), va * 2.0);
+    assert_eq!(per_lane(&|i| a[i] * 2.0), 2.0 * va);
+    assert_eq!(per_lane(&|i| a[i] / 2.0), va / 2.0);
+    assert_eq!(per_lane(&|i| -a[i]), -va);
+    assert_eq!(per_lane(&|i| a[i].min(b[i])), va.min(vb));
+    assert_eq!(per_lane(&|i| a[i].max(b[i])), va.max(vb));
+    assert_eq!(per_lane(&|i| a[i].abs()), va.abs());
+    assert_eq!(per_lane(&|i| a[i].cross(b[i])), va.cross(vb));
+    assert_approx_eq!(per_lane(&|i| a[i].lerp(b[i], s[i])), va.lerp(vb, s));
+
+    let mut v = va;
+    v += vb;
+    v -= vb;
+    v *= vb;
+    v /= vb;
+    v *= s;
+    v /= s;
+    v *= 2.0;
+    v /= 2.0;
+    assert_approx_eq!(va, v, 1e-6);
+
+    let dot = va.dot(vb);
+    let len = va.length();
+    let len_sq = va.length_squared();
+    let dist = va.distance(vb);
+    for i in 0..4 {
+        assert_eq!(a[i].dot(b[i]), dot[i]);
+        assert_approx_eq!(a[i].length(), len[i]);
+        assert_eq!(a[i].length_squared(), len_sq[i]);
+        assert_approx_eq!(a[i].distance(b[i]), dist[i]);
+    }
+    assert_eq!(len_sq, va.distance_squared(Vec3x4::zero()));
+    assert_approx_eq!(len.recip(), va.length_recip());
+}
+
+#[test]
+fn test_vec3x4_normalize() {
+    let v = Vec3x4::from(vectors());
+    let n = v.normalize();
+    assert!(n.is_normalized_mask().all());
+    for (a, b) in vectors().iter().zip(n.to_array().iter()) {
+        assert_approx_eq!(a.normalize(), *b);
+    }
+
+    let v = Vec3x4::from([
+        vec3(3.0, 0.0, 4.0),
+        Vec3::zero(),
+        vec3(f32::INFINITY, 0.0, 0.0),
+        vec3(f32::NAN, 0.0, 0.0),
+    ]);
+    assert_eq!(
+        Vec4Mask::new(false, false, false, false),
+        v.is_normalized_mask()
+    );
+    let n = v.normalize_or_zero();
+    assert_eq!(
+        [
+            vec3(0.6, 0.0, 0.8),
+            Vec3::zero(),
+            Vec3::zero(),
+            Vec3::zero()
+        ],
+        n.to_array()
+    );
+    assert_eq!(
+        Vec4Mask::new(true, false, false, false),
+        n.is_normalized_mask()
+    );
+}
+
+#[test]
+fn test_vec3x4_select() {
+    let a = Vec3x4::from(vectors());
+    let b = Vec3x4::splat(Vec3::one());
+    let mask = a.length_squared().cmpgt(Vec4::splat(20.0));
+    assert_eq!(Vec4Mask::new(false, true, true, false), mask);
+    let v = Vec3x4::select(mask, a, b);
+    let e = vectors();
+    assert_eq!([Vec3::one(), e[1], e[2], Vec3::one()], v.to_array());
+    assert_eq!(mask, v.cmpeq(a));
+    assert_eq!(!mask, v.cmpeq(b));
+}
+
+#[test]
+fn test_vec3x4_transform() {
+    let a = vectors();
+    let v = Vec3x4::from(a);
+
+    let q = Quat::from_axis_angle(vec3(1.0, 2.0, 3.0).normalize(), deg(70.0));
+    let r = q * v;
+    assert_approx_eq!(r, q.mul_vec3x4(v));
+    for (p, rp) in a.iter().zip(r.to_array().iter()) {
+        assert_approx_eq!(q * *p, *rp, 1e-5);
+    }
+
+    let m = Mat4::from_scale_rotation_translation(vec3(1.0, 2.0, 0.5), q, vec3(1.0, -2.0, 3.0));
+    let p = m.transform_point3x4(v).to_array();
+    let d = m.transform_vector3x4(v).to_array();
+    for i in 0..4 {
+        assert_approx_eq!(m.transform_point3(a[i]), p[i], 1e-5);
+        assert_approx_eq!(m.transform_vector3(a[i]), d[i], 1e-5);
+    }
+
+    let proj = Mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+    let p = proj.transform_point3x4(v).to_array();
+    for i in 0..3 {
+        assert_approx_eq!(proj.transform_point3(a[i]), p[i], 1e-5);
+    }
+}
+
+#[test]
+fn test_vec3x4_fmt() {
+    let v = Vec3x4::from(vectors());
+    assert_eq!(
+        format!("{}", v),
+        "[[1, 2, 3], [-4, 5, -6], [0.5, -0.25, 8], [0, 0, -2]]"
+    );
+}
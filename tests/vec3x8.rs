@@ -0,0 +1,265 @@
+mod support;
+
+use glam::{vec3, vec3a, vec4, Mat4, Quat, Vec3, Vec3A, Vec3x4, Vec3x8, Vec4, Vec4Mask};
+use support::deg;
+
+fn vectors() -> [Vec3; 8] {
+    [
+        vec3(1.0, 2.0, 3.0),
+        vec3(-4.0, 5.0, -6.0),
+        vec3(0.5, -0.25, 8.0),
+        vec3(0.0, 0.0, -2.0),
+        vec3(7.0, -1.5, 0.25),
+        vec3(-0.75, -3.0, 2.0),
+        vec3(1.0, 1.0, -1.0),
+        vec3(9.0, 0.125, -5.5),
+    ]
+}
+
+fn halves(a: [Vec3; 8]) -> [Vec3x4; 2] {
+    [
+        Vec3x4::from([a[0], a[1], a[2], a[3]]),
+        Vec3x4::from([a[4], a[5], a[6], a[7]]),
+    ]
+}
+
+#[test]
+fn test_vec3x8_align() {
+    use std::mem;
+    assert_eq!(96, mem::size_of::<Vec3x8>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<Vec3x8>());
+    } else if cfg!(target_feature = "avx") {
+        assert_eq!(32, mem::align_of::<Vec3x8>());
+    } else {
+        assert_eq!(16, mem::align_of::<Vec3x8>());
+    }
+}
+
+#[test]
+fn test_vec3x8_new() {
+    let v = Vec3x8::from(vectors());
+    let [lo, hi] = halves(vectors());
+    assert_eq!([lo.x, hi.x], v.x());
+    assert_eq!([lo.y, hi.y], v.y());
+    assert_eq!([lo.z, hi.z], v.z());
+    assert_eq!(vec4(7.0, -0.75, 1.0, 9.0), v.x()[1]);
+    assert_eq!(v, Vec3x8::new(v.x(), v.y(), v.z()));
+    assert_eq!(v, Vec3x8::from_halves([lo, hi]));
+    assert_eq!([lo, hi], v.to_halves());
+    assert_eq!(vectors(), v.to_array());
+
+    let s = Vec3x8::splat(vec3(1.0, 2.0, 3.0));
+    assert_eq!([Vec4::splat(1.0); 2], s.x());
+    assert_eq!([Vec4::splat(2.0); 2], s.y());
+    assert_eq!([Vec4::splat(3.0); 2], s.z());
+
+    assert_eq!(Vec3x8::splat(Vec3::zero()), Vec3x8::zero());
+    assert_eq!(Vec3x8::splat(Vec3::one()), Vec3x8::one());
+    assert_eq!(Vec3x8::zero(), Vec3x8::default());
+    assert_ne!(Vec3x8::zero(), Vec3x8::one());
+
+    let a: [Vec3A; 8] = v.into();
+    assert_eq!(vec3a(-0.75, -3.0, 2.0), a[5]);
+    assert_eq!(v, Vec3x8::from(a));
+
+    let h: [Vec3x4; 2] = v.into();
+    assert_eq!(v, Vec3x8::from(h));
+}
+
+#[test]
+fn test_vec3x8_gather_scatter() {
+    let src = vectors();
+    let v = Vec3x8::gather(&src, [7, 0, 2, 2, 1, 6, 5, 3]);
+    assert_eq!(
+        [vec4(9.0, 1.0, 0.5, 0.5), vec4(-4.0, 1.0, -0.75, 0.0)],
+        v.x()
+    );
+
+    let mut dst = [Vec3::zero(); 9];
+    v.scatter(&mut dst, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.to_array(), &dst[1..]);
+    assert_eq!(Vec3::zero(), dst[0]);
+
+    let v = Vec3x8::from_slice(&dst[1..]);
+    let mut dst2 = [Vec3::zero(); 8];
+    v.write_to_slice(&mut dst2);
+    assert_eq!(&dst[1..], &dst2[..]);
+
+    let src_a: Vec<Vec3A> = src.iter().map(|&v| Vec3A::from(v)).collect();
+    let v = Vec3x8::gather_vec3a(&src_a, [7, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(
+        [vec4(-5.5, -1.0, 2.0, 0.25), vec4(-2.0, 8.0, -6.0, 3.0)],
+        v.z()
+    );
+    let mut dst_a = [Vec3A::zero(); 9];
+    v.scatter_vec3a(&mut dst_a, [8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(&src_a[..], &dst_a[1..]);
+    assert_eq!(Vec3A::zero(), dst_a[0]);
+
+    let v = Vec3x8::from_slice_vec3a(&src_a);
+    assert_eq!(Vec3x8::from(src), v);
+    let mut dst_a = [Vec3A::zero(); 8];
+    v.write_to_slice_vec3a(&mut dst_a);
+    assert_eq!(&src_a[..], &dst_a[..]);
+}
+
+#[test]
+#[should_panic]
+fn test_vec3x8_from_slice_panics() {
+    Vec3x8::from_slice(&[Vec3::zero(); 7]);
+}
+
+#[test]
+fn test_vec3x8_ops() {
+    // each operation gives the same result as performing it on both `Vec3x4` halves
+    let va = Vec3x8::from(vectors());
+    let vb = Vec3x8::from([
+        vec3(2.0, 1.0, -1.0),
+        vec3(0.5, 2.0, 4.0),
+        vec3(-3.0, 1.0, 1.0),
+        vec3(1.0, 2.0, 4.0),
+        vec3(-1.0, 0.5, 3.0),
+        vec3(0.25, -2.0, 8.0),
+        vec3(4.0, 4.0, -4.0),
+        vec3(1.5, 6.0, 0.5),
+    ]);
+    let s = [vec4(2.0, -1.0, 0.5, 4.0), vec4(-0.5, 3.0, 1.0, 0.25)];
+    let [a0, a1] = va.to_halves();
+    let [b0, b1] = vb.to_halves();
+    let per_half = |f: &dyn Fn(Vec3x4, Vec3x4, Vec4) -> Vec3x4| {
+        Vec3x8::from_halves([f(a0, b0, s[0]), f(a1, b1, s[1])])
+    };
+
+    assert_eq!(per_half(&|a, b, _| a + b), va + vb);
+    assert_eq!(per_half(&|a, b, _| a - b), va - vb);
+    assert_eq!(per_half(&|a, b, _| a * b), va * vb);
+    assert_eq!(per_half(&|a, b, _| a / b), va / vb);
+    assert_eq!(per_half(&|a, _, s| a * s), va * s);
+    assert_eq!(per_half(&|a, _, s| a / s), va / s);
+    assert_eq!(per_half(&|a, _, _| a * 2.0), va * 2.0);
+    assert_eq!(per_half(&|a, _, _| a * 2.0), 2.0 * va);
+    assert_eq!(per_half(&|a, _, _| a / 2.0), va / 2.0);
+    assert_eq!(per_half(&|a, _, _| -a), -va);
+    assert_eq!(per_half(&|a, b, _| a.min(b)), va.min(vb));
+    assert_eq!(per_half(&|a, b, _| a.max(b)), va.max(vb));
+    assert_eq!(per_half(&|a, _, _| a.abs()), va.abs());
+    assert_eq!(per_half(&|a, b, _| a.cross(b)), va.cross(vb));
+    assert_eq!(per_half(&|a, b, s| a.lerp(b, s)), va.lerp(vb, s));
+    assert_eq!(per_half(&|a, _, _| a.normalize()), va.normalize());
+
+    let mut v = va;
+    v += vb;
+    v -= vb;
+    v *= vb;
+    v /= vb;
+    v *= s;
+    v /= s;
+    v *= 2.0;
+    v /= 2.0;
+    assert_approx_eq!(va, v, 1e-6);
+
+    assert_eq!([a0.dot(b0), a1.dot(b1)], va.dot(vb));
+    assert_eq!([a0.length(), a1.length()], va.length());
+    assert_eq!(
+        [a0.length_squared(), a1.length_squared()],
+        va.length_squared()
+    );
+    assert_eq!([a0.length_recip(), a1.length_recip()], va.length_recip());
+    assert_eq!([a0.distance(b0), a1.distance(b1)], va.distance(vb));
+    assert_eq!(
+        [a0.distance_squared(b0), a1.distance_squared(b1)],
+        va.distance_squared(vb)
+    );
+}
+
+#[test]
+fn test_vec3x8_normalize() {
+    let v = Vec3x8::from(vectors());
+    let n = v.normalize();
+    let [lo, hi] = n.is_normalized_mask();
+    assert!(lo.all() && hi.all());
+    for (a, b) in vectors().iter().zip(n.to_array().iter()) {
+        assert_approx_eq!(a.normalize(), *b);
+    }
+
+    let v = Vec3x8::from([
+        vec3(3.0, 0.0, 4.0),
+        Vec3::zero(),
+        vec3(f32::INFINITY, 0.0, 0.0),
+        vec3(f32::NAN, 0.0, 0.0),
+        vec3(0.0, -2.0, 0.0),
+        vec3(1e-30, 0.0, 0.0),
+        vec3(0.0, 0.0, f32::NEG_INFINITY),
+        vec3(1.0, 1.0, 1.0),
+    ]);
+    let [lo, hi] = v.to_halves();
+    let n = v.normalize_or_zero();
+    assert_eq!(
+        Vec3x8::from_halves([lo.normalize_or_zero(), hi.normalize_or_zero()]),
+        n
+    );
+    assert_eq!(
+        [
+            Vec4Mask::new(true, false, false, false),
+            Vec4Mask::new(true, false, false, true)
+        ],
+        n.is_normalized_mask()
+    );
+}
+
+#[test]
+fn test_vec3x8_select() {
+    let a = Vec3x8::from(vectors());
+    let b = Vec3x8::splat(Vec3::one());
+    let [l0, l1] = a.length_squared();
+    let mask = [l0.cmpgt(Vec4::splat(20.0)), l1.cmpgt(Vec4::splat(20.0))];
+    assert_eq!(
+        [
+            Vec4Mask::new(false, true, true, false),
+            Vec4Mask::new(true, false, false, true)
+        ],
+        mask
+    );
+    let v = Vec3x8::select(mask, a, b);
+    let e = vectors();
+    let one = Vec3::one();
+    assert_eq!([one, e[1], e[2], one, e[4], one, one, e[7]], v.to_array());
+    assert_eq!(mask, v.cmpeq(a));
+    assert_eq!([!mask[0], !mask[1]], v.cmpeq(b));
+}
+
+#[test]
+fn test_vec3x8_transform() {
+    let v = Vec3x8::from(vectors());
+    let [lo, hi] = v.to_halves();
+
+    let q = Quat::from_axis_angle(vec3(1.0, 2.0, 3.0).normalize(), deg(70.0));
+    assert_eq!(Vec3x8::from_halves([q * lo, q * hi]), q * v);
+    assert_eq!(q * v, q.mul_vec3x8(v));
+
+    let m = Mat4::from_scale_rotation_translation(vec3(1.0, 2.0, 0.5), q, vec3(1.0, -2.0, 3.0));
+    let proj = Mat4::perspective_rh(deg(60.0), 1.5, 0.1, 100.0);
+    for m in [m, proj].iter() {
+        assert_eq!(
+            Vec3x8::from_halves([m.transform_point3x4(lo), m.transform_point3x4(hi)]),
+            m.transform_point3x8(v)
+        );
+        assert_eq!(
+            Vec3x8::from_halves([m.transform_vector3x4(lo), m.transform_vector3x4(hi)]),
+            m.transform_vector3x8(v)
+        );
+    }
+}
+
+#[test]
+fn test_vec3x8_fmt() {
+    let v = Vec3x8::from(vectors());
+    assert_eq!(
+        format!("{}", v),
+        "[[1, 2, 3], [-4, 5, -6], [0.5, -0.25, 8], [0, 0, -2], [7, -1.5, 0.25], \
+         [-0.75, -3, 2], [1, 1, -1], [9, 0.125, -5.5]]"
+    );
+    let [lo, hi] = v.to_halves();
+    assert_eq!(format!("{:?}", v), format!("Vec3x8({:?}, {:?})", lo, hi));
+}
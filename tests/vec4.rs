@@ -593,6 +593,49 @@ fn test_vec4_m128() {
     assert_eq!([0xffffffff, 0, 0xffffffff, 0], a0.0);
 }
 
+#[cfg(vec4_neon)]
+#[test]
+fn test_vec4_neon() {
+    use core::arch::aarch64::*;
+
+    let v0 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let m0: float32x4_t = v0.into();
+    let mut a0 = [0.0, 0.0, 0.0, 0.0];
+    unsafe {
+        vst1q_f32(a0.as_mut_ptr(), m0);
+    }
+    assert_eq!([1.0, 2.0, 3.0, 4.0], a0);
+    let v1 = Vec4::from(m0);
+    assert_eq!(v0, v1);
+
+    let v0 = Vec4Mask::new(true, false, true, false);
+    let m0: uint32x4_t = v0.into();
+    let mut a0 = [1, 2, 3, 4];
+    unsafe {
+        vst1q_u32(a0.as_mut_ptr(), m0);
+    }
+    assert_eq!([0xffffffff, 0, 0xffffffff, 0], a0);
+    assert_eq!(v0, Vec4Mask::from(m0));
+}
+
+#[cfg(vec4_simd128)]
+#[test]
+fn test_vec4_v128() {
+    use core::arch::wasm32::*;
+
+    let v0 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let m0: v128 = v0.into();
+    assert_eq!(3.0, f32x4_extract_lane::<2>(m0));
+    let v1 = Vec4::from(m0);
+    assert_eq!(v0, v1);
+
+    let v0 = Vec4Mask::new(true, false, true, false);
+    let m0: v128 = v0.into();
+    assert_eq!(0xffffffff, u32x4_extract_lane::<0>(m0));
+    assert_eq!(0, u32x4_extract_lane::<1>(m0));
+    assert_eq!(v0, Vec4Mask::from(m0));
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_vec4_serde() {
@@ -662,8 +705,160 @@ fn test_powf() {
 
 #[test]
 fn test_exp() {
-    assert_eq!(
+    assert_approx_eq!(
         Vec4::new(1.0, 2.0, 3.0, 4.0).exp(),
-        Vec4::new(1.0_f32.exp(), 2.0_f32.exp(), 3.0_f32.exp(), 4.0_f32.exp())
+        Vec4::new(1.0_f32.exp(), 2.0_f32.exp(), 3.0_f32.exp(), 4.0_f32.exp()),
+        1.0e-5
+    );
+}
+
+#[test]
+fn test_vec4_transcendentals() {
+    // The SSE2 versions use polynomial approximations, they are compared against `f64` results
+    // using the maximum error in ULP given in their documentation. Special values must match.
+    fn check(expected: f64, actual: f32, max_ulps: f64) {
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "expected NaN, got {}", actual);
+        } else if (expected as f32).is_infinite() {
+            assert_eq!(expected as f32, actual);
+        } else if expected.abs() < f64::from(std::f32::MIN_POSITIVE) {
+            // results that underflow may be flushed to zero
+            assert!(
+                actual.abs() <= std::f32::MIN_POSITIVE,
+                "expected {}, got {}",
+                expected,
+                actual
+            );
+        } else {
+            let ulps = support::ulp_error(expected, actual);
+            assert!(
+                ulps <= max_ulps,
+                "expected {}, got {} ({} ULP)",
+                expected,
+                actual,
+                ulps
+            );
+        }
+    }
+    let specials = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        std::f32::INFINITY,
+        std::f32::NEG_INFINITY,
+        std::f32::NAN,
+        std::f32::MIN_POSITIVE,
+        std::f32::MAX,
+        -std::f32::MAX,
+    ];
+    let mut values = specials.to_vec();
+    for i in -200..200 {
+        values.push(i as f32 * 0.123);
+        values.push(i as f32 * 41.7);
+    }
+    for i in -37..38 {
+        values.push(1.37 * 10.0_f32.powi(i));
+    }
+    values.extend(support::random_f32s(4096, 100.0));
+    values.extend(support::random_f32s(4096, 16384.0));
+    for v in values.chunks(4).filter(|v| v.len() == 4) {
+        let a = Vec4::new(v[0], v[1], v[2], v[3]);
+        let b = Vec4::new(v[1], v[2], v[3], v[0]);
+        let (sin, cos) = a.sin_cos();
+        for i in 0..4 {
+            let x = f64::from(a[i]);
+            check(x.sqrt(), a.sqrt()[i], 0.5);
+            check(x.sin(), a.sin()[i], 2.0);
+            check(x.cos(), a.cos()[i], 2.0);
+            check(x.sin(), sin[i], 2.0);
+            check(x.cos(), cos[i], 2.0);
+            check(x.exp(), a.exp()[i], 2.0);
+            check(x.ln(), a.ln()[i], 1.0);
+            check(x.log2(), a.log2()[i], 2.0);
+            check(x.atan2(f64::from(b[i])), a.atan2(b)[i], 4.0);
+            let t = x.tan();
+            // tan is ill-conditioned next to its poles
+            if t.abs() < 1.0e4 {
+                check(t, a.tan()[i], 4.0);
+            }
+        }
+        let c = a * 0.01;
+        for i in 0..4 {
+            let x = f64::from(c[i]);
+            check(x.asin(), c.asin()[i], 3.0);
+            check(x.acos(), c.acos()[i], 2.0);
+        }
+    }
+}
+
+#[test]
+fn test_vec4_try_normalize() {
+    assert_approx_eq!(
+        Vec4::unit_x(),
+        (Vec4::unit_x() * 3.0).try_normalize().unwrap()
     );
+    assert_approx_eq!(
+        Vec4::splat(-1.0).normalize(),
+        Vec4::splat(-1.0).normalize_or_zero()
+    );
+    for &v in [
+        Vec4::zero(),
+        Vec4::splat(1.0e-30),
+        Vec4::splat(std::f32::MAX),
+        Vec4::splat(std::f32::NAN),
+        Vec4::unit_x() * std::f32::INFINITY,
+    ]
+    .iter()
+    {
+        assert_eq!(None, v.try_normalize());
+        assert_eq!(Vec4::zero(), v.normalize_or_zero());
+    }
+}
+
+#[test]
+fn test_vec4_matches_scalar() {
+    // Checks the optional SSE4.1 and FMA code paths selected by `build.rs` against scalar
+    // results. Half-way cases are avoided as SSE2 and SSE4.1 round them to even.
+    let mut values = vec![
+        -8388609.0, -3.7, -2.25, -1.3, -0.6, 0.0, 0.4, 1.75, 2.6, 8.2, 8388609.0,
+    ];
+    values.extend(
+        support::random_f32s(64, 1000.0)
+            .into_iter()
+            .filter(|v| v.abs().fract() != 0.5),
+    );
+    for v in values.windows(4) {
+        let a = Vec4::new(v[0], v[1], v[2], v[3]);
+        assert_eq!(
+            Vec4::new(a.x.round(), a.y.round(), a.z.round(), a.w.round()),
+            a.round()
+        );
+        assert_eq!(
+            Vec4::new(a.x.floor(), a.y.floor(), a.z.floor(), a.w.floor()),
+            a.floor()
+        );
+        assert_eq!(
+            Vec4::new(a.x.ceil(), a.y.ceil(), a.z.ceil(), a.w.ceil()),
+            a.ceil()
+        );
+    }
+    // the reference results are computed in `f64`, the tolerances are relative to the
+    // magnitude of the terms
+    for v in values[1..].windows(8) {
+        let a = Vec4::new(v[0], v[1], v[2], v[3]);
+        let b = Vec4::new(v[4], v[5], v[6], v[7]);
+        let (av, bv) = (a.as_ref(), b.as_ref());
+        let terms = (0..4).map(|i| f64::from(av[i]) * f64::from(bv[i]));
+        let dot = terms.clone().sum::<f64>() as f32;
+        let max_abs_diff = terms.map(f64::abs).sum::<f64>() as f32 * 1.0e-6;
+        assert_approx_eq!(dot, a.dot(b), max_abs_diff);
+        let length = (0..4).map(|i| f64::from(av[i]).powi(2)).sum::<f64>().sqrt();
+        assert_approx_eq!(length as f32, a.length(), length as f32 * 1.0e-6);
+        let normalized = (0..4)
+            .map(|i| (f64::from(av[i]) / length) as f32)
+            .collect::<Vec<_>>();
+        let normalized = Vec4::from_slice_unaligned(&normalized);
+        assert!(normalized.abs_diff_eq(a.normalize(), 1.0e-6));
+    }
 }
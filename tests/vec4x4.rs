@@ -0,0 +1,154 @@
+mod support;
+
+use glam::{vec4, Mat4, Quat, Vec3, Vec4, Vec4Mask, Vec4x4};
+use support::deg;
+
+fn vectors() -> [Vec4; 4] {
+    [
+        vec4(1.0, 2.0, 3.0, 4.0),
+        vec4(-4.0, 5.0, -6.0, 7.0),
+        vec4(0.5, -0.25, 8.0, -1.0),
+        vec4(0.0, 0.0, -2.0, 0.0),
+    ]
+}
+
+#[test]
+fn test_vec4x4_align() {
+    use std::mem;
+    assert_eq!(64, mem::size_of::<Vec4x4>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<Vec4x4>());
+    } else {
+        assert_eq!(16, mem::align_of::<Vec4x4>());
+    }
+}
+
+#[test]
+fn test_vec4x4_new() {
+    let v = Vec4x4::from(vectors());
+    assert_eq!(vec4(1.0, -4.0, 0.5, 0.0), v.x);
+    assert_eq!(vec4(2.0, 5.0, -0.25, 0.0), v.y);
+    assert_eq!(vec4(3.0, -6.0, 8.0, -2.0), v.z);
+    assert_eq!(vec4(4.0, 7.0, -1.0, 0.0), v.w);
+    assert_eq!(v, Vec4x4::new(v.x, v.y, v.z, v.w));
+    let a: [Vec4; 4] = v.into();
+    assert_eq!(vectors(), a);
+
+    let s = Vec4x4::splat(vec4(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Vec4::splat(1.0), s.x);
+    assert_eq!(Vec4::splat(2.0), s.y);
+    assert_eq!(Vec4::splat(3.0), s.z);
+    assert_eq!(Vec4::splat(4.0), s.w);
+
+    assert_eq!(Vec4x4::splat(Vec4::zero()), Vec4x4::zero());
+    assert_eq!(Vec4x4::splat(Vec4::one()), Vec4x4::one());
+    assert_eq!(Vec4x4::zero(), Vec4x4::default());
+}
+
+#[test]
+fn test_vec4x4_gather_scatter() {
+    let src = [
+        Vec4::splat(1.0),
+        Vec4::splat(2.0),
+        Vec4::splat(3.0),
+        Vec4::splat(4.0),
+        Vec4::splat(5.0),
+    ];
+    let v = Vec4x4::gather(&src, [4, 0, 2, 2]);
+    assert_eq!(vec4(5.0, 1.0, 3.0, 3.0), v.w);
+
+    let mut dst = [Vec4::zero(); 5];
+    v.scatter(&mut dst, [1, 2, 3, 4]);
+    assert_eq!(Vec4::zero(), dst[0]);
+    assert_eq!(&[src[4], src[0], src[2], src[2]], &dst[1..]);
+
+    let v = Vec4x4::from_slice(&src[1..]);
+    assert_eq!(vec4(2.0, 3.0, 4.0, 5.0), v.x);
+    let mut dst = [Vec4::zero(); 4];
+    v.write_to_slice(&mut dst);
+    assert_eq!(&src[1..], &dst[..]);
+}
+
+#[test]
+fn test_vec4x4_ops() {
+    let a = vectors();
+    let b = [
+        vec4(2.0, 1.0, -1.0, 0.5),
+        vec4(0.5, 2.0, 4.0, -8.0),
+        vec4(-3.0, 1.0, 1.0, 2.0),
+        vec4(1.0, 2.0, 4.0, 1.0),
+    ];
+    let s = vec4(2.0, -1.0, 0.5, 4.0);
+    let va = Vec4x4::from(a);
+    let vb = Vec4x4::from(b);
+    let per_lane = |f: &dyn Fn(usize) -> Vec4| Vec4x4::from([f(0), f(1), f(2), f(3)]);
+
+    assert_eq!(per_lane(&|i| a[i] + b[i]), va + vb);
+    assert_eq!(per_lane(&|i| a[i] - b[i]), va - vb);
+    assert_eq!(per_lane(&|i| a[i] * b[i]), va * vb);
+    assert_eq!(per_lane(&|i| a[i] / b[i]), va / vb);
+    assert_eq!(per_lane(&|i| a[i] * s[i]), va * s);
+    assert_eq!(per_lane(&|i| a[i] / s[i]), va / s);
+    assert_eq!(per_lane(&|i| a[i] * 2.0), va * 2.0);
+    assert_eq!(per_lane(&|i| a[i] * 2.0), 2.0 * va);
+    assert_eq!(per_lane(&|i| a[i] / 2.0), va / 2.0);
+    assert_eq!(per_lane(&|i| -a[i]), -va);
+    assert_eq!(per_lane(&|i| a[i].min(b[i])), va.min(vb));
+    assert_eq!(per_lane(&|i| a[i].max(b[i])), va.max(vb));
+    assert_eq!(per_lane(&|i| a[i].abs()), va.abs());
+    assert_approx_eq!(per_lane(&|i| a[i].lerp(b[i], s[i])), va.lerp(vb, s));
+
+    let mut v = va;
+    v += vb;
+    v -= vb;
+    v *= vb;
+    v /= vb;
+    v *= s;
+    v /= s;
+    v *= 2.0;
+    v /= 2.0;
+    assert_approx_eq!(va, v, 1e-6);
+
+    let dot = va.dot(vb);
+    let len = va.length();
+    let dist = va.distance(vb);
+    for i in 0..4 {
+        assert_approx_eq!(a[i].dot(b[i]), dot[i]);
+        assert_approx_eq!(a[i].length(), len[i]);
+        assert_approx_eq!(a[i].distance(b[i]), dist[i]);
+    }
+    assert_eq!(va.length_squared(), va.distance_squared(Vec4x4::zero()));
+    assert_approx_eq!(len.recip(), va.length_recip());
+
+    let n = va.normalize();
+    assert!(n.is_normalized_mask().all());
+    assert_eq!(n, va.normalize_or_zero());
+    let v = Vec4x4::from([a[0], Vec4::zero(), a[2], Vec4::splat(f32::NAN)]);
+    let n = v.normalize_or_zero().to_array();
+    assert_approx_eq!(a[0].normalize(), n[0]);
+    assert_eq!(Vec4::zero(), n[1]);
+    assert_approx_eq!(a[2].normalize(), n[2]);
+    assert_eq!(Vec4::zero(), n[3]);
+
+    let mask = Vec4Mask::new(true, false, false, true);
+    let v = Vec4x4::select(mask, va, vb);
+    assert_eq!([a[0], b[1], b[2], a[3]], v.to_array());
+    assert_eq!(mask, v.cmpeq(va));
+}
+
+#[test]
+fn test_vec4x4_transform() {
+    let a = vectors();
+    let v = Vec4x4::from(a);
+    let q = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), deg(70.0));
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.0, 2.0, 0.5),
+        q,
+        Vec3::new(1.0, -2.0, 3.0),
+    );
+    let r = m * v;
+    assert_eq!(r, m.mul_vec4x4(v));
+    for (p, rp) in a.iter().zip(r.to_array().iter()) {
+        assert_approx_eq!(m * *p, *rp, 1e-5);
+    }
+}